@@ -4760,12 +4760,10 @@ fn shop_input_system(world: &mut GameWorld) {
                         pattern,
                         rarity,
                         cost,
-                    } => {
-                        if world.resources.economy.money >= *cost {
-                            world.resources.economy.money -= cost;
-                            create_card(world, name, pattern.clone(), *rarity);
-                            world.resources.meta_game.shop_offerings.remove(index);
-                        }
+                    } if world.resources.economy.money >= *cost => {
+                        world.resources.economy.money -= cost;
+                        create_card(world, name, pattern.clone(), *rarity);
+                        world.resources.meta_game.shop_offerings.remove(index);
                     }
                     ShopOffering::Relic { relic_type, cost }
                         if world.resources.economy.money >= *cost =>
@@ -5610,40 +5608,38 @@ fn render_ui(world: &GameWorld) {
     }
 
     match world.resources.combat.game_state {
-        GameState::WaitingForWave => {
-            if world.resources.combat.wave == 0 {
-                let button_width = 300.0;
-                let button_height = 80.0;
-                let button_x = (screen_width() - button_width) / 2.0;
-                let button_y = screen_height() / 2.0 - button_height / 2.0;
-
-                draw_rectangle(
-                    button_x,
-                    button_y,
-                    button_width,
-                    button_height,
-                    Color::new(0.2, 0.6, 0.3, 1.0),
-                );
-                draw_rectangle_lines(
-                    button_x,
-                    button_y,
-                    button_width,
-                    button_height,
-                    3.0,
-                    Color::new(0.3, 0.9, 0.4, 1.0),
-                );
+        GameState::WaitingForWave if world.resources.combat.wave == 0 => {
+            let button_width = 300.0;
+            let button_height = 80.0;
+            let button_x = (screen_width() - button_width) / 2.0;
+            let button_y = screen_height() / 2.0 - button_height / 2.0;
 
-                let text = "START WAVE";
-                let text_size = 32.0;
-                let text_dims = measure_text(text, None, text_size as u16, 1.0);
-                draw_text(
-                    text,
-                    button_x + (button_width - text_dims.width) / 2.0,
-                    button_y + (button_height + text_size) / 2.0 - 5.0,
-                    text_size,
-                    WHITE,
-                );
-            }
+            draw_rectangle(
+                button_x,
+                button_y,
+                button_width,
+                button_height,
+                Color::new(0.2, 0.6, 0.3, 1.0),
+            );
+            draw_rectangle_lines(
+                button_x,
+                button_y,
+                button_width,
+                button_height,
+                3.0,
+                Color::new(0.3, 0.9, 0.4, 1.0),
+            );
+
+            let text = "START WAVE";
+            let text_size = 32.0;
+            let text_dims = measure_text(text, None, text_size as u16, 1.0);
+            draw_text(
+                text,
+                button_x + (button_width - text_dims.width) / 2.0,
+                button_y + (button_height + text_size) / 2.0 - 5.0,
+                text_size,
+                WHITE,
+            );
         }
         GameState::Paused => {
             let text = "PAUSED - Press P to resume";
@@ -0,0 +1,346 @@
+//! Run conditions for gating systems and stages: functions that decide, each
+//! pass, whether a system or a whole [`Stages`](crate::Stages) stage runs.
+//!
+//! A condition is anything [`IntoCondition`] accepts: a closure over the
+//! world (`|world: &DynWorld| ...` or `|world: &mut DynWorld| ...`), or a
+//! function whose parameters are extract system parameters and which returns
+//! `bool`, such as `fn(Res<Paused>) -> bool`. Parameter conditions resolve
+//! through the same [`ExtractParam`] machinery as systems, so a
+//! [`Local`](crate::system_param::Local) or an
+//! [`EventReader`](crate::system_param::EventReader) in a condition keeps its
+//! own state between passes. [`and`], [`or`], and [`not`] compose conditions
+//! into new ones, and [`resource_exists`], [`resource_changed`],
+//! [`on_event`], [`any_with_component`], and [`every_n_runs`] cover the
+//! common cases.
+//!
+//! Gate a param system with
+//! [`ScheduleExt::add_system_if`](crate::system_param::ScheduleExt::add_system_if)
+//! and a stage with [`StagesExt::stage_run_if`]:
+//!
+//! ```rust
+//! use freecs::{Schedule, Stages};
+//! use freecs::condition::{StagesExt, not, resource_exists};
+//! use freecs::dynamic::DynWorld;
+//! use freecs::system_param::{Res, ResMut, ScheduleExt};
+//!
+//! struct Paused;
+//! struct Frames(u32);
+//!
+//! fn count(mut frames: ResMut<Frames>) {
+//!     frames.0 += 1;
+//! }
+//!
+//! let mut world = DynWorld::new();
+//! world.insert_resource(Frames(0));
+//!
+//! let mut stages = Stages::new();
+//! stages.add_stage("simulation");
+//! stages.stage_mut("simulation").add_system("count", count);
+//! stages.stage_run_if("simulation", not(resource_exists::<Paused, _>()));
+//!
+//! stages.run(&mut world);
+//! world.insert_resource(Paused);
+//! stages.run(&mut world);
+//! assert_eq!(world.resource::<Frames>().unwrap().0, 1);
+//! ```
+
+use crate::Stages;
+use crate::dynamic::{ComponentData, DynWorld, ResourceHost};
+use crate::system_param::{EventHost, ExtractParam};
+use std::marker::PhantomData;
+
+/// Converts a condition into the closure a gate evaluates each pass.
+/// `Marker` is inferred from the condition's shape.
+pub trait IntoCondition<W, Marker>: Sized {
+    /// Lowers the condition to a closure over the host.
+    fn into_condition(self) -> impl FnMut(&mut W) -> bool + Send + 'static;
+}
+
+/// The marker for a condition that reads the world, `Fn(&W) -> bool`.
+pub struct ReadCondition;
+
+/// The marker for a condition over the mutable world, `FnMut(&mut W) -> bool`,
+/// the shape the combinators and built-ins return.
+pub struct HostCondition;
+
+/// The marker for a condition whose parameters are extract parameters.
+pub struct ParamCondition<P>(PhantomData<fn() -> P>);
+
+impl<W, F> IntoCondition<W, ReadCondition> for F
+where
+    F: Fn(&W) -> bool + Send + 'static,
+{
+    fn into_condition(self) -> impl FnMut(&mut W) -> bool + Send + 'static {
+        move |world: &mut W| self(world)
+    }
+}
+
+impl<W, F> IntoCondition<W, HostCondition> for F
+where
+    F: FnMut(&mut W) -> bool + Send + 'static,
+{
+    fn into_condition(self) -> impl FnMut(&mut W) -> bool + Send + 'static {
+        self
+    }
+}
+
+macro_rules! impl_param_condition {
+    ($($param:ident $state:ident $owned:ident),+) => {
+        impl<W, Func, $($param,)+> IntoCondition<W, ParamCondition<($($param,)+)>> for Func
+        where
+            W: ResourceHost + 'static,
+            $($param: ExtractParam<W>,)+
+            Func: FnMut($($param,)+) -> bool
+                + for<'item> FnMut($($param::Item<'item>,)+) -> bool
+                + Send
+                + 'static,
+        {
+            fn into_condition(mut self) -> impl FnMut(&mut W) -> bool + Send + 'static {
+                $(let mut $state = <$param as ExtractParam<W>>::init();)+
+                move |host: &mut W| {
+                    $(let mut $owned = <$param as ExtractParam<W>>::extract(&mut $state, host);)+
+                    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                        self($(<$param as ExtractParam<W>>::build(&mut $owned),)+)
+                    }));
+                    $(<$param as ExtractParam<W>>::apply(&mut $state, $owned, host);)+
+                    match result {
+                        Ok(decision) => decision,
+                        Err(panic) => std::panic::resume_unwind(panic),
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_param_condition!(P0 state0 owned0);
+impl_param_condition!(P0 state0 owned0, P1 state1 owned1);
+impl_param_condition!(P0 state0 owned0, P1 state1 owned1, P2 state2 owned2);
+impl_param_condition!(P0 state0 owned0, P1 state1 owned1, P2 state2 owned2, P3 state3 owned3);
+
+/// Holds when both conditions hold. `second` is not evaluated on a pass
+/// where `first` is false, so a stateful condition such as [`on_event`]
+/// placed second only advances on passes it is asked.
+pub fn and<W, A, B>(
+    first: impl IntoCondition<W, A>,
+    second: impl IntoCondition<W, B>,
+) -> impl FnMut(&mut W) -> bool + Send + 'static {
+    let mut first = first.into_condition();
+    let mut second = second.into_condition();
+    move |world: &mut W| first(world) && second(world)
+}
+
+/// Holds when either condition holds. `second` is not evaluated on a pass
+/// where `first` is true.
+pub fn or<W, A, B>(
+    first: impl IntoCondition<W, A>,
+    second: impl IntoCondition<W, B>,
+) -> impl FnMut(&mut W) -> bool + Send + 'static {
+    let mut first = first.into_condition();
+    let mut second = second.into_condition();
+    move |world: &mut W| first(world) || second(world)
+}
+
+/// Holds when `condition` does not.
+pub fn not<W, M>(
+    condition: impl IntoCondition<W, M>,
+) -> impl FnMut(&mut W) -> bool + Send + 'static {
+    let mut condition = condition.into_condition();
+    move |world: &mut W| !condition(world)
+}
+
+/// Holds while resource `T` is present in the host's resource map.
+pub fn resource_exists<T: Send + Sync + 'static, W: ResourceHost>()
+-> impl FnMut(&mut W) -> bool + Send + 'static {
+    |host: &mut W| host.resource_map().get::<T>().is_some()
+}
+
+/// Holds when resource `T` was inserted or mutably accessed since this
+/// condition was last evaluated, including through a
+/// [`ResMut`](crate::system_param::ResMut) parameter. A resource present
+/// before the first evaluation counts as changed once.
+pub fn resource_changed<T: Send + Sync + 'static, W: ResourceHost>()
+-> impl FnMut(&mut W) -> bool + Send + 'static {
+    let mut seen = 0u64;
+    move |host: &mut W| {
+        let resources = host.resource_map();
+        let changed = resources.changed_since::<T>(seen);
+        seen = resources.version;
+        changed
+    }
+}
+
+/// Holds when an event of type `E` was sent since this condition last ran.
+/// Reads through its own cursor, so it neither consumes events for other
+/// readers nor sees one event twice.
+pub fn on_event<E: Send + Sync + 'static, W: EventHost>()
+-> impl FnMut(&mut W) -> bool + Send + 'static {
+    let mut cursor = 0u64;
+    move |host: &mut W| !host.event_bus_mut().consume::<E>(&mut cursor).is_empty()
+}
+
+/// Holds while at least one live entity in the world carries component `T`.
+pub fn any_with_component<T: ComponentData>() -> impl FnMut(&mut DynWorld) -> bool + Send + 'static
+{
+    |world: &mut DynWorld| world.query_ref::<&T>().iter().next().is_some()
+}
+
+/// Holds on every `n`th evaluation: the `n`th, the `2n`th, and so on, so a
+/// system gated on `every_n_runs(3)` runs on every third pass. It counts its
+/// own evaluations, not world ticks: placed second in an [`and`] it only
+/// counts the passes where the first condition held. Panics when `n` is
+/// zero.
+pub fn every_n_runs<W>(n: u32) -> impl FnMut(&mut W) -> bool + Send + 'static {
+    assert!(n > 0, "every_n_runs requires a positive interval");
+    let mut passes = 0u32;
+    move |_world: &mut W| {
+        passes += 1;
+        if passes == n {
+            passes = 0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Gates whole stages of a [`Stages`] on an [`IntoCondition`], the
+/// condition-aware form of [`Stages::set_condition`].
+pub trait StagesExt<W> {
+    /// Runs stage `stage` only on passes where `condition` holds, replacing
+    /// any earlier condition on it. Panics when the stage is not declared.
+    fn stage_run_if<Marker>(
+        &mut self,
+        stage: &str,
+        condition: impl IntoCondition<W, Marker>,
+    ) -> &mut Self;
+}
+
+impl<W> StagesExt<W> for Stages<W> {
+    fn stage_run_if<Marker>(
+        &mut self,
+        stage: &str,
+        condition: impl IntoCondition<W, Marker>,
+    ) -> &mut Self {
+        self.set_condition(stage, condition.into_condition())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Schedule;
+    use crate::system_param::{Res, ResMut, ScheduleExt};
+
+    #[derive(Default, Clone, Debug)]
+    struct Enemy;
+
+    crate::impl_component!(Enemy);
+
+    struct Paused(bool);
+    struct Speed(u32);
+    struct Frames(u32);
+    #[derive(Clone)]
+    struct Hit;
+
+    fn count(mut frames: ResMut<Frames>) {
+        frames.0 += 1;
+    }
+
+    fn frames(world: &DynWorld) -> u32 {
+        world.resource::<Frames>().unwrap().0
+    }
+
+    #[test]
+    fn param_conditions_compose() {
+        fn paused(paused: Res<Paused>) -> bool {
+            paused.0
+        }
+
+        let mut world = DynWorld::new();
+        world.insert_resources((Paused(false), Frames(0)));
+        let mut schedule = Schedule::new();
+        schedule.add_system_if(
+            "count",
+            and(not(paused), resource_exists::<Speed, _>()),
+            count,
+        );
+
+        schedule.run(&mut world);
+        assert_eq!(frames(&world), 0);
+        world.insert_resource(Speed(1));
+        schedule.run(&mut world);
+        assert_eq!(frames(&world), 1);
+        world.resource_mut::<Paused>().unwrap().0 = true;
+        schedule.run(&mut world);
+        assert_eq!(frames(&world), 1);
+
+        let mut either = or(paused, |world: &DynWorld| world.entity_count() > 0).into_condition();
+        world.resource_mut::<Paused>().unwrap().0 = false;
+        assert!(!either(&mut world));
+        world.spawn((Enemy,));
+        assert!(either(&mut world));
+    }
+
+    #[test]
+    fn built_in_conditions() {
+        let mut world = DynWorld::new();
+        world.insert_resource(Speed(1));
+
+        let mut changed = resource_changed::<Speed, DynWorld>();
+        assert!(changed(&mut world));
+        assert!(!changed(&mut world));
+        let mut read = (|speed: Res<Speed>| speed.0 > 0).into_condition();
+        assert!(read(&mut world));
+        assert!(!changed(&mut world));
+        world.resource_mut::<Speed>().unwrap().0 = 2;
+        assert!(changed(&mut world));
+
+        let mut hit = on_event::<Hit, DynWorld>();
+        assert!(!hit(&mut world));
+        world.send(Hit);
+        assert!(hit(&mut world));
+        assert!(!hit(&mut world));
+
+        let mut positioned = any_with_component::<Enemy>();
+        assert!(!positioned(&mut world));
+        let entity = world.spawn((Enemy,));
+        assert!(positioned(&mut world));
+        world.despawn_entities(&[entity]);
+        assert!(!positioned(&mut world));
+
+        let mut third = every_n_runs::<DynWorld>(3);
+        let passes: Vec<bool> = (0..6).map(|_| third(&mut world)).collect();
+        assert_eq!(passes, [false, false, true, false, false, true]);
+
+        let mut gated = and(resource_exists::<Hit, DynWorld>(), every_n_runs(2));
+        assert!(!gated(&mut world));
+        world.insert_resource(Hit);
+        assert!(!gated(&mut world), "the skipped pass did not count");
+        assert!(gated(&mut world));
+    }
+
+    #[test]
+    fn stage_conditions_gate_every_system_in_the_stage() {
+        let mut world = DynWorld::new();
+        world.insert_resource(Frames(0));
+        let mut stages = Stages::new();
+        stages.add_stage("input").add_stage("simulation");
+        stages.stage_mut("simulation").add_system("count", count);
+        stages
+            .stage_mut("simulation")
+            .add_system("count_again", count);
+        stages.stage_run_if("simulation", every_n_runs(2));
+
+        stages.run(&mut world);
+        assert_eq!(frames(&world), 0);
+        stages.run(&mut world);
+        assert_eq!(frames(&world), 2);
+        stages.run_stage("simulation", &mut world);
+        assert_eq!(frames(&world), 2);
+
+        assert!(stages.clear_condition("simulation"));
+        stages.run_stage("simulation", &mut world);
+        assert_eq!(frames(&world), 4);
+    }
+}
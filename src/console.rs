@@ -0,0 +1,2081 @@
+//! An optional text command language over a [`DynWorld`], behind the
+//! `console` feature: the small interpreter a debug console, a REPL, or a
+//! scripted test drives instead of each team writing its own on top of the
+//! keyed tier.
+//!
+//! A [`Console`] is a plain table of per-type records, one per component,
+//! tag, and resource it can name, each a set of function pointers captured at
+//! registration, the same vtable-as-data shape as
+//! [`ComponentInfo`](crate::dynamic::ComponentInfo). Names resolve by full
+//! type path or by the last path segment, first against the console's
+//! records, then against the world's own registry through
+//! [`DynWorld::component_by_name`] and its [`ComponentInfo`] records, so a
+//! component the console was never told about can still be queried, spawned
+//! at its default, and removed. Reading and writing values as text needs a
+//! console record, because the text form goes through the component's serde
+//! implementation.
+//!
+//! Values are written in a small literal syntax: numbers, `true`/`false`,
+//! quoted or bare-word strings (bare words double as unit enum variants),
+//! `[a, b]` or `(a, b)` lists, and `{field: value}` records. A record
+//! literal is a partial value: fields it omits keep their current value, or
+//! the type's `Default` on spawn, so `Position{x: 1}` leaves `y` alone.
+//!
+//! | Command | Result |
+//! |---|---|
+//! | `spawn Position{x: 1} Velocity Player` | [`ConsoleOutput::Spawned`] |
+//! | `set <entity> Health(50)` | [`ConsoleOutput::Done`] |
+//! | `remove <entity> Velocity` | [`ConsoleOutput::Done`] |
+//! | `query Position Velocity where !Dead` (`--disabled` to list disabled entities too) | [`ConsoleOutput::Entities`] |
+//! | `get <entity> Health` (no names: every component) | [`ConsoleOutput::Components`] |
+//! | `despawn <entity>` | [`ConsoleOutput::Despawned`] |
+//! | `tag add <entity> Player`, `tag remove <entity> Player` | [`ConsoleOutput::Done`] |
+//! | `res set DeltaTime 0.016` | [`ConsoleOutput::Done`] |
+//! | `res get DeltaTime` | [`ConsoleOutput::Resource`] |
+//!
+//! An entity is written `12v0`, id then generation, the form outputs print,
+//! or as a bare id, which resolves to that id's live generation.
+//!
+//! ```rust
+//! use freecs::console::{Console, ConsoleOutput, ConsoleValue};
+//! use freecs::dynamic::DynWorld;
+//!
+//! #[derive(Default, serde::Serialize, serde::Deserialize)]
+//! struct Position {
+//!     x: f32,
+//!     y: f32,
+//! }
+//!
+//! #[derive(Default, serde::Serialize, serde::Deserialize)]
+//! struct DeltaTime(f32);
+//!
+//! struct Player;
+//!
+//! let mut console = Console::new();
+//! console
+//!     .register_component::<Position>()
+//!     .register_tag::<Player>()
+//!     .register_resource::<DeltaTime>();
+//!
+//! let mut world = DynWorld::new();
+//! let ConsoleOutput::Spawned(player) = console
+//!     .execute(&mut world, "spawn Position{x: 1} Player")
+//!     .unwrap()
+//! else {
+//!     unreachable!()
+//! };
+//! assert_eq!(world.get::<Position>(player).unwrap().x, 1.0);
+//!
+//! console.execute(&mut world, "res set DeltaTime 0.016").unwrap();
+//! assert_eq!(world.resource::<DeltaTime>().unwrap().0, 0.016);
+//!
+//! let output = console.execute(&mut world, "query Position where Player").unwrap();
+//! assert_eq!(output, ConsoleOutput::Entities(vec![player]));
+//!
+//! let output = console
+//!     .execute(&mut world, &format!("get {} Position", ConsoleOutput::entity_text(player)))
+//!     .unwrap();
+//! let ConsoleOutput::Components { components, .. } = output else {
+//!     unreachable!()
+//! };
+//! assert_eq!(
+//!     components[0].value,
+//!     Some(ConsoleValue::Record(vec![
+//!         ("x".to_string(), ConsoleValue::Float(1.0)),
+//!         ("y".to_string(), ConsoleValue::Float(0.0)),
+//!     ]))
+//! );
+//! ```
+
+use crate::Entity;
+use crate::dynamic::{ComponentData, DynWorld, TagKey};
+use serde::Serialize;
+use serde::de::{self, DeserializeOwned, IntoDeserializer, Visitor};
+use serde::ser;
+
+/// A value in the console's literal syntax, and the intermediate form every
+/// console read and write passes through: components and resources convert
+/// to and from it through their serde implementations. `Display` prints the
+/// literal syntax back, so any printed value can be pasted into a command.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConsoleValue {
+    /// `()`: unit, unit structs, and `None`.
+    Unit,
+    Bool(bool),
+    /// Wide enough for every integer type up to `u64` and `i128`.
+    Integer(i128),
+    Float(f64),
+    /// A quoted string or a bare word; bare words name unit enum variants.
+    Text(String),
+    /// `[a, b]` or `(a, b)`: sequences, tuples, and tuple structs.
+    List(Vec<ConsoleValue>),
+    /// `{field: value}`: structs and maps, in field order. Data-carrying enum
+    /// variants are a one-field record keyed by the variant name.
+    Record(Vec<(String, ConsoleValue)>),
+}
+
+impl std::fmt::Display for ConsoleValue {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConsoleValue::Unit => write!(formatter, "()"),
+            ConsoleValue::Bool(value) => write!(formatter, "{value}"),
+            ConsoleValue::Integer(value) => write!(formatter, "{value}"),
+            ConsoleValue::Float(value) => write!(formatter, "{value:?}"),
+            ConsoleValue::Text(text) => {
+                if is_bare_word(text) {
+                    write!(formatter, "{text}")
+                } else {
+                    write!(formatter, "{text:?}")
+                }
+            }
+            ConsoleValue::List(items) => {
+                write!(formatter, "[")?;
+                write_items(formatter, items)?;
+                write!(formatter, "]")
+            }
+            ConsoleValue::Record(fields) => {
+                write!(formatter, "{{")?;
+                write_fields(formatter, fields)?;
+                write!(formatter, "}}")
+            }
+        }
+    }
+}
+
+fn write_items(
+    formatter: &mut std::fmt::Formatter<'_>,
+    items: &[ConsoleValue],
+) -> std::fmt::Result {
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            write!(formatter, ", ")?;
+        }
+        write!(formatter, "{item}")?;
+    }
+    Ok(())
+}
+
+fn write_fields(
+    formatter: &mut std::fmt::Formatter<'_>,
+    fields: &[(String, ConsoleValue)],
+) -> std::fmt::Result {
+    for (index, (field, value)) in fields.iter().enumerate() {
+        if index > 0 {
+            write!(formatter, ", ")?;
+        }
+        write!(formatter, "{field}: {value}")?;
+    }
+    Ok(())
+}
+
+/// A word that reads back as [`ConsoleValue::Text`] without quotes: an
+/// identifier that is not a boolean keyword.
+fn is_bare_word(text: &str) -> bool {
+    let mut characters = text.chars();
+    let Some(first) = characters.next() else {
+        return false;
+    };
+    (first.is_alphabetic() || first == '_')
+        && characters.all(|character| character.is_alphanumeric() || character == '_')
+        && text != "true"
+        && text != "false"
+}
+
+/// The last path segment of a type name, generics kept:
+/// `game::Position` is `Position`, `game::Wrapper<u32>` is `Wrapper<u32>`.
+pub fn short_type_name(type_name: &str) -> &str {
+    let generics = type_name.find('<').unwrap_or(type_name.len());
+    let start = type_name[..generics]
+        .rfind("::")
+        .map_or(0, |position| position + 2);
+    &type_name[start..]
+}
+
+fn name_matches(type_name: &str, name: &str) -> bool {
+    type_name == name || short_type_name(type_name) == name
+}
+
+/// Why a command failed. Syntax and name errors are reported before the
+/// world is touched. Values convert as they are written: one that fails
+/// partway through a `spawn` despawns the half-built entity before
+/// returning, though the component types it named stay registered, and one
+/// that fails partway through a `set` leaves the earlier components written.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConsoleError {
+    /// The line is not valid command syntax.
+    Parse(String),
+    /// The first word is not a known command.
+    UnknownCommand(String),
+    /// No console record and no registered component carries this name.
+    UnknownName(String),
+    /// A short name matches more than one type; use the full path.
+    AmbiguousName(String),
+    /// The component is known to the world but was never registered with the
+    /// console, so it has no text form to read or write.
+    NoTextForm(String),
+    /// The text does not name an entity.
+    InvalidEntity(String),
+    /// The entity is not alive.
+    DeadEntity(Entity),
+    /// `get` named a component the entity does not carry.
+    MissingComponent { entity: Entity, name: String },
+    /// `res get` named a resource that is not present.
+    MissingResource(String),
+    /// A record literal named a field the value does not have.
+    UnknownField(String),
+    /// A value did not convert to or from its type.
+    Value(String),
+}
+
+impl std::fmt::Display for ConsoleError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConsoleError::Parse(message) => write!(formatter, "parse error: {message}"),
+            ConsoleError::UnknownCommand(command) => write!(formatter, "unknown command {command}"),
+            ConsoleError::UnknownName(name) => write!(formatter, "nothing is named {name}"),
+            ConsoleError::AmbiguousName(name) => {
+                write!(formatter, "{name} matches several types; use the full path")
+            }
+            ConsoleError::NoTextForm(name) => {
+                write!(formatter, "{name} is not registered with the console")
+            }
+            ConsoleError::InvalidEntity(text) => write!(formatter, "{text} is not an entity"),
+            ConsoleError::DeadEntity(entity) => {
+                write!(
+                    formatter,
+                    "entity {} is not alive",
+                    ConsoleOutput::entity_text(*entity)
+                )
+            }
+            ConsoleError::MissingComponent { entity, name } => write!(
+                formatter,
+                "entity {} has no {name}",
+                ConsoleOutput::entity_text(*entity)
+            ),
+            ConsoleError::MissingResource(name) => write!(formatter, "no {name} resource"),
+            ConsoleError::UnknownField(field) => write!(formatter, "no field named {field}"),
+            ConsoleError::Value(message) => write!(formatter, "value error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ConsoleError {}
+
+impl ser::Error for ConsoleError {
+    fn custom<T: std::fmt::Display>(message: T) -> Self {
+        ConsoleError::Value(message.to_string())
+    }
+}
+
+impl de::Error for ConsoleError {
+    fn custom<T: std::fmt::Display>(message: T) -> Self {
+        ConsoleError::Value(message.to_string())
+    }
+}
+
+/// One component on an entity, as `get` reports it: the registered type
+/// name and its value, or `None` when the component has no console record
+/// and so no text form.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComponentValue {
+    pub name: &'static str,
+    pub value: Option<ConsoleValue>,
+}
+
+/// The structured result of one command. `Display` prints it the way a
+/// console would show it, in the literal syntax commands accept.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConsoleOutput {
+    /// A command that changes the world and reports nothing else.
+    Done,
+    Spawned(Entity),
+    Despawned(Entity),
+    /// The matches of a `query`, in table order.
+    Entities(Vec<Entity>),
+    /// The components a `get` read, in the order named, or in registry
+    /// order when none were named.
+    Components {
+        entity: Entity,
+        components: Vec<ComponentValue>,
+    },
+    /// The resource a `res get` read.
+    Resource {
+        name: &'static str,
+        value: ConsoleValue,
+    },
+}
+
+impl ConsoleOutput {
+    /// The console's text for an entity, `id` `v` `generation`, which
+    /// commands accept back.
+    pub fn entity_text(entity: Entity) -> String {
+        format!("{}v{}", entity.id, entity.generation)
+    }
+}
+
+impl std::fmt::Display for ConsoleOutput {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConsoleOutput::Done => write!(formatter, "ok"),
+            ConsoleOutput::Spawned(entity) => {
+                write!(formatter, "spawned {}", ConsoleOutput::entity_text(*entity))
+            }
+            ConsoleOutput::Despawned(entity) => {
+                write!(
+                    formatter,
+                    "despawned {}",
+                    ConsoleOutput::entity_text(*entity)
+                )
+            }
+            ConsoleOutput::Entities(entities) => {
+                for (index, entity) in entities.iter().enumerate() {
+                    if index > 0 {
+                        write!(formatter, " ")?;
+                    }
+                    write!(formatter, "{}", ConsoleOutput::entity_text(*entity))?;
+                }
+                Ok(())
+            }
+            ConsoleOutput::Components { entity, components } => {
+                write!(formatter, "{}", ConsoleOutput::entity_text(*entity))?;
+                for component in components {
+                    write!(formatter, " ")?;
+                    let name = short_type_name(component.name);
+                    match &component.value {
+                        Some(value) => write_named(formatter, name, value)?,
+                        None => write!(formatter, "{name}")?,
+                    }
+                }
+                Ok(())
+            }
+            ConsoleOutput::Resource { name, value } => {
+                write_named(formatter, short_type_name(name), value)
+            }
+        }
+    }
+}
+
+/// Prints a value as the literal that recreates it under `name`:
+/// `Position{x: 1.0}`, `Health(100)`, or a bare `Marker`.
+fn write_named(
+    formatter: &mut std::fmt::Formatter<'_>,
+    name: &str,
+    value: &ConsoleValue,
+) -> std::fmt::Result {
+    match value {
+        ConsoleValue::Unit => write!(formatter, "{name}"),
+        ConsoleValue::Record(fields) => {
+            write!(formatter, "{name}{{")?;
+            write_fields(formatter, fields)?;
+            write!(formatter, "}}")
+        }
+        ConsoleValue::List(items) => {
+            write!(formatter, "{name}(")?;
+            write_items(formatter, items)?;
+            write!(formatter, ")")
+        }
+        scalar => write!(formatter, "{name}({scalar})"),
+    }
+}
+
+/// A component the console can name and convert, as plain function pointers
+/// captured by [`Console::register_component`].
+#[derive(Clone, Copy)]
+pub struct ConsoleComponent {
+    pub type_name: &'static str,
+    /// Registers the type on the world and returns its mask.
+    pub register: fn(&mut DynWorld) -> u64,
+    /// The entity's value as text, `None` when it does not carry the
+    /// component.
+    pub read: fn(&DynWorld, Entity) -> Option<Result<ConsoleValue, ConsoleError>>,
+    /// Writes a literal onto the entity, merged over its current value (or
+    /// the default when absent); `None` writes the default.
+    pub write: fn(&mut DynWorld, Entity, Option<ConsoleValue>) -> Result<(), ConsoleError>,
+}
+
+/// A marker tag the console can name, captured by
+/// [`Console::register_tag`]. World tags are keyed by type, so the name
+/// lives here.
+#[derive(Clone, Copy)]
+pub struct ConsoleTag {
+    pub type_name: &'static str,
+    pub register: fn(&mut DynWorld) -> TagKey,
+    pub lookup: fn(&DynWorld) -> Option<TagKey>,
+}
+
+/// A resource the console can name and convert, captured by
+/// [`Console::register_resource`].
+#[derive(Clone, Copy)]
+pub struct ConsoleResource {
+    pub type_name: &'static str,
+    pub read: fn(&DynWorld) -> Option<Result<ConsoleValue, ConsoleError>>,
+    /// Writes a literal, merged over the current value when present,
+    /// inserting the resource otherwise.
+    pub write: fn(&mut DynWorld, ConsoleValue) -> Result<(), ConsoleError>,
+}
+
+/// A failed line of [`Console::execute_script`]: the one-based line number
+/// and the error it raised.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScriptError {
+    pub line: usize,
+    pub error: ConsoleError,
+}
+
+impl std::fmt::Display for ScriptError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "line {}: {}", self.line, self.error)
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+/// The interpreter: the records of every type it can name. Build one at
+/// startup and keep it beside the world; it holds no world state, so one
+/// console serves any number of worlds.
+#[derive(Clone, Default)]
+pub struct Console {
+    pub components: Vec<ConsoleComponent>,
+    pub tags: Vec<ConsoleTag>,
+    pub resources: Vec<ConsoleResource>,
+}
+
+/// What a name in a command refers to.
+enum Resolved<'console> {
+    Component {
+        type_name: &'static str,
+        mask: Option<u64>,
+        record: Option<&'console ConsoleComponent>,
+    },
+    Tag(&'console ConsoleTag),
+}
+
+impl Console {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes `T` nameable with readable and writable values. Registering a
+    /// type twice is a no-op.
+    pub fn register_component<T>(&mut self) -> &mut Self
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + Default + 'static,
+    {
+        let type_name = std::any::type_name::<T>();
+        if !self
+            .components
+            .iter()
+            .any(|record| record.type_name == type_name)
+        {
+            self.components.push(ConsoleComponent {
+                type_name,
+                register: register_component_mask::<T>,
+                read: read_component::<T>,
+                write: write_component::<T>,
+            });
+        }
+        self
+    }
+
+    /// Makes the marker type `T`'s tag nameable.
+    pub fn register_tag<T: 'static>(&mut self) -> &mut Self {
+        let type_name = std::any::type_name::<T>();
+        if !self.tags.iter().any(|record| record.type_name == type_name) {
+            self.tags.push(ConsoleTag {
+                type_name,
+                register: DynWorld::tag_key::<T>,
+                lookup: DynWorld::lookup_tag_key::<T>,
+            });
+        }
+        self
+    }
+
+    /// Makes resource `T` nameable by `res set` and `res get`.
+    pub fn register_resource<T>(&mut self) -> &mut Self
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + 'static,
+    {
+        let type_name = std::any::type_name::<T>();
+        if !self
+            .resources
+            .iter()
+            .any(|record| record.type_name == type_name)
+        {
+            self.resources.push(ConsoleResource {
+                type_name,
+                read: read_resource::<T>,
+                write: write_resource::<T>,
+            });
+        }
+        self
+    }
+
+    /// Parses and runs one command line. An empty or comment-only line (`#`
+    /// starts a comment) is [`ConsoleOutput::Done`].
+    pub fn execute(&self, world: &mut DynWorld, line: &str) -> Result<ConsoleOutput, ConsoleError> {
+        let mut parser = Parser::new(tokenize(line)?);
+        let Some(command) = parser.next() else {
+            return Ok(ConsoleOutput::Done);
+        };
+        let command = match command {
+            Token::Word(word) => word,
+            other => {
+                return Err(ConsoleError::Parse(format!(
+                    "expected a command, found {other}"
+                )));
+            }
+        };
+        let output = match command.as_str() {
+            "spawn" => self.spawn(world, &mut parser)?,
+            "set" => self.set(world, &mut parser)?,
+            "remove" => self.remove(world, &mut parser)?,
+            "query" => self.query(world, &mut parser)?,
+            "get" => self.get(world, &mut parser)?,
+            "despawn" => {
+                let entity = parser.entity(world)?;
+                world.despawn_entities(&[entity]);
+                ConsoleOutput::Despawned(entity)
+            }
+            "tag" => self.tag(world, &mut parser)?,
+            "res" => self.resource(world, &mut parser)?,
+            _ => return Err(ConsoleError::UnknownCommand(command)),
+        };
+        parser.finish()?;
+        Ok(output)
+    }
+
+    /// Runs a script one line at a time, stopping at the first failure.
+    /// Lines that ran before it keep their effects.
+    pub fn execute_script(
+        &self,
+        world: &mut DynWorld,
+        script: &str,
+    ) -> Result<Vec<ConsoleOutput>, ScriptError> {
+        let mut outputs = Vec::new();
+        for (index, line) in script.lines().enumerate() {
+            let output = self.execute(world, line).map_err(|error| ScriptError {
+                line: index + 1,
+                error,
+            })?;
+            outputs.push(output);
+        }
+        Ok(outputs)
+    }
+
+    fn resolve(&self, world: &DynWorld, name: &str) -> Result<Resolved<'_>, ConsoleError> {
+        if let Some(record) = find_unique(&self.components, name, |record| record.type_name)? {
+            return Ok(Resolved::Component {
+                type_name: record.type_name,
+                mask: world
+                    .component_by_name(record.type_name)
+                    .map(|info| info.mask),
+                record: Some(record),
+            });
+        }
+        if let Some(info) = find_unique(&world.registry.components, name, |info| info.type_name)? {
+            return Ok(Resolved::Component {
+                type_name: info.type_name,
+                mask: Some(info.mask),
+                record: None,
+            });
+        }
+        if let Some(record) = find_unique(&self.tags, name, |record| record.type_name)? {
+            return Ok(Resolved::Tag(record));
+        }
+        Err(ConsoleError::UnknownName(name.to_string()))
+    }
+
+    fn spawn(
+        &self,
+        world: &mut DynWorld,
+        parser: &mut Parser,
+    ) -> Result<ConsoleOutput, ConsoleError> {
+        let mut mask = 0;
+        let mut records = Vec::new();
+        let mut values = Vec::new();
+        let mut tags = Vec::new();
+        while let Some((name, literal)) = parser.component_literal()? {
+            match self.resolve(world, &name)? {
+                Resolved::Component {
+                    type_name,
+                    mask: component_mask,
+                    record,
+                } => match (record, literal) {
+                    (Some(record), literal) => {
+                        records.push(record);
+                        if let Some(literal) = literal {
+                            values.push((record, literal));
+                        }
+                    }
+                    (None, None) => mask |= component_mask.unwrap_or(0),
+                    (None, Some(_)) => return Err(ConsoleError::NoTextForm(type_name.to_string())),
+                },
+                Resolved::Tag(record) => {
+                    if literal.is_some() {
+                        return Err(ConsoleError::Parse(format!("tag {name} takes no value")));
+                    }
+                    tags.push(record);
+                }
+            }
+        }
+        for record in records {
+            mask |= (record.register)(world);
+        }
+        let entity = world.spawn_entities(mask, 1)[0];
+        for (record, literal) in values {
+            if let Err(error) = (record.write)(world, entity, Some(literal)) {
+                world.despawn_entities(&[entity]);
+                return Err(error);
+            }
+        }
+        for record in tags {
+            let key = (record.register)(world);
+            world.add_tag(key, entity);
+        }
+        Ok(ConsoleOutput::Spawned(entity))
+    }
+
+    fn set(
+        &self,
+        world: &mut DynWorld,
+        parser: &mut Parser,
+    ) -> Result<ConsoleOutput, ConsoleError> {
+        let entity = parser.entity(world)?;
+        while let Some((name, literal)) = parser.component_literal()? {
+            match self.resolve(world, &name)? {
+                Resolved::Component {
+                    record: Some(record),
+                    ..
+                } => (record.write)(world, entity, literal)?,
+                Resolved::Component { type_name, .. } => {
+                    return Err(ConsoleError::NoTextForm(type_name.to_string()));
+                }
+                Resolved::Tag(_) => {
+                    return Err(ConsoleError::Parse(format!("{name} is a tag; use tag add")));
+                }
+            }
+        }
+        Ok(ConsoleOutput::Done)
+    }
+
+    fn remove(
+        &self,
+        world: &mut DynWorld,
+        parser: &mut Parser,
+    ) -> Result<ConsoleOutput, ConsoleError> {
+        let entity = parser.entity(world)?;
+        let mut mask = 0;
+        while let Some(name) = parser.name()? {
+            match self.resolve(world, &name)? {
+                Resolved::Component {
+                    mask: component_mask,
+                    ..
+                } => mask |= component_mask.unwrap_or(0),
+                Resolved::Tag(_) => {
+                    return Err(ConsoleError::Parse(format!(
+                        "{name} is a tag; use tag remove"
+                    )));
+                }
+            }
+        }
+        if mask != 0 {
+            world.remove_components(entity, mask);
+        }
+        Ok(ConsoleOutput::Done)
+    }
+
+    fn query(&self, world: &DynWorld, parser: &mut Parser) -> Result<ConsoleOutput, ConsoleError> {
+        let mut include = 0;
+        let mut exclude = 0;
+        let mut unmatchable = false;
+        let mut filtering = false;
+        let mut include_disabled = false;
+        loop {
+            let negated = if filtering {
+                parser.eat_symbol('!')
+            } else {
+                false
+            };
+            let Some(name) = parser.name()? else {
+                break;
+            };
+            if !filtering && !negated && name == "where" {
+                filtering = true;
+                continue;
+            }
+            if !negated && name == "--disabled" {
+                include_disabled = true;
+                continue;
+            }
+            let mask = match self.resolve(world, &name)? {
+                Resolved::Component { mask, .. } => mask,
+                Resolved::Tag(record) => (record.lookup)(world).map(|key| key.mask),
+            };
+            match (mask, negated) {
+                (Some(mask), false) => include |= mask,
+                (Some(mask), true) => exclude |= mask,
+                (None, false) => unmatchable = true,
+                (None, true) => {}
+            }
+        }
+        if unmatchable {
+            return Ok(ConsoleOutput::Entities(Vec::new()));
+        }
+
+        let component_bits = world.registry.all_components_mask();
+        let component_exclude = exclude & component_bits;
+        let tag_include = include & !component_bits;
+        let tag_exclude = exclude & !component_bits;
+        let entities = world
+            .query_entities(include & component_bits)
+            .filter(|&entity| {
+                world.component_mask(entity).unwrap_or(0) & component_exclude == 0
+                    && tag_mask_matches(world, entity, tag_include, tag_exclude)
+                    && (include_disabled || !world.is_disabled(entity))
+            })
+            .collect();
+        Ok(ConsoleOutput::Entities(entities))
+    }
+
+    fn get(&self, world: &DynWorld, parser: &mut Parser) -> Result<ConsoleOutput, ConsoleError> {
+        let entity = parser.entity(world)?;
+        let mut components = Vec::new();
+        while let Some(name) = parser.name()? {
+            let (type_name, record) = match self.resolve(world, &name)? {
+                Resolved::Component {
+                    type_name, record, ..
+                } => (type_name, record),
+                Resolved::Tag(_) => {
+                    return Err(ConsoleError::Parse(format!(
+                        "{name} is a tag, not a component"
+                    )));
+                }
+            };
+            let present = world
+                .entity_components(entity)
+                .any(|info| info.type_name == type_name);
+            if !present {
+                return Err(ConsoleError::MissingComponent {
+                    entity,
+                    name: type_name.to_string(),
+                });
+            }
+            components.push(self.component_value(world, entity, type_name, record)?);
+        }
+        if components.is_empty() {
+            for info in world.entity_components(entity) {
+                let record = self
+                    .components
+                    .iter()
+                    .find(|record| record.type_name == info.type_name);
+                components.push(self.component_value(world, entity, info.type_name, record)?);
+            }
+        }
+        Ok(ConsoleOutput::Components { entity, components })
+    }
+
+    fn component_value(
+        &self,
+        world: &DynWorld,
+        entity: Entity,
+        type_name: &'static str,
+        record: Option<&ConsoleComponent>,
+    ) -> Result<ComponentValue, ConsoleError> {
+        let value = match record {
+            Some(record) => (record.read)(world, entity).transpose()?,
+            None => None,
+        };
+        Ok(ComponentValue {
+            name: type_name,
+            value,
+        })
+    }
+
+    fn tag(
+        &self,
+        world: &mut DynWorld,
+        parser: &mut Parser,
+    ) -> Result<ConsoleOutput, ConsoleError> {
+        let action = parser.word("add or remove")?;
+        let entity = parser.entity(world)?;
+        let name = parser.word("a tag name")?;
+        let record = find_unique(&self.tags, &name, |record| record.type_name)?
+            .ok_or_else(|| ConsoleError::UnknownName(name.clone()))?;
+        match action.as_str() {
+            "add" => {
+                let key = (record.register)(world);
+                world.add_tag(key, entity);
+            }
+            "remove" => {
+                if let Some(key) = (record.lookup)(world) {
+                    world.remove_tag(key, entity);
+                }
+            }
+            _ => {
+                return Err(ConsoleError::Parse(format!(
+                    "expected add or remove after tag, found {action}"
+                )));
+            }
+        }
+        Ok(ConsoleOutput::Done)
+    }
+
+    fn resource(
+        &self,
+        world: &mut DynWorld,
+        parser: &mut Parser,
+    ) -> Result<ConsoleOutput, ConsoleError> {
+        let action = parser.word("set or get")?;
+        let name = parser.word("a resource name")?;
+        let record = find_unique(&self.resources, &name, |record| record.type_name)?
+            .ok_or_else(|| ConsoleError::UnknownName(name.clone()))?;
+        match action.as_str() {
+            "set" => {
+                let value = parser.value()?;
+                (record.write)(world, value)?;
+                Ok(ConsoleOutput::Done)
+            }
+            "get" => match (record.read)(world) {
+                Some(value) => Ok(ConsoleOutput::Resource {
+                    name: record.type_name,
+                    value: value?,
+                }),
+                None => Err(ConsoleError::MissingResource(record.type_name.to_string())),
+            },
+            _ => Err(ConsoleError::Parse(format!(
+                "expected set or get after res, found {action}"
+            ))),
+        }
+    }
+}
+
+/// The one record whose type name matches `name`. An exact full-path match
+/// wins outright; otherwise several short-name matches are ambiguous.
+fn find_unique<'records, T>(
+    records: &'records [T],
+    name: &str,
+    type_name: impl Fn(&T) -> &'static str,
+) -> Result<Option<&'records T>, ConsoleError> {
+    if let Some(record) = records.iter().find(|record| type_name(record) == name) {
+        return Ok(Some(record));
+    }
+    let mut matches = records
+        .iter()
+        .filter(|record| name_matches(type_name(record), name));
+    let first = matches.next();
+    if matches.next().is_some() {
+        return Err(ConsoleError::AmbiguousName(name.to_string()));
+    }
+    Ok(first)
+}
+
+fn tag_mask_matches(world: &DynWorld, entity: Entity, tag_include: u64, tag_exclude: u64) -> bool {
+    for (tag_index, tag_set) in world.tags.iter().enumerate() {
+        let tag_mask = 1u64 << (63 - tag_index as u32);
+        if tag_include & tag_mask != 0 && !tag_set.contains(entity) {
+            return false;
+        }
+        if tag_exclude & tag_mask != 0 && tag_set.contains(entity) {
+            return false;
+        }
+    }
+    true
+}
+
+fn register_component_mask<T: ComponentData>(world: &mut DynWorld) -> u64 {
+    world.component_key::<T>().mask
+}
+
+fn read_component<T>(world: &DynWorld, entity: Entity) -> Option<Result<ConsoleValue, ConsoleError>>
+where
+    T: Serialize + ComponentData,
+{
+    world.get::<T>(entity).map(to_value)
+}
+
+fn write_component<T>(
+    world: &mut DynWorld,
+    entity: Entity,
+    literal: Option<ConsoleValue>,
+) -> Result<(), ConsoleError>
+where
+    T: Serialize + DeserializeOwned + Send + Sync + Default + 'static,
+{
+    let value = match literal {
+        None => T::default(),
+        Some(literal) => {
+            let base = match world.get::<T>(entity) {
+                Some(current) => to_value(current)?,
+                None => to_value(&T::default())?,
+            };
+            from_value(merge(base, literal)?)?
+        }
+    };
+    world.set(entity, value);
+    Ok(())
+}
+
+fn read_resource<T>(world: &DynWorld) -> Option<Result<ConsoleValue, ConsoleError>>
+where
+    T: Serialize + Send + Sync + 'static,
+{
+    world.resource::<T>().map(to_value)
+}
+
+fn write_resource<T>(world: &mut DynWorld, literal: ConsoleValue) -> Result<(), ConsoleError>
+where
+    T: Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    let merged = match world.resource::<T>() {
+        Some(current) => merge(to_value(current)?, literal)?,
+        None => literal,
+    };
+    world.insert_resource(from_value::<T>(merged)?);
+    Ok(())
+}
+
+/// Lays a literal over a base value: a record literal replaces only the
+/// fields it names, recursively, and rejects fields the base lacks; any
+/// other literal replaces the base whole.
+pub fn merge(base: ConsoleValue, literal: ConsoleValue) -> Result<ConsoleValue, ConsoleError> {
+    match (base, literal) {
+        (ConsoleValue::Record(mut fields), ConsoleValue::Record(patch)) => {
+            for (field, value) in patch {
+                let Some(slot) = fields.iter_mut().find(|(name, _)| *name == field) else {
+                    return Err(ConsoleError::UnknownField(field));
+                };
+                let current = std::mem::replace(&mut slot.1, ConsoleValue::Unit);
+                slot.1 = merge(current, value)?;
+            }
+            Ok(ConsoleValue::Record(fields))
+        }
+        (_, literal) => Ok(literal),
+    }
+}
+
+/// Converts any serde-serializable value to its console form.
+pub fn to_value<T: Serialize + ?Sized>(value: &T) -> Result<ConsoleValue, ConsoleError> {
+    value.serialize(ValueSerializer)
+}
+
+/// Converts a console value back into `T` through its serde
+/// implementation. Integers convert into float fields, and a one-element
+/// list unwraps into a newtype, so `Health(100)` reads as a literal.
+pub fn from_value<T: DeserializeOwned>(value: ConsoleValue) -> Result<T, ConsoleError> {
+    T::deserialize(value)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Word(String),
+    Text(String),
+    Symbol(char),
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Word(word) => write!(formatter, "{word}"),
+            Token::Text(text) => write!(formatter, "{text:?}"),
+            Token::Symbol(symbol) => write!(formatter, "{symbol}"),
+        }
+    }
+}
+
+fn is_word_character(character: char) -> bool {
+    character.is_alphanumeric() || matches!(character, '_' | '.' | '-' | '+')
+}
+
+/// Splits a line into words, quoted strings, and punctuation. Words keep
+/// `::` path separators, so full type paths are one word.
+fn tokenize(line: &str) -> Result<Vec<Token>, ConsoleError> {
+    let mut tokens = Vec::new();
+    let mut characters = line.chars().peekable();
+    while let Some(&character) = characters.peek() {
+        if character.is_whitespace() {
+            characters.next();
+        } else if character == '#' {
+            break;
+        } else if character == '"' {
+            characters.next();
+            let mut text = String::new();
+            loop {
+                match characters.next() {
+                    None => return Err(ConsoleError::Parse("unterminated string".to_string())),
+                    Some('"') => break,
+                    Some('\\') => match characters.next() {
+                        Some('n') => text.push('\n'),
+                        Some('t') => text.push('\t'),
+                        Some(escaped @ ('"' | '\\')) => text.push(escaped),
+                        other => {
+                            return Err(ConsoleError::Parse(format!(
+                                "unknown escape \\{}",
+                                other.map(String::from).unwrap_or_default()
+                            )));
+                        }
+                    },
+                    Some(character) => text.push(character),
+                }
+            }
+            tokens.push(Token::Text(text));
+        } else if matches!(
+            character,
+            '{' | '}' | '[' | ']' | '(' | ')' | ':' | ',' | '!'
+        ) {
+            characters.next();
+            tokens.push(Token::Symbol(character));
+        } else if is_word_character(character) {
+            let mut word = String::new();
+            while let Some(&character) = characters.peek() {
+                if is_word_character(character) {
+                    word.push(character);
+                    characters.next();
+                } else if character == ':' {
+                    let mut lookahead = characters.clone();
+                    lookahead.next();
+                    if lookahead.peek() != Some(&':') {
+                        break;
+                    }
+                    word.push_str("::");
+                    characters.next();
+                    characters.next();
+                } else {
+                    break;
+                }
+            }
+            tokens.push(Token::Word(word));
+        } else {
+            return Err(ConsoleError::Parse(format!(
+                "unexpected character {character:?}"
+            )));
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn new(tokens: Vec<Token>) -> Self {
+        Self {
+            tokens,
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        if token.is_some() {
+            self.position += 1;
+        }
+        token
+    }
+
+    fn peek_symbol(&self, symbol: char) -> bool {
+        self.peek() == Some(&Token::Symbol(symbol))
+    }
+
+    fn eat_symbol(&mut self, symbol: char) -> bool {
+        if self.peek_symbol(symbol) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> Result<(), ConsoleError> {
+        match self.next() {
+            Some(Token::Symbol(found)) if found == symbol => Ok(()),
+            Some(other) => Err(ConsoleError::Parse(format!(
+                "expected {symbol}, found {other}"
+            ))),
+            None => Err(ConsoleError::Parse(format!(
+                "expected {symbol}, found end of line"
+            ))),
+        }
+    }
+
+    fn finish(&self) -> Result<(), ConsoleError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(token) => Err(ConsoleError::Parse(format!("unexpected {token}"))),
+        }
+    }
+
+    fn word(&mut self, expected: &str) -> Result<String, ConsoleError> {
+        match self.next() {
+            Some(Token::Word(word)) => Ok(word),
+            Some(other) => Err(ConsoleError::Parse(format!(
+                "expected {expected}, found {other}"
+            ))),
+            None => Err(ConsoleError::Parse(format!(
+                "expected {expected}, found end of line"
+            ))),
+        }
+    }
+
+    /// The next word if there is one, for name lists that run to the end of
+    /// the line.
+    fn name(&mut self) -> Result<Option<String>, ConsoleError> {
+        if self.peek().is_none() {
+            return Ok(None);
+        }
+        self.word("a name").map(Some)
+    }
+
+    /// `12v3` names an exact handle; a bare `12` takes the id's live
+    /// generation. Either way the entity must be alive.
+    fn entity(&mut self, world: &DynWorld) -> Result<Entity, ConsoleError> {
+        let text = self.word("an entity")?;
+        let invalid = || ConsoleError::InvalidEntity(text.clone());
+        let entity = match text.split_once('v') {
+            Some((id, generation)) => Entity {
+                id: id.parse().map_err(|_| invalid())?,
+                generation: generation.parse().map_err(|_| invalid())?,
+            },
+            None => {
+                let id: u32 = text.parse().map_err(|_| invalid())?;
+                let generation = world
+                    .allocator
+                    .slots
+                    .get(id as usize)
+                    .map_or(0, |slot| slot.generation);
+                Entity { id, generation }
+            }
+        };
+        if !world.is_alive(entity) {
+            return Err(ConsoleError::DeadEntity(entity));
+        }
+        Ok(entity)
+    }
+
+    /// `Name`, `Name{...}`, or `Name(...)`: a type name and its optional
+    /// literal. `None` at the end of the line.
+    fn component_literal(
+        &mut self,
+    ) -> Result<Option<(String, Option<ConsoleValue>)>, ConsoleError> {
+        let Some(name) = self.name()? else {
+            return Ok(None);
+        };
+        let literal = if self.peek_symbol('{') || self.peek_symbol('(') {
+            Some(self.value()?)
+        } else {
+            None
+        };
+        Ok(Some((name, literal)))
+    }
+
+    fn value(&mut self) -> Result<ConsoleValue, ConsoleError> {
+        match self.next() {
+            Some(Token::Symbol('{')) => self.record(),
+            Some(Token::Symbol('[')) => self.list(']').map(ConsoleValue::List),
+            Some(Token::Symbol('(')) => {
+                let items = self.list(')')?;
+                Ok(if items.is_empty() {
+                    ConsoleValue::Unit
+                } else {
+                    ConsoleValue::List(items)
+                })
+            }
+            Some(Token::Text(text)) => Ok(ConsoleValue::Text(text)),
+            Some(Token::Word(word)) => {
+                if self.peek_symbol('{') || self.peek_symbol('(') {
+                    self.value()
+                } else {
+                    Ok(scalar(word))
+                }
+            }
+            Some(other) => Err(ConsoleError::Parse(format!(
+                "expected a value, found {other}"
+            ))),
+            None => Err(ConsoleError::Parse(
+                "expected a value, found end of line".to_string(),
+            )),
+        }
+    }
+
+    fn list(&mut self, close: char) -> Result<Vec<ConsoleValue>, ConsoleError> {
+        let mut items = Vec::new();
+        while !self.eat_symbol(close) {
+            items.push(self.value()?);
+            if !self.eat_symbol(',') {
+                self.expect_symbol(close)?;
+                break;
+            }
+        }
+        Ok(items)
+    }
+
+    fn record(&mut self) -> Result<ConsoleValue, ConsoleError> {
+        let mut fields = Vec::new();
+        while !self.eat_symbol('}') {
+            let field = match self.next() {
+                Some(Token::Word(word)) | Some(Token::Text(word)) => word,
+                Some(other) => {
+                    return Err(ConsoleError::Parse(format!(
+                        "expected a field name, found {other}"
+                    )));
+                }
+                None => {
+                    return Err(ConsoleError::Parse(
+                        "expected a field name, found end of line".to_string(),
+                    ));
+                }
+            };
+            self.expect_symbol(':')?;
+            fields.push((field, self.value()?));
+            if !self.eat_symbol(',') {
+                self.expect_symbol('}')?;
+                break;
+            }
+        }
+        Ok(ConsoleValue::Record(fields))
+    }
+}
+
+fn scalar(word: String) -> ConsoleValue {
+    match word.as_str() {
+        "true" => return ConsoleValue::Bool(true),
+        "false" => return ConsoleValue::Bool(false),
+        _ => {}
+    }
+    if let Ok(integer) = word.parse::<i128>() {
+        return ConsoleValue::Integer(integer);
+    }
+    let numeric = word
+        .chars()
+        .next()
+        .is_some_and(|first| first.is_ascii_digit() || matches!(first, '-' | '+' | '.'));
+    if numeric && let Ok(float) = word.parse::<f64>() {
+        return ConsoleValue::Float(float);
+    }
+    ConsoleValue::Text(word)
+}
+
+struct ValueSerializer;
+
+struct ListSerializer {
+    items: Vec<ConsoleValue>,
+    variant: Option<&'static str>,
+}
+
+impl ListSerializer {
+    fn finish(self) -> ConsoleValue {
+        let list = ConsoleValue::List(self.items);
+        match self.variant {
+            Some(variant) => ConsoleValue::Record(vec![(variant.to_string(), list)]),
+            None => list,
+        }
+    }
+}
+
+struct RecordSerializer {
+    fields: Vec<(String, ConsoleValue)>,
+    pending_key: Option<String>,
+    variant: Option<&'static str>,
+}
+
+impl RecordSerializer {
+    fn finish(self) -> ConsoleValue {
+        let record = ConsoleValue::Record(self.fields);
+        match self.variant {
+            Some(variant) => ConsoleValue::Record(vec![(variant.to_string(), record)]),
+            None => record,
+        }
+    }
+}
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = ConsoleValue;
+    type Error = ConsoleError;
+    type SerializeSeq = ListSerializer;
+    type SerializeTuple = ListSerializer;
+    type SerializeTupleStruct = ListSerializer;
+    type SerializeTupleVariant = ListSerializer;
+    type SerializeMap = RecordSerializer;
+    type SerializeStruct = RecordSerializer;
+    type SerializeStructVariant = RecordSerializer;
+
+    fn serialize_bool(self, value: bool) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Bool(value))
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Integer(value.into()))
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Integer(value.into()))
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Integer(value.into()))
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Integer(value.into()))
+    }
+
+    fn serialize_i128(self, value: i128) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Integer(value))
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Integer(value.into()))
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Integer(value.into()))
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Integer(value.into()))
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Integer(value.into()))
+    }
+
+    fn serialize_u128(self, value: u128) -> Result<ConsoleValue, ConsoleError> {
+        i128::try_from(value)
+            .map(ConsoleValue::Integer)
+            .map_err(|_| ConsoleError::Value(format!("{value} does not fit a console integer")))
+    }
+
+    /// Widens through the shortest decimal form, so `0.016f32` reads back
+    /// as `0.016` rather than its exact binary expansion.
+    fn serialize_f32(self, value: f32) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Float(
+            value.to_string().parse().unwrap_or(f64::from(value)),
+        ))
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Float(value))
+    }
+
+    fn serialize_char(self, value: char) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Text(value.to_string()))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Text(value.to_string()))
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::List(
+            value
+                .iter()
+                .map(|&byte| ConsoleValue::Integer(byte.into()))
+                .collect(),
+        ))
+    }
+
+    fn serialize_none(self) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Unit)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(
+        self,
+        value: &T,
+    ) -> Result<ConsoleValue, ConsoleError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Unit)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Unit)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Text(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<ConsoleValue, ConsoleError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<ConsoleValue, ConsoleError> {
+        Ok(ConsoleValue::Record(vec![(
+            variant.to_string(),
+            value.serialize(self)?,
+        )]))
+    }
+
+    fn serialize_seq(self, length: Option<usize>) -> Result<ListSerializer, ConsoleError> {
+        Ok(ListSerializer {
+            items: Vec::with_capacity(length.unwrap_or(0)),
+            variant: None,
+        })
+    }
+
+    fn serialize_tuple(self, length: usize) -> Result<ListSerializer, ConsoleError> {
+        self.serialize_seq(Some(length))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        length: usize,
+    ) -> Result<ListSerializer, ConsoleError> {
+        self.serialize_seq(Some(length))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        length: usize,
+    ) -> Result<ListSerializer, ConsoleError> {
+        Ok(ListSerializer {
+            items: Vec::with_capacity(length),
+            variant: Some(variant),
+        })
+    }
+
+    fn serialize_map(self, length: Option<usize>) -> Result<RecordSerializer, ConsoleError> {
+        Ok(RecordSerializer {
+            fields: Vec::with_capacity(length.unwrap_or(0)),
+            pending_key: None,
+            variant: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        length: usize,
+    ) -> Result<RecordSerializer, ConsoleError> {
+        self.serialize_map(Some(length))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        length: usize,
+    ) -> Result<RecordSerializer, ConsoleError> {
+        Ok(RecordSerializer {
+            fields: Vec::with_capacity(length),
+            pending_key: None,
+            variant: Some(variant),
+        })
+    }
+}
+
+impl ser::SerializeSeq for ListSerializer {
+    type Ok = ConsoleValue;
+    type Error = ConsoleError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ConsoleError> {
+        self.items.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<ConsoleValue, ConsoleError> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTuple for ListSerializer {
+    type Ok = ConsoleValue;
+    type Error = ConsoleError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ConsoleError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<ConsoleValue, ConsoleError> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTupleStruct for ListSerializer {
+    type Ok = ConsoleValue;
+    type Error = ConsoleError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ConsoleError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<ConsoleValue, ConsoleError> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTupleVariant for ListSerializer {
+    type Ok = ConsoleValue;
+    type Error = ConsoleError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ConsoleError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<ConsoleValue, ConsoleError> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeMap for RecordSerializer {
+    type Ok = ConsoleValue;
+    type Error = ConsoleError;
+
+    /// Map keys become field names: text keys verbatim, anything else in
+    /// its literal form.
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), ConsoleError> {
+        self.pending_key = Some(match key.serialize(ValueSerializer)? {
+            ConsoleValue::Text(text) => text,
+            other => other.to_string(),
+        });
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ConsoleError> {
+        let key = self
+            .pending_key
+            .take()
+            .ok_or_else(|| ConsoleError::Value("map value without a key".to_string()))?;
+        self.fields.push((key, value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<ConsoleValue, ConsoleError> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeStruct for RecordSerializer {
+    type Ok = ConsoleValue;
+    type Error = ConsoleError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), ConsoleError> {
+        self.fields
+            .push((key.to_string(), value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<ConsoleValue, ConsoleError> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeStructVariant for RecordSerializer {
+    type Ok = ConsoleValue;
+    type Error = ConsoleError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), ConsoleError> {
+        ser::SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<ConsoleValue, ConsoleError> {
+        Ok(self.finish())
+    }
+}
+
+impl<'de> IntoDeserializer<'de, ConsoleError> for ConsoleValue {
+    type Deserializer = ConsoleValue;
+
+    fn into_deserializer(self) -> ConsoleValue {
+        self
+    }
+}
+
+impl<'de> de::Deserializer<'de> for ConsoleValue {
+    type Error = ConsoleError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ConsoleError> {
+        match self {
+            ConsoleValue::Unit => visitor.visit_unit(),
+            ConsoleValue::Bool(value) => visitor.visit_bool(value),
+            ConsoleValue::Integer(value) => {
+                if let Ok(value) = i64::try_from(value) {
+                    visitor.visit_i64(value)
+                } else if let Ok(value) = u64::try_from(value) {
+                    visitor.visit_u64(value)
+                } else {
+                    visitor.visit_i128(value)
+                }
+            }
+            ConsoleValue::Float(value) => visitor.visit_f64(value),
+            ConsoleValue::Text(text) => visitor.visit_string(text),
+            ConsoleValue::List(items) => {
+                let mut access = de::value::SeqDeserializer::new(items.into_iter());
+                let value = visitor.visit_seq(&mut access)?;
+                access.end()?;
+                Ok(value)
+            }
+            ConsoleValue::Record(fields) => {
+                let mut access = de::value::MapDeserializer::new(fields.into_iter());
+                let value = visitor.visit_map(&mut access)?;
+                access.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ConsoleError> {
+        match self {
+            ConsoleValue::Unit => visitor.visit_none(),
+            other => visitor.visit_some(other),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ConsoleError> {
+        match self {
+            ConsoleValue::List(items) if items.is_empty() => visitor.visit_unit(),
+            other => other.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ConsoleError> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ConsoleError> {
+        match self {
+            ConsoleValue::List(mut items) if items.len() == 1 => {
+                visitor.visit_newtype_struct(items.remove(0))
+            }
+            other => visitor.visit_newtype_struct(other),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ConsoleError> {
+        match self {
+            ConsoleValue::List(mut items) if items.len() == 1 => {
+                items.remove(0).deserialize_enum(name, variants, visitor)
+            }
+            ConsoleValue::Text(variant) => visitor.visit_enum(variant.into_deserializer()),
+            ConsoleValue::Record(mut fields) if fields.len() == 1 => {
+                let (variant, payload) = fields.remove(0);
+                visitor.visit_enum(VariantAccess { variant, payload })
+            }
+            other => Err(ConsoleError::Value(format!(
+                "expected a {name} variant, found {other}"
+            ))),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// A data-carrying enum variant: the one-field record `{Variant: payload}`.
+struct VariantAccess {
+    variant: String,
+    payload: ConsoleValue,
+}
+
+impl<'de> de::EnumAccess<'de> for VariantAccess {
+    type Error = ConsoleError;
+    type Variant = ConsoleValue;
+
+    fn variant_seed<S: de::DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<(S::Value, ConsoleValue), ConsoleError> {
+        let variant = seed.deserialize(ConsoleValue::Text(self.variant))?;
+        Ok((variant, self.payload))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for ConsoleValue {
+    type Error = ConsoleError;
+
+    fn unit_variant(self) -> Result<(), ConsoleError> {
+        de::Deserialize::deserialize(self)
+    }
+
+    fn newtype_variant_seed<S: de::DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<S::Value, ConsoleError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _length: usize,
+        visitor: V,
+    ) -> Result<V::Value, ConsoleError> {
+        de::Deserializer::deserialize_any(self, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ConsoleError> {
+        de::Deserializer::deserialize_any(self, visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Default, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Velocity {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Default, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Health(u32);
+
+    #[derive(Default, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    enum Mood {
+        #[default]
+        Calm,
+        Angry {
+            level: u8,
+        },
+    }
+
+    #[derive(Default, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct DeltaTime(f32);
+
+    #[derive(Default)]
+    struct Unregistered;
+
+    struct Player;
+    struct Dead;
+
+    fn console() -> Console {
+        let mut console = Console::new();
+        console
+            .register_component::<Position>()
+            .register_component::<Velocity>()
+            .register_component::<Health>()
+            .register_component::<Mood>()
+            .register_tag::<Player>()
+            .register_tag::<Dead>()
+            .register_resource::<DeltaTime>();
+        console
+    }
+
+    fn spawned(output: ConsoleOutput) -> Entity {
+        match output {
+            ConsoleOutput::Spawned(entity) => entity,
+            other => panic!("expected a spawn, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn spawn_merges_partial_literals_over_defaults() {
+        let console = console();
+        let mut world = DynWorld::new();
+        let entity = spawned(
+            console
+                .execute(
+                    &mut world,
+                    "spawn Position{x: 1} Velocity Health(30) Player",
+                )
+                .unwrap(),
+        );
+        assert_eq!(
+            world.get::<Position>(entity),
+            Some(&Position { x: 1.0, y: 0.0 })
+        );
+        assert_eq!(world.get::<Velocity>(entity), Some(&Velocity::default()));
+        assert_eq!(world.get::<Health>(entity), Some(&Health(30)));
+        assert!(world.has_tag_type::<Player>(entity));
+    }
+
+    #[test]
+    fn query_filters_by_components_and_tags() {
+        let console = console();
+        let mut world = DynWorld::new();
+        let moving = spawned(
+            console
+                .execute(&mut world, "spawn Position Velocity")
+                .unwrap(),
+        );
+        let corpse = spawned(
+            console
+                .execute(&mut world, "spawn Position Velocity Dead")
+                .unwrap(),
+        );
+        let still = spawned(console.execute(&mut world, "spawn Position").unwrap());
+
+        let all = console.execute(&mut world, "query Position").unwrap();
+        assert_eq!(all, ConsoleOutput::Entities(vec![moving, corpse, still]));
+        let living = console
+            .execute(&mut world, "query Position Velocity where !Dead")
+            .unwrap();
+        assert_eq!(living, ConsoleOutput::Entities(vec![moving]));
+        let dead = console
+            .execute(&mut world, "query Position where Dead")
+            .unwrap();
+        assert_eq!(dead, ConsoleOutput::Entities(vec![corpse]));
+        let players = console
+            .execute(&mut world, "query Position where Player")
+            .unwrap();
+        assert_eq!(players, ConsoleOutput::Entities(Vec::new()));
+
+        world.disable(still);
+        let enabled = console.execute(&mut world, "query Position").unwrap();
+        assert_eq!(enabled, ConsoleOutput::Entities(vec![moving, corpse]));
+        let everything = console
+            .execute(&mut world, "query Position --disabled")
+            .unwrap();
+        assert_eq!(
+            everything,
+            ConsoleOutput::Entities(vec![moving, corpse, still])
+        );
+    }
+
+    #[test]
+    fn get_reads_values_and_prints_literals() {
+        let console = console();
+        let mut world = DynWorld::new();
+        let entity = spawned(
+            console
+                .execute(&mut world, "spawn Position{x: 1.5, y: -2} Health(7)")
+                .unwrap(),
+        );
+        let handle = ConsoleOutput::entity_text(entity);
+        let output = console
+            .execute(&mut world, &format!("get {handle} Health"))
+            .unwrap();
+        assert_eq!(
+            output,
+            ConsoleOutput::Components {
+                entity,
+                components: vec![ComponentValue {
+                    name: std::any::type_name::<Health>(),
+                    value: Some(ConsoleValue::Integer(7)),
+                }],
+            }
+        );
+        let all = console
+            .execute(&mut world, &format!("get {}", entity.id))
+            .unwrap();
+        assert_eq!(
+            all.to_string(),
+            format!("{handle} Position{{x: 1.5, y: -2.0}} Health(7)")
+        );
+
+        let missing = console.execute(&mut world, &format!("get {handle} Velocity"));
+        assert!(matches!(
+            missing,
+            Err(ConsoleError::MissingComponent { .. })
+        ));
+    }
+
+    #[test]
+    fn set_and_remove_edit_existing_entities() {
+        let console = console();
+        let mut world = DynWorld::new();
+        let entity = spawned(
+            console
+                .execute(&mut world, "spawn Position{x: 1, y: 2}")
+                .unwrap(),
+        );
+        let handle = ConsoleOutput::entity_text(entity);
+        console
+            .execute(
+                &mut world,
+                &format!("set {handle} Position{{y: 5}} Mood{{Angry: {{level: 3}}}}"),
+            )
+            .unwrap();
+        assert_eq!(
+            world.get::<Position>(entity),
+            Some(&Position { x: 1.0, y: 5.0 })
+        );
+        assert_eq!(world.get::<Mood>(entity), Some(&Mood::Angry { level: 3 }));
+        console
+            .execute(&mut world, &format!("set {handle} Mood(Calm)"))
+            .unwrap();
+        assert_eq!(world.get::<Mood>(entity), Some(&Mood::Calm));
+
+        console
+            .execute(&mut world, &format!("remove {handle} Mood"))
+            .unwrap();
+        assert!(!world.has::<Mood>(entity));
+
+        let typo = console.execute(&mut world, &format!("set {handle} Position{{z: 1}}"));
+        assert_eq!(typo, Err(ConsoleError::UnknownField("z".to_string())));
+    }
+
+    #[test]
+    fn tags_despawn_and_dead_handles() {
+        let console = console();
+        let mut world = DynWorld::new();
+        let entity = spawned(console.execute(&mut world, "spawn Position").unwrap());
+        let handle = ConsoleOutput::entity_text(entity);
+        console
+            .execute(&mut world, &format!("tag add {handle} Player"))
+            .unwrap();
+        assert!(world.has_tag_type::<Player>(entity));
+        console
+            .execute(&mut world, &format!("tag remove {handle} Player"))
+            .unwrap();
+        assert!(!world.has_tag_type::<Player>(entity));
+
+        let output = console
+            .execute(&mut world, &format!("despawn {handle}"))
+            .unwrap();
+        assert_eq!(output, ConsoleOutput::Despawned(entity));
+        assert!(!world.is_alive(entity));
+        assert_eq!(
+            console.execute(&mut world, &format!("despawn {handle}")),
+            Err(ConsoleError::DeadEntity(entity))
+        );
+    }
+
+    #[test]
+    fn resources_set_and_get_by_name() {
+        let console = console();
+        let mut world = DynWorld::new();
+        console
+            .execute(&mut world, "res set DeltaTime 0.016")
+            .unwrap();
+        assert_eq!(world.resource::<DeltaTime>(), Some(&DeltaTime(0.016)));
+        let output = console.execute(&mut world, "res get DeltaTime").unwrap();
+        assert_eq!(output.to_string(), "DeltaTime(0.016)");
+        world.remove_resource::<DeltaTime>();
+        assert!(matches!(
+            console.execute(&mut world, "res get DeltaTime"),
+            Err(ConsoleError::MissingResource(_))
+        ));
+    }
+
+    #[test]
+    fn names_resolve_through_the_world_registry() {
+        let console = console();
+        let mut world = DynWorld::new();
+        world.register::<Unregistered>();
+        let entity = spawned(
+            console
+                .execute(&mut world, "spawn Position Unregistered")
+                .unwrap(),
+        );
+        assert!(world.has::<Unregistered>(entity));
+        let output = console.execute(&mut world, "query Unregistered").unwrap();
+        assert_eq!(output, ConsoleOutput::Entities(vec![entity]));
+        assert_eq!(
+            console.execute(&mut world, "spawn Unregistered(1)"),
+            Err(ConsoleError::NoTextForm(
+                std::any::type_name::<Unregistered>().to_string()
+            ))
+        );
+        let full_path = format!("query {}", std::any::type_name::<Position>());
+        assert_eq!(
+            console.execute(&mut world, &full_path).unwrap(),
+            ConsoleOutput::Entities(vec![entity])
+        );
+        assert_eq!(
+            console.execute(&mut world, "query Nothing"),
+            Err(ConsoleError::UnknownName("Nothing".to_string()))
+        );
+    }
+
+    #[test]
+    fn failed_spawn_leaves_no_entity_behind() {
+        let console = console();
+        let mut world = DynWorld::new();
+        let result = console.execute(&mut world, "spawn Position Health(\"lots\")");
+        assert!(matches!(result, Err(ConsoleError::Value(_))));
+        assert_eq!(world.entity_count(), 0);
+    }
+
+    #[test]
+    fn scripts_report_the_failing_line() {
+        let console = console();
+        let mut world = DynWorld::new();
+        let outputs = console
+            .execute_script(
+                &mut world,
+                "# setup\nspawn Position Player\n\nres set DeltaTime 0.5",
+            )
+            .unwrap();
+        assert_eq!(outputs.len(), 4);
+        assert_eq!(world.resource::<DeltaTime>(), Some(&DeltaTime(0.5)));
+
+        let error = console
+            .execute_script(&mut world, "spawn Position\nfly away")
+            .unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.error, ConsoleError::UnknownCommand("fly".to_string()));
+    }
+
+    #[test]
+    fn values_round_trip_through_their_printed_form() {
+        let value = to_value(&Mood::Angry { level: 9 }).unwrap();
+        let printed = value.to_string();
+        assert_eq!(printed, "{Angry: {level: 9}}");
+        let mut parser = Parser::new(tokenize(&printed).unwrap());
+        let reparsed = parser.value().unwrap();
+        assert_eq!(
+            from_value::<Mood>(reparsed).unwrap(),
+            Mood::Angry { level: 9 }
+        );
+
+        let text = to_value(&"two words").unwrap();
+        assert_eq!(text.to_string(), "\"two words\"");
+        let list = to_value(&vec![Some(1u8), None]).unwrap();
+        assert_eq!(list.to_string(), "[1, ()]");
+        assert_eq!(
+            from_value::<Vec<Option<u8>>>(list).unwrap(),
+            vec![Some(1), None]
+        );
+    }
+}
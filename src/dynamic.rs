@@ -94,16 +94,22 @@
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use std::marker::PhantomData;
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::{AtomicU32, AtomicU64, AtomicUsize, Ordering};
 
+use crate::system_param::IntoSystem;
 use crate::{
     ArchetypeEdges, ArchetypeRouting, Entity, EntityAllocator, EntityLocation, EntityLocations,
-    EventChannel, STRUCTURAL_LOG_CAPACITY, SparseTagSet, StructuralChange, StructuralChangeKind,
-    archetype_cached_tables, archetype_register_table, tick_is_newer,
+    EventChannel, MemoryUsage, STRUCTURAL_LOG_CAPACITY, SparseTagSet, StructuralBatch,
+    StructuralChange, StructuralChangeKind, SystemError, SystemResult, archetype_cached_tables,
+    archetype_register_table, sort_batch_rows, structural_change_entities, tick_is_newer,
 };
 
 static NEXT_REGISTRY_ID: AtomicU32 = AtomicU32::new(1);
 
+/// Source of [`DynWorld`] identities, which [`LiveQuery`] handles carry so
+/// a handle used on a world that did not issue it is caught.
+static NEXT_WORLD_ID: AtomicU64 = AtomicU64::new(1);
+
 /// Type-erased boxed storage for events and resources, which stay `Box<dyn
 /// Any>` regardless of the column storage backend.
 type BoxedAny = Box<dyn Any + Send + Sync>;
@@ -172,7 +178,7 @@ pub struct ErasedColumn {
 }
 
 impl ErasedColumn {
-    fn new<T: Send + Sync + Default + 'static>() -> Self {
+    fn new<T: Send + Sync + 'static>() -> Self {
         ErasedColumn {
             #[cfg(not(feature = "raw_storage"))]
             storage: Box::new(Vec::<T>::new()),
@@ -181,6 +187,43 @@ impl ErasedColumn {
         }
     }
 
+    /// A column of `layout`'s rows, stride-packed bytes on either backend.
+    fn for_layout(layout: &'static ComponentLayout) -> Self {
+        let rows = raw_storage::RawColumn::for_layout(layout);
+        ErasedColumn {
+            #[cfg(not(feature = "raw_storage"))]
+            storage: Box::new(rows),
+            #[cfg(feature = "raw_storage")]
+            storage: rows,
+        }
+    }
+
+    fn layout_rows(&self) -> &raw_storage::RawColumn {
+        #[cfg(not(feature = "raw_storage"))]
+        {
+            self.storage
+                .downcast_ref::<raw_storage::RawColumn>()
+                .expect("layout access on a column of a Rust type")
+        }
+        #[cfg(feature = "raw_storage")]
+        {
+            &self.storage
+        }
+    }
+
+    fn layout_rows_mut(&mut self) -> &mut raw_storage::RawColumn {
+        #[cfg(not(feature = "raw_storage"))]
+        {
+            self.storage
+                .downcast_mut::<raw_storage::RawColumn>()
+                .expect("layout access on a column of a Rust type")
+        }
+        #[cfg(feature = "raw_storage")]
+        {
+            &mut self.storage
+        }
+    }
+
     #[inline]
     fn slice<T: 'static>(&self) -> &[T] {
         #[cfg(not(feature = "raw_storage"))]
@@ -225,11 +268,7 @@ impl ErasedColumn {
         }
     }
 
-    fn extend_clone<T: Send + Sync + Default + Clone + 'static>(
-        &mut self,
-        count: usize,
-        value: &T,
-    ) {
+    fn extend_clone<T: ComponentData + Clone>(&mut self, count: usize, value: &T) {
         #[cfg(not(feature = "raw_storage"))]
         {
             let column = self
@@ -247,7 +286,32 @@ impl ErasedColumn {
         }
     }
 
-    fn push_defaults<T: Send + Sync + Default + 'static>(&mut self, count: usize) {
+    /// Moves a whole `Vec<T>` onto the end of the column.
+    fn append<T: 'static>(&mut self, mut values: Vec<T>) {
+        #[cfg(not(feature = "raw_storage"))]
+        {
+            self.storage
+                .downcast_mut::<Vec<T>>()
+                .expect("column type does not match its registered component")
+                .append(&mut values);
+        }
+        #[cfg(feature = "raw_storage")]
+        {
+            let count = values.len();
+            let mut values = values.drain(..);
+            self.storage.extend_with::<T>(count, || {
+                values.next().expect("drained exactly count values")
+            });
+        }
+    }
+
+    fn push_defaults<T: ComponentData>(&mut self, count: usize) {
+        let make = T::DEFAULT.unwrap_or_else(|| {
+            panic!(
+                "{} has no default value; supply one instead of adding it by mask",
+                std::any::type_name::<T>()
+            )
+        });
         #[cfg(not(feature = "raw_storage"))]
         {
             let column = self
@@ -256,16 +320,31 @@ impl ErasedColumn {
                 .expect("column type does not match its registered component");
             column.reserve(count);
             for _ in 0..count {
-                column.push(T::default());
+                column.push(make());
             }
         }
         #[cfg(feature = "raw_storage")]
         {
-            self.storage.extend_default::<T>(count);
+            self.storage.extend_with::<T>(count, make);
+        }
+    }
+
+    /// Moves the last row's value out by ownership.
+    fn pop<T: 'static>(&mut self) -> Option<T> {
+        #[cfg(not(feature = "raw_storage"))]
+        {
+            self.storage
+                .downcast_mut::<Vec<T>>()
+                .expect("column type does not match its registered component")
+                .pop()
+        }
+        #[cfg(feature = "raw_storage")]
+        {
+            self.storage.pop::<T>()
         }
     }
 
-    fn swap_remove<T: Send + Sync + Default + 'static>(&mut self, index: usize) {
+    fn swap_remove<T: Send + Sync + 'static>(&mut self, index: usize) {
         #[cfg(not(feature = "raw_storage"))]
         {
             self.storage
@@ -280,16 +359,7 @@ impl ErasedColumn {
         }
     }
 
-    fn move_row<T: Send + Sync + Default + 'static>(
-        &mut self,
-        index: usize,
-        destination: &mut ErasedColumn,
-    ) {
-        let value = std::mem::take(&mut self.slice_mut::<T>()[index]);
-        destination.push::<T>(value);
-    }
-
-    fn swap_remove_into<T: Send + Sync + Default + 'static>(
+    fn swap_remove_into<T: Send + Sync + 'static>(
         &mut self,
         index: usize,
         destination: &mut ErasedColumn,
@@ -327,6 +397,10 @@ impl ErasedColumn {
             .swap_remove_into(index, &mut destination.storage);
     }
 
+    fn clone_row_into<T: Clone + 'static>(&self, index: usize, destination: &mut ErasedColumn) {
+        destination.push::<T>(self.slice::<T>()[index].clone());
+    }
+
     fn len<T: 'static>(&self) -> usize {
         #[cfg(not(feature = "raw_storage"))]
         {
@@ -341,20 +415,54 @@ impl ErasedColumn {
             self.storage.len()
         }
     }
+
+    fn capacity<T: 'static>(&self) -> usize {
+        #[cfg(not(feature = "raw_storage"))]
+        {
+            self.storage
+                .downcast_ref::<Vec<T>>()
+                .expect("column type does not match its registered component")
+                .capacity()
+        }
+        #[cfg(feature = "raw_storage")]
+        {
+            let _ = std::marker::PhantomData::<T>;
+            self.storage.capacity()
+        }
+    }
+
+    fn shrink_to_fit<T: 'static>(&mut self) {
+        #[cfg(not(feature = "raw_storage"))]
+        {
+            self.storage
+                .downcast_mut::<Vec<T>>()
+                .expect("column type does not match its registered component")
+                .shrink_to_fit();
+        }
+        #[cfg(feature = "raw_storage")]
+        {
+            let _ = std::marker::PhantomData::<T>;
+            self.storage.shrink_to_fit();
+        }
+    }
 }
 
-/// The contiguous storage backend used when the `raw_storage` feature is on.
-/// Every `unsafe` in that backend is contained in this module. The invariant
-/// upheld by [`ErasedColumn`] is narrow: each method is called with the exact
-/// `T` the column was registered for, so size, alignment, and drop glue always
+/// The contiguous storage backend used when the `raw_storage` feature is on,
+/// and the byte storage of layout components on either backend. Every
+/// `unsafe` in that storage is contained in this module. The invariant upheld
+/// by [`ErasedColumn`] is narrow: each typed method is called with the exact
+/// `T` the column was registered for, and each layout method only on a column
+/// built from a [`ComponentLayout`], so size, alignment, and drop glue always
 /// match the bytes it holds.
-#[cfg(feature = "raw_storage")]
+#[cfg_attr(not(feature = "raw_storage"), allow(dead_code))]
 mod raw_storage {
     use std::alloc::{self, Layout};
     use std::cell::RefCell;
     use std::collections::HashMap;
     use std::ptr::{self, NonNull};
 
+    use super::ComponentLayout;
+
     /// A thread-local free list of raw column allocations, bucketed by
     /// element size and alignment. Dropping a column returns its buffer here
     /// instead of to the system allocator, so the next column of the same
@@ -370,6 +478,11 @@ mod raw_storage {
 
     const MAX_POOLED_PER_BUCKET: usize = 64;
 
+    /// Only the `raw_storage` backend pools buffers. On the default backend
+    /// this module holds nothing but layout columns, whose buffers go
+    /// straight back to the system allocator.
+    const POOLING: bool = cfg!(feature = "raw_storage");
+
     impl Drop for BufferPool {
         fn drop(&mut self) {
             for ((item_size, item_align), bucket) in self.buckets.drain() {
@@ -398,6 +511,9 @@ mod raw_storage {
         item_align: usize,
         required: usize,
     ) -> Option<(NonNull<u8>, usize)> {
+        if !POOLING {
+            return None;
+        }
         POOL.with(|pool| {
             let mut pool = pool.borrow_mut();
             let bucket = pool.buckets.get_mut(&(item_size, item_align))?;
@@ -415,19 +531,62 @@ mod raw_storage {
     /// Returns a buffer to the pool, or deallocates it when the bucket is
     /// already at capacity.
     fn pool_return(pointer: NonNull<u8>, item_size: usize, item_align: usize, capacity: usize) {
+        let release = || {
+            let layout = Layout::from_size_align(item_size * capacity, item_align)
+                .expect("pooled column layout overflow");
+            unsafe { alloc::dealloc(pointer.as_ptr(), layout) };
+        };
+        if !POOLING {
+            release();
+            return;
+        }
         POOL.with(|pool| {
             let mut pool = pool.borrow_mut();
             let bucket = pool.buckets.entry((item_size, item_align)).or_default();
             if bucket.len() < MAX_POOLED_PER_BUCKET {
                 bucket.push((pointer, capacity));
             } else {
-                let layout = Layout::from_size_align(item_size * capacity, item_align)
-                    .expect("pooled column layout overflow");
-                unsafe { alloc::dealloc(pointer.as_ptr(), layout) };
+                release();
             }
         });
     }
 
+    /// Bytes held by this thread's pool, waiting to be reused.
+    #[cfg(feature = "raw_storage")]
+    pub(crate) fn pool_held_bytes() -> usize {
+        POOL.with(|pool| {
+            pool.borrow()
+                .buckets
+                .iter()
+                .map(|(&(item_size, _), bucket)| {
+                    bucket
+                        .iter()
+                        .map(|&(_, capacity)| item_size * capacity)
+                        .sum::<usize>()
+                })
+                .sum()
+        })
+    }
+
+    /// Deallocates every buffer in this thread's pool, returning the bytes
+    /// released.
+    #[cfg(feature = "raw_storage")]
+    pub(crate) fn pool_release() -> usize {
+        POOL.with(|pool| {
+            let mut pool = pool.borrow_mut();
+            let mut released = 0;
+            for ((item_size, item_align), bucket) in pool.buckets.drain() {
+                for (pointer, capacity) in bucket {
+                    let layout = Layout::from_size_align(item_size * capacity, item_align)
+                        .expect("pooled column layout overflow");
+                    unsafe { alloc::dealloc(pointer.as_ptr(), layout) };
+                    released += layout.size();
+                }
+            }
+            released
+        })
+    }
+
     pub(crate) struct RawColumn {
         pointer: NonNull<u8>,
         len: usize,
@@ -435,6 +594,10 @@ mod raw_storage {
         item_size: usize,
         item_align: usize,
         drop_fn: Option<unsafe fn(*mut u8)>,
+        /// The descriptor of a layout column, whose rows hold the layout's
+        /// strings followed by its packed fixed-size image. It supplies the
+        /// drop glue `drop_fn` does for a typed column.
+        row_layout: Option<&'static ComponentLayout>,
     }
 
     // A column only ever holds a registered component, and every registered
@@ -457,7 +620,131 @@ mod raw_storage {
                 item_align: layout.align(),
                 drop_fn: std::mem::needs_drop::<T>()
                     .then_some(drop_in_place_as::<T> as unsafe fn(*mut u8)),
+                row_layout: None,
+            }
+        }
+
+        /// A column of `layout`'s rows: its strings first, at their natural
+        /// alignment, then the packed fixed-size image, padded to a stride
+        /// that keeps the next row's strings aligned.
+        pub(crate) fn for_layout(layout: &'static ComponentLayout) -> Self {
+            let item_align = if layout.string_count == 0 {
+                1
+            } else {
+                std::mem::align_of::<String>()
+            };
+            let strings_size = layout.string_count * std::mem::size_of::<String>();
+            RawColumn {
+                pointer: NonNull::new(item_align as *mut u8).expect("alignment is never zero"),
+                len: 0,
+                capacity: 0,
+                item_size: (strings_size + layout.size).next_multiple_of(item_align),
+                item_align,
+                drop_fn: None,
+                row_layout: Some(layout),
+            }
+        }
+
+        /// Bytes per row, padding included.
+        pub(crate) fn item_size(&self) -> usize {
+            self.item_size
+        }
+
+        /// Runs one element's drop glue: its type's, or its layout's strings.
+        ///
+        /// # Safety
+        /// `index` must hold an initialized element.
+        unsafe fn drop_element(&self, index: usize) {
+            let element = self.element_pointer(index);
+            if let Some(drop_fn) = self.drop_fn {
+                unsafe { drop_fn(element) }
+            } else if let Some(layout) = self.row_layout
+                && layout.string_count != 0
+            {
+                unsafe {
+                    ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                        element.cast::<String>(),
+                        layout.string_count,
+                    ))
+                }
+            }
+        }
+
+        fn layout(&self) -> &'static ComponentLayout {
+            self.row_layout
+                .expect("layout row access on a column of a Rust type")
+        }
+
+        /// Appends a layout row with every string empty and every byte zero.
+        pub(crate) fn push_blank_row(&mut self) {
+            let layout = self.layout();
+            if self.item_size != 0 {
+                self.reserve(1);
+                let element = self.element_pointer(self.len);
+                unsafe {
+                    ptr::write_bytes(element, 0, self.item_size);
+                    for slot in 0..layout.string_count {
+                        ptr::write(element.cast::<String>().add(slot), String::new());
+                    }
+                }
+            }
+            self.len += 1;
+        }
+
+        /// One layout row's strings and packed fixed-size image.
+        pub(crate) fn layout_row(&self, index: usize) -> (&[String], &[u8]) {
+            let layout = self.layout();
+            assert!(index < self.len, "layout row index out of bounds");
+            let element = self.element_pointer(index);
+            let strings: &[String] = if layout.string_count == 0 {
+                &[]
+            } else {
+                unsafe { std::slice::from_raw_parts(element.cast::<String>(), layout.string_count) }
+            };
+            let image = unsafe {
+                std::slice::from_raw_parts(
+                    element.add(layout.string_count * std::mem::size_of::<String>()),
+                    layout.size,
+                )
+            };
+            (strings, image)
+        }
+
+        /// [`layout_row`](Self::layout_row), mutably.
+        pub(crate) fn layout_row_mut(&mut self, index: usize) -> (&mut [String], &mut [u8]) {
+            let layout = self.layout();
+            assert!(index < self.len, "layout row index out of bounds");
+            let element = self.element_pointer(index);
+            let strings: &mut [String] = if layout.string_count == 0 {
+                &mut []
+            } else {
+                unsafe {
+                    std::slice::from_raw_parts_mut(element.cast::<String>(), layout.string_count)
+                }
+            };
+            let image = unsafe {
+                std::slice::from_raw_parts_mut(
+                    element.add(layout.string_count * std::mem::size_of::<String>()),
+                    layout.size,
+                )
+            };
+            (strings, image)
+        }
+
+        /// Reorders the column so row `i` holds what row `order[i]` held,
+        /// moving whole elements as bytes.
+        pub(crate) fn permute(&mut self, order: &[usize]) {
+            if self.item_size == 0 || self.len == 0 {
+                return;
             }
+            debug_assert_eq!(self.len, order.len(), "row order must cover every row");
+            crate::apply_row_order_with(order, |current, source| unsafe {
+                ptr::swap_nonoverlapping(
+                    self.element_pointer(current),
+                    self.element_pointer(source),
+                    self.item_size,
+                )
+            });
         }
 
         #[inline]
@@ -465,6 +752,36 @@ mod raw_storage {
             self.len
         }
 
+        pub(crate) fn capacity(&self) -> usize {
+            if self.item_size == 0 {
+                usize::MAX
+            } else {
+                self.capacity
+            }
+        }
+
+        /// Reallocates down to exactly `len` elements, or frees the buffer
+        /// outright when the column is empty. Bypasses the pool on purpose:
+        /// the point is to hand the bytes back.
+        pub(crate) fn shrink_to_fit(&mut self) {
+            if self.item_size == 0 || self.capacity == self.len {
+                return;
+            }
+            let old_layout = self.layout_for(self.capacity);
+            if self.len == 0 {
+                unsafe { alloc::dealloc(self.pointer.as_ptr(), old_layout) };
+                self.pointer =
+                    NonNull::new(self.item_align as *mut u8).expect("alignment is never zero");
+            } else {
+                let new_layout = self.layout_for(self.len);
+                let shrunk =
+                    unsafe { alloc::realloc(self.pointer.as_ptr(), old_layout, new_layout.size()) };
+                self.pointer =
+                    NonNull::new(shrunk).unwrap_or_else(|| alloc::handle_alloc_error(new_layout));
+            }
+            self.capacity = self.len;
+        }
+
         #[inline]
         fn element_pointer(&self, index: usize) -> *mut u8 {
             unsafe { self.pointer.as_ptr().add(index * self.item_size) }
@@ -546,7 +863,11 @@ mod raw_storage {
         /// around it hides the stride from the optimizer. Reserving once and
         /// writing through a `*mut T` gives the same codegen `Vec<T>` gets,
         /// which is what the bulk spawn path wants.
-        fn extend_with<T: 'static>(&mut self, count: usize, mut make: impl FnMut() -> T) {
+        pub(crate) fn extend_with<T: 'static>(
+            &mut self,
+            count: usize,
+            mut make: impl FnMut() -> T,
+        ) {
             if count == 0 {
                 return;
             }
@@ -569,15 +890,17 @@ mod raw_storage {
             self.extend_with(count, || value.clone());
         }
 
-        pub(crate) fn extend_default<T: Default + 'static>(&mut self, count: usize) {
-            self.extend_with(count, T::default);
+        pub(crate) fn pop<T: 'static>(&mut self) -> Option<T> {
+            if self.len == 0 {
+                return None;
+            }
+            self.len -= 1;
+            Some(unsafe { ptr::read(self.element_pointer(self.len).cast::<T>()) })
         }
 
         pub(crate) fn swap_remove(&mut self, index: usize) {
             assert!(index < self.len, "column swap_remove index out of bounds");
-            if let Some(drop_fn) = self.drop_fn {
-                unsafe { drop_fn(self.element_pointer(index)) }
-            }
+            unsafe { self.drop_element(index) }
             self.len -= 1;
             if index != self.len && self.item_size != 0 {
                 unsafe {
@@ -621,9 +944,9 @@ mod raw_storage {
 
     impl Drop for RawColumn {
         fn drop(&mut self) {
-            if let Some(drop_fn) = self.drop_fn {
+            if self.drop_fn.is_some() || self.row_layout.is_some() {
                 for index in 0..self.len {
-                    unsafe { drop_fn(self.element_pointer(index)) }
+                    unsafe { self.drop_element(index) }
                 }
             }
             if self.item_size != 0 && self.capacity != 0 {
@@ -633,7 +956,7 @@ mod raw_storage {
     }
 }
 
-fn column_new<T: Send + Sync + Default + 'static>() -> ErasedColumn {
+fn column_new<T: Send + Sync + 'static>(_info: &ComponentInfo) -> ErasedColumn {
     ErasedColumn::new::<T>()
 }
 
@@ -645,107 +968,738 @@ fn column_vec_mut<T: 'static>(column: &mut ErasedColumn) -> &mut [T] {
     column.slice_mut::<T>()
 }
 
-fn column_push_default<T: Send + Sync + Default + 'static>(
-    column: &mut ErasedColumn,
-    count: usize,
-) {
+fn column_push_default<T: ComponentData>(column: &mut ErasedColumn, count: usize) {
     column.push_defaults::<T>(count);
 }
 
-fn column_len_of<T: Send + Sync + Default + 'static>(column: &ErasedColumn) -> usize {
+fn column_len_of<T: Send + Sync + 'static>(column: &ErasedColumn) -> usize {
     column.len::<T>()
 }
 
-fn column_swap_remove<T: Send + Sync + Default + 'static>(column: &mut ErasedColumn, index: usize) {
+fn column_swap_remove<T: Send + Sync + 'static>(column: &mut ErasedColumn, index: usize) {
     column.swap_remove::<T>(index);
 }
 
-fn column_move_row<T: Send + Sync + Default + 'static>(
+fn column_swap_remove_into<T: Send + Sync + 'static>(
     source: &mut ErasedColumn,
     index: usize,
     destination: &mut ErasedColumn,
 ) {
-    source.move_row::<T>(index, destination);
+    source.swap_remove_into::<T>(index, destination);
 }
 
-fn column_swap_remove_into<T: Send + Sync + Default + 'static>(
-    source: &mut ErasedColumn,
+fn column_memory_of<T: Send + Sync + 'static>(column: &ErasedColumn) -> MemoryUsage {
+    MemoryUsage::of_elements::<T>(column.len::<T>(), column.capacity::<T>())
+}
+
+fn column_shrink<T: 'static>(column: &mut ErasedColumn) {
+    column.shrink_to_fit::<T>();
+}
+
+fn column_permute_rows<T: 'static>(column: &mut ErasedColumn, order: &[usize]) {
+    crate::apply_row_order(column.slice_mut::<T>(), order);
+}
+
+fn column_clone_row<T: Clone + 'static>(
+    source: &ErasedColumn,
     index: usize,
     destination: &mut ErasedColumn,
 ) {
-    source.swap_remove_into::<T>(index, destination);
+    source.clone_row_into::<T>(index, destination);
 }
 
-/// The per-type operations a column needs, as a plain record of function
-/// pointers captured at registration. This is the vtable, visible as data.
-#[derive(Clone, Copy)]
-pub struct ComponentInfo {
-    pub type_id: TypeId,
-    pub type_name: &'static str,
-    pub mask: u64,
-    pub new_column: fn() -> ErasedColumn,
-    pub push_default: fn(&mut ErasedColumn, usize),
-    pub swap_remove: fn(&mut ErasedColumn, usize),
-    pub move_row: fn(&mut ErasedColumn, usize, &mut ErasedColumn),
-    pub swap_remove_into: fn(&mut ErasedColumn, usize, &mut ErasedColumn),
-    pub column_len: fn(&ErasedColumn) -> usize,
+fn layout_column_new(info: &ComponentInfo) -> ErasedColumn {
+    ErasedColumn::for_layout(info.layout.expect("layout records carry their layout"))
 }
 
-/// A typed handle to a registered component: the component's index, its mask
-/// bit, and the registry it belongs to. Copyable plain data; holding one
-/// skips the `TypeId` lookup the lazy typed API pays per call.
-pub struct ComponentKey<T> {
-    pub component_index: u32,
-    pub mask: u64,
-    pub registry_id: u32,
-    marker: PhantomData<fn() -> T>,
+fn layout_len(column: &ErasedColumn) -> usize {
+    column.layout_rows().len()
 }
 
-impl<T> Clone for ComponentKey<T> {
-    fn clone(&self) -> Self {
-        *self
-    }
+fn layout_swap_remove(column: &mut ErasedColumn, index: usize) {
+    column.layout_rows_mut().swap_remove(index);
 }
 
-impl<T> std::fmt::Debug for ComponentKey<T> {
-    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        formatter
-            .debug_struct("ComponentKey")
-            .field("component_index", &self.component_index)
-            .field("mask", &self.mask)
-            .field("registry_id", &self.registry_id)
-            .finish()
+fn layout_swap_remove_into(
+    source: &mut ErasedColumn,
+    index: usize,
+    destination: &mut ErasedColumn,
+) {
+    source
+        .layout_rows_mut()
+        .swap_remove_into(index, destination.layout_rows_mut());
+}
+
+fn layout_permute_rows(column: &mut ErasedColumn, order: &[usize]) {
+    column.layout_rows_mut().permute(order);
+}
+
+fn layout_memory(column: &ErasedColumn) -> MemoryUsage {
+    let rows = column.layout_rows();
+    MemoryUsage {
+        live_bytes: rows.len() * rows.item_size(),
+        capacity_bytes: rows.capacity().saturating_mul(rows.item_size()),
     }
 }
 
-impl<T> Copy for ComponentKey<T> {}
+fn layout_shrink(column: &mut ErasedColumn) {
+    column.layout_rows_mut().shrink_to_fit();
+}
 
-/// A handle to a registered tag. Tag mask bits are assigned from the top of
-/// the `u64` downward, so they never collide with component bits.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct TagKey {
-    pub tag_index: u32,
-    pub mask: u64,
-    pub registry_id: u32,
+fn layout_clone_row(source: &ErasedColumn, index: usize, destination: &mut ErasedColumn) {
+    let (strings, image) = source.layout_rows().layout_row(index);
+    let destination = destination.layout_rows_mut();
+    destination.push_blank_row();
+    let (copied_strings, copied_image) = destination.layout_row_mut(destination.len() - 1);
+    copied_strings.clone_from_slice(strings);
+    copied_image.copy_from_slice(image);
 }
 
-/// The component and tag schema for dynamic worlds. Bits are assigned in
-/// registration order, so a registry built once and shared across worlds
-/// guarantees every world agrees on masks.
-#[derive(Clone)]
-pub struct ComponentRegistry {
-    pub registry_id: u32,
-    pub components: Vec<ComponentInfo>,
-    pub components_by_type: TypeIdMap<u32>,
-    pub tag_count: u32,
-    pub tags_by_type: TypeIdMap<u32>,
+/// One cloned component waiting for a row: its registry record (and codec,
+/// so a destination registry that adopts the type can still snapshot it)
+/// and a one-row column holding the clone.
+struct StagedComponent {
+    info: ComponentInfo,
     #[cfg(feature = "snapshot")]
-    pub codecs: Vec<Option<ComponentCodec>>,
-    /// One-entry cache of the most recently resolved component type. A hot
-    /// loop of `set`/`remove` over one component type hits this on every call
-    /// after the first, resolving through a `TypeId` equality instead of a map
-    /// probe. Never wrong: a miss just falls through to the map.
-    recent_component: Option<(TypeId, u32)>,
+    codec: Option<ComponentCodec>,
+    value: ErasedColumn,
+}
+
+/// An entity's cloned components plus its tags, as tag indices with the
+/// marker type behind each when it has one.
+#[derive(Default)]
+struct StagedRow {
+    components: Vec<StagedComponent>,
+    tags: Vec<(u32, Option<TypeId>)>,
+}
+
+/// Components moved out of an entity by a bundle take, one single-row column
+/// per component index, for [`Bundle::take`] to claim by type.
+#[derive(Default)]
+pub struct TakenComponents {
+    columns: Vec<(u32, ErasedColumn)>,
+}
+
+impl TakenComponents {
+    fn claim<T: 'static>(&mut self, component_index: u32) -> T {
+        let position = self
+            .columns
+            .iter()
+            .position(|&(index, _)| index == component_index)
+            .expect("take requires the component to be present");
+        let (_, mut column) = self.columns.swap_remove(position);
+        column
+            .pop::<T>()
+            .expect("a taken column holds exactly one row")
+    }
+}
+
+/// Whole component columns for [`DynWorld::spawn_columns`]: one `Vec<T>` per
+/// component, every one the same length, moved into the destination table
+/// as-is with no per-row callback. Row `i` of each column lands on the `i`th
+/// spawned entity.
+///
+/// ```
+/// use freecs::dynamic::{ColumnBatch, DynWorld};
+///
+/// #[derive(Default, Clone)]
+/// struct Position { x: f32 }
+/// #[derive(Default, Clone)]
+/// struct Velocity { x: f32 }
+///
+/// let mut world = DynWorld::new();
+/// let batch = ColumnBatch::new()
+///     .column(vec![Position { x: 0.0 }, Position { x: 1.0 }])
+///     .column(vec![Velocity { x: 2.0 }, Velocity { x: 3.0 }]);
+/// let entities = world.spawn_columns(batch);
+/// assert_eq!(world.get::<Velocity>(entities[1]).unwrap().x, 3.0);
+/// ```
+#[derive(Default)]
+pub struct ColumnBatch {
+    rows: Option<usize>,
+    columns: Vec<StagedColumn>,
+}
+
+/// One column of a [`ColumnBatch`], erased until the world registers its type.
+struct StagedColumn {
+    type_id: TypeId,
+    register: fn(&mut ComponentRegistry) -> u64,
+    append: fn(&mut ErasedColumn, BoxedAny),
+    values: BoxedAny,
+}
+
+fn staged_register<T: ComponentData>(registry: &mut ComponentRegistry) -> u64 {
+    registry.register::<T>().mask
+}
+
+fn staged_append<T: 'static>(column: &mut ErasedColumn, values: BoxedAny) {
+    let values = values
+        .downcast::<Vec<T>>()
+        .expect("staged column type does not match its component");
+    column.append::<T>(*values);
+}
+
+impl ColumnBatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds one component's column. Panics when the length differs from the
+    /// columns already added, or when the batch already has a column of `T`.
+    pub fn column<T: ComponentData>(mut self, values: Vec<T>) -> Self {
+        let type_name = std::any::type_name::<T>();
+        let rows = *self.rows.get_or_insert(values.len());
+        assert_eq!(
+            values.len(),
+            rows,
+            "column of {type_name} has {} rows but the batch has {rows}",
+            values.len()
+        );
+        assert!(
+            self.columns
+                .iter()
+                .all(|column| column.type_id != TypeId::of::<T>()),
+            "the batch already has a column of {type_name}"
+        );
+        self.columns.push(StagedColumn {
+            type_id: TypeId::of::<T>(),
+            register: staged_register::<T>,
+            append: staged_append::<T>,
+            values: Box::new(values),
+        });
+        self
+    }
+
+    /// How many entities the batch spawns.
+    pub fn len(&self) -> usize {
+        self.rows.unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// What a removal does when it would strip a component that another
+/// component left on the entity requires; see [`ComponentRegistry::require`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RequiredRemoval {
+    /// The removal is refused: the entity keeps every component and the call
+    /// reports false.
+    #[default]
+    Reject,
+    /// The dependents go too, transitively, in the same table move.
+    Cascade,
+}
+
+/// What happens to an entity's handle when it moves to another world or
+/// group, for [`DynWorld::transfer_entity`] and [`DynEcs::transfer_entity`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferHandle {
+    /// The destination allocates a fresh handle for the entity.
+    Remap,
+    /// The entity keeps its handle, so references to it stay valid. The
+    /// destination must not already have that id live, nor have retired it
+    /// at the same or a later generation.
+    Preserve,
+}
+
+/// Makes `entity`'s handle-to-be live in the destination allocator before
+/// anything moves, so a refused transfer leaves both sides untouched.
+fn claim_transfer_handle(
+    allocator: &mut EntityAllocator,
+    entity: Entity,
+    handle: TransferHandle,
+) -> Option<Entity> {
+    match handle {
+        TransferHandle::Remap => Some(allocator.allocate()),
+        TransferHandle::Preserve => allocator.try_revive(entity).then_some(entity),
+    }
+}
+
+/// The staged row bound for one destination world during a group transfer,
+/// merged across source worlds. Indices are reused only while every
+/// contributing source shares the destination's registry.
+fn route_staged(routed: &mut Option<(StagedRow, bool)>, same_registry: bool) -> &mut StagedRow {
+    let (staged, all_same_registry) = routed.get_or_insert_with(|| (StagedRow::default(), true));
+    *all_same_registry &= same_registry;
+    staged
+}
+
+/// The per-type operations a column needs, as a plain record of function
+/// pointers captured at registration. This is the vtable, visible as data.
+#[derive(Clone, Copy)]
+pub struct ComponentInfo {
+    pub type_id: TypeId,
+    pub type_name: &'static str,
+    pub mask: u64,
+    pub new_column: fn(&ComponentInfo) -> ErasedColumn,
+    /// Appends default rows. `None` for a type whose [`ComponentData`] has
+    /// no default, which can only arrive with a value in hand.
+    pub push_default: Option<fn(&mut ErasedColumn, usize)>,
+    pub swap_remove: fn(&mut ErasedColumn, usize),
+    pub swap_remove_into: fn(&mut ErasedColumn, usize, &mut ErasedColumn),
+    pub column_len: fn(&ErasedColumn) -> usize,
+    /// Reorders the column so row `i` holds what row `order[i]` held.
+    pub permute_rows: fn(&mut ErasedColumn, &[usize]),
+    /// The column's live and allocated bytes.
+    pub column_memory: fn(&ErasedColumn) -> MemoryUsage,
+    /// Releases the column's spare capacity.
+    pub shrink_column: fn(&mut ErasedColumn),
+    /// Pushes a clone of one row onto another column of the same type.
+    /// `None` until the type is registered through
+    /// [`ComponentRegistry::register_clone`] or spawned with
+    /// [`DynWorld::spawn_bundles`], which is what entity cloning needs.
+    pub clone_row: Option<fn(&ErasedColumn, usize, &mut ErasedColumn)>,
+    /// The descriptor of a component registered through
+    /// [`ComponentRegistry::register_layout`], whose column is packed bytes
+    /// only this layout can read. `None` for a Rust type.
+    pub layout: Option<&'static ComponentLayout>,
+}
+
+/// A type that can be stored as a component column. Every
+/// `Send + Sync + Default + 'static` type is one already, with `DEFAULT`
+/// pointing at `T::default`. A type with no sensible default, such as a
+/// handle, a non-empty collection, or an id newtype, implements it by hand
+/// with `DEFAULT` set to `None` (or through
+/// [`impl_component!`](crate::impl_component)'s `no_default` form). Such a
+/// component only ever arrives with a value: through `set`, `spawn`, or a
+/// bundle. Adding it by bare mask is refused, and migrations move its values
+/// by ownership on either storage backend.
+///
+/// ```rust
+/// use freecs::dynamic::{ComponentData, DynWorld};
+///
+/// struct TextureHandle(u32);
+///
+/// impl ComponentData for TextureHandle {
+///     const DEFAULT: Option<fn() -> Self> = None;
+/// }
+///
+/// let mut world = DynWorld::new();
+/// let entity = world.spawn_entities(0, 1)[0];
+/// world.set(entity, TextureHandle(7));
+/// assert_eq!(world.get::<TextureHandle>(entity).map(|handle| handle.0), Some(7));
+/// ```
+pub trait ComponentData: Send + Sync + Sized + 'static {
+    /// Builds the value a row gets when it gains this component without
+    /// one being supplied, or `None` when there is no such value.
+    const DEFAULT: Option<fn() -> Self>;
+}
+
+impl<T: Send + Sync + Default + 'static> ComponentData for T {
+    const DEFAULT: Option<fn() -> Self> = Some(T::default);
+}
+
+/// A typed handle to a registered component: the component's index, its mask
+/// bit, and the registry it belongs to. Copyable plain data; holding one
+/// skips the `TypeId` lookup the lazy typed API pays per call.
+pub struct ComponentKey<T> {
+    pub component_index: u32,
+    pub mask: u64,
+    pub registry_id: u32,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for ComponentKey<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> std::fmt::Debug for ComponentKey<T> {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("ComponentKey")
+            .field("component_index", &self.component_index)
+            .field("mask", &self.mask)
+            .field("registry_id", &self.registry_id)
+            .finish()
+    }
+}
+
+impl<T> Copy for ComponentKey<T> {}
+
+/// A handle to a registered tag. Tag mask bits are assigned from the top of
+/// the `u64` downward, so they never collide with component bits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TagKey {
+    pub tag_index: u32,
+    pub mask: u64,
+    pub registry_id: u32,
+}
+
+/// The type of one field in a [`ComponentLayout`]. Fixed-size fields pack
+/// little-endian into the row's byte image with no padding; strings sit
+/// ahead of the image in the row, owned and dropped through the layout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FieldType {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    F32,
+    F64,
+    /// A fixed-length array of a fixed-size element type.
+    Array(Box<FieldType>, usize),
+    String,
+}
+
+impl FieldType {
+    /// Bytes the field takes in the row image; `None` for a string.
+    pub fn size(&self) -> Option<usize> {
+        match self {
+            FieldType::Bool | FieldType::U8 | FieldType::I8 => Some(1),
+            FieldType::U16 | FieldType::I16 => Some(2),
+            FieldType::U32 | FieldType::I32 | FieldType::F32 => Some(4),
+            FieldType::U64 | FieldType::I64 | FieldType::F64 => Some(8),
+            FieldType::Array(element, length) => element.size().map(|size| size * length),
+            FieldType::String => None,
+        }
+    }
+
+    fn read(&self, bytes: &[u8]) -> FieldValue {
+        fn take<const N: usize>(bytes: &[u8]) -> [u8; N] {
+            bytes[..N].try_into().expect("field slice matches its size")
+        }
+        match self {
+            FieldType::Bool => FieldValue::Bool(bytes[0] != 0),
+            FieldType::U8 => FieldValue::U8(bytes[0]),
+            FieldType::U16 => FieldValue::U16(u16::from_le_bytes(take(bytes))),
+            FieldType::U32 => FieldValue::U32(u32::from_le_bytes(take(bytes))),
+            FieldType::U64 => FieldValue::U64(u64::from_le_bytes(take(bytes))),
+            FieldType::I8 => FieldValue::I8(bytes[0] as i8),
+            FieldType::I16 => FieldValue::I16(i16::from_le_bytes(take(bytes))),
+            FieldType::I32 => FieldValue::I32(i32::from_le_bytes(take(bytes))),
+            FieldType::I64 => FieldValue::I64(i64::from_le_bytes(take(bytes))),
+            FieldType::F32 => FieldValue::F32(f32::from_le_bytes(take(bytes))),
+            FieldType::F64 => FieldValue::F64(f64::from_le_bytes(take(bytes))),
+            FieldType::Array(element, length) => {
+                let stride = element.size().expect("array elements are fixed-size");
+                FieldValue::Array(
+                    (0..*length)
+                        .map(|index| element.read(&bytes[index * stride..]))
+                        .collect(),
+                )
+            }
+            FieldType::String => unreachable!("strings are stored out of line"),
+        }
+    }
+
+    /// Writes `value` into `bytes`, returning false when its shape does not
+    /// match this type. Nothing is written on a mismatch.
+    fn write(&self, bytes: &mut [u8], value: &FieldValue) -> bool {
+        if !self.accepts(value) {
+            return false;
+        }
+        match (self, value) {
+            (FieldType::Bool, FieldValue::Bool(value)) => bytes[0] = *value as u8,
+            (FieldType::U8, FieldValue::U8(value)) => bytes[0] = *value,
+            (FieldType::U16, FieldValue::U16(value)) => {
+                bytes[..2].copy_from_slice(&value.to_le_bytes())
+            }
+            (FieldType::U32, FieldValue::U32(value)) => {
+                bytes[..4].copy_from_slice(&value.to_le_bytes())
+            }
+            (FieldType::U64, FieldValue::U64(value)) => {
+                bytes[..8].copy_from_slice(&value.to_le_bytes())
+            }
+            (FieldType::I8, FieldValue::I8(value)) => bytes[0] = *value as u8,
+            (FieldType::I16, FieldValue::I16(value)) => {
+                bytes[..2].copy_from_slice(&value.to_le_bytes())
+            }
+            (FieldType::I32, FieldValue::I32(value)) => {
+                bytes[..4].copy_from_slice(&value.to_le_bytes())
+            }
+            (FieldType::I64, FieldValue::I64(value)) => {
+                bytes[..8].copy_from_slice(&value.to_le_bytes())
+            }
+            (FieldType::F32, FieldValue::F32(value)) => {
+                bytes[..4].copy_from_slice(&value.to_le_bytes())
+            }
+            (FieldType::F64, FieldValue::F64(value)) => {
+                bytes[..8].copy_from_slice(&value.to_le_bytes())
+            }
+            (FieldType::Array(element, _), FieldValue::Array(values)) => {
+                let stride = element.size().expect("array elements are fixed-size");
+                for (index, value) in values.iter().enumerate() {
+                    element.write(&mut bytes[index * stride..], value);
+                }
+            }
+            _ => unreachable!("accepts checked the pairing"),
+        }
+        true
+    }
+
+    fn accepts(&self, value: &FieldValue) -> bool {
+        match (self, value) {
+            (FieldType::Array(element, length), FieldValue::Array(values)) => {
+                values.len() == *length && values.iter().all(|value| element.accepts(value))
+            }
+            (FieldType::Bool, FieldValue::Bool(_))
+            | (FieldType::U8, FieldValue::U8(_))
+            | (FieldType::U16, FieldValue::U16(_))
+            | (FieldType::U32, FieldValue::U32(_))
+            | (FieldType::U64, FieldValue::U64(_))
+            | (FieldType::I8, FieldValue::I8(_))
+            | (FieldType::I16, FieldValue::I16(_))
+            | (FieldType::I32, FieldValue::I32(_))
+            | (FieldType::I64, FieldValue::I64(_))
+            | (FieldType::F32, FieldValue::F32(_))
+            | (FieldType::F64, FieldValue::F64(_))
+            | (FieldType::String, FieldValue::String(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+/// One field's value, read from or written to a layout component's row
+/// through its [`ComponentLayout`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldValue {
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    F32(f32),
+    F64(f64),
+    Array(Vec<FieldValue>),
+    String(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct LayoutField {
+    name: String,
+    field_type: FieldType,
+    /// Byte offset into the row image, or the index into its strings.
+    slot: usize,
+}
+
+/// The descriptor of a component defined at load time rather than by a Rust
+/// type: a name plus named fields, built up with [`field`](Self::field).
+/// Registering it through [`ComponentRegistry::register_layout`] gives a
+/// component stored as a column of stride-packed rows, moved with its table
+/// and dropped through the layout, queryable by its mask bit, and reached by
+/// field name or as raw bytes by component name.
+///
+/// ```rust
+/// use freecs::dynamic::{ComponentLayout, DynWorld, FieldType, FieldValue};
+///
+/// let mut world = DynWorld::new();
+/// let key = world.register_layout(
+///     ComponentLayout::new("mod::Mana")
+///         .field("current", FieldType::U32)
+///         .field("regen", FieldType::F32),
+/// );
+/// let entity = world.spawn_entities(0, 1)[0];
+/// assert!(world.set_runtime_field(entity, "mod::Mana", "current", FieldValue::U32(40)));
+/// assert_eq!(
+///     world.runtime_field(entity, "mod::Mana", "current"),
+///     Some(FieldValue::U32(40))
+/// );
+/// assert_eq!(world.query_entities(key.mask).count(), 1);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComponentLayout {
+    name: String,
+    fields: Vec<LayoutField>,
+    size: usize,
+    string_count: usize,
+}
+
+impl ComponentLayout {
+    /// An empty layout; the name is the component's registered type name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            fields: Vec::new(),
+            size: 0,
+            string_count: 0,
+        }
+    }
+
+    /// Appends a field. Panics on a duplicate field name or an array of
+    /// strings, since array elements must be fixed-size.
+    pub fn field(mut self, name: impl Into<String>, field_type: FieldType) -> Self {
+        let name = name.into();
+        assert!(
+            self.fields.iter().all(|field| field.name != name),
+            "{} declares field {name} twice",
+            self.name
+        );
+        let slot = match field_type.size() {
+            Some(size) => {
+                self.size += size;
+                self.size - size
+            }
+            None => {
+                assert!(
+                    field_type == FieldType::String,
+                    "{}: array fields need a fixed-size element type",
+                    self.name
+                );
+                self.string_count += 1;
+                self.string_count - 1
+            }
+        };
+        self.fields.push(LayoutField {
+            name,
+            field_type,
+            slot,
+        });
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Bytes in a row's fixed image, the length
+    /// [`DynWorld::set_runtime_bytes`] expects.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The field names and types, in declaration order.
+    pub fn fields(&self) -> impl Iterator<Item = (&str, &FieldType)> + '_ {
+        self.fields
+            .iter()
+            .map(|field| (field.name.as_str(), &field.field_type))
+    }
+
+    /// Whether `row` has this layout's shape: the right image length and
+    /// string count.
+    pub fn fits(&self, row: &RuntimeRow) -> bool {
+        row.bytes.len() == self.size && row.strings.len() == self.string_count
+    }
+
+    /// Reads one field from a row's strings and image, `None` when the
+    /// layout has no such field.
+    fn read(&self, strings: &[String], image: &[u8], field: &str) -> Option<FieldValue> {
+        let field = self
+            .fields
+            .iter()
+            .find(|candidate| candidate.name == field)?;
+        Some(match field.field_type {
+            FieldType::String => FieldValue::String(strings[field.slot].clone()),
+            _ => field.field_type.read(&image[field.slot..]),
+        })
+    }
+
+    /// Whether `value` has the shape of `field`, so
+    /// [`DynWorld::set_runtime_field`] would take it.
+    pub fn accepts(&self, field: &str, value: &FieldValue) -> bool {
+        self.fields
+            .iter()
+            .find(|candidate| candidate.name == field)
+            .is_some_and(|field| field.field_type.accepts(value))
+    }
+
+    /// Writes one field into a row's strings and image, returning false
+    /// when the layout has no such field or the value's shape does not match
+    /// its type.
+    fn write(
+        &self,
+        strings: &mut [String],
+        image: &mut [u8],
+        field: &str,
+        value: FieldValue,
+    ) -> bool {
+        let Some(field) = self.fields.iter().find(|candidate| candidate.name == field) else {
+            return false;
+        };
+        match (&field.field_type, value) {
+            (FieldType::String, FieldValue::String(value)) => {
+                strings[field.slot] = value;
+                true
+            }
+            (field_type, value) => field_type.write(&mut image[field.slot..], &value),
+        }
+    }
+}
+
+/// Layout descriptors registered so far, shared by every registry. An equal
+/// layout registered again, in this registry or another, reuses the first
+/// copy, so each distinct layout is allocated once for the life of the
+/// process rather than once per registration.
+static INTERNED_LAYOUTS: std::sync::Mutex<Vec<&'static ComponentLayout>> =
+    std::sync::Mutex::new(Vec::new());
+
+fn intern_layout(layout: ComponentLayout) -> &'static ComponentLayout {
+    let mut interned = INTERNED_LAYOUTS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(&existing) = interned.iter().find(|&&existing| *existing == layout) {
+        return existing;
+    }
+    let leaked: &'static ComponentLayout = Box::leak(Box::new(layout));
+    interned.push(leaked);
+    leaked
+}
+
+/// One layout component row detached from its column, the form it takes on
+/// the wire: snapshots, deltas, and
+/// [`DynWorld::get_component_by_name`] encode rows as these. Decoding checks
+/// [`ComponentLayout::fits`] before a row reaches a column, and the column
+/// itself stores rows as packed bytes, so no row in a world can be
+/// misshapen.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RuntimeRow {
+    pub bytes: Vec<u8>,
+    pub strings: Vec<String>,
+}
+
+/// A handle to a component registered through
+/// [`ComponentRegistry::register_layout`]: its index, mask bit, and
+/// registry. Unlike a [`ComponentKey`] it names no Rust type, since the
+/// rows are bytes only the layout can read; reach them through
+/// [`DynWorld::runtime_field`] and [`DynWorld::runtime_bytes`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LayoutKey {
+    pub component_index: u32,
+    pub mask: u64,
+    pub registry_id: u32,
+}
+
+/// The component and tag schema for dynamic worlds. Bits are assigned in
+/// registration order, so a registry built once and shared across worlds
+/// guarantees every world agrees on masks.
+#[derive(Clone)]
+pub struct ComponentRegistry {
+    pub registry_id: u32,
+    pub components: Vec<ComponentInfo>,
+    pub components_by_type: TypeIdMap<u32>,
+    pub tag_count: u32,
+    pub tags_by_type: TypeIdMap<u32>,
+    #[cfg(feature = "snapshot")]
+    pub codecs: Vec<Option<ComponentCodec>>,
+    /// Per component index, the components declared through
+    /// [`require`](Self::require) that must ride along with it. Direct
+    /// requirements only; [`required_mask`](Self::required_mask) walks them
+    /// transitively.
+    pub required: Vec<u64>,
+    /// Every component that declares at least one requirement, so a mask
+    /// touching none of them skips the walk.
+    pub requiring: u64,
+    /// Every component whose [`ComponentData`] has no default. These never
+    /// join a row by bare mask.
+    pub no_default: u64,
+    /// One-entry cache of the most recently resolved component type. A hot
+    /// loop of `set`/`remove` over one component type hits this on every call
+    /// after the first, resolving through a `TypeId` equality instead of a map
+    /// probe. Never wrong: a miss just falls through to the map.
+    recent_component: Option<(TypeId, u32)>,
 }
 
 impl Default for ComponentRegistry {
@@ -764,15 +1718,19 @@ impl ComponentRegistry {
             tags_by_type: TypeIdMap::default(),
             #[cfg(feature = "snapshot")]
             codecs: Vec::new(),
+            required: Vec::new(),
+            requiring: 0,
+            no_default: 0,
             recent_component: None,
         }
     }
 
     /// Registers `T` if it is not already registered and returns its key.
-    /// Idempotent per type. `Default` is required because archetype migration
-    /// moves values with `mem::take`, and `Send + Sync` because columns are
-    /// shared across threads by the parallel iteration paths.
-    pub fn register<T: Send + Sync + Default + 'static>(&mut self) -> ComponentKey<T> {
+    /// Idempotent per type. `Send + Sync` is required because columns are
+    /// shared across threads by the parallel iteration paths; a default is
+    /// not, since migrations move values by ownership, but a type without one
+    /// lands in [`no_default`](Self::no_default).
+    pub fn register<T: ComponentData>(&mut self) -> ComponentKey<T> {
         let type_id = TypeId::of::<T>();
         if let Some((cached_id, component_index)) = self.recent_component
             && cached_id == type_id
@@ -794,16 +1752,25 @@ impl ComponentRegistry {
             type_name: std::any::type_name::<T>(),
             mask: 1 << component_index,
             new_column: column_new::<T>,
-            push_default: column_push_default::<T>,
+            push_default: T::DEFAULT
+                .map(|_| column_push_default::<T> as fn(&mut ErasedColumn, usize)),
             swap_remove: column_swap_remove::<T>,
-            move_row: column_move_row::<T>,
             swap_remove_into: column_swap_remove_into::<T>,
             column_len: column_len_of::<T>,
+            permute_rows: column_permute_rows::<T>,
+            column_memory: column_memory_of::<T>,
+            shrink_column: column_shrink::<T>,
+            clone_row: None,
+            layout: None,
         });
         self.components_by_type
             .insert(TypeId::of::<T>(), component_index);
         #[cfg(feature = "snapshot")]
         self.codecs.push(None);
+        self.required.push(0);
+        if T::DEFAULT.is_none() {
+            self.no_default |= 1 << component_index;
+        }
         self.key_for(component_index)
     }
 
@@ -814,7 +1781,7 @@ impl ComponentRegistry {
     #[cfg(feature = "snapshot")]
     pub fn register_serde<T>(&mut self) -> ComponentKey<T>
     where
-        T: serde::Serialize + serde::de::DeserializeOwned + Send + Sync + Default + 'static,
+        T: serde::Serialize + serde::de::DeserializeOwned + ComponentData,
     {
         self.register_codec::<T>(ComponentCodec {
             encode_column: encode_column_postcard::<T>,
@@ -826,15 +1793,218 @@ impl ComponentRegistry {
 
     /// Registers `T` with an explicit snapshot codec.
     #[cfg(feature = "snapshot")]
-    pub fn register_codec<T: Send + Sync + Default + 'static>(
-        &mut self,
-        codec: ComponentCodec,
-    ) -> ComponentKey<T> {
+    pub fn register_codec<T: ComponentData>(&mut self, codec: ComponentCodec) -> ComponentKey<T> {
         let key = self.register::<T>();
         self.codecs[key.component_index as usize] = Some(codec);
         key
     }
 
+    /// Registers `T` as cloneable, so [`DynWorld::clone_entity`] and its
+    /// cross-world forms can copy it. Upgrades an existing registration in
+    /// place; the mask bit does not change.
+    pub fn register_clone<T: ComponentData + Clone>(&mut self) -> ComponentKey<T> {
+        let key = self.register::<T>();
+        self.components[key.component_index as usize].clone_row = Some(column_clone_row::<T>);
+        key
+    }
+
+    /// Registers a component from another registry's record, matched by type
+    /// name: an existing component of that name is reused, otherwise the
+    /// record is appended with this registry's next mask bit. The record's
+    /// function pointers are per type, not per registry, so they carry over
+    /// unchanged. This is how rows move between worlds whose registries
+    /// were built independently. Returns the component index here.
+    pub fn adopt(&mut self, info: &ComponentInfo) -> u32 {
+        if let Some(existing) = self.component_by_name(info.type_name) {
+            assert!(
+                existing.type_id == info.type_id && existing.layout == info.layout,
+                "{} names different types in the two registries",
+                info.type_name
+            );
+            let component_index = existing.mask.trailing_zeros();
+            let existing = &mut self.components[component_index as usize];
+            if existing.clone_row.is_none() {
+                existing.clone_row = info.clone_row;
+            }
+            return component_index;
+        }
+        let component_index = self.components.len() as u32;
+        assert!(
+            (self.components.len() + self.tag_count as usize) < 64,
+            "components plus tags must fit in a u64 mask"
+        );
+        self.components.push(ComponentInfo {
+            mask: 1 << component_index,
+            ..*info
+        });
+        if info.layout.is_none() {
+            self.components_by_type
+                .insert(info.type_id, component_index);
+        }
+        #[cfg(feature = "snapshot")]
+        self.codecs.push(None);
+        self.required.push(0);
+        if info.push_default.is_none() {
+            self.no_default |= 1 << component_index;
+        }
+        component_index
+    }
+
+    /// Registers a component described by `layout` instead of a Rust type,
+    /// named by [`ComponentLayout::name`], and returns its key. Its column
+    /// holds stride-packed rows whose strings the layout drops, and it has
+    /// no default, so a row only gains it with a value, such as one written
+    /// through [`DynWorld::set_runtime_field`]. Idempotent per name;
+    /// registering a different layout under a taken name panics. The
+    /// descriptor lives as long as the record, like a Rust type's, and is
+    /// interned, so registering an equal layout in any number of registries
+    /// keeps one copy. With the `snapshot` feature the component gets a
+    /// postcard codec over [`RuntimeRow`]s, so worlds carrying it save and
+    /// restore like any other.
+    pub fn register_layout(&mut self, layout: ComponentLayout) -> LayoutKey {
+        if let Some(existing) = self.component_by_name(&layout.name) {
+            assert!(
+                existing.layout == Some(&layout),
+                "{} is already registered with a different layout",
+                layout.name
+            );
+            return self.layout_key_for(existing.mask.trailing_zeros());
+        }
+
+        let component_index = self.components.len() as u32;
+        assert!(
+            (self.components.len() + self.tag_count as usize) < 64,
+            "components plus tags must fit in a u64 mask"
+        );
+        let layout = intern_layout(layout);
+        self.components.push(ComponentInfo {
+            type_id: TypeId::of::<ComponentLayout>(),
+            type_name: &layout.name,
+            mask: 1 << component_index,
+            new_column: layout_column_new,
+            push_default: None,
+            swap_remove: layout_swap_remove,
+            swap_remove_into: layout_swap_remove_into,
+            column_len: layout_len,
+            permute_rows: layout_permute_rows,
+            column_memory: layout_memory,
+            shrink_column: layout_shrink,
+            clone_row: Some(layout_clone_row),
+            layout: Some(layout),
+        });
+        #[cfg(feature = "snapshot")]
+        self.codecs.push(Some(ComponentCodec {
+            encode_column: encode_layout_column,
+            decode_column: decode_column_unbound,
+            encode_value: encode_value_unbound,
+            apply_value: apply_value_unbound,
+        }));
+        self.required.push(0);
+        self.no_default |= 1 << component_index;
+        self.layout_key_for(component_index)
+    }
+
+    /// The key of a component registered through
+    /// [`register_layout`](Self::register_layout), by name.
+    pub fn layout_key(&self, name: &str) -> Option<LayoutKey> {
+        let info = self.component_by_name(name)?;
+        info.layout?;
+        Some(self.layout_key_for(info.mask.trailing_zeros()))
+    }
+
+    fn layout_key_for(&self, component_index: u32) -> LayoutKey {
+        LayoutKey {
+            component_index,
+            mask: 1 << component_index,
+            registry_id: self.registry_id,
+        }
+    }
+
+    /// Declares that every entity carrying `T` also carries `R`, registering
+    /// both. Spawning with `T`, or adding or setting it, brings a missing `R`
+    /// along at `R::default()` in the same table move, and requirements chain:
+    /// when `R` requires a third component, `T` gets that too. Removing `R`
+    /// while `T` stays is governed by [`DynWorld::required_removal`].
+    /// Requirements belong to this registry, so
+    /// [`adopt`](Self::adopt) does not carry them between registries.
+    pub fn require<T, R>(&mut self)
+    where
+        T: ComponentData,
+        R: ComponentData + Default,
+    {
+        let dependent = self.register::<T>();
+        let required = self.register::<R>();
+        if dependent.mask == required.mask {
+            return;
+        }
+        self.required[dependent.component_index as usize] |= required.mask;
+        self.requiring |= dependent.mask;
+    }
+
+    /// The first component in `mask` whose [`ComponentData`] has no default,
+    /// for the refusal a bare-mask add of it meets.
+    pub fn first_without_default(&self, mask: u64) -> Option<&'static str> {
+        let missing = mask & self.no_default;
+        (missing != 0).then(|| self.components[missing.trailing_zeros() as usize].type_name)
+    }
+
+    /// `mask` plus every component it requires, transitively.
+    #[inline]
+    pub fn required_mask(&self, mask: u64) -> u64 {
+        let mut closed = mask;
+        let mut pending = mask & self.requiring;
+        while pending != 0 {
+            let component_mask = pending & pending.wrapping_neg();
+            pending &= pending - 1;
+            let gained = self.required[component_mask.trailing_zeros() as usize] & !closed;
+            closed |= gained;
+            pending |= gained & self.requiring;
+        }
+        closed
+    }
+
+    /// The components in `present`, outside `removing`, that require one of
+    /// the `removing` components directly or through a chain, which is what a
+    /// removal would leave without its requirement.
+    pub fn dependents(&self, present: u64, mut removing: u64) -> u64 {
+        let mut dependents = 0;
+        loop {
+            let mut grown = 0;
+            let mut candidates = present & self.requiring & !removing;
+            while candidates != 0 {
+                let component_mask = candidates & candidates.wrapping_neg();
+                candidates &= candidates - 1;
+                if self.required[component_mask.trailing_zeros() as usize] & removing != 0 {
+                    grown |= component_mask;
+                }
+            }
+            if grown == 0 {
+                return dependents;
+            }
+            dependents |= grown;
+            removing |= grown;
+        }
+    }
+
+    /// The marker type behind a tag, if it was registered by type.
+    pub fn tag_type_id(&self, tag_index: u32) -> Option<TypeId> {
+        self.tags_by_type
+            .iter()
+            .find(|&(_, &index)| index == tag_index)
+            .map(|(&type_id, _)| type_id)
+    }
+
+    /// [`register_tag_type`](Self::register_tag_type) keyed by a `TypeId`
+    /// already in hand, for carrying marker tags between registries.
+    pub fn register_tag_type_id(&mut self, type_id: TypeId) -> TagKey {
+        if let Some(&tag_index) = self.tags_by_type.get(&type_id) {
+            return self.tag_key_for(tag_index);
+        }
+        let key = self.register_tag();
+        self.tags_by_type.insert(type_id, key.tag_index);
+        key
+    }
+
     pub fn register_tag(&mut self) -> TagKey {
         assert!(
             (self.components.len() + self.tag_count as usize) < 64,
@@ -849,12 +2019,7 @@ impl ComponentRegistry {
     /// already registered and returns its key. Idempotent per type; the tag
     /// is an ordinary sparse-set tag underneath, `T` is only its name.
     pub fn register_tag_type<T: 'static>(&mut self) -> TagKey {
-        if let Some(&tag_index) = self.tags_by_type.get(&TypeId::of::<T>()) {
-            return self.tag_key_for(tag_index);
-        }
-        let key = self.register_tag();
-        self.tags_by_type.insert(TypeId::of::<T>(), key.tag_index);
-        key
+        self.register_tag_type_id(TypeId::of::<T>())
     }
 
     /// Resolves the marker type `T`'s tag key without registering.
@@ -1070,21 +2235,74 @@ impl DynComponentArrays {
     }
 }
 
+/// One table's share of [`DynWorld::export_columns`]: the table's entities and
+/// each of its component columns as a contiguous slice, row `i` of every
+/// column belonging to `entities()[i]`.
+#[derive(Clone, Copy)]
+pub struct ColumnChunk<'w> {
+    world: &'w DynWorld,
+    table: &'w DynComponentArrays,
+}
+
+impl<'w> ColumnChunk<'w> {
+    pub fn mask(&self) -> u64 {
+        self.table.mask
+    }
+
+    pub fn entities(&self) -> &'w [Entity] {
+        &self.table.entity_indices
+    }
+
+    pub fn len(&self) -> usize {
+        self.table.entity_indices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table.entity_indices.is_empty()
+    }
+
+    /// The column of `T`, or `None` when the table does not carry it.
+    pub fn column<T: ComponentData>(&self) -> Option<&'w [T]> {
+        self.column_keyed(self.world.lookup_key::<T>()?)
+    }
+
+    /// The column behind `key`, the form layout components need.
+    pub fn column_keyed<T: 'static>(&self, key: ComponentKey<T>) -> Option<&'w [T]> {
+        self.world.check_key(key.registry_id);
+        let table = self.table;
+        (table.mask & key.mask != 0).then(|| table.column(key))
+    }
+
+    /// The registered names of the table's components, in column order.
+    pub fn component_names(&self) -> impl Iterator<Item = &'static str> + 'w {
+        let registry = &self.world.registry;
+        self.table
+            .columns
+            .iter()
+            .map(|column| registry.components[column.component_index as usize].type_name)
+    }
+}
+
 enum DynCommand {
     SpawnEntities { mask: u64, count: usize },
     DespawnEntity(Entity),
     DespawnEntities(Vec<Entity>),
     AddComponents(Entity, u64),
     RemoveComponents(Entity, u64),
+    AddComponentsBatch(Vec<Entity>, u64),
+    RemoveComponentsBatch(Vec<Entity>, u64),
     AddTag(Entity, TagKey),
     RemoveTag(Entity, TagKey),
     Closure(Box<dyn FnOnce(&mut DynWorld) + Send + Sync>),
+    RunSystem(SystemId),
 }
 
 struct EventSlot {
     type_id: TypeId,
     data: BoxedAny,
     update: fn(&mut (dyn Any + Send + Sync)),
+    memory: fn(&(dyn Any + Send + Sync)) -> MemoryUsage,
+    shrink: fn(&mut (dyn Any + Send + Sync)),
 }
 
 fn event_update<T: Send + Sync + 'static>(data: &mut (dyn Any + Send + Sync)) {
@@ -1093,6 +2311,18 @@ fn event_update<T: Send + Sync + 'static>(data: &mut (dyn Any + Send + Sync)) {
         .update();
 }
 
+fn event_memory<T: Send + Sync + 'static>(data: &(dyn Any + Send + Sync)) -> MemoryUsage {
+    data.downcast_ref::<EventChannel<T>>()
+        .expect("event channel type mismatch")
+        .memory_usage()
+}
+
+fn event_shrink<T: Send + Sync + 'static>(data: &mut (dyn Any + Send + Sync)) {
+    data.downcast_mut::<EventChannel<T>>()
+        .expect("event channel type mismatch")
+        .shrink_to_fit();
+}
+
 /// The type-erased event channels one container owns. [`DynWorld`] and
 /// [`DynEcs`] both embed one, so world-local and group-shared events run on
 /// identical machinery: sequence-numbered channels, two-frame buffering
@@ -1114,6 +2344,8 @@ impl EventBus {
             type_id: TypeId::of::<T>(),
             data: Box::new(EventChannel::<T>::new()),
             update: event_update::<T>,
+            memory: event_memory::<T>,
+            shrink: event_shrink::<T>,
         });
         self.by_type.insert(TypeId::of::<T>(), index);
         index
@@ -1188,6 +2420,21 @@ impl EventBus {
         self.slots.len()
     }
 
+    /// The buffered events' bytes across every channel.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        self.slots
+            .iter()
+            .map(|slot| (slot.memory)(&*slot.data))
+            .sum()
+    }
+
+    /// Returns every channel's spare capacity.
+    pub fn shrink_to_fit(&mut self) {
+        for slot in &mut self.slots {
+            (slot.shrink)(&mut *slot.data);
+        }
+    }
+
     /// Advances every channel one frame, expiring events past their
     /// two-frame window. The containers call this from their `step`.
     pub fn update(&mut self) {
@@ -1200,14 +2447,30 @@ impl EventBus {
 /// The type-keyed resource singletons one container owns. [`DynWorld`] and
 /// [`DynEcs`] both embed one, so world-local and group-shared resources use
 /// identical machinery; the containers add the expect and scope forms.
+///
+/// Each insert and each mutable access stamps the resource with the next
+/// value of [`version`](Self::version), so a reader holding an earlier
+/// version asks [`changed_since`](Self::changed_since) whether the resource
+/// may have been written. Mutable access counts as a write whether or not
+/// the value changed, as component `get_mut` does.
 #[derive(Default)]
 pub struct ResourceMap {
     pub entries: HashMap<TypeId, BoxedAny>,
+    pub changed: HashMap<TypeId, u64>,
+    pub version: u64,
 }
 
 impl ResourceMap {
     pub fn insert<T: Send + Sync + 'static>(&mut self, value: T) {
         self.entries.insert(TypeId::of::<T>(), Box::new(value));
+        self.mark_changed::<T>();
+    }
+
+    /// Returns a value taken out with [`remove`](Self::remove) without
+    /// stamping it, for read-only scopes that lift a resource out and hand
+    /// it back unchanged.
+    pub fn restore<T: Send + Sync + 'static>(&mut self, value: T) {
+        self.entries.insert(TypeId::of::<T>(), Box::new(value));
     }
 
     pub fn get<T: Send + Sync + 'static>(&self) -> Option<&T> {
@@ -1216,17 +2479,221 @@ impl ResourceMap {
             .and_then(|value| value.downcast_ref::<T>())
     }
 
-    pub fn get_mut<T: Send + Sync + 'static>(&mut self) -> Option<&mut T> {
-        self.entries
-            .get_mut(&TypeId::of::<T>())
-            .and_then(|value| value.downcast_mut::<T>())
+    pub fn get_mut<T: Send + Sync + 'static>(&mut self) -> Option<&mut T> {
+        if self.entries.contains_key(&TypeId::of::<T>()) {
+            self.mark_changed::<T>();
+        }
+        self.entries
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_mut::<T>())
+    }
+
+    /// Stamps `T` as written at the next version.
+    pub fn mark_changed<T: 'static>(&mut self) {
+        self.version += 1;
+        self.changed.insert(TypeId::of::<T>(), self.version);
+    }
+
+    /// Whether `T` is present and was inserted or mutably accessed after
+    /// `version`.
+    pub fn changed_since<T: 'static>(&self, version: u64) -> bool {
+        self.entries.contains_key(&TypeId::of::<T>())
+            && self
+                .changed
+                .get(&TypeId::of::<T>())
+                .is_some_and(|&stamp| stamp > version)
+    }
+
+    pub fn remove<T: Send + Sync + 'static>(&mut self) -> Option<T> {
+        self.entries
+            .remove(&TypeId::of::<T>())
+            .and_then(|value| value.downcast::<T>().ok())
+            .map(|value| *value)
+    }
+}
+
+thread_local! {
+    /// Every non-send value on this thread, keyed by the id of the
+    /// [`NonSendResources`] that owns it and the value's type. Values never
+    /// leave the thread that inserted them, which is what lets a store of
+    /// `!Send` values sit inside a `Send + Sync` world without `unsafe`.
+    static NON_SEND_VALUES: std::cell::RefCell<HashMap<(u64, TypeId), Box<dyn Any>>> =
+        std::cell::RefCell::new(HashMap::new());
+}
+
+static NEXT_NON_SEND_STORE_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Stores dropped away from their owner thread, by owner, waiting for that
+/// thread to drop their values. The count lets every access skip the lock
+/// while nothing is orphaned.
+static ORPHANED_NON_SEND_STORES: std::sync::Mutex<Vec<(std::thread::ThreadId, u64)>> =
+    std::sync::Mutex::new(Vec::new());
+static ORPHANED_NON_SEND_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Removes every value the listed stores hold on this thread. The values
+/// come back to the caller so they drop after the borrow ends, since a
+/// value's own drop may touch non-send resources.
+fn take_non_send_values(ids: &[u64]) -> Vec<Box<dyn Any>> {
+    NON_SEND_VALUES
+        .try_with(|values| {
+            let mut values = values.borrow_mut();
+            let keys: Vec<_> = values
+                .keys()
+                .filter(|key| ids.contains(&key.0))
+                .copied()
+                .collect();
+            keys.iter().filter_map(|key| values.remove(key)).collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Drops the values of stores that were dropped on another thread while
+/// this one owned them.
+fn purge_orphaned_non_send() {
+    if ORPHANED_NON_SEND_COUNT.load(Ordering::Acquire) == 0 {
+        return;
+    }
+    let current = std::thread::current().id();
+    let ids: Vec<u64> = {
+        let mut orphans = ORPHANED_NON_SEND_STORES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut ids = Vec::new();
+        orphans.retain(|&(owner, id)| {
+            let mine = owner == current;
+            if mine {
+                ids.push(id);
+            }
+            !mine
+        });
+        ORPHANED_NON_SEND_COUNT.store(orphans.len(), Ordering::Release);
+        ids
+    };
+    if !ids.is_empty() {
+        drop(take_non_send_values(&ids));
+    }
+}
+
+/// The resources of one container that cannot cross threads: window
+/// handles, audio contexts, `Rc`-based caches, FFI objects. [`DynWorld`] and
+/// [`DynEcs`] each embed one next to their [`ResourceMap`]. Values need only
+/// `'static`; they live in thread-local storage on the thread that inserted
+/// the first of them, the store's owner, and every later access from any
+/// other thread panics rather than touching them. So the world itself stays
+/// `Send + Sync` and can still move or be shared, but its non-send
+/// resources, and every system reading them through
+/// [`NonSend`](crate::system_param::NonSend), are pinned to the owner.
+///
+/// Dropping the store on its owner thread drops its values there. Dropped
+/// anywhere else, which happens when a world moves to another thread and
+/// dies there, the values cannot follow: they stay on the owner thread until
+/// it next touches any non-send store, which drops them, or until it exits.
+pub struct NonSendResources {
+    id: u64,
+    owner: Option<std::thread::ThreadId>,
+}
+
+impl Default for NonSendResources {
+    fn default() -> Self {
+        Self {
+            id: NEXT_NON_SEND_STORE_ID.fetch_add(1, Ordering::Relaxed),
+            owner: None,
+        }
+    }
+}
+
+impl NonSendResources {
+    /// The thread the values live on, `None` before the first insert.
+    pub fn owner(&self) -> Option<std::thread::ThreadId> {
+        self.owner
+    }
+
+    /// Whether the calling thread may access the values: it owns them, or
+    /// nothing has been inserted yet.
+    pub fn is_owner_thread(&self) -> bool {
+        self.owner
+            .is_none_or(|owner| owner == std::thread::current().id())
+    }
+
+    fn check_thread(&self) {
+        assert!(
+            self.is_owner_thread(),
+            "non-send resources belong to thread {:?}; accessed from {:?}",
+            self.owner.expect("a foreign access implies an owner"),
+            std::thread::current().id()
+        );
+    }
+
+    /// Inserts `value`, replacing and dropping any existing value of its
+    /// type. The first insert makes the calling thread the owner.
+    pub fn insert<T: 'static>(&mut self, value: T) {
+        self.check_thread();
+        purge_orphaned_non_send();
+        self.owner = Some(std::thread::current().id());
+        let replaced = NON_SEND_VALUES.with(|values| {
+            values
+                .borrow_mut()
+                .insert((self.id, TypeId::of::<T>()), Box::new(value))
+        });
+        drop(replaced);
+    }
+
+    pub fn remove<T: 'static>(&mut self) -> Option<T> {
+        self.owner?;
+        self.check_thread();
+        purge_orphaned_non_send();
+        NON_SEND_VALUES
+            .with(|values| values.borrow_mut().remove(&(self.id, TypeId::of::<T>())))
+            .and_then(|value| value.downcast::<T>().ok())
+            .map(|value| *value)
+    }
+
+    pub fn contains<T: 'static>(&self) -> bool {
+        if self.owner.is_none() {
+            return false;
+        }
+        self.check_thread();
+        purge_orphaned_non_send();
+        NON_SEND_VALUES.with(|values| values.borrow().contains_key(&(self.id, TypeId::of::<T>())))
+    }
+}
+
+impl Drop for NonSendResources {
+    fn drop(&mut self) {
+        let Some(owner) = self.owner else {
+            return;
+        };
+        if owner != std::thread::current().id() {
+            let mut orphans = ORPHANED_NON_SEND_STORES
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            orphans.push((owner, self.id));
+            ORPHANED_NON_SEND_COUNT.store(orphans.len(), Ordering::Release);
+            return;
+        }
+        drop(take_non_send_values(&[self.id]));
+        purge_orphaned_non_send();
     }
+}
 
-    pub fn remove<T: Send + Sync + 'static>(&mut self) -> Option<T> {
-        self.entries
-            .remove(&TypeId::of::<T>())
-            .and_then(|value| value.downcast::<T>().ok())
-            .map(|value| *value)
+/// The take/put scope behind [`DynWorld::non_send_scope`] and
+/// [`DynEcs::non_send_scope`], over whichever store `store` picks out.
+fn non_send_scope<H, R: 'static, T>(
+    host: &mut H,
+    store: fn(&mut H) -> &mut NonSendResources,
+    f: impl FnOnce(&mut H, &mut R) -> T,
+) -> T {
+    let mut resource = store(host).remove::<R>().unwrap_or_else(|| {
+        panic!(
+            "non_send_scope requires {} to be present",
+            std::any::type_name::<R>()
+        )
+    });
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(host, &mut resource)));
+    store(host).insert(resource);
+    match result {
+        Ok(value) => value,
+        Err(panic) => std::panic::resume_unwind(panic),
     }
 }
 
@@ -1249,6 +2716,66 @@ pub trait ResourceHost {
     /// a run condition that checks a state. Must return the same map as
     /// [`resource_map_mut`](Self::resource_map_mut).
     fn resource_map(&self) -> &ResourceMap;
+
+    /// Called by a system runner before each run, so change filters inside
+    /// the system compare against that system's last run rather than the
+    /// last step. [`DynWorld`] and [`DynEcs`] swap their `last_tick` here; a
+    /// wrapper host delegates to the world it holds. The default does
+    /// nothing, leaving change filters on the world's own baseline.
+    fn enter_system_ticks(&mut self, _ticks: &mut SystemTicks) {}
+
+    /// Called after each run to restore what
+    /// [`enter_system_ticks`](Self::enter_system_ticks) displaced and
+    /// record this run's tick.
+    fn exit_system_ticks(&mut self, _ticks: &mut SystemTicks) {}
+}
+
+/// One system's change-tick baseline, kept in its runner across runs the
+/// way an event reader keeps its cursor. `last_run` holds the tick each
+/// world was at when the system last ran, one entry per world and empty
+/// before the first run, so a first run sees what the world's own
+/// `last_tick` would show. `displaced` holds each world's `(last_tick,
+/// current_tick)` for the span of one run.
+///
+/// Leaving a run advances the world's `current_tick`, so writes made later
+/// in the same step carry a newer tick than the run that preceded them.
+/// A system skipped for a few steps by a run condition, or one that runs
+/// before the systems writing what it reads, therefore sees every change
+/// since its last run exactly once.
+#[derive(Clone, Debug, Default)]
+pub struct SystemTicks {
+    pub last_run: Vec<u32>,
+    pub displaced: Vec<(u32, u32)>,
+}
+
+impl SystemTicks {
+    /// Rebases `world`, the `index`th world of the host, onto this system's
+    /// last run.
+    pub fn enter(&mut self, index: usize, world: &mut DynWorld) {
+        if index == 0 {
+            self.displaced.clear();
+        }
+        self.displaced.push((world.last_tick, world.current_tick));
+        if let Some(&last_run) = self.last_run.get(index) {
+            world.last_tick = last_run;
+        }
+    }
+
+    /// Restores `world`'s baseline, records the run, and advances the
+    /// world's tick past it. A world the system stepped keeps the baseline
+    /// its step set.
+    pub fn exit(&mut self, index: usize, world: &mut DynWorld) {
+        if self.last_run.len() <= index {
+            self.last_run.resize(index + 1, 0);
+        }
+        self.last_run[index] = world.current_tick;
+        if let Some(&(last_tick, current_tick)) = self.displaced.get(index)
+            && world.current_tick == current_tick
+        {
+            world.last_tick = last_tick;
+            world.current_tick = world.current_tick.wrapping_add(1);
+        }
+    }
 }
 
 /// The host scope methods, blanket-implemented for every
@@ -1348,6 +2875,12 @@ impl ResourceHost for DynWorld {
     fn resource_map(&self) -> &ResourceMap {
         &self.resources
     }
+    fn enter_system_ticks(&mut self, ticks: &mut SystemTicks) {
+        ticks.enter(0, self);
+    }
+    fn exit_system_ticks(&mut self, ticks: &mut SystemTicks) {
+        ticks.exit(0, self);
+    }
 }
 
 impl ResourceHost for DynEcs {
@@ -1357,6 +2890,16 @@ impl ResourceHost for DynEcs {
     fn resource_map(&self) -> &ResourceMap {
         &self.resources
     }
+    fn enter_system_ticks(&mut self, ticks: &mut SystemTicks) {
+        for (index, world) in self.worlds.iter_mut().enumerate() {
+            ticks.enter(index, world);
+        }
+    }
+    fn exit_system_ticks(&mut self, ticks: &mut SystemTicks) {
+        for (index, world) in self.worlds.iter_mut().enumerate() {
+            ticks.exit(index, world);
+        }
+    }
 }
 
 /// The column moves one table pair implies, resolved once instead of per row.
@@ -1394,6 +2937,10 @@ pub struct DynWorld {
     /// check. Grouped worlds under [`DynEcs`] need this; a standalone world
     /// leaves it false so unknown handles are refused outright.
     pub insert_missing_rows: bool,
+    /// What [`remove_components`](Self::remove_components) and the removals
+    /// built on it do when they would leave a component without one it
+    /// requires. Rejects by default.
+    pub required_removal: RequiredRemoval,
     pub entity_locations: EntityLocations,
     pub tables: Vec<DynComponentArrays>,
     pub table_lookup: HashMap<u64, usize>,
@@ -1412,6 +2959,9 @@ pub struct DynWorld {
     pub current_tick: u32,
     pub last_tick: u32,
     pub structural_log: Vec<StructuralChange>,
+    /// Entity lists for the batch entries in [`Self::structural_log`], in
+    /// sequence order. Trimmed and cleared with the log.
+    pub structural_batches: Vec<StructuralBatch>,
     pub structural_sequence: u64,
     /// Whether spawns, despawns, and migrations append to [`Self::structural_log`].
     ///
@@ -1433,8 +2983,67 @@ pub struct DynWorld {
     command_buffer: Vec<DynCommand>,
     pub events: EventBus,
     pub resources: ResourceMap,
+    /// Resources pinned to one thread; see [`NonSendResources`].
+    pub non_send: NonSendResources,
+    /// Queries registered through [`DynQuery::live`], kept matched against
+    /// tables as they are created. Indexed by [`LiveQuery::index`]; a slot
+    /// freed by [`LiveQuery::release`] is reused by the next registration.
+    pub live_queries: Vec<LiveQueryState>,
+    /// This world's identity, unique in the process and fresh for every
+    /// world built, restored ones included. Stamped on [`LiveQuery`]
+    /// handles.
+    world_id: u64,
+    /// Bumped on every structural change, logged or not, so cached live
+    /// query results know when the rows under them have moved.
+    structural_epoch: u64,
+    /// One-shot systems registered through
+    /// [`register_system`](Self::register_system), indexed by [`SystemId`].
+    systems: Vec<SystemSlot>,
+}
+
+/// A one-shot system's runner. It is only `Send`, so the mutex keeps the
+/// world `Sync`; it is reached through `get_mut` and never locked.
+type OneShotRunner = std::sync::Mutex<Box<dyn FnMut(&mut DynWorld) -> SystemResult + Send>>;
+
+/// One entry of [`DynWorld::systems`]. The runner leaves its slot for the
+/// span of a run, so a nested call can tell a running system from a dropped
+/// one, and an unregister during the run lands after it.
+enum SystemSlot {
+    Ready(&'static str, OneShotRunner),
+    Running { unregistered: bool },
+    Vacant,
 }
 
+/// The handle [`DynWorld::register_system`] returns for running a one-shot
+/// system on demand. Ids are never reused, so a stale id after
+/// [`unregister_system`](DynWorld::unregister_system) reports
+/// [`RunSystemError::NotFound`] rather than running another system.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SystemId(pub u32);
+
+/// Why [`DynWorld::run_system`] did not run a system to success.
+#[derive(Clone, Debug)]
+pub enum RunSystemError {
+    /// No system is registered under the id.
+    NotFound(SystemId),
+    /// The system is already running further up the call stack.
+    Recursive(SystemId),
+    /// The system ran and returned an error.
+    Failed(SystemError),
+}
+
+impl std::fmt::Display for RunSystemError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound(id) => write!(f, "no system is registered under {id:?}"),
+            Self::Recursive(id) => write!(f, "system {id:?} is already running"),
+            Self::Failed(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for RunSystemError {}
+
 impl Default for DynWorld {
     fn default() -> Self {
         Self::new()
@@ -1474,6 +3083,7 @@ impl DynWorld {
             registry,
             allocator: EntityAllocator::default(),
             insert_missing_rows: false,
+            required_removal: RequiredRemoval::Reject,
             entity_locations: EntityLocations::default(),
             tables: Vec::new(),
             table_lookup: HashMap::new(),
@@ -1486,6 +3096,7 @@ impl DynWorld {
             current_tick: 0,
             last_tick: 0,
             structural_log: Vec::new(),
+            structural_batches: Vec::new(),
             structural_sequence: 0,
             structural_logging: false,
             change_detection: false,
@@ -1493,6 +3104,11 @@ impl DynWorld {
             command_buffer: Vec::new(),
             events: EventBus::default(),
             resources: ResourceMap::default(),
+            non_send: NonSendResources::default(),
+            live_queries: Vec::new(),
+            world_id: NEXT_WORLD_ID.fetch_add(1, Ordering::Relaxed),
+            structural_epoch: 0,
+            systems: Vec::new(),
         };
         while world.tags.len() < tag_count {
             world.tags.push(SparseTagSet::default());
@@ -1501,10 +3117,170 @@ impl DynWorld {
     }
 
     /// Registers `T` on this world's registry and returns its key.
-    pub fn register<T: Send + Sync + Default + 'static>(&mut self) -> ComponentKey<T> {
+    pub fn register<T: ComponentData>(&mut self) -> ComponentKey<T> {
         self.registry.register::<T>()
     }
 
+    /// Registers `T` as cloneable on this world's registry; see
+    /// [`ComponentRegistry::register_clone`].
+    pub fn register_clone<T: ComponentData + Clone>(&mut self) -> ComponentKey<T> {
+        self.registry.register_clone::<T>()
+    }
+
+    /// Declares that `T` requires `R` on this world's registry; see
+    /// [`ComponentRegistry::require`].
+    pub fn require<T, R>(&mut self)
+    where
+        T: ComponentData,
+        R: ComponentData + Default,
+    {
+        self.registry.require::<T, R>();
+    }
+
+    /// Registers a layout-described component on this world's registry; see
+    /// [`ComponentRegistry::register_layout`].
+    pub fn register_layout(&mut self, layout: ComponentLayout) -> LayoutKey {
+        self.registry.register_layout(layout)
+    }
+
+    /// The fixed-size fields of a layout component on an entity, as the
+    /// packed little-endian image its [`ComponentLayout`] describes. `None`
+    /// when the name is not a layout component or the entity lacks it.
+    pub fn runtime_bytes(&self, entity: Entity, component: &str) -> Option<&[u8]> {
+        let key = self.registry.layout_key(component)?;
+        self.layout_row(key, entity).map(|(_, image)| image)
+    }
+
+    /// Overwrites the fixed-size fields of a layout component on an entity
+    /// with a packed image, adding the component with empty strings when the
+    /// entity lacks it. Returns false when the name is not a layout
+    /// component, the image length does not match the layout, or the entity
+    /// is dead.
+    pub fn set_runtime_bytes(&mut self, entity: Entity, component: &str, bytes: &[u8]) -> bool {
+        let Some(key) = self.registry.layout_key(component) else {
+            return false;
+        };
+        if bytes.len() != self.layout_of(key).size() || !self.has_row_slot(entity) {
+            return false;
+        }
+        let Some((_, image)) = self.layout_row_for_write(key, entity) else {
+            return false;
+        };
+        image.copy_from_slice(bytes);
+        true
+    }
+
+    /// One field of a layout component on an entity. `None` when the name
+    /// is not a layout component, the entity lacks it, or the field does not
+    /// exist.
+    pub fn runtime_field(
+        &self,
+        entity: Entity,
+        component: &str,
+        field: &str,
+    ) -> Option<FieldValue> {
+        let key = self.registry.layout_key(component)?;
+        let (strings, image) = self.layout_row(key, entity)?;
+        self.layout_of(key).read(strings, image, field)
+    }
+
+    /// Writes one field of a layout component on an entity, adding the
+    /// component as a zeroed row when the entity lacks it. Returns false when
+    /// the name is not a layout component, the field does not exist or the
+    /// value's shape does not match it, or the entity is dead.
+    pub fn set_runtime_field(
+        &mut self,
+        entity: Entity,
+        component: &str,
+        field: &str,
+        value: FieldValue,
+    ) -> bool {
+        let Some(key) = self.registry.layout_key(component) else {
+            return false;
+        };
+        let layout = self.layout_of(key);
+        if !layout.accepts(field, &value) || !self.has_row_slot(entity) {
+            return false;
+        }
+        let Some((strings, image)) = self.layout_row_for_write(key, entity) else {
+            return false;
+        };
+        layout.write(strings, image, field, value)
+    }
+
+    fn layout_of(&self, key: LayoutKey) -> &'static ComponentLayout {
+        self.registry.components[key.component_index as usize]
+            .layout
+            .expect("layout keys come from layout components")
+    }
+
+    /// A layout component's row on `entity`: its strings and packed image.
+    fn layout_row(&self, key: LayoutKey, entity: Entity) -> Option<(&[String], &[u8])> {
+        self.check_key(key.registry_id);
+        let (table_index, array_index) = get_location(&self.entity_locations, entity)?;
+        let table = &self.tables[table_index];
+        if table.mask & key.mask == 0 {
+            return None;
+        }
+        let position = column_position(table.mask, key.mask);
+        Some(
+            table.columns[position]
+                .data
+                .layout_rows()
+                .layout_row(array_index),
+        )
+    }
+
+    /// [`layout_row`](Self::layout_row) for a write, stamping the change
+    /// tick and first moving the entity into a table with the component,
+    /// on a blank row, when it lacks it. `None` when the entity has no row
+    /// to extend.
+    fn layout_row_for_write(
+        &mut self,
+        key: LayoutKey,
+        entity: Entity,
+    ) -> Option<(&mut [String], &mut [u8])> {
+        self.check_key(key.registry_id);
+        if get_location(&self.entity_locations, entity).is_none() {
+            self.add_components(entity, 0);
+        }
+        let (mut table_index, mut array_index) = get_location(&self.entity_locations, entity)?;
+        let current_tick = self.current_tick;
+        let current_mask = self.tables[table_index].mask;
+        if current_mask & key.mask == 0 {
+            let track = self.change_detection;
+            let added = self.registry.required_mask(key.mask) & !current_mask;
+            let target = self.resolve_add_target(table_index, added);
+            (table_index, array_index) = self.move_entity_skipping(
+                entity,
+                table_index,
+                array_index,
+                target,
+                key.component_index,
+                None,
+            );
+            self.record_structural(entity, StructuralChangeKind::ComponentsAdded, added);
+            let position = column_position(self.tables[table_index].mask, key.mask);
+            let column = &mut self.tables[table_index].columns[position];
+            column.data.layout_rows_mut().push_blank_row();
+            column.track_push(track, current_tick, current_tick);
+        }
+        let table = &mut self.tables[table_index];
+        let position = column_position(table.mask, key.mask);
+        let column = &mut table.columns[position];
+        if let Some(cell) = column.changed.get_mut(array_index) {
+            *cell = current_tick;
+        }
+        column.peak_changed = current_tick;
+        Some(column.data.layout_rows_mut().layout_row_mut(array_index))
+    }
+
+    /// Whether a write may give `entity` a row: it is alive here, or this is
+    /// a grouped member world, which defers liveness to its group.
+    fn has_row_slot(&self, entity: Entity) -> bool {
+        self.insert_missing_rows || self.is_alive(entity)
+    }
+
     pub fn register_tag(&mut self) -> TagKey {
         let key = self.registry.register_tag();
         while self.tags.len() < self.registry.tag_count as usize {
@@ -1566,7 +3342,93 @@ impl DynWorld {
             resource_count: self.resources.entries.len(),
             event_channels: self.events.channel_count(),
             pending_commands: self.command_count(),
+            component_memory: self.component_memory(),
+            entity_index_memory: self
+                .tables
+                .iter()
+                .map(|table| MemoryUsage::of_vec(&table.entity_indices))
+                .sum(),
+            tag_memory: self.tags.iter().map(SparseTagSet::memory_usage).sum(),
+            location_memory: self.entity_locations.memory_usage(),
+            structural_log_memory: MemoryUsage::of_vec(&self.structural_log)
+                + MemoryUsage::of_vec(&self.structural_batches)
+                + self
+                    .structural_batches
+                    .iter()
+                    .map(|batch| MemoryUsage::of_vec(&batch.entities))
+                    .sum(),
+            event_memory: self.events.memory_usage(),
+            #[cfg(feature = "raw_storage")]
+            pooled_bytes: raw_storage::pool_held_bytes(),
+            #[cfg(not(feature = "raw_storage"))]
+            pooled_bytes: 0,
+        }
+    }
+
+    fn component_memory(&self) -> Vec<ComponentMemory> {
+        let mut memory: Vec<ComponentMemory> = self
+            .registry
+            .components
+            .iter()
+            .map(|info| ComponentMemory {
+                name: info.type_name,
+                mask: info.mask,
+                ..ComponentMemory::default()
+            })
+            .collect();
+        for table in &self.tables {
+            for slot in &table.columns {
+                let index = slot.component_index as usize;
+                let info = &self.registry.components[index];
+                memory[index].data += (info.column_memory)(&slot.data);
+                memory[index].ticks +=
+                    MemoryUsage::of_vec(&slot.changed) + MemoryUsage::of_vec(&slot.added);
+            }
+        }
+        memory
+    }
+
+    /// Returns the spare capacity of every column, tick column, tag set,
+    /// log, and event channel, keeping every value and every table. Cheap
+    /// enough between levels; the next spawns regrow what they need.
+    pub fn shrink_to_fit(&mut self) {
+        for table in &mut self.tables {
+            for slot in &mut table.columns {
+                let info = &self.registry.components[slot.component_index as usize];
+                (info.shrink_column)(&mut slot.data);
+                slot.changed.shrink_to_fit();
+                slot.added.shrink_to_fit();
+            }
+            table.entity_indices.shrink_to_fit();
+        }
+        for tag in &mut self.tags {
+            tag.shrink_to_fit();
         }
+        self.entity_locations.shrink_to_fit();
+        self.structural_log.shrink_to_fit();
+        self.structural_batches.shrink_to_fit();
+        self.events.shrink_to_fit();
+        self.query_cache.shrink_to_fit();
+        self.added_scratch = Vec::new();
+    }
+
+    /// The after-unload cleanup: drops empty tables through
+    /// [`compact`](Self::compact) and shrinks everything through
+    /// [`shrink_to_fit`](Self::shrink_to_fit). Returns the capacity bytes
+    /// handed back to the allocator, as measured by
+    /// [`WorldStats::total_memory`] plus the pool.
+    ///
+    /// With `raw_storage`, the columns of dropped tables go to the calling
+    /// thread's recycle pool, which every world on the thread shares, so
+    /// they are not counted as released. Trimming one world leaves the pool
+    /// alone; call [`release_column_pool`] once every world on the thread is
+    /// trimmed.
+    pub fn trim_memory(&mut self) -> usize {
+        let held = |stats: WorldStats| stats.total_memory().capacity_bytes + stats.pooled_bytes;
+        let before = held(self.stats());
+        self.compact();
+        self.shrink_to_fit();
+        before.saturating_sub(held(self.stats()))
     }
 
     /// Drops empty archetype tables and rebuilds every structure that
@@ -1610,9 +3472,69 @@ impl DynWorld {
         self.migration_plans.clear();
         self.migration_plan_lookup.clear();
         self.last_migration_plan = None;
+        for live_query in &mut self.live_queries {
+            live_query.rematch(&self.tables);
+        }
+        self.structural_epoch = self.structural_epoch.wrapping_add(1);
         dropped
     }
 
+    /// Reorders the rows of every table carrying `T` by `key` over each
+    /// row's `T`, for locality in sprite batching or spatial sweeps. All of
+    /// a table's columns move together and entity locations follow; rows
+    /// with equal keys keep their relative order. Change and added ticks
+    /// travel with their rows instead of being stamped, because no value
+    /// changed. Works the same on either storage backend.
+    pub fn sort_table_rows_by<T: ComponentData, K: Ord>(&mut self, mut key: impl FnMut(&T) -> K) {
+        let Some(component) = self.lookup_key::<T>() else {
+            return;
+        };
+        for table_index in 0..self.tables.len() {
+            let table = &self.tables[table_index];
+            if table.mask & component.mask == 0 || table.entity_indices.len() < 2 {
+                continue;
+            }
+            let column = table.column(component);
+            let mut order: Vec<usize> = (0..column.len()).collect();
+            order.sort_by_cached_key(|&row| key(&column[row]));
+            self.reorder_table(table_index, &order);
+        }
+    }
+
+    /// Reorders every table's rows by ascending entity id, the same
+    /// tick-preserving permutation as
+    /// [`sort_table_rows_by`](Self::sort_table_rows_by). Useful after heavy
+    /// churn, or to give two worlds the same row order before comparing them.
+    pub fn sort_by_entity(&mut self) {
+        for table_index in 0..self.tables.len() {
+            let entities = &self.tables[table_index].entity_indices;
+            let mut order: Vec<usize> = (0..entities.len()).collect();
+            order.sort_unstable_by_key(|&row| entities[row].id);
+            self.reorder_table(table_index, &order);
+        }
+    }
+
+    /// Permutes one table so row `i` holds what row `order[i]` held, across
+    /// every column and its tick columns, then rewrites the locations. Bumps
+    /// the structural epoch, since live queries cache `(table, row)` pairs.
+    fn reorder_table(&mut self, table_index: usize, order: &[usize]) {
+        if order.iter().enumerate().all(|(row, &source)| row == source) {
+            return;
+        }
+        let table = &mut self.tables[table_index];
+        for slot in &mut table.columns {
+            let info = &self.registry.components[slot.component_index as usize];
+            (info.permute_rows)(&mut slot.data, order);
+            crate::apply_row_order(&mut slot.changed, order);
+            crate::apply_row_order(&mut slot.added, order);
+        }
+        crate::apply_row_order(&mut table.entity_indices, order);
+        for (row, &entity) in table.entity_indices.iter().enumerate() {
+            insert_location(&mut self.entity_locations, entity, (table_index, row));
+        }
+        self.structural_epoch = self.structural_epoch.wrapping_add(1);
+    }
+
     /// The components an entity currently carries, as registry records with
     /// their type names, masks, and vtables. Dead or rowless entities yield
     /// nothing. This is the inspection surface for editors and tooling; pair
@@ -1682,11 +3604,13 @@ impl DynWorld {
     }
 
     fn record_structural(&mut self, entity: Entity, kind: StructuralChangeKind, mask: u64) {
+        self.structural_epoch = self.structural_epoch.wrapping_add(1);
         if !self.structural_logging {
             return;
         }
         if self.structural_log.len() >= STRUCTURAL_LOG_CAPACITY {
             self.structural_log.clear();
+            self.structural_batches.clear();
         }
         self.structural_sequence += 1;
         self.structural_log.push(StructuralChange {
@@ -1697,6 +3621,26 @@ impl DynWorld {
         });
     }
 
+    /// Records one batch entry: the log entry names the first entity and the
+    /// full list goes to [`Self::structural_batches`] under its sequence.
+    fn record_structural_batch(
+        &mut self,
+        entities: Vec<Entity>,
+        kind: StructuralChangeKind,
+        mask: u64,
+    ) {
+        let Some(&first) = entities.first() else {
+            return;
+        };
+        self.record_structural(first, kind, mask);
+        if self.structural_logging {
+            self.structural_batches.push(StructuralBatch {
+                sequence: self.structural_sequence,
+                entities,
+            });
+        }
+    }
+
     fn get_or_create_table(&mut self, mask: u64) -> usize {
         debug_assert_eq!(
             mask & !self.registry.all_components_mask(),
@@ -1721,7 +3665,7 @@ impl DynWorld {
             if mask & info.mask != 0 {
                 columns.push(ColumnSlot {
                     component_index: (info.mask.trailing_zeros()),
-                    data: (info.new_column)(),
+                    data: (info.new_column)(info),
                     changed: Vec::new(),
                     peak_changed: self.current_tick,
                     added: Vec::new(),
@@ -1751,10 +3695,19 @@ impl DynWorld {
                 .enumerate()
                 .map(|(component_index, info)| (info.mask, component_index)),
         );
+        for live_query in &mut self.live_queries {
+            if live_query.matches_table(mask) {
+                live_query.tables.push(table_index);
+            }
+        }
 
         table_index
     }
 
+    /// Spawns `count` entities carrying default values for the components in
+    /// `mask` and the ones they require. Panics if one of them has no default:
+    /// such a component spawns with its value through [`spawn`](Self::spawn)
+    /// or [`spawn_bundles`](Self::spawn_bundles).
     pub fn spawn_entities(&mut self, mask: u64, count: usize) -> Vec<Entity> {
         let mut allocator = std::mem::take(&mut self.allocator);
         let entities = self.spawn_entities_in(&mut allocator, mask, count);
@@ -1770,6 +3723,10 @@ impl DynWorld {
         mask: u64,
         count: usize,
     ) -> Vec<Entity> {
+        let mask = self.registry.required_mask(mask);
+        if let Some(type_name) = self.registry.first_without_default(mask) {
+            panic!("{type_name} has no default value; spawn it with a value instead of by mask");
+        }
         let table_index = self.get_or_create_table(mask);
         let current_tick = self.current_tick;
 
@@ -1783,7 +3740,8 @@ impl DynWorld {
             table.entity_indices.reserve(count);
             for column in &mut table.columns {
                 let info = &self.registry.components[column.component_index as usize];
-                (info.push_default)(&mut column.data, count);
+                let push_default = info.push_default.expect("checked above");
+                push_default(&mut column.data, count);
                 column.track_extend(track, count, current_tick);
             }
             for &entity in &entities {
@@ -1821,6 +3779,18 @@ impl DynWorld {
 
     fn remove_row(&mut self, table_index: usize, array_index: usize) {
         let track = self.change_detection;
+        let table = &mut self.tables[table_index];
+        for column in &mut table.columns {
+            let info = &self.registry.components[column.component_index as usize];
+            (info.swap_remove)(&mut column.data, array_index);
+            column.track_swap_remove(track, array_index);
+        }
+        self.detach_row(table_index, array_index);
+    }
+
+    /// Swap-removes a row's entity slot once its columns are gone, repointing
+    /// the entity that moved into the hole.
+    fn detach_row(&mut self, table_index: usize, array_index: usize) {
         let table = &mut self.tables[table_index];
         let last_index = table.entity_indices.len() - 1;
         let swapped = if array_index < last_index {
@@ -1828,12 +3798,6 @@ impl DynWorld {
         } else {
             None
         };
-
-        for column in &mut table.columns {
-            let info = &self.registry.components[column.component_index as usize];
-            (info.swap_remove)(&mut column.data, array_index);
-            column.track_swap_remove(track, array_index);
-        }
         table.entity_indices.swap_remove(array_index);
 
         if let Some(swapped_entity) = swapped
@@ -1896,14 +3860,22 @@ impl DynWorld {
     }
 
     /// Direct children of a parent: every entity whose [`ChildOf`] link
-    /// points at it. A full scan of `ChildOf` carriers on each call, pull
-    /// not push; cache user-side when it measures hot.
+    /// points at it, in [`ChildOrder`] order. A full scan of `ChildOf`
+    /// carriers on each call, pull not push; cache user-side when it
+    /// measures hot.
     pub fn children(&self, parent: Entity) -> Vec<Entity> {
-        self.query_ref::<&ChildOf>()
+        let mut children: Vec<Entity> = self
+            .query_ref::<&ChildOf>()
+            .include_disabled()
             .iter()
             .filter(|(_entity, child_of)| child_of.0 == parent)
             .map(|(entity, _child_of)| entity)
-            .collect()
+            .collect();
+        children.sort_by_key(|&child| {
+            self.get::<ChildOrder>(child)
+                .map_or(u32::MAX, |order| order.0)
+        });
+        children
     }
 
     /// Despawns an entity and every descendant reachable through
@@ -2021,7 +3993,7 @@ impl DynWorld {
         from_index: usize,
         to_table: usize,
     ) -> (usize, usize) {
-        self.move_entity_skipping(entity, from_table, from_index, to_table, u32::MAX)
+        self.move_entity_skipping(entity, from_table, from_index, to_table, u32::MAX, None)
     }
 
     /// Moves a row between tables, leaving the column whose component index is
@@ -2032,6 +4004,9 @@ impl DynWorld {
     /// dispatch through the registry, a `T::default()`, and a write, all
     /// discarded. It skips that column and pushes the real value instead.
     /// `u32::MAX` skips nothing, which is what every other caller wants.
+    ///
+    /// With `taken`, the components the move drops are handed over by
+    /// ownership instead of dropped, which is how a bundle is taken.
     fn move_entity_skipping(
         &mut self,
         entity: Entity,
@@ -2039,6 +4014,7 @@ impl DynWorld {
         from_index: usize,
         to_table: usize,
         skip_gained: u32,
+        mut taken: Option<&mut TakenComponents>,
     ) -> (usize, usize) {
         let tick = self.current_tick;
         let track = self.change_detection;
@@ -2057,7 +4033,10 @@ impl DynWorld {
                 }
                 let destination_column = &mut destination.columns[destination_position as usize];
                 let info = &self.registry.components[component_index as usize];
-                (info.push_default)(&mut destination_column.data, 1);
+                let push_default = info
+                    .push_default
+                    .expect("a gained component without a default arrives with its value");
+                push_default(&mut destination_column.data, 1);
                 destination_column.track_push(track, tick, tick);
             }
 
@@ -2087,50 +4066,157 @@ impl DynWorld {
             }
 
             for &(source_position, component_index) in &plan.removed {
-                let source_position = source_position as usize;
+                let source_position = source_position as usize;
+                if let Some(taken) = taken.as_deref_mut() {
+                    let info = &self.registry.components[component_index as usize];
+                    let mut value = (info.new_column)(info);
+                    (info.swap_remove_into)(
+                        &mut source.columns[source_position].data,
+                        from_index,
+                        &mut value,
+                    );
+                    taken.columns.push((component_index, value));
+                } else {
+                    #[cfg(not(feature = "raw_storage"))]
+                    {
+                        let info = &self.registry.components[component_index as usize];
+                        (info.swap_remove)(&mut source.columns[source_position].data, from_index);
+                    }
+                    #[cfg(feature = "raw_storage")]
+                    {
+                        let _ = component_index;
+                        source.columns[source_position]
+                            .data
+                            .swap_remove_raw(from_index);
+                    }
+                }
+                source.columns[source_position].track_swap_remove(track, from_index);
+            }
+
+            destination.entity_indices.push(entity);
+
+            let last_index = source.entity_indices.len() - 1;
+            swapped_entity = if from_index < last_index {
+                Some(source.entity_indices[last_index])
+            } else {
+                None
+            };
+            source.entity_indices.swap_remove(from_index);
+        }
+
+        let new_index = self.tables[to_table].entity_indices.len() - 1;
+        insert_location(&mut self.entity_locations, entity, (to_table, new_index));
+        if let Some(swapped) = swapped_entity
+            && let Some(location) = self.entity_locations.get_mut(swapped.id)
+            && location.allocated
+        {
+            location.array_index = from_index as u32;
+        }
+        (to_table, new_index)
+    }
+
+    /// [`move_entity_skipping`](Self::move_entity_skipping) for many rows of
+    /// one table at once, column by column: each column's plan entry and
+    /// dispatch resolve once for the whole run. `rows` must be distinct and
+    /// sorted descending, so every swap-remove pulls its replacement from past
+    /// the rows still to move and a run at the table's tail is a string of
+    /// pops. Gained columns are defaulted, so callers refuse components
+    /// without a default first.
+    fn move_entities(&mut self, from_table: usize, rows: &[usize], to_table: usize) {
+        let tick = self.current_tick;
+        let track = self.change_detection;
+        let plan_index = self.resolve_migration_plan(from_table, to_table);
+        {
+            let plan = &self.migration_plans[plan_index];
+            let [source, destination] = self
+                .tables
+                .get_disjoint_mut([from_table, to_table])
+                .expect("migration source and destination must differ");
+
+            for &(destination_position, component_index) in &plan.gained {
+                let destination_column = &mut destination.columns[destination_position as usize];
+                let info = &self.registry.components[component_index as usize];
+                let push_default = info
+                    .push_default
+                    .expect("batch adds refuse components without a default");
+                push_default(&mut destination_column.data, rows.len());
+                for _ in rows {
+                    destination_column.track_push(track, tick, tick);
+                }
+            }
+
+            for &(source_position, destination_position, component_index) in &plan.shared {
+                let source_position = source_position as usize;
+                let destination_position = destination_position as usize;
+                #[cfg(not(feature = "raw_storage"))]
+                let swap_remove_into =
+                    self.registry.components[component_index as usize].swap_remove_into;
+                #[cfg(feature = "raw_storage")]
+                let _ = component_index;
+                for &row in rows {
+                    let carried_added = source.columns[source_position].carried_added(row);
+                    #[cfg(not(feature = "raw_storage"))]
+                    swap_remove_into(
+                        &mut source.columns[source_position].data,
+                        row,
+                        &mut destination.columns[destination_position].data,
+                    );
+                    #[cfg(feature = "raw_storage")]
+                    source.columns[source_position].data.swap_remove_into_raw(
+                        row,
+                        &mut destination.columns[destination_position].data,
+                    );
+                    source.columns[source_position].track_swap_remove(track, row);
+                    destination.columns[destination_position].track_push(
+                        track,
+                        tick,
+                        carried_added,
+                    );
+                }
+            }
+
+            for &(source_position, component_index) in &plan.removed {
+                let column = &mut source.columns[source_position as usize];
                 #[cfg(not(feature = "raw_storage"))]
-                {
-                    let info = &self.registry.components[component_index as usize];
-                    (info.swap_remove)(&mut source.columns[source_position].data, from_index);
-                }
+                let swap_remove = self.registry.components[component_index as usize].swap_remove;
                 #[cfg(feature = "raw_storage")]
-                {
-                    let _ = component_index;
-                    source.columns[source_position]
-                        .data
-                        .swap_remove_raw(from_index);
+                let _ = component_index;
+                for &row in rows {
+                    #[cfg(not(feature = "raw_storage"))]
+                    swap_remove(&mut column.data, row);
+                    #[cfg(feature = "raw_storage")]
+                    column.data.swap_remove_raw(row);
+                    column.track_swap_remove(track, row);
                 }
-                source.columns[source_position].track_swap_remove(track, from_index);
             }
-
-            destination.entity_indices.push(entity);
-
-            let last_index = source.entity_indices.len() - 1;
-            swapped_entity = if from_index < last_index {
-                Some(source.entity_indices[last_index])
-            } else {
-                None
-            };
-            source.entity_indices.swap_remove(from_index);
         }
 
-        let new_index = self.tables[to_table].entity_indices.len() - 1;
-        insert_location(&mut self.entity_locations, entity, (to_table, new_index));
-        if let Some(swapped) = swapped_entity
-            && let Some(location) = self.entity_locations.get_mut(swapped.id)
-            && location.allocated
-        {
-            location.array_index = from_index as u32;
+        for &row in rows {
+            let entity = self.tables[from_table].entity_indices.swap_remove(row);
+            let destination = &mut self.tables[to_table].entity_indices;
+            destination.push(entity);
+            let new_index = destination.len() - 1;
+            insert_location(&mut self.entity_locations, entity, (to_table, new_index));
+            if let Some(&swapped) = self.tables[from_table].entity_indices.get(row)
+                && let Some(location) = self.entity_locations.get_mut(swapped.id)
+                && location.allocated
+            {
+                location.array_index = row as u32;
+            }
         }
-        (to_table, new_index)
     }
 
+    /// Adds the components in `mask`, and the ones they require, at their
+    /// defaults in one table move. Refused, returning false, when one of the
+    /// added components has no default; [`set`](Self::set) adds it with a
+    /// value instead.
     pub fn add_components(&mut self, entity: Entity, mask: u64) -> bool {
         debug_assert_eq!(
             mask & !self.registry.all_components_mask(),
             0,
             "component masks must not contain tag bits or unregistered component bits"
         );
+        let mask = self.registry.required_mask(mask);
         let Some((table_index, array_index)) = get_location(&self.entity_locations, entity) else {
             return self.insert_missing_rows && self.insert_row(entity, mask);
         };
@@ -2138,6 +4224,9 @@ impl DynWorld {
         if current_mask & mask == mask {
             return true;
         }
+        if mask & !current_mask & self.registry.no_default != 0 {
+            return false;
+        }
 
         let new_table_index = self.resolve_add_target(table_index, mask);
         self.move_entity(entity, table_index, array_index, new_table_index);
@@ -2149,6 +4238,110 @@ impl DynWorld {
         true
     }
 
+    /// [`add_components`](Self::add_components) for many entities at once.
+    /// Rows are grouped by source table and each group moves column by
+    /// column, recording one [`StructuralChangeKind::ComponentsAddedBatch`]
+    /// entry per group instead of one entry per entity. Entities without a
+    /// row take the single path, and ones the single path would refuse are
+    /// skipped. Returns how many entities gained components.
+    pub fn add_components_batch(&mut self, entities: &[Entity], mask: u64) -> usize {
+        debug_assert_eq!(
+            mask & !self.registry.all_components_mask(),
+            0,
+            "component masks must not contain tag bits or unregistered component bits"
+        );
+        let mask = self.registry.required_mask(mask);
+        let mut rows = Vec::with_capacity(entities.len());
+        let mut moved = 0;
+        for &entity in entities {
+            match get_location(&self.entity_locations, entity) {
+                Some((table_index, array_index)) => {
+                    let current_mask = self.tables[table_index].mask;
+                    if current_mask & mask != mask
+                        && mask & !current_mask & self.registry.no_default == 0
+                    {
+                        rows.push((table_index, array_index));
+                    }
+                }
+                None => {
+                    if self.insert_missing_rows && self.insert_row(entity, mask) {
+                        moved += 1;
+                    }
+                }
+            }
+        }
+        sort_batch_rows(&mut rows);
+        for group in rows.chunk_by(|left, right| left.0 == right.0) {
+            let table_index = group[0].0;
+            let current_mask = self.tables[table_index].mask;
+            let (group_rows, group_entities) = self.batch_group(table_index, group);
+            let new_table_index = self.resolve_add_target(table_index, mask);
+            self.move_entities(table_index, &group_rows, new_table_index);
+            moved += group_entities.len();
+            self.record_structural_batch(
+                group_entities,
+                StructuralChangeKind::ComponentsAddedBatch,
+                mask & !current_mask,
+            );
+        }
+        moved
+    }
+
+    /// [`remove_components`](Self::remove_components) for many entities at
+    /// once, grouped and moved like
+    /// [`add_components_batch`](Self::add_components_batch) under one
+    /// [`StructuralChangeKind::ComponentsRemovedBatch`] entry per source
+    /// table. A group [`required_removal`](Self::required_removal) rejects
+    /// keeps its components. Returns how many entities lost components.
+    pub fn remove_components_batch(&mut self, entities: &[Entity], mask: u64) -> usize {
+        debug_assert_eq!(
+            mask & !self.registry.all_components_mask(),
+            0,
+            "component masks must not contain tag bits or unregistered component bits"
+        );
+        let mut rows = Vec::with_capacity(entities.len());
+        for &entity in entities {
+            if let Some((table_index, array_index)) = get_location(&self.entity_locations, entity)
+                && self.tables[table_index].mask & mask != 0
+            {
+                rows.push((table_index, array_index));
+            }
+        }
+        sort_batch_rows(&mut rows);
+        let mut moved = 0;
+        for group in rows.chunk_by(|left, right| left.0 == right.0) {
+            let table_index = group[0].0;
+            let current_mask = self.tables[table_index].mask;
+            let Some(removal) = self.resolve_removal(current_mask, mask) else {
+                continue;
+            };
+            let (group_rows, group_entities) = self.batch_group(table_index, group);
+            let new_table_index = self.resolve_remove_target(table_index, removal);
+            self.move_entities(table_index, &group_rows, new_table_index);
+            moved += group_entities.len();
+            self.record_structural_batch(
+                group_entities,
+                StructuralChangeKind::ComponentsRemovedBatch,
+                current_mask & removal,
+            );
+        }
+        moved
+    }
+
+    /// Splits one table's sorted `(table, row)` run into its rows and the
+    /// entities at them, read before the move reorders the table.
+    fn batch_group(
+        &self,
+        table_index: usize,
+        group: &[(usize, usize)],
+    ) -> (Vec<usize>, Vec<Entity>) {
+        let entity_indices = &self.tables[table_index].entity_indices;
+        group
+            .iter()
+            .map(|&(_, row)| (row, entity_indices[row]))
+            .unzip()
+    }
+
     /// Creates a row for a live handle this world has never stored. Refuses
     /// stale handles via the generation the despawn broadcast retired.
     fn insert_row(&mut self, entity: Entity, mask: u64) -> bool {
@@ -2157,6 +4350,9 @@ impl DynWorld {
         {
             return false;
         }
+        if mask & self.registry.no_default != 0 {
+            return false;
+        }
 
         let table_index = self.get_or_create_table(mask);
         let current_tick = self.current_tick;
@@ -2166,7 +4362,8 @@ impl DynWorld {
             let table = &mut self.tables[table_index];
             for column in &mut table.columns {
                 let info = &self.registry.components[column.component_index as usize];
-                (info.push_default)(&mut column.data, 1);
+                let push_default = info.push_default.expect("checked above");
+                push_default(&mut column.data, 1);
                 column.track_push(track, current_tick, current_tick);
             }
             table.entity_indices.push(entity);
@@ -2181,6 +4378,17 @@ impl DynWorld {
     }
 
     pub fn remove_components(&mut self, entity: Entity, mask: u64) -> bool {
+        self.remove_components_into(entity, mask, None)
+    }
+
+    /// [`remove_components`](Self::remove_components), handing the removed
+    /// values to `taken` when given.
+    fn remove_components_into(
+        &mut self,
+        entity: Entity,
+        mask: u64,
+        taken: Option<&mut TakenComponents>,
+    ) -> bool {
         debug_assert_eq!(
             mask & !self.registry.all_components_mask(),
             0,
@@ -2193,8 +4401,32 @@ impl DynWorld {
         if current_mask & mask == 0 {
             return true;
         }
+        let Some(mask) = self.resolve_removal(current_mask, mask) else {
+            return false;
+        };
+
+        let new_table_index = self.resolve_remove_target(table_index, mask);
+        self.move_entity_skipping(
+            entity,
+            table_index,
+            array_index,
+            new_table_index,
+            u32::MAX,
+            taken,
+        );
+        self.record_structural(
+            entity,
+            StructuralChangeKind::ComponentsRemoved,
+            current_mask & mask,
+        );
+        true
+    }
 
-        let target_table = if mask.count_ones() == 1 {
+    /// Resolves the table an entity moves to when `mask` is removed, creating
+    /// and caching the edge on first use.
+    fn resolve_remove_target(&mut self, table_index: usize, mask: u64) -> usize {
+        let current_mask = self.tables[table_index].mask;
+        let cached = if mask.count_ones() == 1 {
             self.table_edges[table_index]
                 .remove_edges
                 .get(mask.trailing_zeros() as usize)
@@ -2206,22 +4438,27 @@ impl DynWorld {
                 .get(&mask)
                 .copied()
         };
-
-        let new_table_index = target_table.unwrap_or_else(|| {
+        cached.unwrap_or_else(|| {
             let new_index = self.get_or_create_table(current_mask & !mask);
             self.table_edges[table_index]
                 .multi_remove_cache
                 .insert(mask, new_index);
             new_index
-        });
+        })
+    }
 
-        self.move_entity(entity, table_index, array_index, new_table_index);
-        self.record_structural(
-            entity,
-            StructuralChangeKind::ComponentsRemoved,
-            current_mask & mask,
-        );
-        true
+    /// The mask a removal of `mask` really strips from a row of
+    /// `current_mask` under [`Self::required_removal`], or `None` when the
+    /// policy refuses it.
+    fn resolve_removal(&self, current_mask: u64, mask: u64) -> Option<u64> {
+        let dependents = self.registry.dependents(current_mask, current_mask & mask);
+        if dependents == 0 {
+            return Some(mask);
+        }
+        match self.required_removal {
+            RequiredRemoval::Reject => None,
+            RequiredRemoval::Cascade => Some(mask | dependents),
+        }
     }
 
     #[inline]
@@ -2332,15 +4569,17 @@ impl DynWorld {
                 }
 
                 let track = self.change_detection;
-                let target = self.resolve_add_target(table_index, key.mask);
+                let added = self.registry.required_mask(key.mask) & !current_mask;
+                let target = self.resolve_add_target(table_index, added);
                 let (new_table, _) = self.move_entity_skipping(
                     entity,
                     table_index,
                     array_index,
                     target,
                     key.component_index,
+                    None,
                 );
-                self.record_structural(entity, StructuralChangeKind::ComponentsAdded, key.mask);
+                self.record_structural(entity, StructuralChangeKind::ComponentsAdded, added);
                 let position = column_position(self.tables[new_table].mask, key.mask);
                 let column = &mut self.tables[new_table].columns[position];
                 column.data.push::<T>(value);
@@ -2349,18 +4588,10 @@ impl DynWorld {
                 return;
             }
 
-            if self.add_components(entity, key.mask)
-                && let Some((table_index, array_index)) =
-                    get_location(&self.entity_locations, entity)
+            if self.add_components(entity, key.mask & !self.registry.no_default)
+                && get_location(&self.entity_locations, entity).is_some()
             {
-                let table = &mut self.tables[table_index];
-                let position = column_position(table.mask, key.mask);
-                let column = &mut table.columns[position];
-                column_vec_mut::<T>(&mut column.data)[array_index] = value;
-                if let Some(cell) = column.changed.get_mut(array_index) {
-                    *cell = current_tick;
-                }
-                column.peak_changed = current_tick;
+                self.set_keyed(key, entity, value);
             }
         }
     }
@@ -2369,7 +4600,7 @@ impl DynWorld {
     /// the column once. The batch spawn path grows every column this way, so a
     /// bundle spawn writes each component once rather than a default followed
     /// by an overwrite.
-    pub fn extend_column<T: Send + Sync + Default + Clone + 'static>(
+    pub fn extend_column<T: ComponentData + Clone>(
         &mut self,
         table_index: usize,
         count: usize,
@@ -2438,15 +4669,37 @@ impl DynWorld {
         &self.structural_log[start..]
     }
 
+    /// The entity list of the batch entry recorded at `sequence`, while it is
+    /// still in the log.
+    pub fn structural_batch(&self, sequence: u64) -> Option<&[Entity]> {
+        self.structural_batches
+            .binary_search_by_key(&sequence, |batch| batch.sequence)
+            .ok()
+            .map(|index| self.structural_batches[index].entities.as_slice())
+    }
+
+    /// Every entity a log entry covers: the batch list for
+    /// [`ComponentsAddedBatch`](StructuralChangeKind::ComponentsAddedBatch)
+    /// and [`ComponentsRemovedBatch`](StructuralChangeKind::ComponentsRemovedBatch),
+    /// otherwise the entry's own entity.
+    pub fn change_entities<'a>(&'a self, change: &'a StructuralChange) -> &'a [Entity] {
+        structural_change_entities(&self.structural_batches, change)
+    }
+
     pub fn trim_structural_log(&mut self, up_to_sequence: u64) {
         let end = self
             .structural_log
             .partition_point(|change| change.sequence <= up_to_sequence);
         self.structural_log.drain(..end);
+        let end = self
+            .structural_batches
+            .partition_point(|batch| batch.sequence <= up_to_sequence);
+        self.structural_batches.drain(..end);
     }
 
     pub fn clear_structural_log(&mut self) {
         self.structural_log.clear();
+        self.structural_batches.clear();
     }
 
     pub fn add_tag(&mut self, key: TagKey, entity: Entity) {
@@ -2508,6 +4761,86 @@ impl DynWorld {
             .flat_map(|key| self.tags[key.tag_index as usize].iter())
     }
 
+    /// The [`Disabled`] tag's mask when queries naming neither bit in
+    /// `mentioned` should exclude it, zero when the tag was never used or
+    /// nothing is disabled.
+    fn disabled_exclusion(&self, mentioned: u64) -> u64 {
+        match self.registry.lookup_tag_type::<Disabled>() {
+            Some(key)
+                if mentioned & key.mask == 0 && !self.tags[key.tag_index as usize].is_empty() =>
+            {
+                key.mask
+            }
+            _ => 0,
+        }
+    }
+
+    /// The slot behind a [`LiveQuery`] handle. Panics when this world did
+    /// not issue the handle or its query has been released, rather than
+    /// reading some other query's state.
+    fn live_index<Q: QueryTuple>(&self, query: &LiveQuery<Q>) -> usize {
+        assert!(
+            query.world_id == self.world_id,
+            "live query used on a world that did not issue it"
+        );
+        assert!(
+            self.live_queries
+                .get(query.index)
+                .is_some_and(|state| !state.released && state.generation == query.generation),
+            "live query used after it was released"
+        );
+        query.index
+    }
+
+    fn live_disabled_exclusion(&self, live_index: usize) -> u64 {
+        let state = &self.live_queries[live_index];
+        if state.include_disabled {
+            0
+        } else {
+            self.disabled_exclusion(state.tag_include | state.tag_exclude)
+        }
+    }
+
+    /// Disables an entity: it keeps its components and its row, but every
+    /// query, `for_each`, join, and system-param `Query` skips it until
+    /// [`enable`](Self::enable). Backed by the [`Disabled`] marker tag,
+    /// registered on first use, and recorded in the structural log as
+    /// [`StructuralChangeKind::Disabled`]. Returns false when the entity is
+    /// not in this world or already disabled.
+    pub fn disable(&mut self, entity: Entity) -> bool {
+        if !self.contains_entity(entity) {
+            return false;
+        }
+        let key = self.tag_key::<Disabled>();
+        if !self.tags[key.tag_index as usize].insert(entity) {
+            return false;
+        }
+        self.record_structural(entity, StructuralChangeKind::Disabled, key.mask);
+        true
+    }
+
+    /// Re-enables a disabled entity. Returns false when it was not
+    /// disabled.
+    pub fn enable(&mut self, entity: Entity) -> bool {
+        let Some(key) = self.lookup_tag_key::<Disabled>() else {
+            return false;
+        };
+        if !self.tags[key.tag_index as usize].remove(entity) {
+            return false;
+        }
+        self.record_structural(entity, StructuralChangeKind::Enabled, key.mask);
+        true
+    }
+
+    pub fn is_disabled(&self, entity: Entity) -> bool {
+        self.has_tag_type::<Disabled>(entity)
+    }
+
+    /// Iterates the disabled entities.
+    pub fn query_disabled(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.query_tag_type::<Disabled>()
+    }
+
     fn entity_matches_tags(&self, entity: Entity, tag_include: u64, tag_exclude: u64) -> bool {
         for (tag_index, tag_set) in self.tags.iter().enumerate() {
             let tag_mask = 1u64 << (63 - tag_index as u32);
@@ -2539,6 +4872,23 @@ impl DynWorld {
     }
 
     fn split_masks(&self, include: u64, exclude: u64) -> Option<(u64, u64, u64, u64)> {
+        self.split_masks_with(include, exclude, false)
+    }
+
+    /// [`split_masks`](Self::split_masks) with the [`Disabled`] default
+    /// made explicit: unless `include_disabled` is set or either mask names
+    /// the disabled tag itself, disabled entities are excluded.
+    fn split_masks_with(
+        &self,
+        include: u64,
+        exclude: u64,
+        include_disabled: bool,
+    ) -> Option<(u64, u64, u64, u64)> {
+        let exclude = if include_disabled {
+            exclude
+        } else {
+            exclude | self.disabled_exclusion(include | exclude)
+        };
         let all_tags = self.registry.all_tags_mask();
         let (tag_include, tag_exclude) =
             self.reduce_tag_masks(include & all_tags, exclude & all_tags)?;
@@ -2921,6 +5271,32 @@ impl DynWorld {
         self.resources.remove::<T>()
     }
 
+    /// Inserts a resource that is not `Send` or `Sync`, pinning the world's
+    /// non-send resources to the calling thread; see [`NonSendResources`].
+    pub fn insert_non_send<T: 'static>(&mut self, value: T) {
+        self.non_send.insert(value);
+    }
+
+    pub fn remove_non_send<T: 'static>(&mut self) -> Option<T> {
+        self.non_send.remove::<T>()
+    }
+
+    pub fn has_non_send<T: 'static>(&self) -> bool {
+        self.non_send.contains::<T>()
+    }
+
+    /// [`resource_scope`](Self::resource_scope) for a non-send resource:
+    /// takes `R` out, runs the closure with the world and the resource as
+    /// independent borrows, and puts it back, even when the closure panics.
+    /// Panics if `R` is not present or the calling thread does not own the
+    /// non-send resources.
+    pub fn non_send_scope<R: 'static, T>(
+        &mut self,
+        f: impl FnOnce(&mut DynWorld, &mut R) -> T,
+    ) -> T {
+        non_send_scope(self, |host| &mut host.non_send, f)
+    }
+
     /// Takes `R` out of the world, runs the closure with the world and the
     /// resource as independent borrows, then puts the resource back. This is
     /// the take/put pattern for systems that mutate both a resource and the
@@ -3003,6 +5379,18 @@ impl DynWorld {
             .push(DynCommand::RemoveComponents(entity, mask));
     }
 
+    /// The deferred [`add_components_batch`](Self::add_components_batch).
+    pub fn queue_add_components_batch(&mut self, entities: Vec<Entity>, mask: u64) {
+        self.command_buffer
+            .push(DynCommand::AddComponentsBatch(entities, mask));
+    }
+
+    /// The deferred [`remove_components_batch`](Self::remove_components_batch).
+    pub fn queue_remove_components_batch(&mut self, entities: Vec<Entity>, mask: u64) {
+        self.command_buffer
+            .push(DynCommand::RemoveComponentsBatch(entities, mask));
+    }
+
     pub fn queue_add_tag(&mut self, key: TagKey, entity: Entity) {
         self.command_buffer.push(DynCommand::AddTag(entity, key));
     }
@@ -3023,7 +5411,7 @@ impl DynWorld {
 
     /// Queues a typed component write. The value is boxed with the command;
     /// registration happens at queue time so apply order cannot depend on it.
-    pub fn queue_set<T: Send + Sync + Default + 'static>(&mut self, entity: Entity, value: T) {
+    pub fn queue_set<T: ComponentData>(&mut self, entity: Entity, value: T) {
         let key = self.component_key::<T>();
         self.command_buffer
             .push(DynCommand::Closure(Box::new(move |world| {
@@ -3043,14 +5431,48 @@ impl DynWorld {
                 return;
             }
             let mask = B::component_mask(world);
+            let mask = world.registry.required_mask(mask);
             if !world.contains_entity(entity) {
-                world.insert_row(entity, mask);
+                world.insert_row(entity, mask & !world.registry.no_default);
             }
             bundle.write(world, entity);
         });
         entity
     }
 
+    /// Reserves an entity handle through a shared borrow, so read-only
+    /// systems and [`DynQueryRef`] consumers on worker threads can plan
+    /// spawns and reference the new entities in the same frame. The handle
+    /// is generation-correct but not alive yet; the next
+    /// [`apply_commands`](Self::apply_commands) gives it an empty row, before
+    /// any queued command runs, so commands queued against it later in the
+    /// frame land on a live entity. Insert components then with
+    /// `insert_bundle` or `set`.
+    pub fn reserve_entity(&self) -> Entity {
+        self.allocator.reserve()
+    }
+
+    /// Reserves `count` handles at once, with one atomic increment.
+    pub fn reserve_entities(&self, count: usize) -> Vec<Entity> {
+        let mut entities = Vec::with_capacity(count);
+        self.allocator.reserve_batch(count, &mut entities);
+        entities
+    }
+
+    /// Materializes every reserved handle as a live entity with an empty
+    /// row, logged as `Spawned` with mask 0, and returns them in reservation
+    /// order. [`apply_commands`](Self::apply_commands) calls this first;
+    /// call it directly to materialize without applying commands. Handles
+    /// despawned between settling and now are skipped.
+    pub fn spawn_reserved(&mut self) -> Vec<Entity> {
+        let mut reserved = self.allocator.take_reserved();
+        reserved.retain(|&entity| {
+            self.allocator.is_alive(entity)
+                && (self.contains_entity(entity) || self.insert_row(entity, 0))
+        });
+        reserved
+    }
+
     /// Queues an arbitrary deferred mutation.
     pub fn queue(&mut self, command: impl FnOnce(&mut DynWorld) + Send + Sync + 'static) {
         self.command_buffer
@@ -3065,7 +5487,13 @@ impl DynWorld {
         self.command_buffer.clear();
     }
 
-    pub fn apply_commands(&mut self) {
+    /// Applies the queued commands in order. Returns the failures of the
+    /// one-shot systems queued through
+    /// [`queue_run_system`](Self::queue_run_system), in the order they ran;
+    /// the other commands cannot fail.
+    pub fn apply_commands(&mut self) -> Vec<RunSystemError> {
+        self.spawn_reserved();
+        let mut errors = Vec::new();
         let commands = std::mem::take(&mut self.command_buffer);
         for command in commands {
             match command {
@@ -3084,6 +5512,12 @@ impl DynWorld {
                 DynCommand::RemoveComponents(entity, mask) => {
                     self.remove_components(entity, mask);
                 }
+                DynCommand::AddComponentsBatch(entities, mask) => {
+                    self.add_components_batch(&entities, mask);
+                }
+                DynCommand::RemoveComponentsBatch(entities, mask) => {
+                    self.remove_components_batch(&entities, mask);
+                }
                 DynCommand::AddTag(entity, key) => {
                     self.add_tag(key, entity);
                 }
@@ -3093,55 +5527,142 @@ impl DynWorld {
                 DynCommand::Closure(command) => {
                     command(self);
                 }
+                DynCommand::RunSystem(id) => {
+                    if let Err(error) = self.run_system(id) {
+                        errors.push(error);
+                    }
+                }
+            }
+        }
+        errors
+    }
+
+    /// Registers a system-parameter function as a one-shot system, run only
+    /// when asked through [`run_system`](Self::run_system) or
+    /// [`queue_run_system`](Self::queue_run_system). The runner is built
+    /// once, so its [`Local`](crate::system_param::Local) values and
+    /// [`EventReader`](crate::system_param::EventReader) cursors carry over
+    /// from one call to the next.
+    pub fn register_system<Marker>(
+        &mut self,
+        system: impl IntoSystem<DynWorld, Marker>,
+    ) -> SystemId {
+        let name = std::any::type_name_of_val(&system);
+        let id = SystemId(self.systems.len() as u32);
+        let runner = std::sync::Mutex::new(Box::new(system.into_runner()) as Box<_>);
+        self.systems.push(SystemSlot::Ready(name, runner));
+        id
+    }
+
+    /// Drops a one-shot system. Returns `false` when the id names no
+    /// registered system. A system may unregister itself while running.
+    pub fn unregister_system(&mut self, id: SystemId) -> bool {
+        match self.systems.get_mut(id.0 as usize) {
+            Some(slot @ SystemSlot::Ready(..)) => {
+                *slot = SystemSlot::Vacant;
+                true
             }
+            Some(SystemSlot::Running { unregistered }) if !*unregistered => {
+                *unregistered = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Runs a one-shot system now. A system may run other one-shot systems
+    /// through a `&mut DynWorld` argument, but not itself.
+    pub fn run_system(&mut self, id: SystemId) -> Result<(), RunSystemError> {
+        let index = id.0 as usize;
+        let Some(slot) = self.systems.get_mut(index) else {
+            return Err(RunSystemError::NotFound(id));
+        };
+        let (name, mut runner) = match std::mem::replace(
+            slot,
+            SystemSlot::Running {
+                unregistered: false,
+            },
+        ) {
+            SystemSlot::Ready(name, runner) => (name, runner),
+            SystemSlot::Running { unregistered } => {
+                *slot = SystemSlot::Running { unregistered };
+                return Err(RunSystemError::Recursive(id));
+            }
+            SystemSlot::Vacant => {
+                *slot = SystemSlot::Vacant;
+                return Err(RunSystemError::NotFound(id));
+            }
+        };
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let runner = runner
+                .get_mut()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            runner(self)
+        }));
+        self.systems[index] = match self.systems[index] {
+            SystemSlot::Running {
+                unregistered: false,
+            } => SystemSlot::Ready(name, runner),
+            _ => SystemSlot::Vacant,
+        };
+        match result {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(error)) => Err(RunSystemError::Failed(SystemError::new(name, error))),
+            Err(panic) => std::panic::resume_unwind(panic),
         }
     }
 
+    /// Queues a one-shot system to run when the command buffer is applied,
+    /// in order with the other queued commands. A queued run that fails
+    /// shows up in what [`apply_commands`](Self::apply_commands) returns.
+    pub fn queue_run_system(&mut self, id: SystemId) {
+        self.command_buffer.push(DynCommand::RunSystem(id));
+    }
+
     /// The lazy typed tier: resolves or registers `T` and returns its key.
-    pub fn component_key<T: Send + Sync + Default + 'static>(&mut self) -> ComponentKey<T> {
+    pub fn component_key<T: ComponentData>(&mut self) -> ComponentKey<T> {
         self.registry.register::<T>()
     }
 
     /// Resolves `T`'s key without registering. Returns `None` for types this
     /// world has never seen.
-    pub fn lookup_key<T: Send + Sync + Default + 'static>(&self) -> Option<ComponentKey<T>> {
+    pub fn lookup_key<T: ComponentData>(&self) -> Option<ComponentKey<T>> {
         let &component_index = self.registry.components_by_type.get(&TypeId::of::<T>())?;
         Some(self.registry.key_for::<T>(component_index))
     }
 
     /// Typed read. Unregistered types read as absent.
-    pub fn get<T: Send + Sync + Default + 'static>(&self, entity: Entity) -> Option<&T> {
+    pub fn get<T: ComponentData>(&self, entity: Entity) -> Option<&T> {
         let key = self.lookup_key::<T>()?;
         self.get_keyed(key, entity)
     }
 
-    pub fn get_mut<T: Send + Sync + Default + 'static>(
-        &mut self,
-        entity: Entity,
-    ) -> Option<&mut T> {
+    pub fn get_mut<T: ComponentData>(&mut self, entity: Entity) -> Option<&mut T> {
         let key = self.component_key::<T>();
         self.get_mut_keyed(key, entity)
     }
 
-    pub fn set<T: Send + Sync + Default + 'static>(&mut self, entity: Entity, value: T) {
+    pub fn set<T: ComponentData>(&mut self, entity: Entity, value: T) {
         let key = self.component_key::<T>();
         self.set_keyed(key, entity, value);
     }
 
-    pub fn remove<T: Send + Sync + Default + 'static>(&mut self, entity: Entity) -> bool {
+    pub fn remove<T: ComponentData>(&mut self, entity: Entity) -> bool {
         let key = self.component_key::<T>();
         self.remove_components(entity, key.mask)
     }
 
-    pub fn has<T: Send + Sync + Default + 'static>(&self, entity: Entity) -> bool {
+    pub fn has<T: ComponentData>(&self, entity: Entity) -> bool {
         self.get::<T>(entity).is_some()
     }
 
     /// Spawns one entity carrying the bundle's components, set to the given
-    /// values. Bundle types register lazily.
+    /// values. Bundle types register lazily. Components without a default
+    /// join by the write rather than the spawn, since there is no value to
+    /// park in the row before it.
     pub fn spawn<B: Bundle>(&mut self, bundle: B) -> Entity {
         let mask = B::component_mask(self);
-        let entity = self.spawn_entities(mask, 1)[0];
+        let entity = self.spawn_entities(mask & !self.registry.no_default, 1)[0];
         bundle.write(self, entity);
         entity
     }
@@ -3170,6 +5691,31 @@ impl DynWorld {
         });
     }
 
+    /// [`insert_bundle`](Self::insert_bundle) for many entities: the bundle's
+    /// components join through one
+    /// [`add_components_batch`](Self::add_components_batch), then each entity
+    /// takes a clone of the values in place. Components without a default
+    /// still join one entity at a time, by the write. Dead entities are
+    /// skipped.
+    pub fn insert_bundle_batch<B: Bundle + Clone>(&mut self, entities: &[Entity], bundle: B) {
+        let mask = B::component_mask(self);
+        self.add_components_batch(entities, mask & !self.registry.no_default);
+        for &entity in entities {
+            if self.is_alive(entity) {
+                bundle.clone().write(self, entity);
+            }
+        }
+    }
+
+    /// The deferred [`insert_bundle_batch`](Self::insert_bundle_batch).
+    pub fn queue_insert_bundle_batch<B: Bundle + Clone + Send + Sync + 'static>(
+        &mut self,
+        entities: Vec<Entity>,
+        bundle: B,
+    ) {
+        self.queue(move |world| world.insert_bundle_batch(&entities, bundle));
+    }
+
     /// Removes every component a bundle names from an entity, returning whether
     /// the entity lost any. Reads the bundle's mask without registering any of
     /// its types, so removing a bundle an entity never had touches nothing.
@@ -3180,8 +5726,9 @@ impl DynWorld {
 
     /// Removes a bundle from an entity and hands its components back, all or
     /// nothing: when the entity is missing any of the bundle's components it
-    /// keeps them all and returns `None`. The values are moved out, so the
-    /// bundle needs no `Clone`.
+    /// keeps them all and returns `None`, as it does when
+    /// [`required_removal`](Self::required_removal) rejects the removal. The
+    /// values are moved out, so the bundle needs no `Clone`.
     pub fn take_bundle<B: Bundle>(&mut self, entity: Entity) -> Option<B> {
         let mask = B::lookup_mask(self);
         if mask == 0 {
@@ -3191,16 +5738,18 @@ impl DynWorld {
         if present & mask != mask {
             return None;
         }
-        let value = B::take(self, entity);
-        self.remove_components(entity, mask);
-        Some(value)
+        self.resolve_removal(present, mask)?;
+        let mut taken = TakenComponents::default();
+        self.remove_components_into(entity, mask, Some(&mut taken));
+        Some(B::take(self, &mut taken))
     }
 
     /// The typed bulk spawn: `count` entities each carrying a clone of the
     /// bundle. For per-entity initialization at batch speed, use the keyed
     /// [`spawn_batch`](Self::spawn_batch) instead.
     pub fn spawn_bundles<B: CloneBundle>(&mut self, bundle: B, count: usize) -> Vec<Entity> {
-        let mask = B::component_mask(self);
+        let bundle_mask = B::component_mask(self);
+        let mask = self.registry.required_mask(bundle_mask);
         let table_index = self.get_or_create_table(mask);
         let current_tick = self.current_tick;
         let start_index = self.tables[table_index].entity_indices.len();
@@ -3216,6 +5765,70 @@ impl DynWorld {
         {
             let table = &mut self.tables[table_index];
             for column in &mut table.columns {
+                if bundle_mask & (1 << column.component_index) == 0 {
+                    let info = &self.registry.components[column.component_index as usize];
+                    let push_default = info
+                        .push_default
+                        .expect("required components always have a default");
+                    push_default(&mut column.data, count);
+                }
+                column.track_extend(track, count, current_tick);
+            }
+            table.entity_indices.extend_from_slice(&entities);
+        }
+
+        for (offset, &entity) in entities.iter().enumerate() {
+            insert_location(
+                &mut self.entity_locations,
+                entity,
+                (table_index, start_index + offset),
+            );
+            self.record_structural(entity, StructuralChangeKind::Spawned, mask);
+        }
+
+        entities
+    }
+
+    /// Spawns one entity per row of `batch`, moving each column into the
+    /// table whole. Components the batch's columns require join at their
+    /// defaults; a required component without a default panics, since the
+    /// batch has no value for it. Unregistered column types register
+    /// lazily, like bundles.
+    pub fn spawn_columns(&mut self, batch: ColumnBatch) -> Vec<Entity> {
+        let count = batch.len();
+        let mut batch_mask = 0;
+        let mut staged = Vec::with_capacity(batch.columns.len());
+        for column in batch.columns {
+            let component_mask = (column.register)(&mut self.registry);
+            batch_mask |= component_mask;
+            staged.push((component_mask, column));
+        }
+        let mask = self.registry.required_mask(batch_mask);
+        if let Some(type_name) = self.registry.first_without_default(mask & !batch_mask) {
+            panic!("{type_name} has no default value; add its column to the batch");
+        }
+        let table_index = self.get_or_create_table(mask);
+        let current_tick = self.current_tick;
+        let start_index = self.tables[table_index].entity_indices.len();
+
+        let mut entities = Vec::new();
+        let mut allocator = std::mem::take(&mut self.allocator);
+        allocator.allocate_batch(count, &mut entities);
+        self.allocator = allocator;
+
+        let track = self.change_detection;
+        {
+            let table = &mut self.tables[table_index];
+            for (component_mask, column) in staged {
+                let position = column_position(table.mask, component_mask);
+                (column.append)(&mut table.columns[position].data, column.values);
+            }
+            for column in &mut table.columns {
+                if batch_mask & (1 << column.component_index) == 0 {
+                    let info = &self.registry.components[column.component_index as usize];
+                    let push_default = info.push_default.expect("checked above");
+                    push_default(&mut column.data, count);
+                }
                 column.track_extend(track, count, current_tick);
             }
             table.entity_indices.extend_from_slice(&entities);
@@ -3233,6 +5846,258 @@ impl DynWorld {
         entities
     }
 
+    /// The columnar read-out of every non-empty table holding all of
+    /// `include` and none of `exclude`, one [`ColumnChunk`] per table, for
+    /// analytics passes and bulk export. Chunks are whole tables, so
+    /// disabled entities appear in them; filter `entities()` with
+    /// [`is_disabled`](Self::is_disabled) when that matters.
+    pub fn export_columns(
+        &self,
+        include: u64,
+        exclude: u64,
+    ) -> impl Iterator<Item = ColumnChunk<'_>> + '_ {
+        debug_assert_eq!(
+            include & !self.registry.all_components_mask(),
+            0,
+            "table-granular iteration takes component masks only"
+        );
+        self.tables
+            .iter()
+            .filter(move |table| {
+                table.mask & include == include
+                    && table.mask & exclude == 0
+                    && !table.entity_indices.is_empty()
+            })
+            .map(move |table| ColumnChunk { world: self, table })
+    }
+
+    /// Spawns a copy of `entity` carrying clones of all its components and
+    /// tags. Every component must be cloneable, registered through
+    /// [`register_clone`](Self::register_clone) or spawned with
+    /// [`spawn_bundles`](Self::spawn_bundles). `None` when `entity` has no
+    /// row here or carries a component that is not cloneable, in which case
+    /// nothing is spawned; leave such components out with
+    /// [`clone_entity_filtered`](Self::clone_entity_filtered).
+    pub fn clone_entity(&mut self, entity: Entity) -> Option<Entity> {
+        self.clone_entity_filtered(entity, u64::MAX)
+    }
+
+    /// [`clone_entity`](Self::clone_entity) restricted to the components and
+    /// tags whose bits are in `mask`; the rest are left off the copy, and
+    /// only the selected components need to be cloneable. `None` when one of
+    /// them is not.
+    pub fn clone_entity_filtered(&mut self, entity: Entity, mask: u64) -> Option<Entity> {
+        let staged = self.stage_clone(entity, mask)?;
+        let clone = self.allocator.allocate();
+        self.insert_staged(clone, staged, self.registry.registry_id);
+        Some(clone)
+    }
+
+    /// Spawns a copy of `entity` in another world, with clones of all its
+    /// components. Components are matched by type name, so the two
+    /// registries may have been built independently; a component the
+    /// destination has never seen is registered there on the way in.
+    /// Marker-type tags carry over by type, anonymous tags only between
+    /// worlds sharing one registry. The destination allocates the handle, so
+    /// it must be a standalone world; clone between the member worlds of a
+    /// group with [`DynEcs::clone_entity`]. `None` when `entity` has no row
+    /// here or carries a component that is not cloneable.
+    pub fn clone_entity_into(&self, destination: &mut DynWorld, entity: Entity) -> Option<Entity> {
+        self.clone_entity_into_filtered(destination, entity, u64::MAX)
+    }
+
+    /// [`clone_entity_into`](Self::clone_entity_into) restricted to the
+    /// components and tags whose bits, in this world's registry, are in
+    /// `mask`. `None` when one of the selected components is not cloneable.
+    pub fn clone_entity_into_filtered(
+        &self,
+        destination: &mut DynWorld,
+        entity: Entity,
+        mask: u64,
+    ) -> Option<Entity> {
+        let staged = self.stage_clone(entity, mask)?;
+        let clone = destination.allocator.allocate();
+        destination.insert_staged(clone, staged, self.registry.registry_id);
+        Some(clone)
+    }
+
+    /// Moves `entity` and all its components and tags into another world,
+    /// despawning it here. Components are matched by type name exactly as
+    /// for [`clone_entity_into`](Self::clone_entity_into), but values are
+    /// moved rather than cloned, so no component needs to be cloneable. With
+    /// [`TransferHandle::Remap`] the destination allocates a fresh handle;
+    /// with [`TransferHandle::Preserve`] the entity keeps its handle, and the
+    /// transfer is refused if the destination already has that id live or
+    /// retired it at that generation or later.
+    /// Both structural logs see the move: `Despawned` here, `Spawned` there,
+    /// each with its own world's mask. Entity links held inside components,
+    /// such as [`ChildOf`], are not rewritten when the handle is remapped.
+    /// Returns the handle the entity lives under in `destination`, or `None`
+    /// when it has no row here or the preserved handle is unavailable.
+    pub fn transfer_entity(
+        &mut self,
+        destination: &mut DynWorld,
+        entity: Entity,
+        handle: TransferHandle,
+    ) -> Option<Entity> {
+        if !self.allocator.is_alive(entity) {
+            return None;
+        }
+        get_location(&self.entity_locations, entity)?;
+        let target = claim_transfer_handle(&mut destination.allocator, entity, handle)?;
+        let staged = self.stage_move(entity).expect("entity located above");
+        self.despawn_entities(&[entity]);
+        destination.insert_staged(target, staged, self.registry.registry_id);
+        Some(target)
+    }
+
+    /// Whether every component in `mask` that `entity` carries here can be
+    /// cloned; true when it has no row here.
+    fn clone_supported(&self, entity: Entity, mask: u64) -> bool {
+        let Some((table_index, _)) = get_location(&self.entity_locations, entity) else {
+            return true;
+        };
+        self.tables[table_index].columns.iter().all(|column| {
+            let info = self.registry.components[column.component_index as usize];
+            info.mask & mask == 0 || info.clone_row.is_some()
+        })
+    }
+
+    /// Clones one entity's selected components into single-row columns
+    /// outside any table, so the copy can land in the world it came from.
+    /// `None` when it has no row here or a selected component is not
+    /// cloneable.
+    fn stage_clone(&self, entity: Entity, mask: u64) -> Option<StagedRow> {
+        let (table_index, row) = get_location(&self.entity_locations, entity)?;
+        if !self.clone_supported(entity, mask) {
+            return None;
+        }
+        let table = &self.tables[table_index];
+        let mut components = Vec::new();
+        for column in &table.columns {
+            let info = self.registry.components[column.component_index as usize];
+            if info.mask & mask == 0 {
+                continue;
+            }
+            let clone_row = info.clone_row.expect("checked by clone_supported");
+            let mut value = (info.new_column)(&info);
+            clone_row(&column.data, row, &mut value);
+            components.push(StagedComponent {
+                info,
+                #[cfg(feature = "snapshot")]
+                codec: self.registry.codecs[column.component_index as usize],
+                value,
+            });
+        }
+        let tags = self.staged_tags(entity, mask);
+        Some(StagedRow { components, tags })
+    }
+
+    /// Moves one entity's components out into single-row columns by
+    /// ownership and detaches its row, logging the despawn. The despawn that
+    /// follows only retires the handle and its tags.
+    fn stage_move(&mut self, entity: Entity) -> Option<StagedRow> {
+        let (table_index, row) = get_location(&self.entity_locations, entity)?;
+        let tags = self.staged_tags(entity, u64::MAX);
+        let track = self.change_detection;
+        let table = &mut self.tables[table_index];
+        let mask = table.mask;
+        let mut components = Vec::with_capacity(table.columns.len());
+        for column in &mut table.columns {
+            let info = self.registry.components[column.component_index as usize];
+            let mut value = (info.new_column)(&info);
+            (info.swap_remove_into)(&mut column.data, row, &mut value);
+            column.track_swap_remove(track, row);
+            components.push(StagedComponent {
+                info,
+                #[cfg(feature = "snapshot")]
+                codec: self.registry.codecs[column.component_index as usize],
+                value,
+            });
+        }
+        self.detach_row(table_index, row);
+        self.entity_locations.mark_deallocated(entity.id);
+        self.record_structural(entity, StructuralChangeKind::Despawned, mask);
+        Some(StagedRow { components, tags })
+    }
+
+    fn staged_tags(&self, entity: Entity, mask: u64) -> Vec<(u32, Option<TypeId>)> {
+        let mut tags = Vec::new();
+        for (tag_index, tag_set) in self.tags.iter().enumerate() {
+            let tag_mask = 1u64 << (63 - tag_index as u32);
+            if mask & tag_mask != 0 && tag_set.contains(entity) {
+                tags.push((
+                    tag_index as u32,
+                    self.registry.tag_type_id(tag_index as u32),
+                ));
+            }
+        }
+        tags
+    }
+
+    /// Gives `entity` a fresh row built from staged columns, adopting any
+    /// component this registry lacks. `source_registry` decides whether tag
+    /// indices can be reused as they are or must be matched by marker type.
+    fn insert_staged(&mut self, entity: Entity, staged: StagedRow, source_registry: u32) {
+        let same_registry = source_registry == self.registry.registry_id;
+        self.insert_staged_row(entity, staged, same_registry);
+    }
+
+    fn insert_staged_row(&mut self, entity: Entity, staged: StagedRow, same_registry: bool) {
+        let mut placed: Vec<(u32, ErasedColumn)> = Vec::with_capacity(staged.components.len());
+        let mut mask = 0u64;
+        for component in staged.components {
+            let component_index = if same_registry {
+                component.info.mask.trailing_zeros()
+            } else {
+                let component_index = self.registry.adopt(&component.info);
+                #[cfg(feature = "snapshot")]
+                if self.registry.codecs[component_index as usize].is_none() {
+                    self.registry.codecs[component_index as usize] = component.codec;
+                }
+                component_index
+            };
+            mask |= 1u64 << component_index;
+            placed.push((component_index, component.value));
+        }
+        placed.sort_unstable_by_key(|&(component_index, _)| component_index);
+
+        let table_index = self.get_or_create_table(mask);
+        let current_tick = self.current_tick;
+        let track = self.change_detection;
+        let start_index = self.tables[table_index].entity_indices.len();
+        {
+            let table = &mut self.tables[table_index];
+            for (column, (_, mut value)) in table.columns.iter_mut().zip(placed) {
+                let info = &self.registry.components[column.component_index as usize];
+                (info.swap_remove_into)(&mut value, 0, &mut column.data);
+                column.track_push(track, current_tick, current_tick);
+            }
+            table.entity_indices.push(entity);
+        }
+        insert_location(
+            &mut self.entity_locations,
+            entity,
+            (table_index, start_index),
+        );
+        self.record_structural(entity, StructuralChangeKind::Spawned, mask);
+
+        for (tag_index, type_id) in staged.tags {
+            let key = if same_registry {
+                self.registry.tag_key_for(tag_index)
+            } else if let Some(type_id) = type_id {
+                let key = self.registry.register_tag_type_id(type_id);
+                while self.tags.len() < self.registry.tag_count as usize {
+                    self.tags.push(SparseTagSet::default());
+                }
+                key
+            } else {
+                continue;
+            };
+            self.add_tag(key, entity);
+        }
+    }
+
     /// Starts a typed query. Component types register lazily, borrow
     /// mutability comes from the tuple (`&T`, `&mut T`, and their `Option`
     /// forms), and mutable elements stamp change ticks per visited entity.
@@ -3253,6 +6118,8 @@ impl DynWorld {
             element_masks: None,
             include_tag_sets: [None; 4],
             exclude_tag_sets: [None; 4],
+            disabled_set: None,
+            include_disabled: false,
             dead: false,
             marker: PhantomData,
         }
@@ -3274,6 +6141,8 @@ impl DynWorld {
             resolved_masks: None,
             include_tag_sets: [None; 4],
             exclude_tag_sets: [None; 4],
+            disabled_set: None,
+            include_disabled: false,
             dead: false,
             marker: PhantomData,
         }
@@ -3359,6 +6228,8 @@ pub struct DynEcs {
     pub tag_type_indices: HashMap<TypeId, usize>,
     pub tag_type_names: Vec<Option<String>>,
     pub resources: ResourceMap,
+    /// Group-shared resources pinned to one thread; see [`NonSendResources`].
+    pub non_send: NonSendResources,
     pub events: EventBus,
 }
 
@@ -3470,9 +6341,22 @@ impl DynEcs {
         if !B::present_group(self, entity) {
             return None;
         }
-        let value = B::take_group(self, entity);
-        B::remove_group(self, entity);
-        Some(value)
+        let mut masks = vec![0u64; self.worlds.len()];
+        B::lookup_group_masks(self, &mut masks);
+        for (world, &mask) in self.worlds.iter().zip(&masks) {
+            if mask != 0 {
+                world.resolve_removal(world.component_mask(entity)?, mask)?;
+            }
+        }
+        let mut taken: Vec<TakenComponents> = Vec::with_capacity(masks.len());
+        for (world, &mask) in self.worlds.iter_mut().zip(&masks) {
+            let mut world_taken = TakenComponents::default();
+            if mask != 0 {
+                world.remove_components_into(entity, mask, Some(&mut world_taken));
+            }
+            taken.push(world_taken);
+        }
+        Some(B::take_group(self, &mut taken))
     }
 
     /// Which member world holds `T`, scanning members in index order and
@@ -3480,7 +6364,7 @@ impl DynEcs {
     /// `None` when no member world has registered `T`; group-typed access
     /// never registers lazily, because only a schema decides where a type
     /// lives.
-    pub fn route<T: Send + Sync + Default + 'static>(&mut self) -> Option<usize> {
+    pub fn route<T: ComponentData>(&mut self) -> Option<usize> {
         if let Some(&index) = self.type_routes.get(&TypeId::of::<T>()) {
             return Some(index);
         }
@@ -3493,7 +6377,7 @@ impl DynEcs {
         Some(index)
     }
 
-    fn route_ref<T: Send + Sync + Default + 'static>(&self) -> Option<usize> {
+    fn route_ref<T: ComponentData>(&self) -> Option<usize> {
         if let Some(&index) = self.type_routes.get(&TypeId::of::<T>()) {
             return Some(index);
         }
@@ -3506,17 +6390,14 @@ impl DynEcs {
 
     /// Reads `T` from whichever member world holds it, no world index
     /// required.
-    pub fn get<T: Send + Sync + Default + 'static>(&self, entity: Entity) -> Option<&T> {
+    pub fn get<T: ComponentData>(&self, entity: Entity) -> Option<&T> {
         let index = self.route_ref::<T>()?;
         self.worlds[index].get::<T>(entity)
     }
 
     /// The mutable form of [`get`](Self::get); stamps change ticks exactly
     /// like the member world's accessor.
-    pub fn get_mut<T: Send + Sync + Default + 'static>(
-        &mut self,
-        entity: Entity,
-    ) -> Option<&mut T> {
+    pub fn get_mut<T: ComponentData>(&mut self, entity: Entity) -> Option<&mut T> {
         let index = self.route::<T>()?;
         self.worlds[index].get_mut::<T>(entity)
     }
@@ -3525,7 +6406,7 @@ impl DynEcs {
     /// component if the entity lacks it. Panics if no member world has
     /// registered `T`: group-typed access never picks a world for a new
     /// type, that is a schema decision.
-    pub fn set<T: Send + Sync + Default + 'static>(&mut self, entity: Entity, value: T) {
+    pub fn set<T: ComponentData>(&mut self, entity: Entity, value: T) {
         let Some(index) = self.route::<T>() else {
             panic!(
                 "{} is not registered in any member world; add it to a member schema first",
@@ -3536,14 +6417,14 @@ impl DynEcs {
     }
 
     /// Whether the entity carries `T` in whichever member world holds it.
-    pub fn has<T: Send + Sync + Default + 'static>(&self, entity: Entity) -> bool {
+    pub fn has<T: ComponentData>(&self, entity: Entity) -> bool {
         self.route_ref::<T>()
             .is_some_and(|index| self.worlds[index].has::<T>(entity))
     }
 
     /// Removes `T` from the member world that holds it. Returns false when
     /// the type is registered nowhere or the entity lacks it.
-    pub fn remove<T: Send + Sync + Default + 'static>(&mut self, entity: Entity) -> bool {
+    pub fn remove<T: ComponentData>(&mut self, entity: Entity) -> bool {
         match self.route::<T>() {
             Some(index) => self.worlds[index].remove::<T>(entity),
             None => false,
@@ -3558,8 +6439,15 @@ impl DynEcs {
     /// live in different member worlds runs through
     /// [`query_join`](Self::query_join) to iterate for real.
     pub fn query<Q: QueryTuple>(&mut self) -> DynQuery<'_, Q> {
+        let disabled_set = self
+            .lookup_tag_type::<Disabled>()
+            .map(|index| &self.tags[index]);
         match self.worlds.iter().position(|world| Q::routing_match(world)) {
-            Some(index) => self.worlds[index].query::<Q>(),
+            Some(index) => {
+                let mut query = self.worlds[index].query::<Q>();
+                query.disabled_set = disabled_set;
+                query
+            }
             None => DynQuery {
                 world: &mut self.worlds[0],
                 include: 0,
@@ -3568,6 +6456,8 @@ impl DynEcs {
                 added_mask: 0,
                 include_tag_sets: [None; 4],
                 exclude_tag_sets: [None; 4],
+                disabled_set: None,
+                include_disabled: false,
                 element_masks: None,
                 dead: true,
                 marker: PhantomData,
@@ -3591,6 +6481,7 @@ impl DynEcs {
             exclude_tag_types: [None; 4],
             changed_lookups: [None; 4],
             added_lookups: [None; 4],
+            include_disabled: false,
             marker: PhantomData,
         }
     }
@@ -3607,6 +6498,7 @@ impl DynEcs {
             exclude_tag_types: [None; 4],
             changed_lookups: [None; 4],
             added_lookups: [None; 4],
+            include_disabled: false,
             marker: PhantomData,
         }
     }
@@ -3615,14 +6507,16 @@ impl DynEcs {
     /// required element the query is empty rather than a panic, matching
     /// [`DynWorld::query_ref`]'s graceful degradation.
     pub fn query_ref<Q: ReadQueryTuple>(&self) -> DynQueryRef<'_, Q> {
-        match self.worlds.iter().position(|world| Q::routing_match(world)) {
+        let mut query = match self.worlds.iter().position(|world| Q::routing_match(world)) {
             Some(index) => self.worlds[index].query_ref::<Q>(),
             None => {
                 let mut query = self.worlds[0].query_ref::<Q>();
                 query.dead = true;
                 query
             }
-        }
+        };
+        query.disabled_set = self.tag_set_type::<Disabled>();
+        query
     }
 
     /// Advances the group frame: expires group events past their two-frame
@@ -3720,6 +6614,29 @@ impl DynEcs {
         self.resources.remove::<T>()
     }
 
+    /// Inserts a resource that is not `Send` or `Sync`, pinning the group's
+    /// non-send resources to the calling thread; see [`NonSendResources`].
+    pub fn insert_non_send<T: 'static>(&mut self, value: T) {
+        self.non_send.insert(value);
+    }
+
+    pub fn remove_non_send<T: 'static>(&mut self) -> Option<T> {
+        self.non_send.remove::<T>()
+    }
+
+    pub fn has_non_send<T: 'static>(&self) -> bool {
+        self.non_send.contains::<T>()
+    }
+
+    /// [`resource_scope`](Self::resource_scope) for a non-send resource:
+    /// takes `R` out, runs the closure with the group and the resource as
+    /// independent borrows, and puts it back, even when the closure panics.
+    /// Panics if `R` is not present or the calling thread does not own the
+    /// non-send resources.
+    pub fn non_send_scope<R: 'static, T>(&mut self, f: impl FnOnce(&mut DynEcs, &mut R) -> T) -> T {
+        non_send_scope(self, |host| &mut host.non_send, f)
+    }
+
     /// Takes a group resource out, runs the closure with the group and the
     /// resource as independent borrows, then puts it back, even when the
     /// closure panics. Panics if `R` is not present.
@@ -3757,6 +6674,158 @@ impl DynEcs {
         index
     }
 
+    /// Reserves a group handle through a shared borrow; the group form of
+    /// [`DynWorld::reserve_entity`]. The handle goes live with no rows
+    /// anywhere at the next [`spawn_reserved`](Self::spawn_reserved).
+    pub fn reserve_entity(&self) -> Entity {
+        self.allocator.reserve()
+    }
+
+    /// Reserves `count` group handles at once, with one atomic increment.
+    pub fn reserve_entities(&self, count: usize) -> Vec<Entity> {
+        let mut entities = Vec::with_capacity(count);
+        self.allocator.reserve_batch(count, &mut entities);
+        entities
+    }
+
+    /// Materializes every reserved group handle, logging each as `Spawned`
+    /// with mask 0 like [`spawn`](Self::spawn), and returns them in
+    /// reservation order. Give them components through any member world.
+    pub fn spawn_reserved(&mut self) -> Vec<Entity> {
+        let mut reserved = self.allocator.take_reserved();
+        reserved.retain(|&entity| self.allocator.is_alive(entity));
+        for &entity in &reserved {
+            self.record_structural(entity, StructuralChangeKind::Spawned, 0);
+        }
+        reserved
+    }
+
+    /// Spawns a copy of a group entity: a fresh group handle with clones of
+    /// its rows in every member world and its group tags. Every component
+    /// it carries must be cloneable, as for [`DynWorld::clone_entity`].
+    /// `None` for a dead entity or one carrying a component that is not
+    /// cloneable in any member world; nothing is spawned then.
+    pub fn clone_entity(&mut self, entity: Entity) -> Option<Entity> {
+        if !self.is_alive(entity)
+            || !self
+                .worlds
+                .iter()
+                .all(|world| world.clone_supported(entity, u64::MAX))
+        {
+            return None;
+        }
+        let staged: Vec<Option<StagedRow>> = self
+            .worlds
+            .iter()
+            .map(|world| world.stage_clone(entity, u64::MAX))
+            .collect();
+        let clone = self.spawn();
+        for (world, staged) in self.worlds.iter_mut().zip(staged) {
+            if let Some(staged) = staged {
+                let registry_id = world.registry.registry_id;
+                world.insert_staged(clone, staged, registry_id);
+            }
+        }
+        let tag_indices: Vec<usize> = (0..self.tags.len())
+            .filter(|&index| self.tags[index].contains(entity))
+            .collect();
+        for index in tag_indices {
+            self.add_tag(index, clone);
+        }
+        Some(clone)
+    }
+
+    /// Moves a group entity into another group, the group form of
+    /// [`DynWorld::transfer_entity`]. Each component goes to whichever
+    /// destination member world registered its type name, so the two groups
+    /// may split their schemas differently; a component with no home there
+    /// refuses the transfer with `None` before anything moves. A member world's marker-type tags
+    /// follow that world's components, and group tags carry over by marker
+    /// type name; anonymous tags of either kind are dropped. The entity is
+    /// despawned here, so this group logs `Despawned` and the destination
+    /// logs `Spawned`, while member worlds log their own row moves.
+    pub fn transfer_entity(
+        &mut self,
+        destination: &mut DynEcs,
+        entity: Entity,
+        handle: TransferHandle,
+    ) -> Option<Entity> {
+        if !self.is_alive(entity) {
+            return None;
+        }
+        let home_of = |type_name: &str| {
+            destination
+                .worlds
+                .iter()
+                .position(|world| world.registry.component_by_name(type_name).is_some())
+        };
+        let all_homed = self.worlds.iter().all(|world| {
+            get_location(&world.entity_locations, entity).is_none_or(|(table_index, _)| {
+                world.tables[table_index].columns.iter().all(|column| {
+                    let info = &world.registry.components[column.component_index as usize];
+                    home_of(info.type_name).is_some()
+                })
+            })
+        });
+        if !all_homed {
+            return None;
+        }
+        let target = claim_transfer_handle(&mut destination.allocator, entity, handle)?;
+
+        let mut routed: Vec<Option<(StagedRow, bool)>> =
+            (0..destination.worlds.len()).map(|_| None).collect();
+        for (world_index, world) in self.worlds.iter_mut().enumerate() {
+            let Some(staged) = world.stage_move(entity) else {
+                continue;
+            };
+            let registry_id = world.registry.registry_id;
+            let same_registry =
+                |home: usize| destination.worlds[home].registry.registry_id == registry_id;
+            let mut tag_home = None;
+            for component in staged.components {
+                let home =
+                    home_of(component.info.type_name).expect("checked before anything moved");
+                tag_home.get_or_insert(home);
+                route_staged(&mut routed[home], same_registry(home))
+                    .components
+                    .push(component);
+            }
+            let tag_home = tag_home
+                .or_else(|| (world_index < destination.worlds.len()).then_some(world_index));
+            if let Some(home) = tag_home
+                && !staged.tags.is_empty()
+            {
+                route_staged(&mut routed[home], same_registry(home))
+                    .tags
+                    .extend(staged.tags);
+            }
+        }
+        let group_tags: Vec<String> = (0..self.tags.len())
+            .filter(|&index| self.tags[index].contains(entity))
+            .filter_map(|index| self.tag_type_names[index].clone())
+            .collect();
+        self.despawn(entity);
+
+        destination.record_structural(target, StructuralChangeKind::Spawned, 0);
+        for (world, routed) in destination.worlds.iter_mut().zip(routed) {
+            if let Some((staged, same_registry)) = routed {
+                world.insert_staged_row(target, staged, same_registry);
+            }
+        }
+        for name in group_tags {
+            let index = match destination.scan_tag_type_names(&name) {
+                Some(index) => index,
+                None => {
+                    let index = destination.register_tag();
+                    destination.tag_type_names[index] = Some(name);
+                    index
+                }
+            };
+            destination.add_tag(index, target);
+        }
+        Some(target)
+    }
+
     pub fn spawn_count(&mut self, count: usize) -> Vec<Entity> {
         let mut entities = Vec::new();
         self.allocator.allocate_batch(count, &mut entities);
@@ -3876,6 +6945,9 @@ impl DynEcs {
             group_structural_log_entries: self.structural_log.len(),
             group_resource_count: self.resources.entries.len(),
             group_event_channels: self.events.channel_count(),
+            group_tag_memory: self.tags.iter().map(SparseTagSet::memory_usage).sum(),
+            group_structural_log_memory: MemoryUsage::of_vec(&self.structural_log),
+            group_event_memory: self.events.memory_usage(),
             worlds: self.worlds.iter().map(|world| world.stats()).collect(),
         }
     }
@@ -3886,6 +6958,34 @@ impl DynEcs {
         self.worlds.iter_mut().map(|world| world.compact()).sum()
     }
 
+    /// [`DynWorld::shrink_to_fit`] over every member world, plus the
+    /// group's own tags, log, and event channels.
+    pub fn shrink_to_fit(&mut self) {
+        for world in &mut self.worlds {
+            world.shrink_to_fit();
+        }
+        for tag in &mut self.tags {
+            tag.shrink_to_fit();
+        }
+        self.structural_log.shrink_to_fit();
+        self.events.shrink_to_fit();
+    }
+
+    /// [`DynWorld::trim_memory`] over every member world, plus the group's
+    /// own tags, log, and event channels. Returns the capacity bytes handed
+    /// back. Like the per-world form, it leaves the thread's shared
+    /// `raw_storage` recycle pool to [`release_column_pool`].
+    pub fn trim_memory(&mut self) -> usize {
+        let group = |stats: EcsStats| {
+            (stats.group_tag_memory + stats.group_structural_log_memory + stats.group_event_memory)
+                .capacity_bytes
+        };
+        let before = group(self.stats());
+        let released: usize = self.worlds.iter_mut().map(DynWorld::trim_memory).sum();
+        self.shrink_to_fit();
+        released + before.saturating_sub(group(self.stats()))
+    }
+
     /// The group tag index for marker type `T`, registering the tag on
     /// first use. Group tags are the natural home for entity-scoped
     /// markers: they consume no member world's mask bits and need no world
@@ -3957,6 +7057,39 @@ impl DynEcs {
             .flat_map(|index| self.tags[index].iter())
     }
 
+    /// Disables an entity for the whole group through the [`Disabled`]
+    /// group tag: joins and routed queries skip it until
+    /// [`enable`](Self::enable), while its rows stay put in every member
+    /// world. Recorded in the group log as
+    /// [`StructuralChangeKind::Disabled`] with the tag's index. Returns
+    /// false for dead or already-disabled entities.
+    pub fn disable(&mut self, entity: Entity) -> bool {
+        if !self.allocator.is_alive(entity) {
+            return false;
+        }
+        let index = self.tag_type_index::<Disabled>();
+        if !self.tags[index].insert(entity) {
+            return false;
+        }
+        self.record_structural(entity, StructuralChangeKind::Disabled, index as u64);
+        true
+    }
+
+    pub fn enable(&mut self, entity: Entity) -> bool {
+        let Some(index) = self.lookup_tag_type::<Disabled>() else {
+            return false;
+        };
+        if !self.tags[index].remove(entity) {
+            return false;
+        }
+        self.record_structural(entity, StructuralChangeKind::Enabled, index as u64);
+        true
+    }
+
+    pub fn is_disabled(&self, entity: Entity) -> bool {
+        self.has_tag_type::<Disabled>(entity)
+    }
+
     /// The marker type `T`'s group tag set, for composing into per-world
     /// typed queries with `with_tag_set`/`without_tag_set`. `None` until
     /// the tag's first use.
@@ -3989,7 +7122,7 @@ mod snapshot {
         entity: Entity,
     ) -> Option<Result<Vec<u8>, SnapshotError>>
     where
-        T: serde::Serialize + Send + Sync + Default + 'static,
+        T: serde::Serialize + ComponentData,
     {
         world.get::<T>(entity).map(|value| {
             postcard::to_allocvec(value).map_err(|error| SnapshotError::Codec(error.to_string()))
@@ -4002,7 +7135,7 @@ mod snapshot {
         bytes: &[u8],
     ) -> Result<(), SnapshotError>
     where
-        T: serde::de::DeserializeOwned + Send + Sync + Default + 'static,
+        T: serde::de::DeserializeOwned + ComponentData,
     {
         let value: T =
             postcard::from_bytes(bytes).map_err(|error| SnapshotError::Codec(error.to_string()))?;
@@ -4010,11 +7143,92 @@ mod snapshot {
         Ok(())
     }
 
+    /// The value half of a layout component's codec. Every layout shares
+    /// one row type, so a bare function cannot tell which component it
+    /// serves; the world resolves layout values by component index instead
+    /// and never calls these.
+    pub(super) fn encode_value_unbound(
+        _world: &DynWorld,
+        _entity: Entity,
+    ) -> Option<Result<Vec<u8>, SnapshotError>> {
+        Some(Err(SnapshotError::Codec(
+            "layout component values resolve by component index".to_string(),
+        )))
+    }
+
+    pub(super) fn apply_value_unbound(
+        _world: &mut DynWorld,
+        _entity: Entity,
+        _bytes: &[u8],
+    ) -> Result<(), SnapshotError> {
+        Err(SnapshotError::Codec(
+            "layout component values resolve by component index".to_string(),
+        ))
+    }
+
+    /// Encodes a layout column as postcard [`RuntimeRow`]s, one per row.
+    pub(super) fn encode_layout_column(
+        column: &(dyn Any + Send + Sync),
+    ) -> Result<Vec<u8>, SnapshotError> {
+        let column = column
+            .downcast_ref::<ErasedColumn>()
+            .expect("snapshot column codec received a value that is not an ErasedColumn");
+        let rows = column.layout_rows();
+        let detached: Vec<RuntimeRow> = (0..rows.len())
+            .map(|index| {
+                let (strings, image) = rows.layout_row(index);
+                RuntimeRow {
+                    bytes: image.to_vec(),
+                    strings: strings.to_vec(),
+                }
+            })
+            .collect();
+        postcard::to_allocvec(&detached).map_err(|error| SnapshotError::Codec(error.to_string()))
+    }
+
+    pub(super) fn decode_column_unbound(_bytes: &[u8]) -> Result<ErasedColumn, SnapshotError> {
+        Err(SnapshotError::Codec(
+            "layout columns decode through their layout".to_string(),
+        ))
+    }
+
+    /// Decodes a column written by [`encode_layout_column`], refusing any
+    /// row that does not fit `layout`.
+    fn decode_layout_column(
+        layout: &'static ComponentLayout,
+        bytes: &[u8],
+    ) -> Result<ErasedColumn, SnapshotError> {
+        let detached: Vec<RuntimeRow> =
+            postcard::from_bytes(bytes).map_err(|error| SnapshotError::Codec(error.to_string()))?;
+        let mut column = ErasedColumn::for_layout(layout);
+        let rows = column.layout_rows_mut();
+        for row in detached {
+            if !layout.fits(&row) {
+                return Err(misshapen_row(layout));
+            }
+            rows.push_blank_row();
+            let (strings, image) = rows.layout_row_mut(rows.len() - 1);
+            fill_layout_row(strings, image, row);
+        }
+        Ok(column)
+    }
+
+    fn misshapen_row(layout: &ComponentLayout) -> SnapshotError {
+        SnapshotError::Codec(format!("row does not match the layout of {}", layout.name))
+    }
+
+    fn fill_layout_row(strings: &mut [String], image: &mut [u8], row: RuntimeRow) {
+        for (slot, value) in strings.iter_mut().zip(row.strings) {
+            *slot = value;
+        }
+        image.copy_from_slice(&row.bytes);
+    }
+
     pub(super) fn encode_column_postcard<T>(
         column: &(dyn Any + Send + Sync),
     ) -> Result<Vec<u8>, SnapshotError>
     where
-        T: serde::Serialize + Send + Sync + Default + 'static,
+        T: serde::Serialize + Send + Sync + 'static,
     {
         let column = column
             .downcast_ref::<ErasedColumn>()
@@ -4025,7 +7239,7 @@ mod snapshot {
 
     pub(super) fn decode_column_postcard<T>(bytes: &[u8]) -> Result<ErasedColumn, SnapshotError>
     where
-        T: serde::de::DeserializeOwned + Send + Sync + Default + 'static,
+        T: serde::de::DeserializeOwned + Send + Sync + 'static,
     {
         let values: Vec<T> =
             postcard::from_bytes(bytes).map_err(|error| SnapshotError::Codec(error.to_string()))?;
@@ -4095,6 +7309,10 @@ mod snapshot {
         pub tags: Vec<Vec<Entity>>,
         pub current_tick: u32,
         pub last_tick: u32,
+        /// The [`Disabled`] tag's index, so a restore over a fresh registry
+        /// lands the disabled set back on the marker type.
+        #[serde(default)]
+        pub disabled_tag: Option<u32>,
     }
 
     /// A serializable image of a [`DynEcs`]: the shared allocator, one world
@@ -4107,6 +7325,30 @@ mod snapshot {
         pub tag_type_names: Vec<Option<String>>,
     }
 
+    /// Binds the [`Disabled`] marker to the tag index a snapshot recorded,
+    /// registering filler tags up to it when the registry stops short.
+    fn restore_disabled_tag(
+        registry: &mut ComponentRegistry,
+        tag_index: u32,
+    ) -> Result<(), SnapshotError> {
+        let found = match registry.lookup_tag_type::<Disabled>() {
+            Some(key) if key.tag_index == tag_index => return Ok(()),
+            Some(key) => format!("the disabled tag at index {}", key.tag_index),
+            None if tag_index < registry.tag_count => format!("tag {tag_index} already registered"),
+            None => {
+                while registry.tag_count < tag_index {
+                    registry.register_tag();
+                }
+                registry.register_tag_type::<Disabled>();
+                return Ok(());
+            }
+        };
+        Err(SnapshotError::SchemaMismatch {
+            expected: format!("the disabled tag at index {tag_index}"),
+            found,
+        })
+    }
+
     /// Rebuilds the retirement stamps a despawn broadcast would have left,
     /// from allocator liveness: dead ids stamp the next generation, live ids
     /// stamp their current one, so stale-handle refusal survives a restore
@@ -4130,14 +7372,68 @@ mod snapshot {
     }
 
     impl DynWorld {
-        fn value_codec(&self, name: &str) -> Result<&ComponentCodec, SnapshotError> {
+        fn value_index(&self, name: &str) -> Result<usize, SnapshotError> {
             let info = self
                 .registry
                 .component_by_name(name)
                 .ok_or_else(|| SnapshotError::UnknownComponent(name.to_string()))?;
-            self.registry.codecs[info.mask.trailing_zeros() as usize]
+            Ok(info.mask.trailing_zeros() as usize)
+        }
+
+        fn value_codec(&self, component_index: usize) -> Result<&ComponentCodec, SnapshotError> {
+            self.registry.codecs[component_index]
                 .as_ref()
-                .ok_or(SnapshotError::MissingCodec(info.type_name))
+                .ok_or(SnapshotError::MissingCodec(
+                    self.registry.components[component_index].type_name,
+                ))
+        }
+
+        /// One component's codec bytes for an entity, by component index.
+        /// Layout components are encoded here as postcard [`RuntimeRow`]s,
+        /// since their shared codec cannot tell them apart.
+        fn encode_value_at(
+            &self,
+            component_index: usize,
+            entity: Entity,
+        ) -> Result<Option<Vec<u8>>, SnapshotError> {
+            if self.registry.components[component_index].layout.is_some() {
+                let key = self.registry.layout_key_for(component_index as u32);
+                return self
+                    .layout_row(key, entity)
+                    .map(|(strings, image)| {
+                        let row = RuntimeRow {
+                            bytes: image.to_vec(),
+                            strings: strings.to_vec(),
+                        };
+                        postcard::to_allocvec(&row)
+                            .map_err(|error| SnapshotError::Codec(error.to_string()))
+                    })
+                    .transpose();
+            }
+            (self.value_codec(component_index)?.encode_value)(self, entity).transpose()
+        }
+
+        /// Applies one component's codec bytes to an entity, by component
+        /// index, checking a layout component's row against its layout.
+        fn apply_value_at(
+            &mut self,
+            component_index: usize,
+            entity: Entity,
+            bytes: &[u8],
+        ) -> Result<(), SnapshotError> {
+            if let Some(layout) = self.registry.components[component_index].layout {
+                let row: RuntimeRow = postcard::from_bytes(bytes)
+                    .map_err(|error| SnapshotError::Codec(error.to_string()))?;
+                if !layout.fits(&row) {
+                    return Err(misshapen_row(layout));
+                }
+                let key = self.registry.layout_key_for(component_index as u32);
+                if let Some((strings, image)) = self.layout_row_for_write(key, entity) {
+                    fill_layout_row(strings, image, row);
+                }
+                return Ok(());
+            }
+            (self.value_codec(component_index)?.apply_value)(self, entity, bytes)
         }
 
         /// Sets one component on an entity from codec bytes, resolved by
@@ -4160,8 +7456,8 @@ mod snapshot {
             if !self.insert_missing_rows && !self.is_alive(entity) {
                 return Err(SnapshotError::DeadEntity);
             }
-            let apply = self.value_codec(name)?.apply_value;
-            apply(self, entity, bytes)
+            let component_index = self.value_index(name)?;
+            self.apply_value_at(component_index, entity, bytes)
         }
 
         /// One component's codec bytes for an entity, resolved by registered
@@ -4171,8 +7467,8 @@ mod snapshot {
             entity: Entity,
             name: &str,
         ) -> Result<Option<Vec<u8>>, SnapshotError> {
-            let encode = self.value_codec(name)?.encode_value;
-            encode(self, entity).transpose()
+            let component_index = self.value_index(name)?;
+            self.encode_value_at(component_index, entity)
         }
 
         /// Captures the world. Fails with [`SnapshotError::MissingCodec`] if
@@ -4202,11 +7498,7 @@ mod snapshot {
                     .iter()
                     .map(|info| info.type_name.to_string())
                     .collect(),
-                allocator: EntityAllocator {
-                    next_id: self.allocator.next_id,
-                    free_ids: self.allocator.free_ids.clone(),
-                    slots: self.allocator.slots.clone(),
-                },
+                allocator: self.allocator.clone(),
                 tables,
                 tags: self
                     .tags
@@ -4215,6 +7507,7 @@ mod snapshot {
                     .collect(),
                 current_tick: self.current_tick,
                 last_tick: self.last_tick,
+                disabled_tag: self.lookup_tag_key::<Disabled>().map(|key| key.tag_index),
             })
         }
 
@@ -4225,7 +7518,7 @@ mod snapshot {
         /// stamped with the restored `current_tick`, so change-detection
         /// consumers see the whole world as changed on load.
         pub fn from_snapshot(
-            registry: ComponentRegistry,
+            mut registry: ComponentRegistry,
             snapshot: &DynWorldSnapshot,
         ) -> Result<DynWorld, SnapshotError> {
             for (index, expected) in snapshot.component_types.iter().enumerate() {
@@ -4242,12 +7535,12 @@ mod snapshot {
                 }
             }
 
+            if let Some(tag_index) = snapshot.disabled_tag {
+                restore_disabled_tag(&mut registry, tag_index)?;
+            }
+
             let mut world = DynWorld::from_registry(registry);
-            world.allocator = EntityAllocator {
-                next_id: snapshot.allocator.next_id,
-                free_ids: snapshot.allocator.free_ids.clone(),
-                slots: snapshot.allocator.slots.clone(),
-            };
+            world.allocator = snapshot.allocator.clone();
             world.current_tick = snapshot.current_tick;
             world.last_tick = snapshot.last_tick;
 
@@ -4265,7 +7558,10 @@ mod snapshot {
                     let payload = column_payloads.next().ok_or_else(|| {
                         SnapshotError::Codec("missing column payload".to_string())
                     })?;
-                    column.data = (codec.decode_column)(payload)?;
+                    column.data = match info.layout {
+                        Some(layout) => decode_layout_column(layout, payload)?,
+                        None => (codec.decode_column)(payload)?,
+                    };
                     let decoded_rows = (info.column_len)(&column.data);
                     if decoded_rows != table_snapshot.entities.len() {
                         return Err(SnapshotError::Codec(format!(
@@ -4315,8 +7611,9 @@ mod snapshot {
     }
 
     /// A serialized change-set for one world since a [`DeltaCursor`]:
-    /// the structural entries in order, then one codec payload per changed
-    /// component value, reflecting end-of-window state. Apply with
+    /// the structural entries in order with the entity lists of any batch
+    /// entries among them, then one codec payload per changed component
+    /// value, reflecting end-of-window state. Apply with
     /// [`DynWorld::apply_delta`] to a replica seeded from a snapshot of the
     /// same lineage; deltas must apply in unbroken cursor order, the same
     /// trust boundary snapshots carry.
@@ -4325,6 +7622,7 @@ mod snapshot {
         pub since: DeltaCursor,
         pub to: DeltaCursor,
         pub structural: Vec<StructuralChange>,
+        pub batches: Vec<StructuralBatch>,
         pub values: Vec<(Entity, u32, Vec<u8>)>,
     }
 
@@ -4376,6 +7674,39 @@ mod snapshot {
         Ok(window.to_vec())
     }
 
+    impl ColumnChunk<'_> {
+        /// The named column encoded through its codec, the same payload a
+        /// snapshot stores for it, ready to write out in bulk. `Ok(None)`
+        /// when the chunk's table does not carry the component.
+        pub fn encode_column(&self, name: &str) -> Result<Option<Vec<u8>>, SnapshotError> {
+            let component_index = self.world.value_index(name)?;
+            let mask = self.world.registry.components[component_index].mask;
+            if self.table.mask & mask == 0 {
+                return Ok(None);
+            }
+            let codec = self.world.value_codec(component_index)?;
+            let position = column_position(self.table.mask, mask);
+            (codec.encode_column)(&self.table.columns[position].data).map(Some)
+        }
+    }
+
+    /// The entity list a delta carries for one of its batch entries.
+    fn delta_batch<'a>(
+        delta: &'a DynWorldDelta,
+        change: &StructuralChange,
+    ) -> Result<&'a [Entity], SnapshotError> {
+        delta
+            .batches
+            .binary_search_by_key(&change.sequence, |batch| batch.sequence)
+            .map(|index| delta.batches[index].entities.as_slice())
+            .map_err(|_| {
+                SnapshotError::Codec(format!(
+                    "batch entry {} arrived without its entity list",
+                    change.sequence
+                ))
+            })
+    }
+
     impl DynWorld {
         /// The cursor a delta stream starts from, taken right after the
         /// full snapshot that seeds the replica. Fences the change window
@@ -4407,6 +7738,10 @@ mod snapshot {
                 self.structural_sequence,
                 cursor.sequence,
             )?;
+            let start = self
+                .structural_batches
+                .partition_point(|batch| batch.sequence <= cursor.sequence);
+            let batches = self.structural_batches[start..].to_vec();
 
             let mut values = Vec::new();
             for (component_index, info) in self.registry.components.iter().enumerate() {
@@ -4414,10 +7749,10 @@ mod snapshot {
                     .query_entities_changed_since(info.mask, cursor.tick)
                     .peekable();
                 match &self.registry.codecs[component_index] {
-                    Some(codec) => {
+                    Some(_) => {
                         for entity in changed {
-                            if let Some(bytes) = (codec.encode_value)(self, entity) {
-                                values.push((entity, component_index as u32, bytes?));
+                            if let Some(bytes) = self.encode_value_at(component_index, entity)? {
+                                values.push((entity, component_index as u32, bytes));
                             }
                         }
                     }
@@ -4438,6 +7773,7 @@ mod snapshot {
                 since: *cursor,
                 to,
                 structural,
+                batches,
                 values,
             })
         }
@@ -4445,7 +7781,8 @@ mod snapshot {
         /// Replays a delta onto a replica: structural entries in order
         /// (spawns revive the exact handle, despawns retire it, component
         /// and tag changes reapply), then the changed component values
-        /// through their codecs. The replica must be seeded from a snapshot
+        /// through their codecs. Components without a default join with
+        /// their values rather than by the structural entry. The replica must be seeded from a snapshot
         /// of the same lineage and receive every delta in cursor order;
         /// like snapshots, delta payloads are a trust boundary.
         pub fn apply_delta(&mut self, delta: &DynWorldDelta) -> Result<(), SnapshotError> {
@@ -4454,18 +7791,29 @@ mod snapshot {
                     StructuralChangeKind::Spawned => {
                         self.allocator.revive(change.entity);
                         if change.mask != 0 {
-                            self.insert_row(change.entity, change.mask);
+                            self.insert_row(change.entity, change.mask & !self.registry.no_default);
                         }
                     }
                     StructuralChangeKind::Despawned => {
                         self.despawn_entities(&[change.entity]);
                     }
                     StructuralChangeKind::ComponentsAdded => {
-                        self.add_components(change.entity, change.mask);
+                        self.add_components(change.entity, change.mask & !self.registry.no_default);
                     }
                     StructuralChangeKind::ComponentsRemoved => {
                         self.remove_components(change.entity, change.mask);
                     }
+                    StructuralChangeKind::ComponentsAddedBatch => {
+                        let entities = delta_batch(delta, change)?;
+                        self.add_components_batch(
+                            entities,
+                            change.mask & !self.registry.no_default,
+                        );
+                    }
+                    StructuralChangeKind::ComponentsRemovedBatch => {
+                        let entities = delta_batch(delta, change)?;
+                        self.remove_components_batch(entities, change.mask);
+                    }
                     StructuralChangeKind::TagsAdded => {
                         let tag_index = change.mask.leading_zeros();
                         while self.tags.len() <= tag_index as usize {
@@ -4481,6 +7829,12 @@ mod snapshot {
                             self.remove_tag(key, change.entity);
                         }
                     }
+                    StructuralChangeKind::Disabled => {
+                        self.disable(change.entity);
+                    }
+                    StructuralChangeKind::Enabled => {
+                        self.enable(change.entity);
+                    }
                 }
             }
 
@@ -4494,10 +7848,10 @@ mod snapshot {
                             "component index {component_index}"
                         ))
                     })?;
-                let codec = self.registry.codecs[*component_index as usize]
-                    .as_ref()
-                    .ok_or(SnapshotError::MissingCodec(info.type_name))?;
-                (codec.apply_value)(self, *entity, bytes)?;
+                if self.registry.codecs[*component_index as usize].is_none() {
+                    return Err(SnapshotError::MissingCodec(info.type_name));
+                }
+                self.apply_value_at(*component_index as usize, *entity, bytes)?;
             }
             Ok(())
         }
@@ -4570,7 +7924,7 @@ mod snapshot {
                     StructuralChangeKind::Despawned => {
                         self.despawn(change.entity);
                     }
-                    StructuralChangeKind::TagsAdded => {
+                    StructuralChangeKind::TagsAdded | StructuralChangeKind::Disabled => {
                         let tag_index = change.mask as usize;
                         while self.tags.len() <= tag_index {
                             self.tags.push(SparseTagSet::default());
@@ -4578,13 +7932,15 @@ mod snapshot {
                         }
                         self.tags[tag_index].insert(change.entity);
                     }
-                    StructuralChangeKind::TagsRemoved => {
+                    StructuralChangeKind::TagsRemoved | StructuralChangeKind::Enabled => {
                         if (change.mask as usize) < self.tags.len() {
                             self.tags[change.mask as usize].remove(change.entity);
                         }
                     }
                     StructuralChangeKind::ComponentsAdded
-                    | StructuralChangeKind::ComponentsRemoved => {}
+                    | StructuralChangeKind::ComponentsRemoved
+                    | StructuralChangeKind::ComponentsAddedBatch
+                    | StructuralChangeKind::ComponentsRemovedBatch => {}
                 }
             }
             for (world, world_delta) in self.worlds.iter_mut().zip(&delta.worlds) {
@@ -4635,11 +7991,7 @@ mod snapshot {
                 worlds.push(world.snapshot()?);
             }
             Ok(DynEcsSnapshot {
-                allocator: EntityAllocator {
-                    next_id: self.allocator.next_id,
-                    free_ids: self.allocator.free_ids.clone(),
-                    slots: self.allocator.slots.clone(),
-                },
+                allocator: self.allocator.clone(),
                 worlds,
                 tags: self
                     .tags
@@ -4667,11 +8019,7 @@ mod snapshot {
             }
 
             let mut ecs = DynEcs::new();
-            ecs.allocator = EntityAllocator {
-                next_id: snapshot.allocator.next_id,
-                free_ids: snapshot.allocator.free_ids.clone(),
-                slots: snapshot.allocator.slots.clone(),
-            };
+            ecs.allocator = snapshot.allocator.clone();
             for (registry, world_snapshot) in registries.into_iter().zip(&snapshot.worlds) {
                 let mut world = DynWorld::from_snapshot(registry, world_snapshot)?;
                 world.insert_missing_rows = true;
@@ -4700,7 +8048,8 @@ pub use snapshot::{
 
 #[cfg(feature = "snapshot")]
 use snapshot::{
-    apply_value_postcard, decode_column_postcard, encode_column_postcard, encode_value_postcard,
+    apply_value_postcard, apply_value_unbound, decode_column_postcard, decode_column_unbound,
+    encode_column_postcard, encode_layout_column, encode_value_postcard, encode_value_unbound,
 };
 
 mod sealed {
@@ -4719,6 +8068,8 @@ pub use sealed::SealedBundle;
 pub trait QueryElement: sealed::SealedElement {
     type Fetch<'table>;
     type Item<'item>;
+    /// The component type this element fetches.
+    type Component: 'static;
     const REQUIRED: bool;
     const MUTABLE: bool;
     fn component_mask(world: &mut DynWorld) -> u64;
@@ -4753,6 +8104,12 @@ pub trait QueryElement: sealed::SealedElement {
     /// for any element whose world has change detection off, since the tick
     /// column is then empty.
     fn par_mark_changed_all(fetch: &mut Self::ParFetch<'_>);
+    /// The whole-run view of this element handed to chunked iteration: a
+    /// slice for required elements, an optional slice for `Option` ones.
+    type Slice<'fetch>;
+    /// Turns a fetched row range into its slice, stamping a mutable
+    /// element's tick column across the range on the way out.
+    fn slice<'fetch>(fetch: Self::ParFetch<'fetch>) -> Self::Slice<'fetch>;
     /// Row access without bounds checks, the `raw_storage` fast-path
     /// counterpart of [`par_item`](Self::par_item). Stamping is handled once by
     /// [`par_mark_changed_all`](Self::par_mark_changed_all), so this only reads
@@ -4767,13 +8124,14 @@ pub trait QueryElement: sealed::SealedElement {
     ) -> Self::Item<'fetch>;
 }
 
-impl<T: Send + Sync + Default + 'static> sealed::SealedElement for &T {}
+impl<T: ComponentData> sealed::SealedElement for &T {}
 
-impl<T: Send + Sync + Default + 'static> QueryElement for &T {
+impl<T: ComponentData> QueryElement for &T {
     type Fetch<'table> = (&'table [T], &'table [u32]);
     type Item<'item> = &'item T;
     const REQUIRED: bool = true;
     const MUTABLE: bool = false;
+    type Component = T;
 
     fn component_mask(world: &mut DynWorld) -> u64 {
         world.component_key::<T>().mask
@@ -4845,6 +8203,12 @@ impl<T: Send + Sync + Default + 'static> QueryElement for &T {
 
     fn par_mark_changed_all(_fetch: &mut Self::ParFetch<'_>) {}
 
+    type Slice<'fetch> = &'fetch [T];
+
+    fn slice<'fetch>(fetch: Self::ParFetch<'fetch>) -> Self::Slice<'fetch> {
+        fetch.0
+    }
+
     #[cfg(feature = "raw_storage")]
     unsafe fn par_item_unchecked<'fetch>(
         fetch: &'fetch mut Self::ParFetch<'_>,
@@ -4854,13 +8218,14 @@ impl<T: Send + Sync + Default + 'static> QueryElement for &T {
     }
 }
 
-impl<T: Send + Sync + Default + 'static> sealed::SealedElement for &mut T {}
+impl<T: ComponentData> sealed::SealedElement for &mut T {}
 
-impl<T: Send + Sync + Default + 'static> QueryElement for &mut T {
+impl<T: ComponentData> QueryElement for &mut T {
     type Fetch<'table> = (&'table mut [T], &'table mut [u32], u32, &'table mut u32);
     type Item<'item> = &'item mut T;
     const REQUIRED: bool = true;
     const MUTABLE: bool = true;
+    type Component = T;
 
     fn component_mask(world: &mut DynWorld) -> u64 {
         world.component_key::<T>().mask
@@ -4970,6 +8335,14 @@ impl<T: Send + Sync + Default + 'static> QueryElement for &mut T {
         changed.fill(*tick);
     }
 
+    type Slice<'fetch> = &'fetch mut [T];
+
+    fn slice<'fetch>(fetch: Self::ParFetch<'fetch>) -> Self::Slice<'fetch> {
+        let (data, changed, tick) = fetch;
+        changed.fill(tick);
+        data
+    }
+
     #[cfg(feature = "raw_storage")]
     unsafe fn par_item_unchecked<'fetch>(
         fetch: &'fetch mut Self::ParFetch<'_>,
@@ -4979,13 +8352,14 @@ impl<T: Send + Sync + Default + 'static> QueryElement for &mut T {
     }
 }
 
-impl<T: Send + Sync + Default + 'static> sealed::SealedElement for Option<&T> {}
+impl<T: ComponentData> sealed::SealedElement for Option<&T> {}
 
-impl<T: Send + Sync + Default + 'static> QueryElement for Option<&T> {
+impl<T: ComponentData> QueryElement for Option<&T> {
     type Fetch<'table> = Option<(&'table [T], &'table [u32])>;
     type Item<'item> = Option<&'item T>;
     const REQUIRED: bool = false;
     const MUTABLE: bool = false;
+    type Component = T;
 
     fn component_mask(world: &mut DynWorld) -> u64 {
         world.component_key::<T>().mask
@@ -5063,6 +8437,12 @@ impl<T: Send + Sync + Default + 'static> QueryElement for Option<&T> {
 
     fn par_mark_changed_all(_fetch: &mut Self::ParFetch<'_>) {}
 
+    type Slice<'fetch> = Option<&'fetch [T]>;
+
+    fn slice<'fetch>(fetch: Self::ParFetch<'fetch>) -> Self::Slice<'fetch> {
+        fetch.map(|(data, _)| data)
+    }
+
     #[cfg(feature = "raw_storage")]
     unsafe fn par_item_unchecked<'fetch>(
         fetch: &'fetch mut Self::ParFetch<'_>,
@@ -5074,13 +8454,14 @@ impl<T: Send + Sync + Default + 'static> QueryElement for Option<&T> {
     }
 }
 
-impl<T: Send + Sync + Default + 'static> sealed::SealedElement for Option<&mut T> {}
+impl<T: ComponentData> sealed::SealedElement for Option<&mut T> {}
 
-impl<T: Send + Sync + Default + 'static> QueryElement for Option<&mut T> {
+impl<T: ComponentData> QueryElement for Option<&mut T> {
     type Fetch<'table> = Option<(&'table mut [T], &'table mut [u32], u32, &'table mut u32)>;
     type Item<'item> = Option<&'item mut T>;
     const REQUIRED: bool = false;
     const MUTABLE: bool = true;
+    type Component = T;
 
     fn component_mask(world: &mut DynWorld) -> u64 {
         world.component_key::<T>().mask
@@ -5183,6 +8564,12 @@ impl<T: Send + Sync + Default + 'static> QueryElement for Option<&mut T> {
         }
     }
 
+    type Slice<'fetch> = Option<&'fetch mut [T]>;
+
+    fn slice<'fetch>(fetch: Self::ParFetch<'fetch>) -> Self::Slice<'fetch> {
+        fetch.map(<&mut T as QueryElement>::slice)
+    }
+
     #[cfg(feature = "raw_storage")]
     unsafe fn par_item_unchecked<'fetch>(
         fetch: &'fetch mut Self::ParFetch<'_>,
@@ -5207,7 +8594,7 @@ pub trait ReadQueryElement: QueryElement {
     fn read_item<'table>(fetch: Self::ReadFetch<'table>, index: usize) -> Self::Item<'table>;
 }
 
-impl<T: Send + Sync + Default + 'static> ReadQueryElement for &T {
+impl<T: ComponentData> ReadQueryElement for &T {
     type ReadFetch<'table> = (&'table [T], &'table [u32], &'table [u32]);
 
     fn lookup_mask(world: &DynWorld) -> Option<u64> {
@@ -5246,7 +8633,7 @@ impl<T: Send + Sync + Default + 'static> ReadQueryElement for &T {
     }
 }
 
-impl<T: Send + Sync + Default + 'static> ReadQueryElement for Option<&T> {
+impl<T: ComponentData> ReadQueryElement for Option<&T> {
     type ReadFetch<'table> = Option<(&'table [T], &'table [u32], &'table [u32])>;
 
     fn lookup_mask(world: &DynWorld) -> Option<u64> {
@@ -5316,6 +8703,7 @@ pub(crate) fn route_world_scan(
 pub struct JoinFilters<'sets> {
     pub include_sets: [Option<&'sets SparseTagSet>; 4],
     pub exclude_sets: [Option<&'sets SparseTagSet>; 4],
+    pub disabled_set: Option<&'sets SparseTagSet>,
     pub changed_lookups: [Option<JoinMaskLookup>; 4],
     pub added_lookups: [Option<JoinMaskLookup>; 4],
 }
@@ -5336,6 +8724,9 @@ pub trait QueryTuple: sealed::SealedQueryTuple {
     type Item<'item>;
     fn component_mask(world: &mut DynWorld) -> u64;
     fn element_masks(world: &mut DynWorld) -> [u64; 8];
+    /// Appends each element's component type, name, and mutability, the
+    /// world-free description system parameters report as their access.
+    fn component_types(types: &mut Vec<(TypeId, &'static str, bool)>);
     fn routing_match(world: &DynWorld) -> bool;
     fn join_routes(worlds: &[DynWorld]) -> [Option<JoinRoute>; 8];
     fn join_for_each<F: for<'item> FnMut(Entity, Self::Item<'item>)>(
@@ -5344,13 +8735,20 @@ pub trait QueryTuple: sealed::SealedQueryTuple {
         filters: &JoinFilters<'_>,
         f: F,
     );
+    /// Folds the join's matches table by table in parallel, returning the
+    /// per-table [`RowPartials`](crate::RowPartials) in table and row order.
     #[cfg(not(target_family = "wasm"))]
-    fn join_par_for_each<F: for<'item> Fn(Entity, Self::Item<'item>) + Send + Sync>(
+    fn join_par_fold<A, I, F>(
         driver: &mut DynWorld,
         element_worlds: &[Option<&DynWorld>; 8],
         filters: &JoinFilters<'_>,
-        f: F,
-    );
+        identity: &I,
+        fold: &F,
+    ) -> Vec<A>
+    where
+        A: Send,
+        I: Fn() -> A + Send + Sync,
+        F: for<'item> Fn(A, Entity, Self::Item<'item>) -> A + Send + Sync;
     fn fetch<'table>(
         table_mask: u64,
         columns: &'table mut [ColumnSlot],
@@ -5392,6 +8790,10 @@ pub trait QueryTuple: sealed::SealedQueryTuple {
     /// unchecked fast path can stamp once up front instead of filling as it
     /// hands out rows.
     fn par_mark_changed_all(fetch: &mut Self::ParFetch<'_>);
+    /// Per-element slices over a fetched row range, the argument chunked
+    /// iteration hands its closure.
+    type Slices<'fetch>;
+    fn slices<'fetch>(fetch: Self::ParFetch<'fetch>) -> Self::Slices<'fetch>;
     fn item_marked<'fetch>(fetch: &'fetch mut Self::Fetch<'_>, index: usize) -> Self::Item<'fetch>;
     const ALL_REQUIRED: bool;
     fn fast_for_each<FN>(fetch: Self::ParFetch<'_>, entities: &[Entity], f: &mut FN)
@@ -5570,6 +8972,16 @@ macro_rules! impl_query_tuple {
                 masks
             }
 
+            fn component_types(types: &mut Vec<(TypeId, &'static str, bool)>) {
+                $(
+                    types.push((
+                        TypeId::of::<$element::Component>(),
+                        std::any::type_name::<$element::Component>(),
+                        $element::MUTABLE,
+                    ));
+                )+
+            }
+
             fn routing_match(world: &DynWorld) -> bool {
                 let mut matched = true;
                 $(
@@ -5682,7 +9094,12 @@ macro_rules! impl_query_tuple {
                     )+
                     let mut visited = false;
                     'rows: for (row_index, &entity) in entity_indices.iter().enumerate() {
-                        if !tag_sets_match(&filters.include_sets, &filters.exclude_sets, entity) {
+                        if !tag_sets_match(
+                            &filters.include_sets,
+                            &filters.exclude_sets,
+                            filters.disabled_set,
+                            entity,
+                        ) {
                             continue 'rows;
                         }
                         if added_mask != 0 && !added_scratch[row_index] {
@@ -5745,12 +9162,18 @@ macro_rules! impl_query_tuple {
 
             #[cfg(not(target_family = "wasm"))]
             #[allow(non_snake_case)]
-            fn join_par_for_each<FN: for<'item> Fn(Entity, Self::Item<'item>) + Send + Sync>(
+            fn join_par_fold<ACC, INIT, FN>(
                 driver: &mut DynWorld,
                 element_worlds: &[Option<&DynWorld>; 8],
                 filters: &JoinFilters<'_>,
-                f: FN,
-            ) {
+                identity: &INIT,
+                fold: &FN,
+            ) -> Vec<ACC>
+            where
+                ACC: Send,
+                INIT: Fn() -> ACC + Send + Sync,
+                FN: for<'item> Fn(ACC, Entity, Self::Item<'item>) -> ACC + Send + Sync,
+            {
                 use crate::rayon::prelude::*;
 
                 let mut element_masks = [0u64; 8];
@@ -5790,7 +9213,7 @@ macro_rules! impl_query_tuple {
                         table.mask & local_include == local_include
                             && !table.entity_indices.is_empty()
                     })
-                    .for_each(|table| {
+                    .map(|table| {
                         let table_mask = table.mask;
                         let DynComponentArrays {
                             entity_indices,
@@ -5831,10 +9254,12 @@ macro_rules! impl_query_tuple {
                             };
                         )+
                         let mut visited = false;
+                        let mut partials = crate::RowPartials::default();
                         'rows: for (row_index, &entity) in entity_indices.iter().enumerate() {
                             if !tag_sets_match(
                                 &filters.include_sets,
                                 &filters.exclude_sets,
+                                filters.disabled_set,
                                 entity,
                             ) {
                                 continue 'rows;
@@ -5885,7 +9310,9 @@ macro_rules! impl_query_tuple {
                                     };
                                 )+
                                 visited = true;
-                                f(entity, ($([<item_ $position>],)+));
+                                partials.fold(row_index, identity, |accumulator| {
+                                    fold(accumulator, entity, ($([<item_ $position>],)+))
+                                });
                             }
                         }
                         if visited {
@@ -5895,7 +9322,12 @@ macro_rules! impl_query_tuple {
                                 }
                             )+
                         }
-                    });
+                        partials.finish()
+                    })
+                    .collect::<Vec<Vec<ACC>>>()
+                    .into_iter()
+                    .flatten()
+                    .collect()
             }
 
             #[allow(non_snake_case)]
@@ -5990,6 +9422,12 @@ macro_rules! impl_query_tuple {
                 $($element::par_mark_changed_all(&mut fetch.$position);)+
             }
 
+            type Slices<'fetch> = ($($element::Slice<'fetch>,)+);
+
+            fn slices<'fetch>(fetch: Self::ParFetch<'fetch>) -> Self::Slices<'fetch> {
+                ($($element::slice(fetch.$position),)+)
+            }
+
             fn item_marked<'fetch>(fetch: &'fetch mut Self::Fetch<'_>, index: usize) -> Self::Item<'fetch> {
                 ($($element::item_marked(&mut fetch.$position, index),)+)
             }
@@ -6081,12 +9519,12 @@ where
 macro_rules! impl_bare_element_query {
     ($($element:ty),+) => {
         $(
-            impl<'element, T: Send + Sync + Default + 'static> sealed::SealedQueryTuple
+            impl<'element, T: ComponentData> sealed::SealedQueryTuple
                 for $element
             {
             }
 
-            impl<'element, T: Send + Sync + Default + 'static> QueryTuple for $element {
+            impl<'element, T: ComponentData> QueryTuple for $element {
                 type Fetch<'table> = <$element as QueryElement>::Fetch<'table>;
                 type Item<'item> = <$element as QueryElement>::Item<'item>;
 
@@ -6104,6 +9542,14 @@ macro_rules! impl_bare_element_query {
                     masks
                 }
 
+                fn component_types(types: &mut Vec<(TypeId, &'static str, bool)>) {
+                    types.push((
+                        TypeId::of::<T>(),
+                        std::any::type_name::<T>(),
+                        <$element as QueryElement>::MUTABLE,
+                    ));
+                }
+
                 fn routing_match(world: &DynWorld) -> bool {
                     !<$element as QueryElement>::REQUIRED
                         || <$element as QueryElement>::route_registered(world)
@@ -6201,6 +9647,7 @@ macro_rules! impl_bare_element_query {
                             if !tag_sets_match(
                                 &filters.include_sets,
                                 &filters.exclude_sets,
+                                filters.disabled_set,
                                 entity,
                             ) {
                                 continue 'rows;
@@ -6227,14 +9674,18 @@ macro_rules! impl_bare_element_query {
                 }
 
                 #[cfg(not(target_family = "wasm"))]
-                fn join_par_for_each<
-                    FN: for<'item> Fn(Entity, Self::Item<'item>) + Send + Sync,
-                >(
+                fn join_par_fold<A, I, FN>(
                     driver: &mut DynWorld,
                     element_worlds: &[Option<&DynWorld>; 8],
                     filters: &JoinFilters<'_>,
-                    f: FN,
-                ) {
+                    identity: &I,
+                    fold: &FN,
+                ) -> Vec<A>
+                where
+                    A: Send,
+                    I: Fn() -> A + Send + Sync,
+                    FN: for<'item> Fn(A, Entity, Self::Item<'item>) -> A + Send + Sync,
+                {
                     use crate::rayon::prelude::*;
 
                     debug_assert!(
@@ -6274,7 +9725,7 @@ macro_rules! impl_bare_element_query {
                             table.mask & local_include == local_include
                                 && !table.entity_indices.is_empty()
                         })
-                        .for_each(|table| {
+                        .map(|table| {
                             let table_mask = table.mask;
                             let DynComponentArrays {
                                 entity_indices,
@@ -6306,12 +9757,14 @@ macro_rules! impl_bare_element_query {
                             let mut fetch =
                                 <$element as QueryElement>::fetch(slot, current_tick);
                             let mut visited = false;
+                            let mut partials = crate::RowPartials::default();
                             'rows: for (row_index, &entity) in
                                 entity_indices.iter().enumerate()
                             {
                                 if !tag_sets_match(
                                     &filters.include_sets,
                                     &filters.exclude_sets,
+                                    filters.disabled_set,
                                     entity,
                                 ) {
                                     continue 'rows;
@@ -6329,12 +9782,19 @@ macro_rules! impl_bare_element_query {
                                 let item =
                                     <$element as QueryElement>::item(&mut fetch, row_index);
                                 visited = true;
-                                f(entity, item);
+                                partials.fold(row_index, identity, |accumulator| {
+                                    fold(accumulator, entity, item)
+                                });
                             }
                             if visited {
                                 <$element as QueryElement>::stamp_peaks(&mut fetch);
                             }
-                        });
+                            partials.finish()
+                        })
+                        .collect::<Vec<Vec<A>>>()
+                        .into_iter()
+                        .flatten()
+                        .collect()
                 }
 
                 fn fetch<'table>(
@@ -6421,6 +9881,12 @@ macro_rules! impl_bare_element_query {
                     <$element as QueryElement>::par_mark_changed_all(fetch);
                 }
 
+                type Slices<'fetch> = <$element as QueryElement>::Slice<'fetch>;
+
+                fn slices<'fetch>(fetch: Self::ParFetch<'fetch>) -> Self::Slices<'fetch> {
+                    <$element as QueryElement>::slice(fetch)
+                }
+
                 fn item_marked<'fetch>(
                     fetch: &'fetch mut Self::Fetch<'_>,
                     index: usize,
@@ -6468,7 +9934,7 @@ impl_bare_element_query!(
 macro_rules! impl_bare_element_read_query {
     ($($element:ty),+) => {
         $(
-            impl<'element, T: Send + Sync + Default + 'static> ReadQueryTuple for $element {
+            impl<'element, T: ComponentData> ReadQueryTuple for $element {
                 type ReadFetch<'table> = <$element as ReadQueryElement>::ReadFetch<'table>;
 
                 fn lookup_masks(world: &DynWorld) -> Option<([u64; 8], u64)> {
@@ -6750,6 +10216,18 @@ impl_read_query_tuple!(
     (H, 7)
 );
 
+/// The marker tag behind [`DynWorld::disable`] and [`DynEcs::disable`]. A
+/// disabled entity keeps its row, so pausing or pooling it costs no
+/// archetype churn, and queries skip it unless they opt in with
+/// `include_disabled()` or name the tag themselves:
+/// `with_tag_type::<Disabled>()` visits only disabled entities, and the
+/// mask-level `for_each` family reaches them by naming the tag's mask. Raw
+/// mask-level enumeration ([`DynWorld::query_entities`] and the
+/// table-granular paths) stays unfiltered, which is what keeps hierarchy
+/// and transform maintenance running through disabled subtrees.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Disabled;
+
 /// A typed query in progress. Filters compose before `for_each` runs it.
 /// Like everything else in the crate, the fields are plain data: the filter
 /// methods are conveniences over them, and writing them directly is fine.
@@ -6764,6 +10242,9 @@ pub struct DynQuery<'world, Q: QueryTuple> {
     pub added_mask: u64,
     pub include_tag_sets: [Option<&'world SparseTagSet>; 4],
     pub exclude_tag_sets: [Option<&'world SparseTagSet>; 4],
+    /// The group's disabled set, for queries routed through a [`DynEcs`].
+    pub disabled_set: Option<&'world SparseTagSet>,
+    pub include_disabled: bool,
     pub element_masks: Option<[u64; 8]>,
     pub dead: bool,
     pub marker: PhantomData<Q>,
@@ -6782,11 +10263,25 @@ fn push_tag_set<'world>(
     panic!("a query supports at most four tag-set filters per side");
 }
 
+/// The group's disabled set a query should skip, or `None` when the query
+/// opted into disabled entities or nothing is disabled. It is checked on
+/// its own, so it never takes one of the caller's four exclusion slots.
+fn active_disabled_set(
+    disabled_set: Option<&SparseTagSet>,
+    include_disabled: bool,
+) -> Option<&SparseTagSet> {
+    disabled_set.filter(|tag_set| !include_disabled && !tag_set.is_empty())
+}
+
 fn tag_sets_match(
     include: &[Option<&SparseTagSet>; 4],
     exclude: &[Option<&SparseTagSet>; 4],
+    disabled: Option<&SparseTagSet>,
     entity: Entity,
 ) -> bool {
+    if disabled.is_some_and(|tag_set| tag_set.contains(entity)) {
+        return false;
+    }
     for tag_set in include.iter().flatten() {
         if !tag_set.contains(entity) {
             return false;
@@ -6801,12 +10296,12 @@ fn tag_sets_match(
 }
 
 impl<'world, Q: QueryTuple> DynQuery<'world, Q> {
-    pub fn with<T: Send + Sync + Default + 'static>(mut self) -> Self {
+    pub fn with<T: ComponentData>(mut self) -> Self {
         self.include |= self.world.component_key::<T>().mask;
         self
     }
 
-    pub fn without<T: Send + Sync + Default + 'static>(mut self) -> Self {
+    pub fn without<T: ComponentData>(mut self) -> Self {
         self.exclude |= self.world.component_key::<T>().mask;
         self
     }
@@ -6858,9 +10353,15 @@ impl<'world, Q: QueryTuple> DynQuery<'world, Q> {
         self
     }
 
+    /// Visit [`Disabled`] entities too, which every query skips by default.
+    pub fn include_disabled(mut self) -> Self {
+        self.include_disabled = true;
+        self
+    }
+
     /// Only visit entities whose `T` changed since the last step. `T` must be
     /// one of the tuple's components.
-    pub fn changed<T: Send + Sync + Default + 'static>(mut self) -> Self {
+    pub fn changed<T: ComponentData>(mut self) -> Self {
         let mask = self.world.component_key::<T>().mask;
         self.changed_mask |= mask;
         self
@@ -6870,7 +10371,7 @@ impl<'world, Q: QueryTuple> DynQuery<'world, Q> {
     /// spawn or by a component add; mutating `T` does not retrigger this, and
     /// the added stamp rides along through table migrations. `T` must be one
     /// of the tuple's components.
-    pub fn added<T: Send + Sync + Default + 'static>(mut self) -> Self {
+    pub fn added<T: ComponentData>(mut self) -> Self {
         let mask = self.world.component_key::<T>().mask;
         self.added_mask |= mask;
         self
@@ -6896,6 +10397,68 @@ impl<'world, Q: QueryTuple> DynQuery<'world, Q> {
             exclude: self.exclude,
             changed_mask: self.changed_mask,
             added_mask: self.added_mask,
+            include_disabled: self.include_disabled,
+            marker: PhantomData,
+        }
+    }
+
+    /// Registers this query with the world as a [`LiveQuery`], which keeps
+    /// its matched tables current as tables are created instead of
+    /// resolving them on every run. Component and tag filters are captured;
+    /// tag-set references and changed/added filters are not, so apply those
+    /// with a regular query. Disabled entities are excluded per run rather
+    /// than captured, so entities disabled after registration drop out too.
+    pub fn live(self) -> LiveQuery<Q> {
+        assert!(
+            self.include_tag_sets.iter().all(Option::is_none)
+                && self.exclude_tag_sets.iter().all(Option::is_none)
+                && self.changed_mask == 0
+                && self.added_mask == 0,
+            "live queries capture component and tag filters only"
+        );
+        let element_masks = match self.element_masks {
+            Some(masks) => masks,
+            None => Q::element_masks(self.world),
+        };
+        let mut state = match self
+            .world
+            .split_masks_with(self.include, self.exclude, true)
+        {
+            Some((component_include, component_exclude, tag_include, tag_exclude))
+                if !self.dead =>
+            {
+                LiveQueryState {
+                    component_include,
+                    component_exclude,
+                    tag_include,
+                    tag_exclude,
+                    include_disabled: self.include_disabled,
+                    ..LiveQueryState::default()
+                }
+            }
+            _ => LiveQueryState {
+                dead: true,
+                ..LiveQueryState::default()
+            },
+        };
+        let world = self.world;
+        state.rematch(&world.tables);
+        let index = match world.live_queries.iter().position(|slot| slot.released) {
+            Some(index) => {
+                state.generation = world.live_queries[index].generation;
+                world.live_queries[index] = state;
+                index
+            }
+            None => {
+                world.live_queries.push(state);
+                world.live_queries.len() - 1
+            }
+        };
+        LiveQuery {
+            index,
+            world_id: world.world_id,
+            generation: world.live_queries[index].generation,
+            element_masks,
             marker: PhantomData,
         }
     }
@@ -6915,8 +10478,9 @@ impl<'world, Q: QueryTuple> DynQuery<'world, Q> {
             "changed filters must name components present in the query tuple"
         );
 
-        let Some((component_include, component_exclude, tag_include, tag_exclude)) =
-            self.world.split_masks(self.include, self.exclude)
+        let Some((component_include, component_exclude, tag_include, tag_exclude)) = self
+            .world
+            .split_masks_with(self.include, self.exclude, self.include_disabled)
         else {
             return;
         };
@@ -6925,13 +10489,16 @@ impl<'world, Q: QueryTuple> DynQuery<'world, Q> {
         let current_tick = self.world.current_tick;
         let changed_mask = self.changed_mask;
         let added_mask = self.added_mask;
+        let exclude_tag_sets = self.exclude_tag_sets;
+        let disabled_set = active_disabled_set(self.disabled_set, self.include_disabled);
 
         let has_row_filters = tag_include != 0
             || tag_exclude != 0
             || changed_mask != 0
             || added_mask != 0
             || self.include_tag_sets.iter().any(Option::is_some)
-            || self.exclude_tag_sets.iter().any(Option::is_some);
+            || exclude_tag_sets.iter().any(Option::is_some)
+            || disabled_set.is_some();
 
         let tags = &self.world.tags;
         let table_indices = archetype_cached_tables(
@@ -6976,7 +10543,12 @@ impl<'world, Q: QueryTuple> DynQuery<'world, Q> {
                     {
                         continue;
                     }
-                    if !tag_sets_match(&self.include_tag_sets, &self.exclude_tag_sets, entity) {
+                    if !tag_sets_match(
+                        &self.include_tag_sets,
+                        &exclude_tag_sets,
+                        disabled_set,
+                        entity,
+                    ) {
                         continue;
                     }
                     if changed_mask != 0
@@ -7042,8 +10614,9 @@ impl<'world, Q: QueryTuple> DynQuery<'world, Q> {
             "changed and added filters must name components present in the query tuple"
         );
 
-        let Some((component_include, component_exclude, tag_include, tag_exclude)) =
-            self.world.split_masks(self.include, self.exclude)
+        let Some((component_include, component_exclude, tag_include, tag_exclude)) = self
+            .world
+            .split_masks_with(self.include, self.exclude, self.include_disabled)
         else {
             return;
         };
@@ -7054,13 +10627,15 @@ impl<'world, Q: QueryTuple> DynQuery<'world, Q> {
         let added_mask = self.added_mask;
         let include_tag_sets = self.include_tag_sets;
         let exclude_tag_sets = self.exclude_tag_sets;
+        let disabled_set = active_disabled_set(self.disabled_set, self.include_disabled);
 
         let has_row_filters = tag_include != 0
             || tag_exclude != 0
             || changed_mask != 0
             || added_mask != 0
             || include_tag_sets.iter().any(Option::is_some)
-            || exclude_tag_sets.iter().any(Option::is_some);
+            || exclude_tag_sets.iter().any(Option::is_some)
+            || disabled_set.is_some();
 
         let tags = &self.world.tags;
         self.world
@@ -7105,7 +10680,12 @@ impl<'world, Q: QueryTuple> DynQuery<'world, Q> {
                         {
                             continue;
                         }
-                        if !tag_sets_match(&include_tag_sets, &exclude_tag_sets, entity) {
+                        if !tag_sets_match(
+                            &include_tag_sets,
+                            &exclude_tag_sets,
+                            disabled_set,
+                            entity,
+                        ) {
                             continue;
                         }
                         if changed_mask != 0
@@ -7134,6 +10714,263 @@ impl<'world, Q: QueryTuple> DynQuery<'world, Q> {
                 }
             });
     }
+
+    /// Runs `f` over fixed-size chunks of aligned column slices, the shape
+    /// SIMD-friendly loops want: each matched table is cut into runs of
+    /// `chunk_len` rows plus one shorter remainder chunk, and `f` receives
+    /// the chunk's entities alongside one slice per tuple element, all the
+    /// same length. Row filters (tags, tag sets, changed, added, disabled)
+    /// split a table at every non-matching row, so chunks only ever hold
+    /// matching rows. Mutable elements stamp their tick columns over every
+    /// chunk handed out.
+    ///
+    /// # Panics
+    /// Panics if `chunk_len` is zero.
+    pub fn for_each_chunk(
+        self,
+        chunk_len: usize,
+        mut f: impl for<'chunk> FnMut(&'chunk [Entity], Q::Slices<'chunk>),
+    ) {
+        for (entities, fetch) in self.chunk_jobs(chunk_len, false) {
+            f(entities, Q::slices(fetch));
+        }
+    }
+
+    /// The parallel form of [`for_each_chunk`](Self::for_each_chunk): the
+    /// chunks of every matched table run across the pool, so parallelism
+    /// follows chunk count rather than table count. Same chunking, filter
+    /// and stamping semantics.
+    ///
+    /// # Panics
+    /// Panics if `chunk_len` is zero.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn par_for_each_chunk<F>(self, chunk_len: usize, f: F)
+    where
+        F: for<'chunk> Fn(&'chunk [Entity], Q::Slices<'chunk>) + Send + Sync,
+    {
+        use crate::rayon::prelude::*;
+
+        self.chunk_jobs(chunk_len, false)
+            .into_par_iter()
+            .for_each(|(entities, fetch)| f(entities, Q::slices(fetch)));
+    }
+
+    /// Folds every match into one value in parallel. Matched rows are split
+    /// into partials under the [`PAR_REDUCE_BLOCK`](crate::PAR_REDUCE_BLOCK)
+    /// rule, each partial folds its rows in order starting from
+    /// `identity()`, and the partials are combined left to right in table
+    /// and row order. The result is therefore bitwise identical whatever the
+    /// pool size, which float sums in lockstep simulations depend on.
+    /// Same filter set and stamping semantics as [`par_for_each`](Self::par_for_each).
+    #[cfg(not(target_family = "wasm"))]
+    pub fn par_fold<A, I, F, C>(self, identity: I, fold: F, combine: C) -> A
+    where
+        A: Send,
+        I: Fn() -> A + Send + Sync,
+        F: for<'item> Fn(A, Entity, Q::Item<'item>) -> A + Send + Sync,
+        C: FnMut(A, A) -> A,
+    {
+        use crate::rayon::prelude::*;
+
+        let partials: Vec<A> = self
+            .chunk_jobs(crate::PAR_REDUCE_BLOCK, true)
+            .into_par_iter()
+            .map(|(entities, mut fetch)| {
+                let mut accumulator = identity();
+                for (index, &entity) in entities.iter().enumerate() {
+                    accumulator = fold(accumulator, entity, Q::par_item(&mut fetch, index));
+                }
+                accumulator
+            })
+            .collect();
+        crate::combine_partials(partials, &identity, combine)
+    }
+
+    /// Maps every match and reduces the mapped values in a fixed order,
+    /// `None` when nothing matched. Deterministic like [`par_fold`](Self::par_fold).
+    #[cfg(not(target_family = "wasm"))]
+    pub fn par_reduce<T, M, R>(self, map: M, reduce: R) -> Option<T>
+    where
+        T: Send,
+        M: for<'item> Fn(Entity, Q::Item<'item>) -> T + Send + Sync,
+        R: Fn(T, T) -> T + Send + Sync,
+    {
+        self.par_fold(
+            || None,
+            |accumulator, entity, item| {
+                let value = map(entity, item);
+                Some(match accumulator {
+                    Some(accumulator) => reduce(accumulator, value),
+                    None => value,
+                })
+            },
+            |left, right| match (left, right) {
+                (Some(left), Some(right)) => Some(reduce(left, right)),
+                (left, right) => left.or(right),
+            },
+        )
+    }
+
+    /// Maps every match in parallel and collects the values in match order,
+    /// the order [`for_each`](Self::for_each) visits them.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn par_map_collect<T, M>(self, map: M) -> Vec<T>
+    where
+        T: Send,
+        M: for<'item> Fn(Entity, Q::Item<'item>) -> T + Send + Sync,
+    {
+        self.par_fold(
+            Vec::new,
+            |mut values, entity, item| {
+                values.push(map(entity, item));
+                values
+            },
+            |mut left, mut right| {
+                left.append(&mut right);
+                left
+            },
+        )
+    }
+
+    /// Resolves the query and carves every matched table into chunks of at
+    /// most `chunk_len` contiguous matching rows, each paired with its
+    /// entities and its slice of the fetched columns. `aligned` also cuts at
+    /// every multiple of `chunk_len`, the partition the reductions rely on.
+    fn chunk_jobs(
+        self,
+        chunk_len: usize,
+        aligned: bool,
+    ) -> Vec<(&'world [Entity], Q::ParFetch<'world>)> {
+        assert!(chunk_len > 0, "chunk length must be greater than zero");
+        let mut jobs = Vec::new();
+        if self.dead {
+            return jobs;
+        }
+        let element_masks = match self.element_masks {
+            Some(masks) => masks,
+            None => Q::element_masks(self.world),
+        };
+        let tuple_mask = element_masks.iter().fold(0, |mask, element| mask | element);
+        assert_eq!(
+            (self.changed_mask | self.added_mask) & !tuple_mask,
+            0,
+            "changed and added filters must name components present in the query tuple"
+        );
+
+        let Some((component_include, component_exclude, tag_include, tag_exclude)) = self
+            .world
+            .split_masks_with(self.include, self.exclude, self.include_disabled)
+        else {
+            return jobs;
+        };
+
+        let since_tick = self.world.last_tick;
+        let current_tick = self.world.current_tick;
+        let changed_mask = self.changed_mask;
+        let added_mask = self.added_mask;
+        let include_tag_sets = self.include_tag_sets;
+        let exclude_tag_sets = self.exclude_tag_sets;
+        let disabled_set = active_disabled_set(self.disabled_set, self.include_disabled);
+
+        let has_row_filters = tag_include != 0
+            || tag_exclude != 0
+            || changed_mask != 0
+            || added_mask != 0
+            || include_tag_sets.iter().any(Option::is_some)
+            || exclude_tag_sets.iter().any(Option::is_some)
+            || disabled_set.is_some();
+
+        let DynWorld { tables, tags, .. } = self.world;
+        let tags: &[SparseTagSet] = tags;
+        for table in tables.iter_mut() {
+            if table.mask & component_include != component_include
+                || table.mask & component_exclude != 0
+                || table.entity_indices.is_empty()
+            {
+                continue;
+            }
+            let table_mask = table.mask;
+            let DynComponentArrays {
+                entity_indices,
+                columns,
+                ..
+            } = table;
+            let entities: &'world [Entity] = entity_indices;
+
+            let mut matches = Vec::new();
+            if has_row_filters {
+                matches.resize(entities.len(), true);
+                if added_mask != 0 {
+                    matches.fill(false);
+                    for column in columns.iter() {
+                        if added_mask & (1u64 << column.component_index) == 0 {
+                            continue;
+                        }
+                        for (row, &added_tick) in column.added.iter().enumerate() {
+                            if tick_is_newer(added_tick, since_tick) {
+                                matches[row] = true;
+                            }
+                        }
+                    }
+                }
+                let fetch = Q::fetch(table_mask, columns, &element_masks, current_tick);
+                for (index, &entity) in entities.iter().enumerate() {
+                    matches[index] = matches[index]
+                        && ((tag_include == 0 && tag_exclude == 0)
+                            || tags_match(tags, entity, tag_include, tag_exclude))
+                        && tag_sets_match(
+                            &include_tag_sets,
+                            &exclude_tag_sets,
+                            disabled_set,
+                            entity,
+                        )
+                        && (changed_mask == 0
+                            || Q::changed_newer(
+                                &fetch,
+                                index,
+                                &element_masks,
+                                changed_mask,
+                                since_tick,
+                            ));
+                }
+                if !matches.contains(&true) {
+                    continue;
+                }
+            }
+            let matched = |row: usize| matches.is_empty() || matches[row];
+
+            let mut rest = Q::par_fetch(table_mask, columns, &element_masks, current_tick);
+            let mut offset = 0;
+            let mut row = 0;
+            while row < entities.len() {
+                if !matched(row) {
+                    row += 1;
+                    continue;
+                }
+                let mut end = row + 1;
+                while end < entities.len() && matched(end) {
+                    end += 1;
+                }
+                let (_, tail) = Q::par_split(rest, row - offset);
+                rest = tail;
+                offset = row;
+                while offset < end {
+                    let limit = if aligned {
+                        chunk_len - offset % chunk_len
+                    } else {
+                        chunk_len
+                    };
+                    let len = limit.min(end - offset);
+                    let (chunk, tail) = Q::par_split(rest, len);
+                    jobs.push((&entities[offset..offset + len], chunk));
+                    rest = tail;
+                    offset += len;
+                }
+                row = end;
+            }
+        }
+        jobs
+    }
 }
 
 /// A read-only typed query in progress, from [`DynWorld::query_ref`].
@@ -7149,13 +10986,16 @@ pub struct DynQueryRef<'world, Q: ReadQueryTuple> {
     pub added_mask: u64,
     pub include_tag_sets: [Option<&'world SparseTagSet>; 4],
     pub exclude_tag_sets: [Option<&'world SparseTagSet>; 4],
+    /// The group's disabled set, for queries routed through a [`DynEcs`].
+    pub disabled_set: Option<&'world SparseTagSet>,
+    pub include_disabled: bool,
     pub resolved_masks: Option<([u64; 8], u64)>,
     pub dead: bool,
     pub marker: PhantomData<Q>,
 }
 
 impl<'world, Q: ReadQueryTuple> DynQueryRef<'world, Q> {
-    pub fn with<T: Send + Sync + Default + 'static>(mut self) -> Self {
+    pub fn with<T: ComponentData>(mut self) -> Self {
         match self.world.lookup_key::<T>() {
             Some(key) => self.include |= key.mask,
             None => self.dead = true,
@@ -7163,7 +11003,7 @@ impl<'world, Q: ReadQueryTuple> DynQueryRef<'world, Q> {
         self
     }
 
-    pub fn without<T: Send + Sync + Default + 'static>(mut self) -> Self {
+    pub fn without<T: ComponentData>(mut self) -> Self {
         if let Some(key) = self.world.lookup_key::<T>() {
             self.exclude |= key.mask;
         }
@@ -7217,9 +11057,14 @@ impl<'world, Q: ReadQueryTuple> DynQueryRef<'world, Q> {
         self
     }
 
+    pub fn include_disabled(mut self) -> Self {
+        self.include_disabled = true;
+        self
+    }
+
     /// Only visit entities whose `T` changed since the last step. `T` must be
     /// one of the tuple's components.
-    pub fn changed<T: Send + Sync + Default + 'static>(mut self) -> Self {
+    pub fn changed<T: ComponentData>(mut self) -> Self {
         match self.world.lookup_key::<T>() {
             Some(key) => self.changed_mask |= key.mask,
             None => self.dead = true,
@@ -7230,7 +11075,7 @@ impl<'world, Q: ReadQueryTuple> DynQueryRef<'world, Q> {
     /// Only visit entities that gained `T` since the last step, whether by
     /// spawn or by a component add; mutating `T` does not retrigger this.
     /// `T` must be one of the tuple's components.
-    pub fn added<T: Send + Sync + Default + 'static>(mut self) -> Self {
+    pub fn added<T: ComponentData>(mut self) -> Self {
         match self.world.lookup_key::<T>() {
             Some(key) => self.added_mask |= key.mask,
             None => self.dead = true,
@@ -7261,6 +11106,7 @@ impl<'world, Q: ReadQueryTuple> DynQueryRef<'world, Q> {
             exclude: self.exclude,
             changed_mask: self.changed_mask,
             added_mask: self.added_mask,
+            include_disabled: self.include_disabled,
             marker: PhantomData,
         }
     }
@@ -7296,7 +11142,10 @@ impl<'world, Q: ReadQueryTuple> DynQueryRef<'world, Q> {
         let mut component_exclude = 0;
         let mut tag_include = 0;
         let mut tag_exclude = 0;
-        match self.world.split_masks(include, self.exclude) {
+        match self
+            .world
+            .split_masks_with(include, self.exclude, self.include_disabled)
+        {
             Some((components_in, components_out, tags_in, tags_out)) => {
                 component_include = components_in;
                 component_exclude = components_out;
@@ -7321,6 +11170,7 @@ impl<'world, Q: ReadQueryTuple> DynQueryRef<'world, Q> {
             tag_exclude,
             include_tag_sets: self.include_tag_sets,
             exclude_tag_sets: self.exclude_tag_sets,
+            disabled_set: active_disabled_set(self.disabled_set, self.include_disabled),
             changed_mask: self.changed_mask,
             added_mask: self.added_mask,
             since_tick: self.world.last_tick,
@@ -7332,31 +11182,167 @@ impl<'world, Q: ReadQueryTuple> DynQueryRef<'world, Q> {
         }
     }
 
-    /// The exactly-one match: `Some` when precisely one entity matches,
-    /// `None` for zero or several. The get-the-player call; take the entity
-    /// and go back through `get_mut` when you need to write.
-    pub fn single(self) -> Option<(Entity, Q::Item<'world>)> {
-        let mut matches = self.iter();
-        let first = matches.next()?;
-        if matches.next().is_some() {
-            return None;
-        }
-        Some(first)
+    /// The exactly-one match: `Some` when precisely one entity matches,
+    /// `None` for zero or several. The get-the-player call; take the entity
+    /// and go back through `get_mut` when you need to write.
+    pub fn single(self) -> Option<(Entity, Q::Item<'world>)> {
+        let mut matches = self.iter();
+        let first = matches.next()?;
+        if matches.next().is_some() {
+            return None;
+        }
+        Some(first)
+    }
+
+    /// Every unordered pair of distinct matches, for pairwise logic like
+    /// collision tests. Items are shared borrows of the world, so pairs are
+    /// `Copy` and this is a real [`Iterator`]. Matches are collected once up
+    /// front, one `(Entity, item)` per match.
+    pub fn iter_combinations(self) -> DynQueryCombinations<'world, Q>
+    where
+        Q::Item<'world>: Copy,
+    {
+        DynQueryCombinations {
+            items: self.iter().collect(),
+            first: 0,
+            second: 1,
+        }
+    }
+
+    /// Folds every match into one value in parallel, with the same
+    /// partition and fixed combination order as [`DynQuery::par_fold`], so
+    /// the two agree bit for bit on the same world. Tables are split into
+    /// [`PAR_REDUCE_BLOCK`](crate::PAR_REDUCE_BLOCK)-row blocks and the
+    /// blocks run across the pool.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn par_fold<A, I, F, C>(self, identity: I, fold: F, combine: C) -> A
+    where
+        A: Send,
+        I: Fn() -> A + Send + Sync,
+        F: Fn(A, Entity, Q::Item<'world>) -> A + Send + Sync,
+        C: FnMut(A, A) -> A,
+    {
+        use crate::rayon::prelude::*;
+
+        let query = self.iter();
+        if query.done {
+            return identity();
+        }
+        let tables = &query.world.tables;
+        let mut blocks = Vec::new();
+        let mut push_blocks = |table_index: usize| {
+            let table = &tables[table_index];
+            if table.mask & query.include == query.include && table.mask & query.exclude == 0 {
+                for start in (0..table.entity_indices.len()).step_by(crate::PAR_REDUCE_BLOCK) {
+                    blocks.push((table_index, start));
+                }
+            }
+        };
+        match query.cached_tables {
+            Some(indices) => indices.iter().for_each(|&index| push_blocks(index)),
+            None => (0..tables.len()).for_each(push_blocks),
+        }
+
+        let tags = &query.world.tags;
+        let element_masks = &query.element_masks;
+        let partials: Vec<Vec<A>> = blocks
+            .into_par_iter()
+            .map(|(table_index, start)| {
+                let table = &tables[table_index];
+                let fetch = Q::read_fetch(table.mask, &table.columns, element_masks);
+                let end = (start + crate::PAR_REDUCE_BLOCK).min(table.entity_indices.len());
+                let mut partials = crate::RowPartials::default();
+                for index in start..end {
+                    let entity = table.entity_indices[index];
+                    if (query.tag_include != 0 || query.tag_exclude != 0)
+                        && !tags_match(tags, entity, query.tag_include, query.tag_exclude)
+                    {
+                        continue;
+                    }
+                    if !tag_sets_match(
+                        &query.include_tag_sets,
+                        &query.exclude_tag_sets,
+                        query.disabled_set,
+                        entity,
+                    ) {
+                        continue;
+                    }
+                    if query.changed_mask != 0
+                        && !Q::read_changed_newer(
+                            fetch,
+                            index,
+                            element_masks,
+                            query.changed_mask,
+                            query.since_tick,
+                        )
+                    {
+                        continue;
+                    }
+                    if query.added_mask != 0
+                        && !Q::read_added_newer(
+                            fetch,
+                            index,
+                            element_masks,
+                            query.added_mask,
+                            query.since_tick,
+                        )
+                    {
+                        continue;
+                    }
+                    partials.fold(index, &identity, |accumulator| {
+                        fold(accumulator, entity, Q::read_item(fetch, index))
+                    });
+                }
+                partials.finish()
+            })
+            .collect();
+        crate::combine_partials(partials.into_iter().flatten(), &identity, combine)
+    }
+
+    /// Maps every match and reduces the mapped values in a fixed order,
+    /// `None` when nothing matched. Deterministic like [`par_fold`](Self::par_fold).
+    #[cfg(not(target_family = "wasm"))]
+    pub fn par_reduce<T, M, R>(self, map: M, reduce: R) -> Option<T>
+    where
+        T: Send,
+        M: Fn(Entity, Q::Item<'world>) -> T + Send + Sync,
+        R: Fn(T, T) -> T + Send + Sync,
+    {
+        self.par_fold(
+            || None,
+            |accumulator, entity, item| {
+                let value = map(entity, item);
+                Some(match accumulator {
+                    Some(accumulator) => reduce(accumulator, value),
+                    None => value,
+                })
+            },
+            |left, right| match (left, right) {
+                (Some(left), Some(right)) => Some(reduce(left, right)),
+                (left, right) => left.or(right),
+            },
+        )
     }
 
-    /// Every unordered pair of distinct matches, for pairwise logic like
-    /// collision tests. Items are shared borrows of the world, so pairs are
-    /// `Copy` and this is a real [`Iterator`]. Matches are collected once up
-    /// front, one `(Entity, item)` per match.
-    pub fn iter_combinations(self) -> DynQueryCombinations<'world, Q>
+    /// Maps every match in parallel and collects the values in match order,
+    /// the order [`iter`](Self::iter) yields them.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn par_map_collect<T, M>(self, map: M) -> Vec<T>
     where
-        Q::Item<'world>: Copy,
+        T: Send,
+        M: Fn(Entity, Q::Item<'world>) -> T + Send + Sync,
     {
-        DynQueryCombinations {
-            items: self.iter().collect(),
-            first: 0,
-            second: 1,
-        }
+        self.par_fold(
+            Vec::new,
+            |mut values, entity, item| {
+                values.push(map(entity, item));
+                values
+            },
+            |mut left, mut right| {
+                left.append(&mut right);
+                left
+            },
+        )
     }
 }
 
@@ -7402,6 +11388,7 @@ pub struct DynQueryRefIter<'world, Q: ReadQueryTuple> {
     pub tag_exclude: u64,
     pub include_tag_sets: [Option<&'world SparseTagSet>; 4],
     pub exclude_tag_sets: [Option<&'world SparseTagSet>; 4],
+    pub disabled_set: Option<&'world SparseTagSet>,
     pub changed_mask: u64,
     pub added_mask: u64,
     pub since_tick: u32,
@@ -7430,7 +11417,12 @@ impl<'world, Q: ReadQueryTuple> Iterator for DynQueryRefIter<'world, Q> {
                     {
                         continue;
                     }
-                    if !tag_sets_match(&self.include_tag_sets, &self.exclude_tag_sets, entity) {
+                    if !tag_sets_match(
+                        &self.include_tag_sets,
+                        &self.exclude_tag_sets,
+                        self.disabled_set,
+                        entity,
+                    ) {
                         continue;
                     }
                     if self.changed_mask != 0
@@ -7507,13 +11499,13 @@ fn tags_match(tags: &[SparseTagSet], entity: Entity, tag_include: u64, tag_exclu
 
 /// Marks a type as a component that may participate in a [`Bundle`]. It is
 /// the one nominal opt-in the dynamic path asks for: registration, `get`,
-/// `set`, and queries still take any `Send + Sync + Default + 'static` type,
+/// `set`, and queries still take any [`ComponentData`] type,
 /// but spawning a type as part of a bundle needs this marker so tuples and
 /// nested bundles stay distinguishable from single components. Mark a type
 /// once with [`impl_component!`](crate::impl_component) (or a hand-written
 /// `impl`). The supertraits are exactly the component storage bounds, so the
 /// marker adds no requirement a component did not already meet.
-pub trait Component: Send + Sync + Default + 'static {}
+pub trait Component: ComponentData {}
 
 /// A set of components spawned together. Implemented for any [`Component`], for
 /// tuples of bundles up to fifteen elements, and for
@@ -7526,9 +11518,9 @@ pub trait Bundle: sealed::SealedBundle {
     /// take never grow the schema. Unregistered components contribute nothing.
     fn lookup_mask(world: &DynWorld) -> u64;
     fn write(self, world: &mut DynWorld, entity: Entity);
-    /// Moves the bundle's components out of an entity, leaving each column slot
-    /// at `Default`. The caller must have verified every component is present.
-    fn take(world: &mut DynWorld, entity: Entity) -> Self
+    /// Claims the bundle's components from the values a removal moved out of
+    /// an entity. The caller must have verified every component was present.
+    fn take(world: &DynWorld, taken: &mut TakenComponents) -> Self
     where
         Self: Sized;
     fn write_group(self, ecs: &mut DynEcs, entity: Entity);
@@ -7536,9 +11528,13 @@ pub trait Bundle: sealed::SealedBundle {
     /// Whether every component the bundle names is present on a group entity,
     /// each in its routing member world.
     fn present_group(ecs: &DynEcs, entity: Entity) -> bool;
-    /// The group counterpart of [`take`](Self::take), moving each component out
-    /// of its routing member world. The caller must have verified presence.
-    fn take_group(ecs: &mut DynEcs, entity: Entity) -> Self
+    /// Ors each component's mask into its routing member world's slot of
+    /// `masks`, registering nothing.
+    fn lookup_group_masks(ecs: &DynEcs, masks: &mut [u64]);
+    /// The group counterpart of [`take`](Self::take), claiming each component
+    /// from what its routing member world's removal moved out, indexed by
+    /// world. The caller must have verified presence.
+    fn take_group(ecs: &DynEcs, taken: &mut [TakenComponents]) -> Self
     where
         Self: Sized;
 }
@@ -7566,12 +11562,11 @@ impl<C: Component> Bundle for C {
         world.set(entity, self);
     }
 
-    fn take(world: &mut DynWorld, entity: Entity) -> Self {
-        std::mem::take(
-            world
-                .get_mut::<Self>(entity)
-                .expect("take requires the component to be present"),
-        )
+    fn take(world: &DynWorld, taken: &mut TakenComponents) -> Self {
+        let key = world
+            .lookup_key::<Self>()
+            .expect("take requires the component to be registered");
+        taken.claim(key.component_index)
     }
 
     fn write_group(self, ecs: &mut DynEcs, entity: Entity) {
@@ -7592,20 +11587,25 @@ impl<C: Component> Bundle for C {
             .is_some_and(|world_index| ecs.worlds[world_index].get::<Self>(entity).is_some())
     }
 
-    fn take_group(ecs: &mut DynEcs, entity: Entity) -> Self {
+    fn lookup_group_masks(ecs: &DynEcs, masks: &mut [u64]) {
+        if let Some(world_index) = ecs.route_ref::<Self>()
+            && let Some(key) = ecs.worlds[world_index].lookup_key::<Self>()
+        {
+            masks[world_index] |= key.mask;
+        }
+    }
+
+    fn take_group(ecs: &DynEcs, taken: &mut [TakenComponents]) -> Self {
         let world_index = ecs
-            .route::<Self>()
+            .route_ref::<Self>()
             .expect("take_group requires the component to be routed and present");
-        std::mem::take(
-            ecs.worlds[world_index]
-                .get_mut::<Self>(entity)
-                .expect("take_group requires the component to be present"),
-        )
+        Self::take(&ecs.worlds[world_index], &mut taken[world_index])
     }
 }
 
 impl<C: Component + Clone> CloneBundle for C {
     fn spawn_extend(&self, world: &mut DynWorld, table_index: usize, count: usize) {
+        world.registry.register_clone::<C>();
         world.extend_column(table_index, count, self);
     }
 }
@@ -7641,8 +11641,8 @@ macro_rules! impl_bundle {
                 $($element.write(world, entity);)+
             }
 
-            fn take(world: &mut DynWorld, entity: Entity) -> Self {
-                ($(<$element as Bundle>::take(world, entity),)+)
+            fn take(world: &DynWorld, taken: &mut TakenComponents) -> Self {
+                ($(<$element as Bundle>::take(world, taken),)+)
             }
 
             #[allow(non_snake_case)]
@@ -7661,8 +11661,12 @@ macro_rules! impl_bundle {
                 true $(&& <$element as Bundle>::present_group(ecs, entity))+
             }
 
-            fn take_group(ecs: &mut DynEcs, entity: Entity) -> Self {
-                ($(<$element as Bundle>::take_group(ecs, entity),)+)
+            fn lookup_group_masks(ecs: &DynEcs, masks: &mut [u64]) {
+                $(<$element as Bundle>::lookup_group_masks(ecs, masks);)+
+            }
+
+            fn take_group(ecs: &DynEcs, taken: &mut [TakenComponents]) -> Self {
+                ($(<$element as Bundle>::take_group(ecs, taken),)+)
             }
         }
 
@@ -7706,6 +11710,7 @@ pub struct DynJoin<'ecs, Q: QueryTuple> {
     pub exclude_tag_types: [Option<TypeId>; 4],
     pub changed_lookups: [Option<JoinMaskLookup>; 4],
     pub added_lookups: [Option<JoinMaskLookup>; 4],
+    pub include_disabled: bool,
     pub marker: PhantomData<Q>,
 }
 
@@ -7719,11 +11724,11 @@ fn push_join_slot<T: Copy>(slots: &mut [Option<T>; 4], value: T) {
     panic!("a join filter family holds at most four entries");
 }
 
-fn join_mask_of<T: Send + Sync + Default + 'static>(world: &DynWorld) -> Option<u64> {
+fn join_mask_of<T: ComponentData>(world: &DynWorld) -> Option<u64> {
     world.lookup_key::<T>().map(|key| key.mask)
 }
 
-impl<Q: QueryTuple> DynJoin<'_, Q> {
+impl<'ecs, Q: QueryTuple> DynJoin<'ecs, Q> {
     /// Only visit entities carrying the marker type `T`'s group tag. A tag
     /// nothing has used yet matches nothing.
     pub fn with_tag_type<T: 'static>(mut self) -> Self {
@@ -7737,16 +11742,22 @@ impl<Q: QueryTuple> DynJoin<'_, Q> {
         self
     }
 
+    /// Visit entities disabled through [`DynEcs::disable`] too.
+    pub fn include_disabled(mut self) -> Self {
+        self.include_disabled = true;
+        self
+    }
+
     /// Only visit entities whose `T` changed since the driver world's last
     /// step. `T` must be one of the tuple's driver-world components.
-    pub fn changed<T: Send + Sync + Default + 'static>(mut self) -> Self {
+    pub fn changed<T: ComponentData>(mut self) -> Self {
         push_join_slot(&mut self.changed_lookups, join_mask_of::<T>);
         self
     }
 
     /// Only visit entities that gained `T` since the driver world's last
     /// step. `T` must be one of the tuple's driver-world components.
-    pub fn added<T: Send + Sync + Default + 'static>(mut self) -> Self {
+    pub fn added<T: ComponentData>(mut self) -> Self {
         push_join_slot(&mut self.added_lookups, join_mask_of::<T>);
         self
     }
@@ -7754,93 +11765,9 @@ impl<Q: QueryTuple> DynJoin<'_, Q> {
     /// Runs the join. Routing, the driver rule, and the borrow split are
     /// documented on [`DynEcs::query_join`].
     pub fn for_each(self, f: impl for<'item> FnMut(Entity, Q::Item<'item>)) {
-        let routes = Q::join_routes(&self.ecs.worlds);
-
-        let mut driver: Option<usize> = None;
-        for route in routes.iter().flatten() {
-            if route.required && route.world.is_none() {
-                panic!(
-                    "{} is not registered in any member world; add it to a member schema first",
-                    route.type_name
-                );
-            }
-            if route.mutable
-                && let Some(world) = route.world
-            {
-                match driver {
-                    None => driver = Some(world),
-                    Some(existing) => assert_eq!(
-                        existing, world,
-                        "cross-world queries mutate only one world's components; \
-                         {} lives in member world {world}, but another mutable element \
-                         already fixed the driver to member world {existing}",
-                        route.type_name
-                    ),
-                }
-            }
-        }
-        let driver = driver.unwrap_or_else(|| {
-            let mut counts = vec![0usize; self.ecs.worlds.len()];
-            for route in routes.iter().flatten() {
-                if let Some(world) = route.world {
-                    counts[world] += 1;
-                }
-            }
-            counts
-                .iter()
-                .copied()
-                .enumerate()
-                .max_by_key(|&(index, count)| (count, usize::MAX - index))
-                .map(|(index, _)| index)
-                .expect("query_join requires at least one member world")
-        });
-
-        let mut include_sets: [Option<&SparseTagSet>; 4] = [None; 4];
-        let mut exclude_sets: [Option<&SparseTagSet>; 4] = [None; 4];
-        for (slot, type_id) in include_sets.iter_mut().zip(self.include_tag_types.iter()) {
-            if let Some(type_id) = type_id {
-                match self.ecs.tag_type_indices.get(type_id) {
-                    Some(&index) => *slot = Some(&self.ecs.tags[index]),
-                    None => return,
-                }
-            }
-        }
-        for (slot, type_id) in exclude_sets.iter_mut().zip(self.exclude_tag_types.iter()) {
-            if let Some(type_id) = type_id
-                && let Some(&index) = self.ecs.tag_type_indices.get(type_id)
-            {
-                *slot = Some(&self.ecs.tags[index]);
-            }
-        }
-
-        let (left, rest) = self.ecs.worlds.split_at_mut(driver);
-        let (driver_world, right) = rest
-            .split_first_mut()
-            .expect("the driver index is within the member list");
-        let left: &[DynWorld] = left;
-        let right: &[DynWorld] = right;
-
-        let mut element_worlds: [Option<&DynWorld>; 8] = [None; 8];
-        for (position, route) in routes.iter().enumerate() {
-            if let Some(route) = route
-                && let Some(world) = route.world
-                && world != driver
-            {
-                element_worlds[position] = Some(if world < driver {
-                    &left[world]
-                } else {
-                    &right[world - driver - 1]
-                });
-            }
+        if let Some((driver_world, element_worlds, filters)) = self.split() {
+            Q::join_for_each(driver_world, &element_worlds, &filters, f);
         }
-
-        let filters = JoinFilters {
-            include_sets,
-            exclude_sets,
-            changed_lookups: self.changed_lookups,
-            added_lookups: self.added_lookups,
-        };
-        Q::join_for_each(driver_world, &element_worlds, &filters, f);
     }
 
     /// The parallel form of [`for_each`](Self::for_each): driver tables run
@@ -7852,6 +11779,95 @@ impl<Q: QueryTuple> DynJoin<'_, Q> {
     where
         F: for<'item> Fn(Entity, Q::Item<'item>) + Send + Sync,
     {
+        if let Some((driver_world, element_worlds, filters)) = self.split() {
+            Q::join_par_fold(
+                driver_world,
+                &element_worlds,
+                &filters,
+                &|| (),
+                &|(), entity, item| f(entity, item),
+            );
+        }
+    }
+
+    /// Folds every match into one value in parallel. Driver tables run
+    /// concurrently and fold their matching rows into partials under the
+    /// [`PAR_REDUCE_BLOCK`](crate::PAR_REDUCE_BLOCK) rule; the partials are
+    /// then combined left to right in table and row order, so the result is
+    /// bitwise identical whatever the pool size. Same routing rules and
+    /// stamping as [`par_for_each`](Self::par_for_each).
+    #[cfg(not(target_family = "wasm"))]
+    pub fn par_fold<A, I, F, C>(self, identity: I, fold: F, combine: C) -> A
+    where
+        A: Send,
+        I: Fn() -> A + Send + Sync,
+        F: for<'item> Fn(A, Entity, Q::Item<'item>) -> A + Send + Sync,
+        C: FnMut(A, A) -> A,
+    {
+        let Some((driver_world, element_worlds, filters)) = self.split() else {
+            return identity();
+        };
+        let partials = Q::join_par_fold(driver_world, &element_worlds, &filters, &identity, &fold);
+        crate::combine_partials(partials, &identity, combine)
+    }
+
+    /// Maps every match and reduces the mapped values in a fixed order,
+    /// `None` when nothing matched. Deterministic like [`par_fold`](Self::par_fold).
+    #[cfg(not(target_family = "wasm"))]
+    pub fn par_reduce<T, M, R>(self, map: M, reduce: R) -> Option<T>
+    where
+        T: Send,
+        M: for<'item> Fn(Entity, Q::Item<'item>) -> T + Send + Sync,
+        R: Fn(T, T) -> T + Send + Sync,
+    {
+        self.par_fold(
+            || None,
+            |accumulator, entity, item| {
+                let value = map(entity, item);
+                Some(match accumulator {
+                    Some(accumulator) => reduce(accumulator, value),
+                    None => value,
+                })
+            },
+            |left, right| match (left, right) {
+                (Some(left), Some(right)) => Some(reduce(left, right)),
+                (left, right) => left.or(right),
+            },
+        )
+    }
+
+    /// Maps every match in parallel and collects the values in match order,
+    /// the order [`for_each`](Self::for_each) visits them.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn par_map_collect<T, M>(self, map: M) -> Vec<T>
+    where
+        T: Send,
+        M: for<'item> Fn(Entity, Q::Item<'item>) -> T + Send + Sync,
+    {
+        self.par_fold(
+            Vec::new,
+            |mut values, entity, item| {
+                values.push(map(entity, item));
+                values
+            },
+            |mut left, mut right| {
+                left.append(&mut right);
+                left
+            },
+        )
+    }
+
+    /// Resolves routing, picks the driver world, and splits the member list
+    /// into the driver's `&mut` and every foreign element's shared borrow.
+    /// `None` when an included tag has never been used, so nothing matches.
+    #[allow(clippy::type_complexity)]
+    fn split(
+        self,
+    ) -> Option<(
+        &'ecs mut DynWorld,
+        [Option<&'ecs DynWorld>; 8],
+        JoinFilters<'ecs>,
+    )> {
         let routes = Q::join_routes(&self.ecs.worlds);
 
         let mut driver: Option<usize> = None;
@@ -7899,7 +11915,7 @@ impl<Q: QueryTuple> DynJoin<'_, Q> {
             if let Some(type_id) = type_id {
                 match self.ecs.tag_type_indices.get(type_id) {
                     Some(&index) => *slot = Some(&self.ecs.tags[index]),
-                    None => return,
+                    None => return None,
                 }
             }
         }
@@ -7910,6 +11926,11 @@ impl<Q: QueryTuple> DynJoin<'_, Q> {
                 *slot = Some(&self.ecs.tags[index]);
             }
         }
+        let disabled_set = self
+            .ecs
+            .lookup_tag_type::<Disabled>()
+            .map(|index| &self.ecs.tags[index]);
+        let disabled_set = active_disabled_set(disabled_set, self.include_disabled);
 
         let (left, rest) = self.ecs.worlds.split_at_mut(driver);
         let (driver_world, right) = rest
@@ -7935,10 +11956,11 @@ impl<Q: QueryTuple> DynJoin<'_, Q> {
         let filters = JoinFilters {
             include_sets,
             exclude_sets,
+            disabled_set,
             changed_lookups: self.changed_lookups,
             added_lookups: self.added_lookups,
         };
-        Q::join_par_for_each(driver_world, &element_worlds, &filters, f);
+        Some((driver_world, element_worlds, filters))
     }
 }
 
@@ -7952,6 +11974,7 @@ pub struct DynJoinRef<'ecs, Q: ReadQueryTuple> {
     pub exclude_tag_types: [Option<TypeId>; 4],
     pub changed_lookups: [Option<JoinMaskLookup>; 4],
     pub added_lookups: [Option<JoinMaskLookup>; 4],
+    pub include_disabled: bool,
     pub marker: PhantomData<Q>,
 }
 
@@ -7968,17 +11991,23 @@ impl<'ecs, Q: ReadQueryTuple> DynJoinRef<'ecs, Q> {
         self
     }
 
+    /// Visit entities disabled through [`DynEcs::disable`] too.
+    pub fn include_disabled(mut self) -> Self {
+        self.include_disabled = true;
+        self
+    }
+
     /// Only visit entities whose `T` changed since the driver world's last
     /// step. `T` must be one of the tuple's driver-world components; an
     /// unregistered `T` reads as an empty iterator.
-    pub fn changed<T: Send + Sync + Default + 'static>(mut self) -> Self {
+    pub fn changed<T: ComponentData>(mut self) -> Self {
         push_join_slot(&mut self.changed_lookups, join_mask_of::<T>);
         self
     }
 
     /// Only visit entities that gained `T` since the driver world's last
     /// step, with the same rules as [`changed`](Self::changed).
-    pub fn added<T: Send + Sync + Default + 'static>(mut self) -> Self {
+    pub fn added<T: ComponentData>(mut self) -> Self {
         push_join_slot(&mut self.added_lookups, join_mask_of::<T>);
         self
     }
@@ -7993,6 +12022,7 @@ impl<'ecs, Q: ReadQueryTuple> DynJoinRef<'ecs, Q> {
             include: 0,
             include_sets: [None; 4],
             exclude_sets: [None; 4],
+            disabled_set: None,
             changed_mask: 0,
             added_mask: 0,
             since_tick: 0,
@@ -8045,6 +12075,11 @@ impl<'ecs, Q: ReadQueryTuple> DynJoinRef<'ecs, Q> {
                 *slot = Some(&self.ecs.tags[index]);
             }
         }
+        let disabled_set = self
+            .ecs
+            .lookup_tag_type::<Disabled>()
+            .map(|index| &self.ecs.tags[index]);
+        let disabled_set = active_disabled_set(disabled_set, self.include_disabled);
 
         let driver = &self.ecs.worlds[driver_index];
         let mut element_worlds: [Option<&'ecs DynWorld>; 8] = [None; 8];
@@ -8089,6 +12124,7 @@ impl<'ecs, Q: ReadQueryTuple> DynJoinRef<'ecs, Q> {
             include,
             include_sets,
             exclude_sets,
+            disabled_set,
             changed_mask,
             added_mask,
             since_tick: driver.last_tick,
@@ -8113,6 +12149,7 @@ pub struct DynJoinRefIter<'ecs, Q: ReadQueryTuple> {
     pub include: u64,
     pub include_sets: [Option<&'ecs SparseTagSet>; 4],
     pub exclude_sets: [Option<&'ecs SparseTagSet>; 4],
+    pub disabled_set: Option<&'ecs SparseTagSet>,
     pub changed_mask: u64,
     pub added_mask: u64,
     pub since_tick: u32,
@@ -8137,7 +12174,12 @@ impl<'ecs, Q: ReadQueryTuple> Iterator for DynJoinRefIter<'ecs, Q> {
                     let index = self.row_index;
                     self.row_index += 1;
                     let entity = entities[index];
-                    if !tag_sets_match(&self.include_sets, &self.exclude_sets, entity) {
+                    if !tag_sets_match(
+                        &self.include_sets,
+                        &self.exclude_sets,
+                        self.disabled_set,
+                        entity,
+                    ) {
                         continue;
                     }
                     if self.added_mask != 0 && !self.added_scratch[index] {
@@ -8213,6 +12255,7 @@ pub struct PreparedQuery<Q: QueryTuple> {
     pub exclude: u64,
     pub changed_mask: u64,
     pub added_mask: u64,
+    pub include_disabled: bool,
     pub marker: PhantomData<Q>,
 }
 
@@ -8229,6 +12272,8 @@ impl<Q: QueryTuple> PreparedQuery<Q> {
             added_mask: self.added_mask,
             include_tag_sets: [None; 4],
             exclude_tag_sets: [None; 4],
+            disabled_set: None,
+            include_disabled: self.include_disabled,
             element_masks: Some(self.element_masks),
             dead: false,
             marker: PhantomData,
@@ -8246,6 +12291,7 @@ pub struct PreparedQueryRef<Q: ReadQueryTuple> {
     pub exclude: u64,
     pub changed_mask: u64,
     pub added_mask: u64,
+    pub include_disabled: bool,
     pub marker: PhantomData<Q>,
 }
 
@@ -8260,6 +12306,8 @@ impl<Q: ReadQueryTuple> PreparedQueryRef<Q> {
             added_mask: self.added_mask,
             include_tag_sets: [None; 4],
             exclude_tag_sets: [None; 4],
+            disabled_set: None,
+            include_disabled: self.include_disabled,
             resolved_masks: self.resolved_masks,
             dead: self.resolved_masks.is_none(),
             marker: PhantomData,
@@ -8267,6 +12315,239 @@ impl<Q: ReadQueryTuple> PreparedQueryRef<Q> {
     }
 }
 
+/// A typed query kept registered with a world, from [`DynQuery::live`].
+/// The world appends each newly created table that matches, so a run walks
+/// an exact table list with no cache lookup or mask test. When the query
+/// filters on tags, the matching entities and their rows are also cached,
+/// rebuilt only after a structural change, so a frame with no spawns,
+/// despawns, migrations, or tag flips answers [`len`](Self::len) and
+/// [`entities`](Self::entities) in O(1) and iterates without tag checks.
+/// The handle is plain copyable data stamped with the issuing world's id
+/// and its slot's generation. Using it on another world, including one
+/// restored from a snapshot, or after [`release`](Self::release) panics.
+#[derive(Clone, Copy)]
+pub struct LiveQuery<Q: QueryTuple> {
+    pub index: usize,
+    pub world_id: u64,
+    pub generation: u32,
+    pub element_masks: [u64; 8],
+    pub marker: PhantomData<Q>,
+}
+
+/// The world-side record behind a [`LiveQuery`]: its split filter masks,
+/// the tables matched so far, and the entity cache with the structural
+/// epoch it was built at.
+#[derive(Clone, Default)]
+pub struct LiveQueryState {
+    pub component_include: u64,
+    pub component_exclude: u64,
+    pub tag_include: u64,
+    pub tag_exclude: u64,
+    /// Set when the filters can never match, such as a tag both required
+    /// and excluded. A dead query matches no tables.
+    pub dead: bool,
+    pub include_disabled: bool,
+    pub tables: Vec<usize>,
+    pub entities: Vec<Entity>,
+    /// `(table, row)` for each cached entity, in the same order.
+    pub rows: Vec<(u32, u32)>,
+    pub len: usize,
+    /// Bumped when the slot is released, so handles to the old query stop
+    /// matching it.
+    pub generation: u32,
+    /// Set while the slot is free for the next registration.
+    pub released: bool,
+    entities_epoch: Option<u64>,
+    len_epoch: Option<u64>,
+}
+
+impl LiveQueryState {
+    fn matches_table(&self, mask: u64) -> bool {
+        !self.dead
+            && mask & self.component_include == self.component_include
+            && mask & self.component_exclude == 0
+    }
+
+    fn has_tag_filters(&self, disabled: u64) -> bool {
+        self.tag_include != 0 || self.tag_exclude | disabled != 0
+    }
+
+    fn rematch(&mut self, tables: &[DynComponentArrays]) {
+        self.tables = tables
+            .iter()
+            .enumerate()
+            .filter(|(_, table)| self.matches_table(table.mask))
+            .map(|(table_index, _)| table_index)
+            .collect();
+        self.entities_epoch = None;
+        self.len_epoch = None;
+    }
+
+    fn refresh_entities(
+        &mut self,
+        world_tables: &[DynComponentArrays],
+        tags: &[SparseTagSet],
+        epoch: u64,
+        disabled: u64,
+    ) {
+        if self.entities_epoch == Some(epoch) {
+            return;
+        }
+        self.entities.clear();
+        self.rows.clear();
+        let tag_exclude = self.tag_exclude | disabled;
+        for &table_index in &self.tables {
+            for (row, &entity) in world_tables[table_index].entity_indices.iter().enumerate() {
+                if tags_match(tags, entity, self.tag_include, tag_exclude) {
+                    self.entities.push(entity);
+                    self.rows.push((table_index as u32, row as u32));
+                }
+            }
+        }
+        self.len = self.entities.len();
+        self.entities_epoch = Some(epoch);
+        self.len_epoch = Some(epoch);
+    }
+
+    fn refresh_len(
+        &mut self,
+        world_tables: &[DynComponentArrays],
+        tags: &[SparseTagSet],
+        epoch: u64,
+        disabled: u64,
+    ) {
+        if self.len_epoch == Some(epoch) {
+            return;
+        }
+        if self.has_tag_filters(disabled) {
+            self.refresh_entities(world_tables, tags, epoch, disabled);
+            return;
+        }
+        self.len = self
+            .tables
+            .iter()
+            .map(|&table_index| world_tables[table_index].entity_indices.len())
+            .sum();
+        self.len_epoch = Some(epoch);
+    }
+}
+
+impl<Q: QueryTuple> LiveQuery<Q> {
+    /// Unregisters the query, freeing its cached tables and entities and
+    /// its slot for the next registration. Returns false, changing nothing,
+    /// when the handle is not this world's or was already released.
+    pub fn release(&self, world: &mut DynWorld) -> bool {
+        let Some(state) = world.live_queries.get_mut(self.index) else {
+            return false;
+        };
+        if self.world_id != world.world_id || state.released || state.generation != self.generation
+        {
+            return false;
+        }
+        *state = LiveQueryState {
+            dead: true,
+            generation: state.generation.wrapping_add(1),
+            released: true,
+            ..LiveQueryState::default()
+        };
+        true
+    }
+
+    /// The matched table indices, in creation order. Empty tables stay in
+    /// the list, since entities may arrive in them later.
+    pub fn tables<'world>(&self, world: &'world DynWorld) -> &'world [usize] {
+        &world.live_queries[world.live_index(self)].tables
+    }
+
+    /// How many entities match, recounted only after a structural change.
+    pub fn len(&self, world: &mut DynWorld) -> usize {
+        let index = world.live_index(self);
+        let epoch = world.structural_epoch;
+        let disabled = world.live_disabled_exclusion(index);
+        let state = &mut world.live_queries[index];
+        state.refresh_len(&world.tables, &world.tags, epoch, disabled);
+        state.len
+    }
+
+    pub fn is_empty(&self, world: &mut DynWorld) -> bool {
+        self.len(world) == 0
+    }
+
+    /// The matching entities in table order, rebuilt only after a
+    /// structural change.
+    pub fn entities<'world>(&self, world: &'world mut DynWorld) -> &'world [Entity] {
+        let index = world.live_index(self);
+        let epoch = world.structural_epoch;
+        let disabled = world.live_disabled_exclusion(index);
+        let state = &mut world.live_queries[index];
+        state.refresh_entities(&world.tables, &world.tags, epoch, disabled);
+        &state.entities
+    }
+
+    /// Runs the query over its matched tables with the same stamping as
+    /// [`DynQuery::for_each`]. Tag-filtered queries walk their cached rows
+    /// rather than testing tags per entity.
+    pub fn for_each(
+        &self,
+        world: &mut DynWorld,
+        mut f: impl for<'item> FnMut(Entity, Q::Item<'item>),
+    ) {
+        let index = world.live_index(self);
+        let epoch = world.structural_epoch;
+        let current_tick = world.current_tick;
+        let element_masks = self.element_masks;
+        let disabled = world.live_disabled_exclusion(index);
+        let state = &mut world.live_queries[index];
+        let tables = &mut world.tables;
+
+        if state.has_tag_filters(disabled) {
+            state.refresh_entities(tables, &world.tags, epoch, disabled);
+            let mut start = 0;
+            while start < state.rows.len() {
+                let table_index = state.rows[start].0;
+                let end = start
+                    + state.rows[start..]
+                        .iter()
+                        .take_while(|&&(table, _)| table == table_index)
+                        .count();
+                let table = &mut tables[table_index as usize];
+                let mut fetch =
+                    Q::fetch(table.mask, &mut table.columns, &element_masks, current_tick);
+                for (&entity, &(_, row)) in state.entities[start..end]
+                    .iter()
+                    .zip(&state.rows[start..end])
+                {
+                    f(entity, Q::item(&mut fetch, row as usize));
+                }
+                Q::stamp_peaks(&mut fetch);
+                start = end;
+            }
+            return;
+        }
+
+        for &table_index in &state.tables {
+            let table = &mut tables[table_index];
+            if table.entity_indices.is_empty() {
+                continue;
+            }
+            let table_mask = table.mask;
+            if Q::ALL_REQUIRED {
+                let slice_fetch =
+                    Q::par_fetch(table_mask, &mut table.columns, &element_masks, current_tick);
+                Q::fast_for_each(slice_fetch, table.entity_indices.as_slice(), &mut f);
+            } else {
+                let mut fetch =
+                    Q::fetch(table_mask, &mut table.columns, &element_masks, current_tick);
+                Q::mark_changed_all(&mut fetch);
+                for (index, &entity) in table.entity_indices.iter().enumerate() {
+                    f(entity, Q::item_marked(&mut fetch, index));
+                }
+                Q::stamp_peaks(&mut fetch);
+            }
+        }
+    }
+}
+
 /// A point-in-time census of one world's storage and bookkeeping, from
 /// [`DynWorld::stats`]: table occupancy, schema budget, and the lengths of
 /// every log and cache an editor overlay or a perf investigation reaches
@@ -8287,6 +12568,56 @@ pub struct WorldStats {
     pub resource_count: usize,
     pub event_channels: usize,
     pub pending_commands: usize,
+    /// Column bytes per registered component, in registration order.
+    pub component_memory: Vec<ComponentMemory>,
+    /// Every table's entity index column.
+    pub entity_index_memory: MemoryUsage,
+    pub tag_memory: MemoryUsage,
+    pub location_memory: MemoryUsage,
+    /// The structural log plus the entity lists of its batch entries.
+    pub structural_log_memory: MemoryUsage,
+    pub event_memory: MemoryUsage,
+    /// Bytes the `raw_storage` backend's recycle pool holds for the calling
+    /// thread. Always zero on the default backend.
+    pub pooled_bytes: usize,
+}
+
+/// Deallocates every column buffer the `raw_storage` backend's recycle pool
+/// holds for the calling thread, returning the bytes released. The pool is
+/// shared by every world on the thread, which is why
+/// [`DynWorld::trim_memory`] leaves it alone: call this after trimming at a
+/// point where no world on the thread will soon respawn into pooled
+/// buffers.
+#[cfg(feature = "raw_storage")]
+pub fn release_column_pool() -> usize {
+    raw_storage::pool_release()
+}
+
+impl WorldStats {
+    /// Every storage structure the census measures, summed. The recycle
+    /// pool is not included, since it is shared by every world on the
+    /// thread.
+    pub fn total_memory(&self) -> MemoryUsage {
+        self.component_memory
+            .iter()
+            .map(|component| component.data + component.ticks)
+            .sum::<MemoryUsage>()
+            + self.entity_index_memory
+            + self.tag_memory
+            + self.location_memory
+            + self.structural_log_memory
+            + self.event_memory
+    }
+}
+
+/// One component's share of [`WorldStats`]: its data columns and its
+/// `changed`/`added` tick columns, summed over every table that holds it.
+#[derive(Clone, Debug, Default)]
+pub struct ComponentMemory {
+    pub name: &'static str,
+    pub mask: u64,
+    pub data: MemoryUsage,
+    pub ticks: MemoryUsage,
 }
 
 /// The group-level census, from [`DynEcs::stats`]: allocator liveness,
@@ -8300,6 +12631,9 @@ pub struct EcsStats {
     pub group_structural_log_entries: usize,
     pub group_resource_count: usize,
     pub group_event_channels: usize,
+    pub group_tag_memory: MemoryUsage,
+    pub group_structural_log_memory: MemoryUsage,
+    pub group_event_memory: MemoryUsage,
     pub worlds: Vec<WorldStats>,
 }
 
@@ -8314,6 +12648,52 @@ pub struct ChildOf(pub Entity);
 
 impl Component for ChildOf {}
 
+/// A child's sort key among its siblings: lower comes first, and children
+/// without one follow the ordered ones in the order they were linked. The
+/// [`HierarchyIndex`] editing methods write it, renumbering the siblings
+/// from zero, and since it is plain data it survives snapshots and deltas
+/// along with the links.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChildOrder(pub u32);
+
+impl Component for ChildOrder {}
+
+/// Why a [`HierarchyIndex`] edit was refused.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HierarchyError {
+    /// Linking `child` under `parent` would make the child its own
+    /// ancestor.
+    Cycle { child: Entity, parent: Entity },
+    /// The entity a move was positioned against has no parent.
+    NotAChild(Entity),
+}
+
+impl std::fmt::Display for HierarchyError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cycle { child, parent } => write!(
+                formatter,
+                "linking {child:?} under {parent:?} would create a cycle"
+            ),
+            Self::NotAChild(entity) => write!(formatter, "{entity:?} has no parent"),
+        }
+    }
+}
+
+impl std::error::Error for HierarchyError {}
+
+/// Whether the world's structural log holds every entry after `cursor`. A
+/// restored or replaced world starts its sequence over, and an overflowed or
+/// trimmed log has dropped entries, so a consumer diffing against the log
+/// has to rescan in either case.
+pub(crate) fn structural_log_reaches(world: &DynWorld, cursor: u64) -> bool {
+    match world.structural_changes_since(cursor).first() {
+        Some(first) => first.sequence == cursor + 1,
+        None => world.structural_sequence() == cursor,
+    }
+}
+
 /// A maintained child index over [`ChildOf`] links, for hierarchy-heavy
 /// consumers: [`DynWorld::children`] scans every link carrier on demand,
 /// while this answers from maps kept current by [`sync`](Self::sync).
@@ -8325,9 +12705,16 @@ impl Component for ChildOf {}
 /// changed since the last one. Reads reflect the last sync. In a
 /// [`DynEcs`] group, sync against the member world holding the links and
 /// despawn through the group using [`descendants`](Self::descendants).
+///
+/// Children are kept in [`ChildOrder`] order. The index never holds a
+/// cycle: a link that would close one is left out and listed in
+/// [`rejected`](Self::rejected) until the cycle is broken, and the editing
+/// methods refuse it with [`HierarchyError::Cycle`].
 pub struct HierarchyIndex {
     pub children: HashMap<Entity, Vec<Entity>>,
     pub parent_of: HashMap<Entity, Entity>,
+    pub sibling_order: HashMap<Entity, u32>,
+    pub rejected: Vec<Entity>,
     pub structural_cursor: u64,
     pub tick_cursor: u32,
 }
@@ -8337,6 +12724,8 @@ impl Default for HierarchyIndex {
         Self {
             children: HashMap::new(),
             parent_of: HashMap::new(),
+            sibling_order: HashMap::new(),
+            rejected: Vec::new(),
             structural_cursor: 0,
             tick_cursor: u32::MAX,
         }
@@ -8352,39 +12741,78 @@ impl HierarchyIndex {
     /// window with [`DynWorld::increment_tick`] so writes made after this
     /// call land in the next sync. Despawns and `ChildOf` removals unlink
     /// through the structural log; new and rewritten links relink from the
-    /// component's current value.
+    /// component's current value, and rejected links are retried.
     pub fn sync(&mut self, world: &mut DynWorld) {
         let child_mask = world
             .lookup_key::<ChildOf>()
             .map(|key| key.mask)
             .unwrap_or(0);
+        let order_mask = world
+            .lookup_key::<ChildOrder>()
+            .map(|key| key.mask)
+            .unwrap_or(0);
 
         // The incremental unlink/relink path diffs against the structural log
         // and the change ticks, so it is only available when the world keeps
-        // both. raw_storage never does, and either can be switched off. With
-        // nothing to diff against, rebuild the whole index from a scan of the
-        // current links: same result, just not incremental.
-        if Self::can_track_incrementally(world) {
-            self.sync_incrementally(world, child_mask);
+        // both and the log still reaches back to the cursor. raw_storage never
+        // keeps them, either can be switched off, and a snapshot restore or a
+        // trimmed log leaves a gap. With nothing to diff against, rebuild the
+        // whole index from a scan of the current links: same result, just not
+        // incremental.
+        if Self::can_track_incrementally(world)
+            && structural_log_reaches(world, self.structural_cursor)
+        {
+            self.sync_incrementally(world, child_mask, order_mask);
         } else {
-            self.rebuild_from_scan(world, child_mask);
+            self.rebuild_from_scan(world, child_mask, order_mask);
         }
+        self.retry_rejected(world);
+        self.structural_cursor = world.structural_sequence();
+        self.tick_cursor = world.current_tick();
         world.increment_tick();
     }
 
-    fn sync_incrementally(&mut self, world: &mut DynWorld, child_mask: u64) {
-        let unlinks: Vec<Entity> = world
-            .structural_changes_since(self.structural_cursor)
-            .iter()
-            .filter(|change| match change.kind {
-                StructuralChangeKind::Despawned => true,
-                StructuralChangeKind::ComponentsRemoved => change.mask & child_mask != 0,
-                _ => false,
-            })
-            .map(|change| change.entity)
-            .collect();
+    fn sync_incrementally(&mut self, world: &mut DynWorld, child_mask: u64, order_mask: u64) {
+        let mut unlinks: Vec<Entity> = Vec::new();
+        let mut unordered: Vec<Entity> = Vec::new();
+        for change in world.structural_changes_since(self.structural_cursor) {
+            match change.kind {
+                StructuralChangeKind::Despawned => {
+                    unlinks.push(change.entity);
+                    unordered.push(change.entity);
+                }
+                StructuralChangeKind::ComponentsRemoved
+                | StructuralChangeKind::ComponentsRemovedBatch => {
+                    let entities = world.change_entities(change);
+                    if change.mask & child_mask != 0 {
+                        unlinks.extend_from_slice(entities);
+                    }
+                    if change.mask & order_mask != 0 {
+                        unordered.extend_from_slice(entities);
+                    }
+                }
+                _ => {}
+            }
+        }
         for entity in unlinks {
             self.unlink(entity);
+            self.rejected.retain(|&rejected| rejected != entity);
+        }
+        for entity in unordered {
+            self.sibling_order.remove(&entity);
+            self.reposition(entity);
+        }
+
+        if order_mask != 0 {
+            let reorders: Vec<Entity> = world
+                .query_entities_changed_since(order_mask, self.tick_cursor)
+                .collect();
+            for entity in reorders {
+                if let Some(order) = world.get::<ChildOrder>(entity) {
+                    self.sibling_order.insert(entity, order.0);
+                    self.reposition(entity);
+                }
+            }
         }
 
         if child_mask != 0 {
@@ -8397,9 +12825,6 @@ impl HierarchyIndex {
                 }
             }
         }
-
-        self.structural_cursor = world.structural_sequence();
-        self.tick_cursor = world.current_tick();
     }
 
     /// Whether `world` retains everything the incremental sync path diffs
@@ -8409,18 +12834,36 @@ impl HierarchyIndex {
         world.structural_logging && world.change_detection()
     }
 
-    fn rebuild_from_scan(&mut self, world: &mut DynWorld, child_mask: u64) {
+    fn rebuild_from_scan(&mut self, world: &mut DynWorld, child_mask: u64, order_mask: u64) {
         self.children.clear();
         self.parent_of.clear();
+        self.sibling_order.clear();
+        self.rejected.clear();
+        if order_mask != 0 {
+            let ordered: Vec<Entity> = world.query_entities(order_mask).collect();
+            for entity in ordered {
+                if let Some(order) = world.get::<ChildOrder>(entity) {
+                    self.sibling_order.insert(entity, order.0);
+                }
+            }
+        }
         if child_mask == 0 {
             return;
         }
         let holders: Vec<Entity> = world.query_entities(child_mask).collect();
         for entity in holders {
             if let Some(child_of) = world.get::<ChildOf>(entity) {
-                let parent = child_of.0;
-                self.parent_of.insert(entity, parent);
-                self.children.entry(parent).or_default().push(entity);
+                self.relink(entity, child_of.0);
+            }
+        }
+    }
+
+    /// Relinks entities whose links were refused, now that other links may
+    /// have moved out of the way. Those still closing a cycle stay rejected.
+    fn retry_rejected(&mut self, world: &DynWorld) {
+        for entity in std::mem::take(&mut self.rejected) {
+            if let Some(child_of) = world.get::<ChildOf>(entity) {
+                self.relink(entity, child_of.0);
             }
         }
     }
@@ -8441,31 +12884,171 @@ impl HierarchyIndex {
             return;
         }
         self.unlink(entity);
+        if self.would_cycle(entity, parent) {
+            if !self.rejected.contains(&entity) {
+                self.rejected.push(entity);
+            }
+            return;
+        }
         self.parent_of.insert(entity, parent);
-        self.children.entry(parent).or_default().push(entity);
+        self.insert_sorted(parent, entity);
+    }
+
+    /// Places a child among its parent's children after every sibling whose
+    /// [`ChildOrder`] is not greater, so equal and missing keys keep link
+    /// order.
+    fn insert_sorted(&mut self, parent: Entity, entity: Entity) {
+        let sibling_order = &self.sibling_order;
+        let key = |child: &Entity| sibling_order.get(child).copied().unwrap_or(u32::MAX);
+        let own_key = key(&entity);
+        let siblings = self.children.entry(parent).or_default();
+        let position = siblings.partition_point(|sibling| key(sibling) <= own_key);
+        siblings.insert(position, entity);
+    }
+
+    fn reposition(&mut self, entity: Entity) {
+        let Some(&parent) = self.parent_of.get(&entity) else {
+            return;
+        };
+        if let Some(siblings) = self.children.get_mut(&parent) {
+            siblings.retain(|&child| child != entity);
+        }
+        self.insert_sorted(parent, entity);
+    }
+
+    /// Whether linking `child` under `parent` would make the child its own
+    /// ancestor, as of the last sync.
+    pub fn would_cycle(&self, child: Entity, parent: Entity) -> bool {
+        let mut current = parent;
+        // The index holds no cycle, so the walk ends within its size.
+        for _ in 0..=self.parent_of.len() {
+            if current == child {
+                return true;
+            }
+            match self.parent_of.get(&current) {
+                Some(&next) => current = next,
+                None => return false,
+            }
+        }
+        false
     }
 
-    /// Children of a parent as of the last sync.
+    /// Children of a parent as of the last sync, in [`ChildOrder`] order.
     pub fn children(&self, parent: Entity) -> &[Entity] {
         self.children.get(&parent).map(Vec::as_slice).unwrap_or(&[])
     }
 
     /// Every entity reachable from the root through child edges as of the
-    /// last sync, breadth-first, root excluded. Link cycles are tolerated.
+    /// last sync, breadth-first and in sibling order, root excluded.
     pub fn descendants(&self, root: Entity) -> Vec<Entity> {
-        let mut pending = vec![root];
-        let mut visited: Vec<Entity> = Vec::new();
-        while let Some(parent) = pending.pop() {
-            for &child in self.children(parent) {
-                if child != root && !visited.contains(&child) {
-                    visited.push(child);
-                    pending.push(child);
-                }
-            }
+        let mut visited: Vec<Entity> = self.children(root).to_vec();
+        let mut next = 0;
+        while next < visited.len() {
+            let parent = visited[next];
+            visited.extend_from_slice(self.children(parent));
+            next += 1;
         }
         visited
     }
 
+    /// Links `child` under `parent` at `position` among its children,
+    /// clamped to the end, and renumbers the siblings' [`ChildOrder`] from
+    /// zero. Writes the world and the index together, so reads reflect the
+    /// edit at once; like the other edits it answers cycle checks from the
+    /// index, so sync first if links changed since the last one.
+    pub fn insert_child_at(
+        &mut self,
+        world: &mut DynWorld,
+        parent: Entity,
+        child: Entity,
+        position: usize,
+    ) -> Result<(), HierarchyError> {
+        if self.would_cycle(child, parent) {
+            return Err(HierarchyError::Cycle { child, parent });
+        }
+        if world.get::<ChildOf>(child) != Some(&ChildOf(parent)) {
+            world.set(child, ChildOf(parent));
+        }
+        self.unlink(child);
+        self.rejected.retain(|&rejected| rejected != child);
+        self.parent_of.insert(child, parent);
+
+        let mut siblings = self.children.remove(&parent).unwrap_or_default();
+        siblings.insert(position.min(siblings.len()), child);
+        for (order, &sibling) in siblings.iter().enumerate() {
+            let order = order as u32;
+            if self.sibling_order.get(&sibling) != Some(&order) {
+                world.set(sibling, ChildOrder(order));
+                self.sibling_order.insert(sibling, order);
+            }
+        }
+        self.children.insert(parent, siblings);
+        Ok(())
+    }
+
+    /// Moves `child` under `parent` as its last child.
+    pub fn reparent(
+        &mut self,
+        world: &mut DynWorld,
+        child: Entity,
+        parent: Entity,
+    ) -> Result<(), HierarchyError> {
+        self.insert_child_at(world, parent, child, usize::MAX)
+    }
+
+    /// Moves `child` to just before `sibling`, under the sibling's parent.
+    pub fn move_before(
+        &mut self,
+        world: &mut DynWorld,
+        child: Entity,
+        sibling: Entity,
+    ) -> Result<(), HierarchyError> {
+        self.move_beside(world, child, sibling, 0)
+    }
+
+    /// Moves `child` to just after `sibling`, under the sibling's parent.
+    pub fn move_after(
+        &mut self,
+        world: &mut DynWorld,
+        child: Entity,
+        sibling: Entity,
+    ) -> Result<(), HierarchyError> {
+        self.move_beside(world, child, sibling, 1)
+    }
+
+    fn move_beside(
+        &mut self,
+        world: &mut DynWorld,
+        child: Entity,
+        sibling: Entity,
+        offset: usize,
+    ) -> Result<(), HierarchyError> {
+        let parent = *self
+            .parent_of
+            .get(&sibling)
+            .ok_or(HierarchyError::NotAChild(sibling))?;
+        if child == sibling {
+            return Ok(());
+        }
+        let position = self
+            .children(parent)
+            .iter()
+            .filter(|&&entry| entry != child)
+            .position(|&entry| entry == sibling)
+            .expect("an indexed child is among its parent's children");
+        self.insert_child_at(world, parent, child, position + offset)
+    }
+
+    /// Makes `child` a root: removes its [`ChildOf`] and [`ChildOrder`]
+    /// from the world and the index. Returns whether it had a link.
+    pub fn detach(&mut self, world: &mut DynWorld, child: Entity) -> bool {
+        self.unlink(child);
+        self.sibling_order.remove(&child);
+        self.rejected.retain(|&rejected| rejected != child);
+        world.remove::<ChildOrder>(child);
+        world.remove::<ChildOf>(child)
+    }
+
     /// Despawns the root and its indexed descendants in one pass, eagerly
     /// unlinking them so the index is consistent before the next sync.
     /// Answers from the index, not a scan, so sync first if links changed
@@ -8476,6 +13059,7 @@ impl HierarchyIndex {
         for &entity in &targets {
             self.unlink(entity);
             self.children.remove(&entity);
+            self.sibling_order.remove(&entity);
         }
         world.despawn_entities(&targets)
     }
@@ -8709,36 +13293,420 @@ mod tests {
             Health { value: 7.0 },
         ));
 
-        assert_eq!(
-            world.get::<Position>(entity),
-            Some(&Position { x: 1.0, y: 1.0 })
+        assert_eq!(
+            world.get::<Position>(entity),
+            Some(&Position { x: 1.0, y: 1.0 })
+        );
+        assert_eq!(world.get::<Health>(entity), Some(&Health { value: 7.0 }));
+    }
+
+    #[test]
+    fn test_insert_and_remove_bundle_on_live_entity() {
+        let mut world = DynWorld::new();
+        let entity = world.spawn(Position { x: 1.0, y: 2.0 });
+
+        world.insert_bundle(entity, (Velocity { x: 3.0, y: 4.0 }, Health { value: 5.0 }));
+        assert_eq!(
+            world.get::<Velocity>(entity),
+            Some(&Velocity { x: 3.0, y: 4.0 })
+        );
+        assert_eq!(world.get::<Health>(entity), Some(&Health { value: 5.0 }));
+        assert_eq!(
+            world.get::<Position>(entity),
+            Some(&Position { x: 1.0, y: 2.0 })
+        );
+
+        assert!(world.remove_bundle::<(Velocity, Health)>(entity));
+        assert!(world.get::<Velocity>(entity).is_none());
+        assert!(world.get::<Health>(entity).is_none());
+        assert_eq!(
+            world.get::<Position>(entity),
+            Some(&Position { x: 1.0, y: 2.0 })
+        );
+    }
+
+    #[test]
+    fn test_required_components_arrive_in_one_move() {
+        let mut world = DynWorld::new();
+        world.require::<Velocity, Position>();
+        world.require::<Health, Velocity>();
+        world.structural_logging = true;
+        let position = world.component_key::<Position>().mask;
+        let velocity = world.component_key::<Velocity>().mask;
+        let health = world.component_key::<Health>().mask;
+
+        let mover = world.spawn(Velocity { x: 1.0, y: 0.0 });
+        assert_eq!(world.get::<Position>(mover), Some(&Position::default()));
+        assert_eq!(
+            world.get::<Velocity>(mover),
+            Some(&Velocity { x: 1.0, y: 0.0 })
+        );
+
+        let actor = world.spawn_entities(0, 1)[0];
+        let cursor = world.structural_sequence();
+        world.set(actor, Health { value: 3.0 });
+        assert_eq!(
+            world.component_mask(actor),
+            Some(position | velocity | health)
+        );
+        let changes = world.structural_changes_since(cursor);
+        assert_eq!(changes.len(), 1, "requirements ride the same table move");
+        assert_eq!(changes[0].mask, position | velocity | health);
+
+        let rows = world.spawn_bundles(Health { value: 1.0 }, 3);
+        for &row in &rows {
+            assert_eq!(world.get::<Position>(row), Some(&Position::default()));
+            assert_eq!(world.get::<Health>(row), Some(&Health { value: 1.0 }));
+        }
+        let bare = world.spawn_entities(0, 1)[0];
+        assert!(world.add_components(bare, velocity));
+        assert_eq!(world.component_mask(bare), Some(position | velocity));
+    }
+
+    #[test]
+    fn test_required_removal_rejects_or_cascades() {
+        let mut world = DynWorld::new();
+        world.require::<Velocity, Position>();
+        world.require::<Health, Velocity>();
+        let actor = world.spawn(Health { value: 2.0 });
+
+        assert!(!world.remove::<Position>(actor));
+        assert!(world.take_bundle::<Position>(actor).is_none());
+        assert!(world.has::<Position>(actor) && world.has::<Health>(actor));
+        assert!(
+            world.remove::<Health>(actor),
+            "dropping a dependent is fine"
+        );
+        assert!(world.has::<Velocity>(actor));
+
+        world.required_removal = RequiredRemoval::Cascade;
+        world.set(actor, Health { value: 2.0 });
+        assert!(world.remove::<Position>(actor));
+        assert_eq!(world.component_mask(actor), Some(0));
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Handle(Vec<u32>);
+
+    crate::impl_component!(no_default Handle);
+
+    #[test]
+    fn test_non_default_components_move_by_ownership() {
+        let mut world = DynWorld::new();
+        let velocity = world.component_key::<Velocity>().mask;
+        let entity = world.spawn((Position { x: 1.0, y: 0.0 }, Handle(vec![1, 2])));
+        let other = world.spawn(Handle(vec![3]));
+
+        assert!(world.add_components(entity, velocity));
+        assert!(world.remove::<Position>(other));
+        assert_eq!(world.get::<Handle>(entity), Some(&Handle(vec![1, 2])));
+        assert_eq!(world.get::<Handle>(other), Some(&Handle(vec![3])));
+
+        let handle = world.component_key::<Handle>().mask;
+        let bare = world.spawn(Position::default());
+        assert!(
+            !world.add_components(bare, handle),
+            "no value to add it with"
+        );
+        assert!(!world.has::<Handle>(bare));
+        world.set(bare, Handle(vec![4]));
+        assert_eq!(world.get::<Handle>(bare), Some(&Handle(vec![4])));
+
+        assert_eq!(
+            world.take_bundle::<(Handle, Velocity)>(entity),
+            Some((Handle(vec![1, 2]), Velocity::default()))
+        );
+        assert_eq!(
+            world.component_mask(entity),
+            world.lookup_key::<Position>().map(|key| key.mask)
+        );
+
+        let mut destination = DynWorld::new();
+        let moved = world
+            .transfer_entity(&mut destination, other, TransferHandle::Remap)
+            .unwrap();
+        assert_eq!(destination.get::<Handle>(moved), Some(&Handle(vec![3])));
+
+        let queued = world.queue_spawn((Handle(vec![5]), Health { value: 1.0 }));
+        world.apply_commands();
+        assert_eq!(world.get::<Handle>(queued), Some(&Handle(vec![5])));
+    }
+
+    #[test]
+    #[should_panic(expected = "has no default value")]
+    fn test_non_default_component_refuses_bare_mask_spawn() {
+        let mut world = DynWorld::new();
+        let handle = world.component_key::<Handle>().mask;
+        world.spawn_entities(handle, 1);
+    }
+
+    #[test]
+    fn test_dyn_ecs_non_default_components_set_and_take() {
+        let mut main = ComponentRegistry::new();
+        main.register::<Position>();
+        let mut assets = ComponentRegistry::new();
+        assets.register::<Handle>();
+        let mut ecs = DynEcs::new();
+        ecs.add_world(main);
+        ecs.add_world(assets);
+
+        let entity = ecs.spawn_with((Position::default(), Handle(vec![9])));
+        assert_eq!(ecs.get::<Handle>(entity), Some(&Handle(vec![9])));
+        assert_eq!(
+            ecs.take_bundle::<(Position, Handle)>(entity),
+            Some((Position::default(), Handle(vec![9])))
+        );
+        assert!(!ecs.has::<Handle>(entity));
+    }
+
+    fn item_layout() -> ComponentLayout {
+        ComponentLayout::new("mod::Item")
+            .field("stack", FieldType::U16)
+            .field("weight", FieldType::F32)
+            .field("cursed", FieldType::Bool)
+            .field("slots", FieldType::Array(Box::new(FieldType::I8), 3))
+            .field("label", FieldType::String)
+    }
+
+    #[test]
+    fn test_layout_components_read_write_and_query_by_mask() {
+        let mut world = DynWorld::new();
+        let item = world.register_layout(item_layout());
+        assert_eq!(world.register_layout(item_layout()).mask, item.mask);
+        assert_eq!(
+            world.registry.layout_key("mod::Item").map(|key| key.mask),
+            Some(item.mask)
+        );
+
+        let entity = world.spawn(Position { x: 1.0, y: 0.0 });
+        let bare = world.spawn(Position::default());
+        assert!(world.set_runtime_field(entity, "mod::Item", "stack", FieldValue::U16(12)));
+        assert!(world.set_runtime_field(
+            entity,
+            "mod::Item",
+            "label",
+            FieldValue::String("Rusty sword".to_string())
+        ));
+        assert!(world.set_runtime_field(
+            entity,
+            "mod::Item",
+            "slots",
+            FieldValue::Array(vec![
+                FieldValue::I8(-1),
+                FieldValue::I8(0),
+                FieldValue::I8(7)
+            ])
+        ));
+        assert!(!world.set_runtime_field(entity, "mod::Item", "stack", FieldValue::U32(1)));
+        assert!(!world.set_runtime_field(entity, "mod::Item", "missing", FieldValue::U16(1)));
+        assert!(!world.set_runtime_field(bare, "mod::Item", "weight", FieldValue::U16(1)));
+        assert_eq!(
+            world.component_mask(bare).map(|mask| mask & item.mask),
+            Some(0),
+            "a rejected write adds nothing"
+        );
+
+        let mut image = vec![0u8; item_layout().size()];
+        image[2..6].copy_from_slice(&2.5f32.to_le_bytes());
+        assert!(world.set_runtime_bytes(bare, "mod::Item", &image));
+        assert!(!world.set_runtime_bytes(bare, "mod::Item", &image[1..]));
+        assert_eq!(
+            world.runtime_bytes(bare, "mod::Item"),
+            Some(image.as_slice())
+        );
+        assert_eq!(
+            world.runtime_field(bare, "mod::Item", "weight"),
+            Some(FieldValue::F32(2.5))
+        );
+
+        let velocity = world.component_key::<Velocity>().mask;
+        assert!(world.add_components(entity, velocity));
+        assert_eq!(
+            world.runtime_field(entity, "mod::Item", "stack"),
+            Some(FieldValue::U16(12)),
+            "rows survive migrations"
+        );
+        assert_eq!(
+            world.runtime_field(entity, "mod::Item", "label"),
+            Some(FieldValue::String("Rusty sword".to_string()))
+        );
+        assert_eq!(
+            world.runtime_field(entity, "mod::Item", "slots"),
+            Some(FieldValue::Array(vec![
+                FieldValue::I8(-1),
+                FieldValue::I8(0),
+                FieldValue::I8(7)
+            ]))
+        );
+
+        let mut stacks: Vec<Option<FieldValue>> = world
+            .query_entities(item.mask)
+            .map(|entity| world.runtime_field(entity, "mod::Item", "stack"))
+            .collect();
+        stacks.sort_by_key(|stack| format!("{stack:?}"));
+        assert_eq!(
+            stacks,
+            vec![Some(FieldValue::U16(0)), Some(FieldValue::U16(12))]
+        );
+
+        assert!(world.remove_components(entity, item.mask));
+        assert_eq!(world.runtime_field(entity, "mod::Item", "stack"), None);
+        assert_eq!(world.query_entities(item.mask).count(), 1);
+    }
+
+    #[test]
+    fn test_layout_rows_keep_their_strings_through_moves_sorts_and_clones() {
+        let mut world = DynWorld::new();
+        let item = world.register_layout(item_layout());
+        let labels = ["Sword", "Shield", "Bow"];
+        let entities: Vec<Entity> = labels
+            .iter()
+            .enumerate()
+            .map(|(index, label)| {
+                let entity = world.spawn((Position {
+                    x: -(index as f32),
+                    y: 0.0,
+                },));
+                world.set_runtime_field(
+                    entity,
+                    "mod::Item",
+                    "label",
+                    FieldValue::String(label.to_string()),
+                );
+                world.set_runtime_field(
+                    entity,
+                    "mod::Item",
+                    "stack",
+                    FieldValue::U16(index as u16),
+                );
+                entity
+            })
+            .collect();
+
+        world.sort_table_rows_by::<Position, _>(|position| position.x as i32);
+        let velocity = world.component_key::<Velocity>().mask;
+        world.add_components(entities[1], velocity);
+        world.register_clone::<Position>();
+        let copy = world.clone_entity(entities[2]).unwrap();
+        world.despawn_entities(&[entities[0]]);
+
+        for (entity, label, stack) in [
+            (entities[1], "Shield", 1),
+            (entities[2], "Bow", 2),
+            (copy, "Bow", 2),
+        ] {
+            assert_eq!(
+                world.runtime_field(entity, "mod::Item", "label"),
+                Some(FieldValue::String(label.to_string()))
+            );
+            assert_eq!(
+                world.runtime_field(entity, "mod::Item", "stack"),
+                Some(FieldValue::U16(stack))
+            );
+        }
+        assert!(world.set_runtime_field(
+            copy,
+            "mod::Item",
+            "label",
+            FieldValue::String("Axe".into())
+        ));
+        assert_eq!(
+            world.runtime_field(entities[2], "mod::Item", "label"),
+            Some(FieldValue::String("Bow".to_string())),
+            "a clone owns its own strings"
+        );
+        assert_eq!(world.query_entities(item.mask).count(), 3);
+        world.trim_memory();
+        assert_eq!(
+            world.runtime_field(copy, "mod::Item", "label"),
+            Some(FieldValue::String("Axe".to_string()))
+        );
+
+        let mut other = ComponentRegistry::new();
+        assert_eq!(other.register_layout(item_layout()).mask, 1);
+        assert!(std::ptr::eq(
+            other.components[0].layout.unwrap(),
+            world.registry.components[item.component_index as usize]
+                .layout
+                .unwrap()
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "already registered with a different layout")]
+    fn test_layout_name_conflicts_panic() {
+        let mut registry = ComponentRegistry::new();
+        registry.register_layout(item_layout());
+        registry.register_layout(ComponentLayout::new("mod::Item").field("stack", FieldType::U32));
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[test]
+    fn test_layout_components_snapshot_and_replicate() {
+        let mut registry = ComponentRegistry::new();
+        registry.register_serde::<Position>();
+        registry.register_layout(item_layout());
+        let mut source = DynWorld::from_registry(registry.clone());
+        source.structural_logging = true;
+        source.set_change_detection(true);
+
+        let entity = source.spawn((Position::default(),));
+        source.set_runtime_field(
+            entity,
+            "mod::Item",
+            "label",
+            FieldValue::String("Map".into()),
         );
-        assert_eq!(world.get::<Health>(entity), Some(&Health { value: 7.0 }));
-    }
+        source.set_runtime_field(entity, "mod::Item", "stack", FieldValue::U16(3));
 
-    #[test]
-    fn test_insert_and_remove_bundle_on_live_entity() {
-        let mut world = DynWorld::new();
-        let entity = world.spawn(Position { x: 1.0, y: 2.0 });
+        let snapshot = source.snapshot().unwrap();
+        let bytes = postcard::to_allocvec(&snapshot).unwrap();
+        let decoded: DynWorldSnapshot = postcard::from_bytes(&bytes).unwrap();
+        let mut replica = DynWorld::from_snapshot(registry, &decoded).unwrap();
+        assert_eq!(
+            replica.runtime_field(entity, "mod::Item", "label"),
+            Some(FieldValue::String("Map".to_string()))
+        );
+        assert_eq!(
+            replica.runtime_bytes(entity, "mod::Item"),
+            source.runtime_bytes(entity, "mod::Item")
+        );
 
-        world.insert_bundle(entity, (Velocity { x: 3.0, y: 4.0 }, Health { value: 5.0 }));
+        let cursor = source.delta_cursor();
+        source.set_runtime_field(entity, "mod::Item", "stack", FieldValue::U16(4));
+        let other = source.spawn((Position::default(),));
+        source.set_runtime_field(other, "mod::Item", "cursed", FieldValue::Bool(true));
+        let delta = source.delta_since(&cursor).unwrap();
+        replica.apply_delta(&delta).unwrap();
         assert_eq!(
-            world.get::<Velocity>(entity),
-            Some(&Velocity { x: 3.0, y: 4.0 })
+            replica.runtime_field(entity, "mod::Item", "stack"),
+            Some(FieldValue::U16(4))
         );
-        assert_eq!(world.get::<Health>(entity), Some(&Health { value: 5.0 }));
         assert_eq!(
-            world.get::<Position>(entity),
-            Some(&Position { x: 1.0, y: 2.0 })
+            replica.runtime_field(other, "mod::Item", "cursed"),
+            Some(FieldValue::Bool(true))
         );
 
-        assert!(world.remove_bundle::<(Velocity, Health)>(entity));
-        assert!(world.get::<Velocity>(entity).is_none());
-        assert!(world.get::<Health>(entity).is_none());
+        let encoded = source
+            .get_component_by_name(entity, "mod::Item")
+            .unwrap()
+            .unwrap();
+        replica
+            .set_component_by_name(other, "mod::Item", &encoded)
+            .unwrap();
         assert_eq!(
-            world.get::<Position>(entity),
-            Some(&Position { x: 1.0, y: 2.0 })
+            replica.runtime_field(other, "mod::Item", "label"),
+            Some(FieldValue::String("Map".to_string()))
         );
+        let wrong_shape = postcard::to_allocvec(&RuntimeRow {
+            bytes: vec![0; 2],
+            strings: Vec::new(),
+        })
+        .unwrap();
+        assert!(matches!(
+            replica.set_component_by_name(other, "mod::Item", &wrong_shape),
+            Err(SnapshotError::Codec(_))
+        ));
     }
 
     #[test]
@@ -10010,6 +14978,213 @@ mod tests {
         assert_eq!(with_velocity, 3000);
     }
 
+    #[test]
+    fn test_for_each_chunk_yields_aligned_fixed_size_chunks() {
+        let mut world = DynWorld::new();
+        world.set_change_detection(true);
+        let entities: Vec<Entity> = (0..10)
+            .map(|index| {
+                world.spawn((
+                    Position {
+                        x: index as f32,
+                        y: 0.0,
+                    },
+                    Velocity { x: 1.0, y: 0.0 },
+                ))
+            })
+            .collect();
+        world.spawn((Position { x: 99.0, y: 0.0 },));
+        world.disable(entities[4]);
+        world.increment_tick();
+
+        let mut lengths = Vec::new();
+        let mut seen = Vec::new();
+        world.query::<(&mut Position, &Velocity)>().for_each_chunk(
+            3,
+            |chunk_entities, (positions, velocities)| {
+                assert_eq!(positions.len(), chunk_entities.len());
+                assert_eq!(velocities.len(), chunk_entities.len());
+                lengths.push(chunk_entities.len());
+                for (position, velocity) in positions.iter_mut().zip(velocities) {
+                    position.x += velocity.x * 10.0;
+                }
+                seen.extend_from_slice(chunk_entities);
+            },
+        );
+        assert_eq!(lengths, vec![3, 1, 3, 2]);
+        assert_eq!(seen.len(), 9);
+        assert!(!seen.contains(&entities[4]));
+
+        for (index, &entity) in entities.iter().enumerate() {
+            let expected = if index == 4 {
+                index as f32
+            } else {
+                index as f32 + 10.0
+            };
+            assert_eq!(world.get::<Position>(entity).unwrap().x, expected);
+        }
+
+        let mut changed = Vec::new();
+        world
+            .query::<&Position>()
+            .changed::<Position>()
+            .for_each_chunk(64, |chunk_entities, positions| {
+                assert_eq!(positions.len(), chunk_entities.len());
+                changed.extend_from_slice(chunk_entities);
+            });
+        assert_eq!(changed.len(), 9, "chunks stamp the rows they hand out");
+
+        let mut optional = 0;
+        world
+            .query::<(&Position, Option<&Velocity>)>()
+            .for_each_chunk(4, |chunk_entities, (_, velocities)| {
+                if let Some(velocities) = velocities {
+                    assert_eq!(velocities.len(), chunk_entities.len());
+                    optional += velocities.len();
+                }
+            });
+        assert_eq!(optional, 9);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk length must be greater than zero")]
+    fn test_for_each_chunk_rejects_zero_length() {
+        let mut world = DynWorld::new();
+        world.spawn((Position { x: 0.0, y: 0.0 },));
+        world
+            .query::<&Position>()
+            .for_each_chunk(0, |_entities, _positions| {});
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    #[test]
+    fn test_par_for_each_chunk_covers_every_row() {
+        let mut world = DynWorld::new();
+        let count = 5003;
+        world.spawn_bundles(
+            (Position { x: 1.0, y: 0.0 }, Velocity { x: 2.0, y: 0.0 }),
+            count,
+        );
+
+        let chunks = std::sync::atomic::AtomicUsize::new(0);
+        world
+            .query::<(&mut Position, &Velocity)>()
+            .par_for_each_chunk(64, |chunk_entities, (positions, velocities)| {
+                assert!(chunk_entities.len() <= 64);
+                chunks.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                for (position, velocity) in positions.iter_mut().zip(velocities) {
+                    position.x += velocity.x;
+                }
+            });
+        assert_eq!(chunks.into_inner(), count.div_ceil(64));
+
+        let mut wrong = 0;
+        world.query::<&Position>().for_each(|_entity, position| {
+            if (position.x - 3.0).abs() > 1e-3 {
+                wrong += 1;
+            }
+        });
+        assert_eq!(wrong, 0);
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    #[test]
+    fn test_par_fold_is_bitwise_stable_across_pool_sizes() {
+        let mut world = DynWorld::new();
+        let movers = world.spawn_bundles(
+            (Position { x: 0.0, y: 0.0 }, Velocity { x: 0.0, y: 0.0 }),
+            4000,
+        );
+        let loners = world.spawn_bundles((Position { x: 0.0, y: 0.0 },), 3000);
+        for (index, &entity) in movers.iter().chain(&loners).enumerate() {
+            let x = (index as f32 * 0.37).sin() * 1.0e4 + 1.0e-3 * (index % 7) as f32;
+            world.set(entity, Position { x, y: 0.0 });
+        }
+        for &entity in movers.iter().step_by(97) {
+            world.disable(entity);
+        }
+
+        let sum_with = |threads: usize, world: &mut DynWorld| {
+            let pool = crate::rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            pool.install(|| {
+                let mutable = world.query::<&Position>().par_fold(
+                    || 0.0f32,
+                    |sum, _entity, position| sum + position.x,
+                    |left, right| left + right,
+                );
+                let shared = world.query_ref::<&Position>().par_fold(
+                    || 0.0f32,
+                    |sum, _entity, position| sum + position.x,
+                    |left, right| left + right,
+                );
+                assert_eq!(mutable.to_bits(), shared.to_bits());
+                mutable.to_bits()
+            })
+        };
+        let single = sum_with(1, &mut world);
+        for threads in [2, 3, 8] {
+            assert_eq!(sum_with(threads, &mut world), single);
+        }
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    #[test]
+    fn test_par_reduce_and_par_map_collect_follow_match_order() {
+        let mut world = DynWorld::new();
+        let entities = world.spawn_bundles((Position { x: 0.0, y: 0.0 },), 2500);
+        world.spawn_bundles((Position { x: 0.0, y: 0.0 }, Health { value: 1.0 }), 10);
+        for (index, &entity) in entities.iter().enumerate() {
+            world.set(
+                entity,
+                Position {
+                    x: index as f32,
+                    y: 0.0,
+                },
+            );
+        }
+
+        let mut sequential = Vec::new();
+        world
+            .query::<&Position>()
+            .for_each(|entity, _position| sequential.push(entity));
+        let collected = world
+            .query::<&mut Position>()
+            .par_map_collect(|entity, _position| entity);
+        assert_eq!(collected, sequential);
+        let collected_ref = world
+            .query_ref::<&Position>()
+            .par_map_collect(|entity, _position| entity);
+        assert_eq!(collected_ref, sequential);
+
+        let widest = world
+            .query::<&Position>()
+            .par_reduce(|_entity, position| position.x, f32::max);
+        assert_eq!(widest, Some(2499.0));
+        assert_eq!(
+            world
+                .query_ref::<&Health>()
+                .par_reduce(|_entity, health| health.value, |left, right| left + right),
+            Some(10.0)
+        );
+        assert_eq!(
+            world
+                .query::<(&Position, &Velocity)>()
+                .par_reduce(|_entity, _item| 1, |left, right| left + right),
+            None
+        );
+        assert_eq!(
+            world.query_ref::<&Velocity>().par_fold(
+                || 7,
+                |count, _entity, _velocity| count + 1,
+                |left, right| left + right
+            ),
+            7
+        );
+    }
+
     #[test]
     fn test_bare_element_queries_match_single_tuples() {
         let mut world = DynWorld::new();
@@ -10075,74 +15250,392 @@ mod tests {
         let velocity = world.register::<Velocity>();
         let veteran = world.spawn((Position::default(),));
 
-        world.step();
-        let fresh = world.spawn((Position::default(),));
-        world.add_components(veteran, velocity.mask);
-        let mut added_positions = Vec::new();
-        world
-            .query::<&Position>()
-            .added::<Position>()
-            .for_each(|entity, _position| added_positions.push(entity));
+        world.step();
+        let fresh = world.spawn((Position::default(),));
+        world.add_components(veteran, velocity.mask);
+        let mut added_positions = Vec::new();
+        world
+            .query::<&Position>()
+            .added::<Position>()
+            .for_each(|entity, _position| added_positions.push(entity));
+        assert_eq!(
+            added_positions,
+            vec![fresh],
+            "a migration must carry the original added tick"
+        );
+
+        let mut added_velocities = Vec::new();
+        world
+            .query::<&Velocity>()
+            .added::<Velocity>()
+            .for_each(|entity, _velocity| added_velocities.push(entity));
+        assert_eq!(added_velocities, vec![veteran]);
+
+        world.step();
+        world.get_mut::<Position>(fresh).unwrap().x = 5.0;
+        let added_now: Vec<Entity> = world
+            .query_ref::<&Position>()
+            .added::<Position>()
+            .iter()
+            .map(|(entity, _position)| entity)
+            .collect();
+        assert!(
+            added_now.is_empty(),
+            "mutation must not retrigger the added filter"
+        );
+        let changed_now: Vec<Entity> = world
+            .query_ref::<&Position>()
+            .changed::<Position>()
+            .iter()
+            .map(|(entity, _position)| entity)
+            .collect();
+        assert_eq!(changed_now, vec![fresh]);
+    }
+
+    #[test]
+    #[should_panic(expected = "added filters must name components")]
+    fn test_added_filter_rejects_types_outside_tuple() {
+        let mut world = DynWorld::new();
+        world.register::<Velocity>();
+        world.spawn((Position::default(),));
+        world
+            .query_ref::<&Position>()
+            .added::<Velocity>()
+            .iter()
+            .count();
+    }
+
+    #[test]
+    fn test_queue_spawn_returns_live_handle_before_apply() {
+        let mut world = DynWorld::new();
+
+        let entity = world.queue_spawn((Position { x: 3.0, y: 0.0 }, Velocity::default()));
+        assert!(world.is_alive(entity));
+        assert!(world.get::<Position>(entity).is_none());
+
+        world.queue_set(entity, Health { value: 50.0 });
+        world.apply_commands();
+
+        assert_eq!(world.get::<Position>(entity).unwrap().x, 3.0);
+        assert_eq!(world.get::<Health>(entity).unwrap().value, 50.0);
+    }
+
+    #[test]
+    fn test_reserve_entity_through_shared_borrow_materializes_at_apply() {
+        let mut world = DynWorld::new();
+        let recycled_source = world.spawn((Position::default(),));
+        world.despawn_entities(&[recycled_source]);
+
+        let planned: Vec<Entity> = {
+            let shared = &world;
+            std::thread::scope(|scope| {
+                let worker = scope.spawn(|| shared.reserve_entity());
+                let mut planned = vec![shared.reserve_entity(), worker.join().unwrap()];
+                planned.extend(shared.reserve_entities(2));
+                planned
+            })
+        };
+        assert!(planned.iter().any(|entity| entity.id == recycled_source.id
+            && entity.generation == recycled_source.generation + 1));
+        assert!(planned.iter().all(|&entity| !world.is_alive(entity)));
+
+        let target = planned[0];
+        world.queue_set(target, Health { value: 7.0 });
+        let bystander = world.spawn((Velocity::default(),));
+        assert!(!planned.contains(&bystander));
+
+        world.apply_commands();
+        for &entity in &planned {
+            assert!(world.is_alive(entity));
+            assert!(world.contains_entity(entity));
+        }
+        assert_eq!(world.get::<Health>(target).unwrap().value, 7.0);
+        assert!(world.spawn_reserved().is_empty());
+    }
+
+    #[test]
+    fn test_dyn_ecs_reserve_entity_logs_spawn() {
+        let mut ecs = DynEcs::new();
+        ecs.structural_logging = true;
+        let shared = &ecs;
+        let entity = shared.reserve_entity();
+        assert!(!ecs.is_alive(entity));
+        assert_eq!(ecs.spawn_reserved(), vec![entity]);
+        assert!(ecs.is_alive(entity));
+        let last = ecs.structural_changes_since(0).last().unwrap();
+        assert_eq!(last.kind, StructuralChangeKind::Spawned);
+        assert_eq!(last.entity, entity);
+    }
+
+    #[test]
+    fn test_clone_entity_copies_components_and_tags() {
+        struct Selected;
+        let mut world = DynWorld::new();
+        world.register_clone::<Position>();
+        world.register_clone::<Health>();
+        let original = world.spawn((Position { x: 1.0, y: 2.0 }, Health { value: 9.0 }));
+        world.add_tag_type::<Selected>(original);
+
+        let copy = world.clone_entity(original).unwrap();
+        assert_ne!(copy, original);
+        assert_eq!(
+            world.get::<Position>(copy),
+            Some(&Position { x: 1.0, y: 2.0 })
+        );
+        assert_eq!(world.get::<Health>(copy), Some(&Health { value: 9.0 }));
+        assert!(world.has_tag_type::<Selected>(copy));
+
+        world.get_mut::<Health>(copy).unwrap().value = 1.0;
+        assert_eq!(world.get::<Health>(original).unwrap().value, 9.0);
+
+        let position_only = world.lookup_key::<Position>().unwrap().mask;
+        let partial = world
+            .clone_entity_filtered(original, position_only)
+            .unwrap();
+        assert!(world.has::<Position>(partial));
+        assert!(!world.has::<Health>(partial));
+        assert!(!world.has_tag_type::<Selected>(partial));
+
+        world.despawn_entities(&[original]);
+        assert!(world.clone_entity(original).is_none());
+    }
+
+    #[test]
+    fn test_clone_entity_into_matches_components_by_name() {
+        struct Card;
+        let mut deck = DynWorld::new();
+        deck.register_clone::<Health>();
+        deck.register_clone::<Position>();
+        let card = deck.spawn((Position { x: 4.0, y: 0.0 }, Health { value: 3.0 }));
+        deck.add_tag_type::<Card>(card);
+
+        let mut battlefield = DynWorld::new();
+        battlefield.register::<Velocity>();
+        battlefield.register::<Position>();
+
+        let placed = deck.clone_entity_into(&mut battlefield, card).unwrap();
+        assert_eq!(battlefield.get::<Position>(placed).unwrap().x, 4.0);
+        assert_eq!(battlefield.get::<Health>(placed).unwrap().value, 3.0);
+        assert!(battlefield.has_tag_type::<Card>(placed));
+        assert_eq!(
+            battlefield.lookup_key::<Position>().unwrap().mask,
+            1 << 1,
+            "an existing registration keeps its bit"
+        );
+        assert!(deck.is_alive(card));
+
+        let health_only = deck.lookup_key::<Health>().unwrap().mask;
+        let wounded = deck
+            .clone_entity_into_filtered(&mut battlefield, card, health_only)
+            .unwrap();
+        assert!(!battlefield.has::<Position>(wounded));
+        let again = battlefield.clone_entity(wounded).unwrap();
+        assert_eq!(battlefield.get::<Health>(again).unwrap().value, 3.0);
+    }
+
+    #[test]
+    fn test_clone_entity_refuses_uncloneable_components() {
+        let mut world = DynWorld::new();
+        world.register_clone::<Position>();
+        let entity = world.spawn((Position::default(), Velocity::default()));
+        assert!(world.clone_entity(entity).is_none());
+        let mut other = DynWorld::new();
+        assert!(world.clone_entity_into(&mut other, entity).is_none());
+        assert_eq!(world.entity_count(), 1, "nothing is spawned");
+        assert_eq!(other.entity_count(), 0);
+
+        let position_only = world.lookup_key::<Position>().unwrap().mask;
+        assert!(world.clone_entity_filtered(entity, position_only).is_some());
+
+        let mut ecs = DynEcs::new();
+        let core = ecs.add_world(ComponentRegistry::new());
+        let physics = ecs.add_world(ComponentRegistry::new());
+        ecs.worlds[core].register_clone::<Position>();
+        ecs.worlds[physics].register::<Velocity>();
+        let grouped = ecs.spawn_with((Position::default(), Velocity::default()));
+        assert!(ecs.clone_entity(grouped).is_none());
+        assert_eq!(ecs.worlds[core].entity_count(), 1);
+    }
+
+    #[test]
+    fn test_spawn_bundles_registers_clone_support() {
+        let mut world = DynWorld::new();
+        let entities = world.spawn_bundles((Position { x: 2.0, y: 2.0 },), 2);
+        let copy = world.clone_entity(entities[0]).unwrap();
+        assert_eq!(world.get::<Position>(copy).unwrap().x, 2.0);
+    }
+
+    #[test]
+    fn test_dyn_ecs_clone_entity_spans_member_worlds() {
+        let mut ecs = DynEcs::new();
+        let mut physics = ComponentRegistry::new();
+        physics.register_clone::<Position>();
+        let mut stats = ComponentRegistry::new();
+        stats.register_clone::<Health>();
+        ecs.add_world(physics);
+        ecs.add_world(stats);
+        let entity = ecs.spawn_with((Position { x: 5.0, y: 0.0 }, Health { value: 2.0 }));
+        let tag = ecs.register_tag();
+        ecs.add_tag(tag, entity);
+
+        let copy = ecs.clone_entity(entity).unwrap();
+        assert_eq!(ecs.get::<Position>(copy).unwrap().x, 5.0);
+        assert_eq!(ecs.get::<Health>(copy).unwrap().value, 2.0);
+        assert!(ecs.has_tag(tag, copy));
+        ecs.despawn(entity);
+        assert!(ecs.clone_entity(entity).is_none());
+    }
+
+    #[test]
+    fn test_transfer_entity_moves_row_and_logs_both_worlds() {
+        struct Loaded;
+        let mut loading = DynWorld::new();
+        let mut active = DynWorld::new();
+        active.register::<Velocity>();
+        loading.structural_logging = true;
+        active.structural_logging = true;
+        let entity = loading.spawn((Position { x: 3.0, y: 4.0 }, Health { value: 9.0 }));
+        loading.add_tag_type::<Loaded>(entity);
+        let loading_cursor = loading.structural_sequence();
+
+        let moved = loading
+            .transfer_entity(&mut active, entity, TransferHandle::Remap)
+            .unwrap();
+        assert!(!loading.is_alive(entity));
+        assert_eq!(active.get::<Position>(moved).unwrap().x, 3.0);
+        assert_eq!(active.get::<Health>(moved).unwrap().value, 9.0);
+        assert!(active.has_tag_type::<Loaded>(moved));
+
+        let removed = loading.structural_changes_since(loading_cursor);
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].kind, StructuralChangeKind::Despawned);
+        let added = active.structural_changes_since(0);
+        assert_eq!(added[0].kind, StructuralChangeKind::Spawned);
+        assert_eq!(added[0].entity, moved);
         assert_eq!(
-            added_positions,
-            vec![fresh],
-            "a migration must carry the original added tick"
+            added[0].mask,
+            active.lookup_key::<Position>().unwrap().mask
+                | active.lookup_key::<Health>().unwrap().mask
         );
-
-        let mut added_velocities = Vec::new();
-        world
-            .query::<&Velocity>()
-            .added::<Velocity>()
-            .for_each(|entity, _velocity| added_velocities.push(entity));
-        assert_eq!(added_velocities, vec![veteran]);
-
-        world.step();
-        world.get_mut::<Position>(fresh).unwrap().x = 5.0;
-        let added_now: Vec<Entity> = world
-            .query_ref::<&Position>()
-            .added::<Position>()
-            .iter()
-            .map(|(entity, _position)| entity)
-            .collect();
         assert!(
-            added_now.is_empty(),
-            "mutation must not retrigger the added filter"
+            loading
+                .transfer_entity(&mut active, entity, TransferHandle::Remap)
+                .is_none()
         );
-        let changed_now: Vec<Entity> = world
-            .query_ref::<&Position>()
-            .changed::<Position>()
-            .iter()
-            .map(|(entity, _position)| entity)
-            .collect();
-        assert_eq!(changed_now, vec![fresh]);
     }
 
     #[test]
-    #[should_panic(expected = "added filters must name components")]
-    fn test_added_filter_rejects_types_outside_tuple() {
-        let mut world = DynWorld::new();
-        world.register::<Velocity>();
-        world.spawn((Position::default(),));
-        world
-            .query_ref::<&Position>()
-            .added::<Velocity>()
-            .iter()
-            .count();
+    fn test_transfer_entity_preserves_handle_unless_taken() {
+        let mut source = DynWorld::new();
+        let mut destination = DynWorld::new();
+        let first = source.spawn((Position { x: 1.0, y: 0.0 },));
+        let second = source.spawn((Position { x: 2.0, y: 0.0 },));
+        destination.spawn((Velocity::default(),));
+
+        assert!(
+            source
+                .transfer_entity(&mut destination, first, TransferHandle::Preserve)
+                .is_none()
+        );
+        assert_eq!(source.get::<Position>(first).unwrap().x, 1.0);
+
+        let kept = source
+            .transfer_entity(&mut destination, second, TransferHandle::Preserve)
+            .unwrap();
+        assert_eq!(kept, second);
+        assert!(destination.is_alive(second));
+        assert_eq!(destination.get::<Position>(second).unwrap().x, 2.0);
+        let fresh = destination.spawn((Velocity::default(),));
+        assert_ne!(fresh.id, second.id);
     }
 
     #[test]
-    fn test_queue_spawn_returns_live_handle_before_apply() {
-        let mut world = DynWorld::new();
+    fn test_transfer_entity_refuses_to_revive_retired_handles() {
+        let mut source = DynWorld::new();
+        let mut destination = DynWorld::new();
+        let moving = source.spawn((Position { x: 1.0, y: 0.0 },));
+        let mut retired = destination.spawn((Velocity::default(),));
+        for _ in 0..3 {
+            destination.despawn_entities(&[retired]);
+            retired = destination.spawn((Velocity::default(),));
+        }
+        destination.despawn_entities(&[retired]);
+        assert_eq!((moving.id, retired.id), (0, 0));
 
-        let entity = world.queue_spawn((Position { x: 3.0, y: 0.0 }, Velocity::default()));
-        assert!(world.is_alive(entity));
-        assert!(world.get::<Position>(entity).is_none());
+        assert!(
+            source
+                .transfer_entity(&mut destination, moving, TransferHandle::Preserve)
+                .is_none()
+        );
+        assert!(source.is_alive(moving));
+        assert!(!destination.is_alive(moving));
+        assert!(!destination.is_alive(retired));
 
-        world.queue_set(entity, Health { value: 50.0 });
-        world.apply_commands();
+        let far = (0..3)
+            .map(|_| source.spawn((Position::default(),)))
+            .last()
+            .unwrap();
+        assert_eq!(far.id, 3);
+        source
+            .transfer_entity(&mut destination, far, TransferHandle::Preserve)
+            .unwrap();
+        let mut ids: Vec<u32> = (0..3)
+            .map(|_| destination.spawn((Velocity::default(),)).id)
+            .collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![0, 1, 2]);
+    }
 
-        assert_eq!(world.get::<Position>(entity).unwrap().x, 3.0);
-        assert_eq!(world.get::<Health>(entity).unwrap().value, 50.0);
+    #[test]
+    fn test_dyn_ecs_transfer_entity_routes_components_by_name() {
+        struct Staged;
+        let mut source = DynEcs::new();
+        let mut both = ComponentRegistry::new();
+        both.register::<Position>();
+        both.register::<Health>();
+        source.add_world(both);
+        let entity = source.spawn_with((Position { x: 7.0, y: 0.0 }, Health { value: 1.0 }));
+        source.add_tag_type::<Staged>(entity);
+
+        let mut destination = DynEcs::new();
+        let mut physics = ComponentRegistry::new();
+        physics.register::<Position>();
+        let mut stats = ComponentRegistry::new();
+        stats.register::<Health>();
+        destination.add_world(physics);
+        destination.add_world(stats);
+        destination.structural_logging = true;
+
+        let moved = source
+            .transfer_entity(&mut destination, entity, TransferHandle::Preserve)
+            .unwrap();
+        assert_eq!(moved, entity);
+        assert!(!source.is_alive(entity));
+        assert!(source.worlds[0].get::<Position>(entity).is_none());
+        assert_eq!(destination.worlds[0].get::<Position>(moved).unwrap().x, 7.0);
+        assert_eq!(
+            destination.worlds[1].get::<Health>(moved).unwrap().value,
+            1.0
+        );
+        assert!(destination.has_tag_type::<Staged>(moved));
+        assert_eq!(
+            destination.structural_changes_since(0)[0].kind,
+            StructuralChangeKind::Spawned
+        );
+
+        let homeless = source.spawn_with((Position::default(), Health::default()));
+        let mut positions_only = DynEcs::new();
+        let mut physics = ComponentRegistry::new();
+        physics.register::<Position>();
+        positions_only.add_world(physics);
+        assert!(
+            source
+                .transfer_entity(&mut positions_only, homeless, TransferHandle::Remap)
+                .is_none()
+        );
+        assert!(source.is_alive(homeless));
+        assert!(source.worlds[0].get::<Health>(homeless).is_some());
+        assert!(!positions_only.allocator.is_alive(homeless));
     }
 
     #[test]
@@ -10435,6 +15928,89 @@ mod tests {
         assert!(index.parent_of.is_empty());
     }
 
+    #[test]
+    fn test_hierarchy_index_keeps_sibling_order_through_edits() {
+        let mut world = DynWorld::new();
+        world.set_change_detection(true);
+        world.structural_logging = true;
+        let mut index = HierarchyIndex::new();
+
+        let parent = world.spawn((Position::default(),));
+        let other = world.spawn((Position::default(),));
+        let first = world.spawn((Position::default(),));
+        let second = world.spawn((Position::default(),));
+        let third = world.spawn((Position::default(),));
+        index.sync(&mut world);
+
+        index.reparent(&mut world, first, parent).unwrap();
+        index.reparent(&mut world, third, parent).unwrap();
+        index
+            .insert_child_at(&mut world, parent, second, 1)
+            .unwrap();
+        assert_eq!(index.children(parent), &[first, second, third]);
+
+        index.move_before(&mut world, third, first).unwrap();
+        assert_eq!(index.children(parent), &[third, first, second]);
+        index.move_after(&mut world, third, second).unwrap();
+        assert_eq!(index.children(parent), &[first, second, third]);
+        assert_eq!(world.get::<ChildOrder>(third), Some(&ChildOrder(2)));
+        assert_eq!(world.children(parent), vec![first, second, third]);
+
+        index.sync(&mut world);
+        assert_eq!(index.children(parent), &[first, second, third]);
+
+        world.set(first, ChildOrder(9));
+        index.sync(&mut world);
+        assert_eq!(index.children(parent), &[second, third, first]);
+
+        let mut rebuilt = HierarchyIndex::new();
+        rebuilt.sync(&mut world);
+        assert_eq!(rebuilt.children(parent), &[second, third, first]);
+        assert_eq!(rebuilt.descendants(parent), vec![second, third, first]);
+
+        index.move_after(&mut world, second, other).unwrap_err();
+        assert!(index.detach(&mut world, second));
+        assert_eq!(index.children(parent), &[third, first]);
+        assert!(world.get::<ChildOrder>(second).is_none());
+        index.sync(&mut world);
+        assert_eq!(index.children(parent), &[third, first]);
+    }
+
+    #[test]
+    fn test_hierarchy_index_rejects_cycles() {
+        let mut world = DynWorld::new();
+        world.set_change_detection(true);
+        world.structural_logging = true;
+        let mut index = HierarchyIndex::new();
+
+        let root = world.spawn((Position::default(),));
+        let child = world.spawn((ChildOf(root),));
+        let grandchild = world.spawn((ChildOf(child),));
+        index.sync(&mut world);
+
+        assert_eq!(
+            index.reparent(&mut world, root, grandchild),
+            Err(HierarchyError::Cycle {
+                child: root,
+                parent: grandchild
+            })
+        );
+        assert!(index.reparent(&mut world, child, child).is_err());
+        assert!(world.get::<ChildOf>(root).is_none());
+
+        world.set(root, ChildOf(grandchild));
+        index.sync(&mut world);
+        assert_eq!(index.rejected, vec![root]);
+        assert_eq!(index.descendants(root), vec![child, grandchild]);
+        assert!(!index.parent_of.contains_key(&root));
+
+        world.remove::<ChildOf>(child);
+        index.sync(&mut world);
+        assert!(index.rejected.is_empty(), "a broken cycle admits the link");
+        assert_eq!(index.children(grandchild), &[root]);
+        assert_eq!(index.descendants(child), vec![grandchild, root]);
+    }
+
     #[test]
     fn test_despawn_recursive_follows_child_links() {
         let mut world = DynWorld::new();
@@ -10461,6 +16037,69 @@ mod tests {
         assert!(world.is_alive(bystander));
     }
 
+    #[test]
+    fn test_non_send_resources_stay_on_their_thread() {
+        use std::rc::Rc;
+
+        let handle = Rc::new(5u32);
+        let mut world = DynWorld::new();
+        assert!(!world.has_non_send::<Rc<u32>>());
+        world.insert_non_send(Rc::clone(&handle));
+        world.insert_non_send(Rc::clone(&handle));
+        assert_eq!(Rc::strong_count(&handle), 2, "a replaced value drops");
+
+        let doubled = world.non_send_scope(|world, value: &mut Rc<u32>| {
+            assert!(!world.has_non_send::<Rc<u32>>());
+            **value * 2
+        });
+        assert_eq!(doubled, 10);
+        assert!(world.has_non_send::<Rc<u32>>());
+
+        let shared = &world;
+        let foreign = std::thread::scope(|scope| {
+            scope
+                .spawn(|| shared.has_non_send::<Rc<u32>>())
+                .join()
+                .is_err()
+        });
+        assert!(foreign, "another thread is refused");
+
+        let other = DynWorld::new();
+        assert!(
+            !other.has_non_send::<Rc<u32>>(),
+            "stores do not share values"
+        );
+        drop(world);
+        assert_eq!(
+            Rc::strong_count(&handle),
+            1,
+            "dropping the world drops them"
+        );
+    }
+
+    #[test]
+    fn test_non_send_values_of_a_world_dropped_elsewhere_are_purged() {
+        use std::rc::Rc;
+
+        let handle = Rc::new(3u32);
+        let mut world = DynWorld::new();
+        world.insert_non_send(Rc::clone(&handle));
+        std::thread::spawn(move || drop(world)).join().unwrap();
+        assert_eq!(
+            Rc::strong_count(&handle),
+            2,
+            "the value waits on its owner thread"
+        );
+
+        let mut next = DynWorld::new();
+        next.insert_non_send(0u8);
+        assert_eq!(
+            Rc::strong_count(&handle),
+            1,
+            "the owner's next access drops it"
+        );
+    }
+
     #[test]
     fn test_resource_scope_takes_and_restores() {
         struct Score {
@@ -11009,6 +16648,50 @@ mod tests {
         assert_eq!(tail[0].sequence, world.structural_sequence());
     }
 
+    #[test]
+    fn test_sort_table_rows_by_permutes_columns_and_keeps_ticks() {
+        let mut world = DynWorld::new();
+        world.set_change_detection(true);
+        let keys = [5.0, 3.0, 0.0, 4.0, 1.0, 2.0];
+        let entities: Vec<Entity> = keys
+            .iter()
+            .map(|&x| world.spawn((Position { x, y: 0.0 }, Handle(vec![x as u32]))))
+            .collect();
+        let loner = world.spawn((Velocity { x: 9.0, y: 0.0 },));
+        world.increment_tick();
+
+        world.sort_table_rows_by::<Position, _>(|position| position.x as i32);
+
+        let mut order = Vec::new();
+        world
+            .query::<(&Position, &Handle)>()
+            .for_each(|_entity, (position, handle)| {
+                assert_eq!(handle.0, vec![position.x as u32]);
+                order.push(position.x);
+            });
+        assert_eq!(order, vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+        for (&entity, &x) in entities.iter().zip(&keys) {
+            assert_eq!(world.get::<Position>(entity).unwrap().x, x);
+            assert_eq!(world.get::<Handle>(entity).unwrap().0, vec![x as u32]);
+        }
+        assert_eq!(world.get::<Velocity>(loner).unwrap().x, 9.0);
+        let position = world.component_key::<Position>().mask;
+        assert_eq!(
+            world.query_entities_changed(position).count(),
+            0,
+            "sorting moves rows without marking them changed"
+        );
+
+        world.sort_by_entity();
+        let mut spawned = Vec::new();
+        world
+            .query::<&Position>()
+            .for_each(|entity, _position| spawned.push(entity));
+        assert_eq!(spawned, entities);
+        world.despawn_entities(&entities[..2]);
+        assert_eq!(world.get::<Position>(entities[2]).unwrap().x, 0.0);
+    }
+
     #[test]
     fn test_compact_invalidates_migration_plans() {
         let mut world = DynWorld::new();
@@ -11112,6 +16795,173 @@ mod tests {
         assert_eq!(world.structural_sequence(), 2);
     }
 
+    #[test]
+    fn test_batch_add_and_remove_move_rows_by_table() {
+        let mut world = DynWorld::new();
+        world.structural_logging = true;
+        world.set_change_detection(true);
+        let velocity = world.register::<Velocity>();
+        let health = world.register::<Health>();
+
+        let mut entities = Vec::new();
+        for index in 0..8 {
+            let entity = world.spawn((Position {
+                x: index as f32,
+                y: 0.0,
+            },));
+            entities.push(entity);
+        }
+        let healthy = world.spawn((Position { x: 99.0, y: 0.0 }, Health { value: 3.0 }));
+        let moving = world.spawn((Position { x: 50.0, y: 0.0 }, Velocity { x: 1.0, y: 1.0 }));
+        let cursor = world.structural_sequence();
+
+        let chosen = [
+            entities[7],
+            entities[1],
+            entities[4],
+            entities[6],
+            healthy,
+            entities[1],
+            moving,
+        ];
+        assert_eq!(world.add_components_batch(&chosen, velocity.mask), 5);
+
+        for (index, &entity) in entities.iter().enumerate() {
+            assert_eq!(world.get::<Position>(entity).unwrap().x, index as f32);
+            let expected = matches!(index, 1 | 4 | 6 | 7);
+            assert_eq!(world.get::<Velocity>(entity).is_some(), expected);
+        }
+        assert_eq!(world.get::<Health>(healthy).unwrap().value, 3.0);
+        assert_eq!(
+            world.get::<Velocity>(moving),
+            Some(&Velocity { x: 1.0, y: 1.0 })
+        );
+        assert_eq!(world.query_entities(velocity.mask).count(), 6);
+
+        let changes = world.structural_changes_since(cursor);
+        assert_eq!(changes.len(), 2);
+        assert!(changes.iter().all(|change| change.kind
+            == StructuralChangeKind::ComponentsAddedBatch
+            && change.mask == velocity.mask));
+        let mut logged: Vec<Entity> = changes
+            .iter()
+            .flat_map(|change| world.change_entities(change).to_vec())
+            .collect();
+        logged.sort_by_key(|entity| entity.id);
+        let mut expected = vec![entities[1], entities[4], entities[6], entities[7], healthy];
+        expected.sort_by_key(|entity| entity.id);
+        assert_eq!(logged, expected);
+        assert_eq!(
+            world.structural_batch(changes[0].sequence).unwrap()[0],
+            changes[0].entity
+        );
+
+        let cursor = world.structural_sequence();
+        assert_eq!(
+            world.remove_components_batch(
+                &[entities[4], healthy, entities[0]],
+                velocity.mask | health.mask
+            ),
+            2
+        );
+        assert!(world.get::<Velocity>(entities[4]).is_none());
+        assert!(world.get::<Health>(healthy).is_none());
+        assert_eq!(world.get::<Position>(healthy).unwrap().x, 99.0);
+        assert_eq!(world.get::<Position>(entities[4]).unwrap().x, 4.0);
+        let removed = world.structural_changes_since(cursor);
+        assert_eq!(removed.len(), 2);
+        assert!(
+            removed
+                .iter()
+                .all(|change| change.kind == StructuralChangeKind::ComponentsRemovedBatch)
+        );
+        assert!(
+            removed
+                .iter()
+                .any(|change| change.mask == velocity.mask | health.mask)
+        );
+
+        world.insert_bundle_batch(
+            &[entities[0], entities[2]],
+            (Velocity { x: 7.0, y: 0.0 }, Health { value: 1.0 }),
+        );
+        for entity in [entities[0], entities[2]] {
+            assert_eq!(world.get::<Velocity>(entity).unwrap().x, 7.0);
+            assert_eq!(world.get::<Health>(entity).unwrap().value, 1.0);
+        }
+
+        world.queue_remove_components_batch(entities.clone(), velocity.mask);
+        world.apply_commands();
+        assert_eq!(world.query_entities(velocity.mask).count(), 1);
+
+        world.trim_structural_log(world.structural_sequence());
+        assert!(world.structural_batches.is_empty());
+        assert!(world.structural_changes_since(0).is_empty());
+    }
+
+    #[test]
+    fn test_spawn_columns_and_export_columns() {
+        let mut world = DynWorld::new();
+        world.set_change_detection(true);
+        world.require::<Velocity, Health>();
+        world.spawn((Position { x: -1.0, y: 0.0 },));
+
+        let positions: Vec<Position> = (0..5)
+            .map(|index| Position {
+                x: index as f32,
+                y: 1.0,
+            })
+            .collect();
+        let velocities: Vec<Velocity> = (0..5)
+            .map(|index| Velocity {
+                x: index as f32 * 10.0,
+                y: 0.0,
+            })
+            .collect();
+        let batch = ColumnBatch::new().column(positions).column(velocities);
+        assert_eq!(batch.len(), 5);
+        world.increment_tick();
+        let entities = world.spawn_columns(batch);
+        assert_eq!(entities.len(), 5);
+        for (index, &entity) in entities.iter().enumerate() {
+            assert_eq!(world.get::<Position>(entity).unwrap().x, index as f32);
+            assert_eq!(
+                world.get::<Velocity>(entity).unwrap().x,
+                index as f32 * 10.0
+            );
+            assert_eq!(world.get::<Health>(entity), Some(&Health::default()));
+        }
+        let velocity = world.lookup_key::<Velocity>().unwrap();
+        assert_eq!(world.query_entities_changed(velocity.mask).count(), 5);
+        assert!(world.spawn_columns(ColumnBatch::new()).is_empty());
+
+        let position = world.lookup_key::<Position>().unwrap();
+        let chunks: Vec<ColumnChunk> = world.export_columns(position.mask, 0).collect();
+        assert_eq!(chunks.len(), 2);
+        let moving = chunks
+            .iter()
+            .find(|chunk| chunk.mask() & velocity.mask != 0)
+            .unwrap();
+        assert_eq!(moving.entities(), entities.as_slice());
+        assert_eq!(moving.column::<Velocity>().unwrap()[3].x, 30.0);
+        assert_eq!(moving.column_keyed(position).unwrap().len(), moving.len());
+        assert_eq!(moving.component_names().count(), 3);
+        let still = world
+            .export_columns(position.mask, velocity.mask)
+            .next()
+            .unwrap();
+        assert!(still.column::<Velocity>().is_none());
+        assert_eq!(still.column::<Position>().unwrap()[0].x, -1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "rows but the batch has")]
+    fn test_column_batch_rejects_ragged_columns() {
+        let _ = ColumnBatch::new()
+            .column(vec![Position::default(); 3])
+            .column(vec![Velocity::default(); 2]);
+    }
+
     #[test]
     fn test_commands_full_surface() {
         let mut world = DynWorld::new();
@@ -11653,19 +17503,98 @@ mod tests {
                     }
                 }
             }
-            let delta = source.delta_since(&cursor).unwrap();
-            cursor = delta.to;
-            let bytes = postcard::to_allocvec(&delta).unwrap();
-            let decoded: DynWorldDelta = postcard::from_bytes(&bytes).unwrap();
-            replica.apply_delta(&decoded).unwrap();
-            assert_worlds_equivalent(&source, &replica, &format!("round {round}"));
+            let delta = source.delta_since(&cursor).unwrap();
+            cursor = delta.to;
+            let bytes = postcard::to_allocvec(&delta).unwrap();
+            let decoded: DynWorldDelta = postcard::from_bytes(&bytes).unwrap();
+            replica.apply_delta(&decoded).unwrap();
+            assert_worlds_equivalent(&source, &replica, &format!("round {round}"));
+        }
+
+        let stale = handles[0];
+        source.despawn_entities(&[stale]);
+        let delta = source.delta_since(&cursor).unwrap();
+        replica.apply_delta(&delta).unwrap();
+        assert!(!replica.is_alive(stale) || replica.get::<Position>(stale).is_none());
+    }
+
+    #[cfg(all(feature = "snapshot", not(feature = "raw_storage")))]
+    #[test]
+    fn test_world_deltas_replay_batch_entries() {
+        let mut registry = ComponentRegistry::new();
+        registry.register_serde::<Position>();
+        registry.register_serde::<Velocity>();
+        registry.register_serde::<Health>();
+        let mut source = DynWorld::from_registry(registry.clone());
+        source.structural_logging = true;
+        source.set_change_detection(true);
+
+        let mut entities = Vec::new();
+        for index in 0..12 {
+            entities.push(source.spawn((Position {
+                x: index as f32,
+                y: 0.0,
+            },)));
+        }
+        let snapshot = source.snapshot().unwrap();
+        let mut replica = DynWorld::from_snapshot(registry, &snapshot).unwrap();
+        let cursor = source.delta_cursor();
+
+        let velocity = source.component_key::<Velocity>().mask;
+        let health = source.component_key::<Health>().mask;
+        let evens: Vec<Entity> = entities.iter().copied().step_by(2).collect();
+        source.add_components_batch(&evens, velocity | health);
+        source.remove_components_batch(&entities[..6], health);
+        for &entity in &entities[6..] {
+            if let Some(velocity) = source.get_mut::<Velocity>(entity) {
+                velocity.x = 3.0;
+            }
         }
 
-        let stale = handles[0];
-        source.despawn_entities(&[stale]);
         let delta = source.delta_since(&cursor).unwrap();
-        replica.apply_delta(&delta).unwrap();
-        assert!(!replica.is_alive(stale) || replica.get::<Position>(stale).is_none());
+        assert_eq!(delta.structural.len(), 2);
+        assert_eq!(delta.batches.len(), 2);
+        let bytes = postcard::to_allocvec(&delta).unwrap();
+        let decoded: DynWorldDelta = postcard::from_bytes(&bytes).unwrap();
+        replica.apply_delta(&decoded).unwrap();
+        assert_worlds_equivalent(&source, &replica, "after batch delta");
+
+        let mut truncated = source.delta_since(&delta.to).unwrap();
+        assert!(truncated.structural.is_empty());
+        truncated.structural = delta.structural.clone();
+        assert!(matches!(
+            replica.apply_delta(&truncated),
+            Err(SnapshotError::Codec(_))
+        ));
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[test]
+    fn test_export_columns_encode_through_codecs() {
+        let mut registry = ComponentRegistry::new();
+        registry.register_serde::<Position>();
+        registry.register::<Velocity>();
+        let mut world = DynWorld::from_registry(registry);
+        let positions = vec![Position { x: 1.0, y: 2.0 }, Position { x: 3.0, y: 4.0 }];
+        world.spawn_columns(
+            ColumnBatch::new()
+                .column(positions.clone())
+                .column(vec![Velocity::default(); 2]),
+        );
+
+        let chunk = world.export_columns(0, 0).next().unwrap();
+        let name = std::any::type_name::<Position>();
+        let bytes = chunk.encode_column(name).unwrap().unwrap();
+        let decoded: Vec<Position> = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, positions);
+        assert!(matches!(
+            chunk.encode_column(std::any::type_name::<Velocity>()),
+            Err(SnapshotError::MissingCodec(_))
+        ));
+        assert!(matches!(
+            chunk.encode_column("missing"),
+            Err(SnapshotError::UnknownComponent(_))
+        ));
     }
 
     #[cfg(all(feature = "snapshot", not(feature = "raw_storage")))]
@@ -11991,6 +17920,203 @@ mod tests {
         assert_eq!(total, 64.0);
     }
 
+    #[test]
+    fn test_live_query_tracks_new_tables_and_caches_len() {
+        let mut world = DynWorld::new();
+        world.spawn((Position::default(), Velocity { x: 1.0, y: 0.0 }));
+        let live = world
+            .query::<(&mut Position, &Velocity)>()
+            .without::<Health>()
+            .live();
+        assert_eq!(live.tables(&world).len(), 1);
+        assert_eq!(live.len(&mut world), 1);
+
+        world.spawn((
+            Position::default(),
+            Velocity { x: 2.0, y: 0.0 },
+            Health::default(),
+        ));
+        #[derive(Default)]
+        struct Frozen;
+        let frozen = world.spawn((Position::default(), Velocity { x: 3.0, y: 0.0 }));
+        world.set(frozen, Frozen);
+        assert_eq!(live.tables(&world).len(), 2);
+        assert_eq!(live.len(&mut world), 2);
+
+        live.for_each(&mut world, |_entity, (position, velocity)| {
+            position.x += velocity.x;
+        });
+        let total: f32 = world
+            .query_ref::<&Position>()
+            .iter()
+            .map(|(_entity, position)| position.x)
+            .sum();
+        assert_eq!(total, 4.0);
+
+        let empty = world.spawn((Position::default(), Velocity::default()));
+        world.despawn_entities(&[empty]);
+        world.compact();
+        assert_eq!(live.len(&mut world), 2);
+        assert_eq!(live.entities(&mut world).len(), 2);
+    }
+
+    #[test]
+    fn test_live_query_caches_tag_filtered_entities() {
+        let mut world = DynWorld::new();
+        let selected = world.register_tag();
+        let first = world.spawn((Position::default(),));
+        let second = world.spawn((Position::default(), Health::default()));
+        world.spawn((Position::default(),));
+        world.add_tag(selected, first);
+        world.add_tag(selected, second);
+
+        let live = world.query::<&mut Position>().with_tag(selected).live();
+        assert_eq!(live.entities(&mut world), &[first, second]);
+        assert_eq!(live.len(&mut world), 2);
+
+        live.for_each(&mut world, |_entity, position| position.x = 5.0);
+        assert_eq!(world.get::<Position>(second).unwrap().x, 5.0);
+
+        world.remove_tag(selected, first);
+        assert_eq!(live.entities(&mut world), &[second]);
+        let third = world.spawn((Position::default(), Velocity::default()));
+        world.add_tag(selected, third);
+        assert_eq!(live.len(&mut world), 2);
+        assert_eq!(live.tables(&world).len(), 3);
+    }
+
+    #[test]
+    fn test_live_query_rows_follow_a_table_sort() {
+        let mut world = DynWorld::new();
+        let first = world.spawn((Position { x: 100.0, y: 0.0 },));
+        let paused = world.spawn((Position { x: 1.0, y: 0.0 },));
+        world.disable(paused);
+        let live = world.query::<&mut Position>().live();
+        assert_eq!(live.entities(&mut world), &[first]);
+
+        world.sort_table_rows_by::<Position, _>(|position| position.x as i32);
+        let mut seen = Vec::new();
+        live.for_each(&mut world, |entity, position| {
+            seen.push((entity, position.x));
+            position.x += 1.0;
+        });
+        assert_eq!(seen, vec![(first, 100.0)]);
+        assert_eq!(world.get::<Position>(first).unwrap().x, 101.0);
+        assert_eq!(world.get::<Position>(paused).unwrap().x, 1.0);
+    }
+
+    #[test]
+    fn test_released_live_queries_free_their_slot() {
+        let mut world = DynWorld::new();
+        world.spawn((Position::default(),));
+        let first = world.query::<&mut Position>().live();
+        assert!(first.release(&mut world));
+        assert!(!first.release(&mut world), "already released");
+        assert!(world.live_queries[first.index].tables.is_empty());
+
+        world.spawn((Position::default(), Velocity::default()));
+        let second = world.query::<&Velocity>().live();
+        assert_eq!(second.index, first.index);
+        assert_eq!(world.live_queries.len(), 1);
+        assert_eq!(second.len(&mut world), 1);
+
+        let mut other = DynWorld::new();
+        other.spawn((Velocity::default(),));
+        let foreign = other.query::<&Velocity>().live();
+        assert!(!foreign.release(&mut world));
+        assert_eq!(second.len(&mut world), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "live query used after it was released")]
+    fn test_live_query_handle_panics_after_release() {
+        let mut world = DynWorld::new();
+        let live = world.query::<&Position>().live();
+        live.release(&mut world);
+        world.query::<&Velocity>().live();
+        live.len(&mut world);
+    }
+
+    #[test]
+    #[should_panic(expected = "live query used on a world that did not issue it")]
+    fn test_live_query_handle_panics_on_another_world() {
+        let mut world = DynWorld::new();
+        let mut other = DynWorld::new();
+        other.query::<&Velocity>().live();
+        let live = world.query::<&Position>().live();
+        live.len(&mut other);
+    }
+
+    #[test]
+    fn test_disabled_entities_skip_queries_until_enabled() {
+        let mut world = DynWorld::new();
+        world.structural_logging = true;
+        let position = world.component_key::<Position>();
+        let live = world.query::<&mut Position>().live();
+        let paused = world.spawn((Position::default(),));
+        let running = world.spawn((Position::default(), Velocity::default()));
+        let cursor = world.structural_sequence();
+
+        assert!(world.disable(paused));
+        assert!(!world.disable(paused), "already disabled");
+        assert!(world.is_disabled(paused));
+        assert_eq!(world.get::<Position>(paused), Some(&Position::default()));
+
+        let mut visited = Vec::new();
+        world.for_each(position.mask, 0, |entity, _table, _row| {
+            visited.push(entity)
+        });
+        assert_eq!(visited, vec![running]);
+        world
+            .query::<&mut Position>()
+            .for_each(|_entity, position| position.x = 1.0);
+        assert_eq!(world.get::<Position>(paused).unwrap().x, 0.0);
+        assert_eq!(world.query_ref::<&Position>().iter().count(), 1);
+        assert_eq!(live.entities(&mut world), &[running]);
+        assert_eq!(
+            world
+                .query_ref::<&Position>()
+                .include_disabled()
+                .iter()
+                .count(),
+            2
+        );
+        let only_disabled: Vec<Entity> = world
+            .query_ref::<&Position>()
+            .with_tag_type::<Disabled>()
+            .iter()
+            .map(|(entity, _)| entity)
+            .collect();
+        assert_eq!(only_disabled, vec![paused]);
+        let prepared = world.query::<&Position>().include_disabled().prepare();
+        let mut prepared_count = 0;
+        prepared
+            .query(&mut world)
+            .for_each(|_entity, _position| prepared_count += 1);
+        assert_eq!(prepared_count, 2);
+
+        assert!(world.enable(paused));
+        assert!(!world.enable(paused));
+        assert_eq!(world.query_ref::<&Position>().iter().count(), 2);
+        assert_eq!(live.len(&mut world), 2);
+        let kinds: Vec<StructuralChangeKind> = world
+            .structural_changes_since(cursor)
+            .iter()
+            .map(|change| change.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                StructuralChangeKind::Disabled,
+                StructuralChangeKind::Enabled
+            ]
+        );
+
+        world.disable(running);
+        world.despawn_entities(&[running]);
+        assert_eq!(world.query_disabled().count(), 0, "despawn clears the flag");
+    }
+
     #[cfg(all(feature = "snapshot", not(feature = "raw_storage")))]
     #[test]
     fn test_group_delta_serializes_and_group_compact_runs() {
@@ -12074,6 +18200,59 @@ mod tests {
         );
     }
 
+    #[cfg(not(target_family = "wasm"))]
+    #[test]
+    fn test_query_join_par_fold_is_deterministic() {
+        let mut core_registry = ComponentRegistry::new();
+        core_registry.register::<Position>();
+        let mut game_registry = ComponentRegistry::new();
+        game_registry.register::<Health>();
+
+        let mut ecs = DynEcs::new();
+        ecs.add_world_at(0, core_registry);
+        ecs.add_world_at(1, game_registry);
+        for index in 0..3000 {
+            let entity = ecs.spawn_with((Position {
+                x: (index as f32 * 0.73).cos() * 1.0e3,
+                y: 0.0,
+            },));
+            if index % 3 != 0 {
+                ecs.set(entity, Health { value: 0.1 });
+            }
+        }
+
+        let mut sums = Vec::new();
+        for threads in [1, 4] {
+            let pool = crate::rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            let sum = pool.install(|| {
+                ecs.query_join::<(&Position, &Health)>().par_fold(
+                    || 0.0f32,
+                    |sum, _entity, (position, health)| sum + position.x * health.value,
+                    |left, right| left + right,
+                )
+            });
+            sums.push(sum.to_bits());
+        }
+        assert_eq!(sums[0], sums[1]);
+
+        let mut sequential = Vec::new();
+        ecs.query_join::<(&Position, &Health)>()
+            .for_each(|entity, _item| sequential.push(entity));
+        assert_eq!(sequential.len(), 2000);
+        let collected = ecs
+            .query_join::<(&Position, &Health)>()
+            .par_map_collect(|entity, _item| entity);
+        assert_eq!(collected, sequential);
+        assert_eq!(
+            ecs.query_join::<&Health>()
+                .par_reduce(|_entity, health| health.value, f32::max),
+            Some(0.1)
+        );
+    }
+
     #[test]
     fn test_stats_and_compact() {
         let mut world = DynWorld::new();
@@ -12111,6 +18290,104 @@ mod tests {
         assert_eq!(group_stats.worlds.len(), 1);
     }
 
+    #[test]
+    fn test_stats_memory_accounting_and_trim_memory() {
+        let mut world = DynWorld::new();
+        world.set_change_detection(true);
+        world.structural_logging = true;
+        let entities = world.spawn_bundles((Position::default(), Velocity::default()), 1000);
+        world.send(3u8);
+
+        let stats = world.stats();
+        let position = stats
+            .component_memory
+            .iter()
+            .find(|component| component.name.ends_with("Position"))
+            .unwrap();
+        let row_bytes = std::mem::size_of::<Position>();
+        assert_eq!(position.data.live_bytes, 1000 * row_bytes);
+        assert!(position.data.capacity_bytes >= position.data.live_bytes);
+        assert_eq!(position.ticks.live_bytes, 2 * 1000 * 4);
+        assert_eq!(
+            stats.entity_index_memory.live_bytes,
+            1000 * std::mem::size_of::<Entity>()
+        );
+        assert!(stats.location_memory.live_bytes > 0);
+        assert!(stats.structural_log_memory.live_bytes > 0);
+        assert_eq!(stats.event_memory.live_bytes, 1);
+
+        world.despawn_entities(&entities[10..]);
+        world.clear_structural_log();
+        let wasted = world.stats().total_memory().wasted_bytes();
+        assert!(wasted > 0);
+
+        let released = world.trim_memory();
+        assert!(released >= wasted);
+        let trimmed = world.stats();
+        let position = trimmed
+            .component_memory
+            .iter()
+            .find(|component| component.name.ends_with("Position"))
+            .unwrap();
+        assert_eq!(position.data.capacity_bytes, 10 * row_bytes);
+        assert_eq!(position.data.wasted_bytes(), 0);
+        assert_eq!(position.ticks.wasted_bytes(), 0);
+        assert_eq!(trimmed.entity_index_memory.wasted_bytes(), 0);
+        assert_eq!(world.get::<Position>(entities[3]).unwrap().x, 0.0);
+
+        world.spawn_bundles((Position::default(), Velocity::default()), 5);
+        assert_eq!(world.entity_count(), 15);
+    }
+
+    #[cfg(feature = "raw_storage")]
+    #[test]
+    fn test_raw_storage_shrink_and_trim_account_for_freed_bytes() {
+        fn position_capacity(world: &DynWorld) -> usize {
+            world
+                .stats()
+                .component_memory
+                .iter()
+                .find(|component| component.name.ends_with("Position"))
+                .map(|component| component.data.capacity_bytes)
+                .unwrap()
+        }
+        fn held(world: &DynWorld) -> usize {
+            let stats = world.stats();
+            stats.total_memory().capacity_bytes + stats.pooled_bytes
+        }
+
+        release_column_pool();
+        let mut world = DynWorld::new();
+        let row_bytes = std::mem::size_of::<Position>();
+        let entities = world.spawn_bundles((Position::default(), Velocity::default()), 1000);
+        world.despawn_entities(&entities[10..]);
+        assert!(position_capacity(&world) >= 1000 * row_bytes);
+        world.shrink_to_fit();
+        assert_eq!(position_capacity(&world), 10 * row_bytes);
+
+        let doomed = world.spawn_bundles((Health::default(),), 100);
+        world.despawn_entities(&doomed);
+        let health_capacity = world
+            .stats()
+            .component_memory
+            .iter()
+            .find(|component| component.name.ends_with("Health"))
+            .map(|component| component.data.capacity_bytes)
+            .unwrap();
+        assert!(health_capacity > 0);
+
+        let before = held(&world);
+        let released = world.trim_memory();
+        assert_eq!(released, before - held(&world));
+        let pooled = world.stats().pooled_bytes;
+        assert!(
+            pooled >= health_capacity,
+            "the dropped table's column waits in the shared pool"
+        );
+        assert_eq!(release_column_pool(), pooled);
+        assert_eq!(world.stats().pooled_bytes, 0);
+    }
+
     #[test]
     fn test_query_join_filters() {
         struct Cursed;
@@ -12626,6 +18903,97 @@ mod tests {
         assert!(!ecs.has_tag(selected, first), "despawn drops group tags");
     }
 
+    #[test]
+    fn test_dyn_ecs_disable_skips_joins_and_routed_queries() {
+        let mut ecs = DynEcs::new();
+        let core = ecs.add_world(ComponentRegistry::new());
+        let physics = ecs.add_world(ComponentRegistry::new());
+        ecs.worlds[core].register::<Position>();
+        ecs.worlds[physics].register::<Velocity>();
+        let paused = ecs.spawn_with((Position::default(), Velocity { x: 1.0, y: 0.0 }));
+        let running = ecs.spawn_with((Position::default(), Velocity { x: 1.0, y: 0.0 }));
+        assert!(ecs.disable(paused));
+        assert!(ecs.is_disabled(paused));
+
+        ecs.query_join::<(&mut Position, &Velocity)>()
+            .for_each(|_entity, (position, velocity)| position.x += velocity.x);
+        assert_eq!(ecs.get::<Position>(paused).unwrap().x, 0.0);
+        assert_eq!(ecs.get::<Position>(running).unwrap().x, 1.0);
+        assert_eq!(
+            ecs.query_join_ref::<(&Position, &Velocity)>()
+                .include_disabled()
+                .iter()
+                .count(),
+            2
+        );
+        let routed: Vec<Entity> = ecs
+            .query_ref::<&Position>()
+            .iter()
+            .map(|(entity, _)| entity)
+            .collect();
+        assert_eq!(routed, vec![running]);
+
+        assert!(ecs.enable(paused));
+        let mut visited = 0;
+        ecs.query::<&Velocity>()
+            .for_each(|_entity, _velocity| visited += 1);
+        assert_eq!(visited, 2);
+    }
+
+    #[test]
+    fn test_dyn_ecs_disabled_set_leaves_four_exclusion_slots() {
+        struct First;
+        struct Second;
+        struct Third;
+        struct Fourth;
+
+        let mut ecs = DynEcs::new();
+        let core = ecs.add_world(ComponentRegistry::new());
+        let physics = ecs.add_world(ComponentRegistry::new());
+        ecs.worlds[core].register::<Position>();
+        ecs.worlds[physics].register::<Velocity>();
+        let paused = ecs.spawn_with((Position::default(), Velocity { x: 1.0, y: 0.0 }));
+        let running = ecs.spawn_with((Position::default(), Velocity { x: 1.0, y: 0.0 }));
+        let tagged = ecs.spawn_with((Position::default(), Velocity { x: 1.0, y: 0.0 }));
+        ecs.add_tag_type::<First>(tagged);
+        ecs.add_tag_type::<Second>(tagged);
+        ecs.add_tag_type::<Third>(tagged);
+        ecs.add_tag_type::<Fourth>(tagged);
+        assert!(ecs.disable(paused));
+
+        ecs.query_join::<(&mut Position, &Velocity)>()
+            .without_tag_type::<First>()
+            .without_tag_type::<Second>()
+            .without_tag_type::<Third>()
+            .without_tag_type::<Fourth>()
+            .for_each(|_entity, (position, velocity)| position.x += velocity.x);
+        assert_eq!(ecs.get::<Position>(paused).unwrap().x, 0.0);
+        assert_eq!(ecs.get::<Position>(running).unwrap().x, 1.0);
+        assert_eq!(ecs.get::<Position>(tagged).unwrap().x, 0.0);
+
+        let joined: Vec<Entity> = ecs
+            .query_join_ref::<(&Position, &Velocity)>()
+            .without_tag_type::<First>()
+            .without_tag_type::<Second>()
+            .without_tag_type::<Third>()
+            .without_tag_type::<Fourth>()
+            .iter()
+            .map(|(entity, _)| entity)
+            .collect();
+        assert_eq!(joined, vec![running]);
+
+        let routed: Vec<Entity> = ecs
+            .query_ref::<&Position>()
+            .without_tag_set(ecs.tag_set_type::<First>().unwrap())
+            .without_tag_set(ecs.tag_set_type::<Second>().unwrap())
+            .without_tag_set(ecs.tag_set_type::<Third>().unwrap())
+            .without_tag_set(ecs.tag_set_type::<Fourth>().unwrap())
+            .iter()
+            .map(|(entity, _)| entity)
+            .collect();
+        assert_eq!(routed, vec![running]);
+    }
+
     #[test]
     fn test_dyn_ecs_spawn_entities_in_member_world() {
         let mut ecs = DynEcs::new();
@@ -12685,6 +19053,71 @@ mod tests {
             (world, entities)
         }
 
+        #[test]
+        fn test_snapshot_restore_keeps_hierarchy_order() {
+            let mut registry = ComponentRegistry::new();
+            registry.register_serde::<ChildOf>();
+            registry.register_serde::<ChildOrder>();
+            let mut world = DynWorld::from_registry(registry.clone());
+            world.set_change_detection(true);
+            world.structural_logging = true;
+            let mut index = HierarchyIndex::new();
+
+            let parent = world.spawn((ChildOrder(0),));
+            let children: Vec<Entity> = (0..4).map(|_| world.spawn((ChildOrder(0),))).collect();
+            index.sync(&mut world);
+            for &child in children.iter().rev() {
+                index.insert_child_at(&mut world, parent, child, 0).unwrap();
+            }
+            index.sync(&mut world);
+
+            let snapshot = world.snapshot().unwrap();
+            let mut restored = DynWorld::from_snapshot(registry, &snapshot).unwrap();
+            restored.set_change_detection(true);
+            restored.structural_logging = true;
+            assert_eq!(restored.children(parent), children);
+
+            index.sync(&mut restored);
+            assert_eq!(
+                index.children(parent),
+                children.as_slice(),
+                "a reused index notices the restore and rebuilds"
+            );
+            index
+                .move_before(&mut restored, children[3], children[0])
+                .unwrap();
+            index.sync(&mut restored);
+            assert_eq!(index.children(parent)[0], children[3]);
+        }
+
+        #[test]
+        fn test_snapshot_and_delta_keep_disabled_entities() {
+            let mut registry = ComponentRegistry::new();
+            registry.register_serde::<Position>();
+            let mut world = DynWorld::from_registry(registry.clone());
+            world.set_change_detection(true);
+            world.structural_logging = true;
+            let paused = world.spawn((Position::default(),));
+            let running = world.spawn((Position::default(),));
+            world.disable(paused);
+
+            let snapshot = world.snapshot().unwrap();
+            let bytes = postcard::to_allocvec(&snapshot).unwrap();
+            let decoded: DynWorldSnapshot = postcard::from_bytes(&bytes).unwrap();
+            let mut replica = DynWorld::from_snapshot(registry, &decoded).unwrap();
+            assert!(replica.is_disabled(paused));
+            assert_eq!(replica.query_ref::<&Position>().iter().count(), 1);
+
+            let cursor = world.delta_cursor();
+            world.enable(paused);
+            world.disable(running);
+            replica
+                .apply_delta(&world.delta_since(&cursor).unwrap())
+                .unwrap();
+            assert!(!replica.is_disabled(paused));
+            assert!(replica.is_disabled(running));
+        }
+
         #[test]
         fn test_snapshot_round_trip_preserves_state() {
             let (world, entities) = populated_world();
@@ -12724,6 +19157,32 @@ mod tests {
             assert_eq!(respawned.generation, entities[5].generation + 1);
         }
 
+        #[test]
+        fn test_snapshot_carries_pending_reservations() {
+            let (mut world, _entities) = populated_world();
+            let settled = world.reserve_entity();
+            world.spawn((Position::default(),));
+            let pending = world.reserve_entities(2);
+
+            let snapshot = world.snapshot().unwrap();
+            let bytes = postcard::to_allocvec(&snapshot).unwrap();
+            let decoded: DynWorldSnapshot = postcard::from_bytes(&bytes).unwrap();
+            let mut restored = DynWorld::from_snapshot(build_registry(), &decoded).unwrap();
+            assert_eq!(restored.allocator.reserved_count(), 3);
+
+            let fresh = restored.spawn((Position::default(),));
+            assert!(!pending.contains(&fresh) && fresh != settled);
+            restored.apply_commands();
+            world.apply_commands();
+            for entity in [settled, pending[0], pending[1]] {
+                assert!(restored.is_alive(entity));
+                assert_eq!(
+                    restored.component_mask(entity),
+                    world.component_mask(entity)
+                );
+            }
+        }
+
         #[test]
         fn test_snapshot_restored_world_stays_in_lockstep() {
             let (mut original, _) = populated_world();
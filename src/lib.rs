@@ -459,15 +459,26 @@
 
 pub use paste;
 
+pub mod static_query;
+
 #[cfg(feature = "dynamic")]
 pub mod dynamic;
 
 #[cfg(feature = "dynamic")]
 pub mod system_param;
 
+#[cfg(feature = "dynamic")]
+pub mod condition;
+
 #[cfg(feature = "state")]
 pub mod state;
 
+#[cfg(feature = "transform")]
+pub mod transform;
+
+#[cfg(feature = "console")]
+pub mod console;
+
 /// Declares a dynamic world's schema in one place: the mask constants (bits
 /// assigned in declaration order, which is the registration order and
 /// therefore the snapshot schema) and the registration function that builds
@@ -566,9 +577,33 @@ macro_rules! dynamic_schema {
 ///
 /// freecs::impl_component!(Position);
 /// ```
+///
+/// A type with no sensible default takes the `no_default` form, which also
+/// implements [`dynamic::ComponentData`] without one. Such a component always
+/// arrives with a value, by `spawn`, `set`, or a bundle:
+///
+/// ```rust
+/// use freecs::dynamic::DynWorld;
+///
+/// struct TextureHandle(u32);
+///
+/// freecs::impl_component!(no_default TextureHandle);
+///
+/// let mut world = DynWorld::new();
+/// let entity = world.spawn(TextureHandle(3));
+/// assert_eq!(world.get::<TextureHandle>(entity).map(|handle| handle.0), Some(3));
+/// ```
 #[cfg(feature = "dynamic")]
 #[macro_export]
 macro_rules! impl_component {
+    (no_default $($ty:ty),+ $(,)?) => {
+        $(
+            impl $crate::dynamic::ComponentData for $ty {
+                const DEFAULT: ::core::option::Option<fn() -> Self> = ::core::option::Option::None;
+            }
+            impl $crate::dynamic::Component for $ty {}
+        )+
+    };
     ($($ty:ty),+ $(,)?) => {
         $(impl $crate::dynamic::Component for $ty {})+
     };
@@ -583,9 +618,8 @@ macro_rules! impl_component {
 /// field's visibility pass through verbatim. The macro provides `Default`
 /// field by field (do not derive it yourself); `Name::builder()` returns that
 /// default and each field name is a consuming setter returning `Self`, so the
-/// value spawns directly. No `Clone` on the struct is needed; batch spawns and
-/// takes work when the fields are `Clone` and `Default`, which components
-/// already are. Repeating a component type across the flattened set panics on
+/// value spawns directly. No `Clone` on the struct is needed; batch spawns
+/// work when the fields are `Clone`, and takes move the values out. Repeating a component type across the flattened set panics on
 /// spawn.
 ///
 /// ```rust
@@ -690,9 +724,12 @@ macro_rules! bundle {
                 $($crate::dynamic::Bundle::write(self.$field, world, entity);)+
             }
 
-            fn take(world: &mut $crate::dynamic::DynWorld, entity: $crate::Entity) -> Self {
+            fn take(
+                world: &$crate::dynamic::DynWorld,
+                taken: &mut $crate::dynamic::TakenComponents,
+            ) -> Self {
                 Self {
-                    $($field: <$ty as $crate::dynamic::Bundle>::take(world, entity),)+
+                    $($field: <$ty as $crate::dynamic::Bundle>::take(world, taken),)+
                 }
             }
 
@@ -713,9 +750,16 @@ macro_rules! bundle {
                 true $(&& <$ty as $crate::dynamic::Bundle>::present_group(ecs, entity))+
             }
 
-            fn take_group(ecs: &mut $crate::dynamic::DynEcs, entity: $crate::Entity) -> Self {
+            fn lookup_group_masks(ecs: &$crate::dynamic::DynEcs, masks: &mut [u64]) {
+                $(<$ty as $crate::dynamic::Bundle>::lookup_group_masks(ecs, masks);)+
+            }
+
+            fn take_group(
+                ecs: &$crate::dynamic::DynEcs,
+                taken: &mut [$crate::dynamic::TakenComponents],
+            ) -> Self {
                 Self {
-                    $($field: <$ty as $crate::dynamic::Bundle>::take_group(ecs, entity),)+
+                    $($field: <$ty as $crate::dynamic::Bundle>::take_group(ecs, taken),)+
                 }
             }
         }
@@ -913,6 +957,212 @@ macro_rules! dynamic_accessors {
     };
 }
 
+/// Declares a struct of system parameters that a system takes as one
+/// argument, so a group such as `Res<Time>, ResMut<Rng>, EventWriter<Sound>`
+/// is written once rather than re-listed in every system. The struct also
+/// lifts a system past the arity of the built-in runners, since a bundle
+/// counts as a single parameter however many fields it has.
+///
+/// The plain form bundles extract parameters ([`Res`](crate::system_param::Res),
+/// [`ResMut`](crate::system_param::ResMut), their `Option` forms,
+/// [`Local`](crate::system_param::Local), event readers and writers, and
+/// other extract bundles) and implements
+/// [`ExtractParam`](crate::system_param::ExtractParam) over any host that is
+/// both a [`ResourceHost`](crate::dynamic::ResourceHost) and an
+/// [`EventHost`](crate::system_param::EventHost). Name a narrower host bound
+/// with `where W: ...` after the generics, say `where W: ResourceHost` for a
+/// resource-only bundle meant for an engine wrapper. The form ending in
+/// `: queries` bundles [`Query`](crate::system_param::Query) parameters and
+/// other query bundles over a [`DynWorld`](crate::dynamic::DynWorld); its
+/// fields share the world the way several query arguments do, and the bundle
+/// stands either alone as the system's world parameter or beside other
+/// query parameters. Spell query element lifetimes `'static`, as in
+/// `Query<'w, &'static mut Position>`, so the bundle's one lifetime is the
+/// world borrow.
+///
+/// Either form implements
+/// [`SystemParamAccess`](crate::system_param::SystemParamAccess) as the union
+/// of its fields, for conflict checks through
+/// [`SystemAccess`](crate::system_param::SystemAccess).
+///
+/// ```rust
+/// use freecs::Schedule;
+/// use freecs::dynamic::DynWorld;
+/// use freecs::system_param::{EventWriter, Query, Res, ResMut, ScheduleExt, SystemAccess};
+///
+/// #[derive(Default, Clone, Debug)]
+/// struct Position { x: f32 }
+/// #[derive(Default, Clone, Debug)]
+/// struct Velocity { x: f32 }
+/// freecs::impl_component!(Position, Velocity);
+///
+/// struct Time(f32);
+/// struct Steps(u32);
+/// #[derive(Clone)]
+/// struct Moved;
+///
+/// freecs::system_param! {
+///     struct Clock<'a> {
+///         time: Res<'a, Time>,
+///         steps: ResMut<'a, Steps>,
+///         moved: EventWriter<'a, Moved>,
+///     }
+/// }
+///
+/// freecs::system_param! {
+///     struct Motion<'w>: queries {
+///         positions: Query<'w, &'static mut Position>,
+///         velocities: Query<'w, &'static Velocity>,
+///     }
+/// }
+///
+/// fn movement(mut clock: Clock, motion: Motion) {
+///     let mut speed = 0.0;
+///     motion.velocities.for_each(|_entity, velocity| speed += velocity.x);
+///     let step = speed * clock.time.0;
+///     motion.positions.for_each(|_entity, position| position.x += step);
+///     clock.steps.0 += 1;
+///     clock.moved.send(Moved);
+/// }
+///
+/// let mut world = DynWorld::new();
+/// world.insert_resources((Time(0.5), Steps(0)));
+/// let entity = world.spawn((Position { x: 0.0 }, Velocity { x: 4.0 }));
+///
+/// let mut schedule = Schedule::new();
+/// schedule.add_system("movement", movement);
+/// schedule.run(&mut world);
+///
+/// assert_eq!(world.get::<Position>(entity).unwrap().x, 2.0);
+/// assert_eq!(world.resource::<Steps>().unwrap().0, 1);
+/// assert!(SystemAccess::of::<Clock>().conflicts_with(&SystemAccess::of::<Res<Steps>>()));
+/// ```
+#[cfg(feature = "dynamic")]
+#[macro_export]
+macro_rules! system_param {
+    (
+        @extract [$(#[$meta:meta])*] $vis:vis $name:ident<$lt:lifetime>
+        [$host:ident: $($bounds:tt)+]
+        { $([$(#[$fmeta:meta])*] $fvis:vis $field:ident: $ty:ty),+ }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name<$lt> {
+            $($(#[$fmeta])* $fvis $field: $ty),+
+        }
+
+        impl<$lt, $host: $($bounds)+> $crate::system_param::ExtractParam<$host> for $name<$lt> {
+            type State = ($(<$ty as $crate::system_param::ExtractParam<$host>>::State,)+);
+            type Owned = ($(<$ty as $crate::system_param::ExtractParam<$host>>::Owned,)+);
+            type Item<'item> = $name<'item>;
+            fn init() -> Self::State {
+                ($(<$ty as $crate::system_param::ExtractParam<$host>>::init(),)+)
+            }
+            fn extract(state: &mut Self::State, host: &mut $host) -> Self::Owned {
+                $crate::paste::paste! {
+                    let ($([<$field _state>],)+) = state;
+                    ($(
+                        <$ty as $crate::system_param::ExtractParam<$host>>::extract(
+                            [<$field _state>],
+                            host,
+                        ),
+                    )+)
+                }
+            }
+            fn build(owned: &mut Self::Owned) -> $name<'_> {
+                let ($($field,)+) = owned;
+                $name {
+                    $($field: <$ty as $crate::system_param::ExtractParam<$host>>::build($field),)+
+                }
+            }
+            fn apply(state: &mut Self::State, owned: Self::Owned, host: &mut $host) {
+                $crate::paste::paste! {
+                    let ($([<$field _state>],)+) = state;
+                    let ($($field,)+) = owned;
+                    $(
+                        <$ty as $crate::system_param::ExtractParam<$host>>::apply(
+                            [<$field _state>],
+                            $field,
+                            host,
+                        );
+                    )+
+                }
+            }
+        }
+
+        $crate::system_param!(@access $name<$lt> { $($ty),+ });
+    };
+    (@access $name:ident<$lt:lifetime> { $($ty:ty),+ }) => {
+        impl<$lt> $crate::system_param::SystemParamAccess for $name<$lt> {
+            fn add_access(access: &mut $crate::system_param::SystemAccess) {
+                $(<$ty as $crate::system_param::SystemParamAccess>::add_access(access);)+
+            }
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident<$lt:lifetime>: queries {
+            $($(#[$fmeta:meta])* $fvis:vis $field:ident : $ty:ty),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name<$lt> {
+            $($(#[$fmeta])* $fvis $field: $ty),+
+        }
+
+        impl<$lt> $crate::system_param::MultiQueryParam for $name<$lt> {
+            type Item<'world> = $name<'world>;
+            fn build_lazy<'world>(
+                cell: &'world ::core::cell::RefCell<&'world mut $crate::dynamic::DynWorld>,
+            ) -> $name<'world> {
+                $name {
+                    $($field: <$ty as $crate::system_param::MultiQueryParam>::build_lazy(cell),)+
+                }
+            }
+            fn build_shared<'world>(
+                cell: &::std::rc::Rc<::core::cell::RefCell<&'world mut $crate::dynamic::DynWorld>>,
+            ) -> $name<'world> {
+                $name {
+                    $($field: <$ty as $crate::system_param::MultiQueryParam>::build_shared(cell),)+
+                }
+            }
+        }
+
+        impl<$lt> $crate::system_param::WorldParam for $name<$lt> {
+            type Item<'world> = $name<'world>;
+            fn build(world: &mut $crate::dynamic::DynWorld) -> $name<'_> {
+                let cell = ::std::rc::Rc::new(::core::cell::RefCell::new(world));
+                <Self as $crate::system_param::MultiQueryParam>::build_shared(&cell)
+            }
+        }
+
+        $crate::system_param!(@access $name<$lt> { $($ty),+ });
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident<$lt:lifetime> where $host:ident: $first:ident $(+ $rest:ident)* {
+            $($(#[$fmeta:meta])* $fvis:vis $field:ident : $ty:ty),+ $(,)?
+        }
+    ) => {
+        $crate::system_param!(
+            @extract [$(#[$meta])*] $vis $name<$lt> [$host: $first $(+ $rest)* + 'static]
+            { $([$(#[$fmeta])*] $fvis $field: $ty),+ }
+        );
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident<$lt:lifetime> {
+            $($(#[$fmeta:meta])* $fvis:vis $field:ident : $ty:ty),+ $(,)?
+        }
+    ) => {
+        $crate::system_param!(
+            @extract [$(#[$meta])*] $vis $name<$lt>
+            [W: $crate::dynamic::ResourceHost + $crate::system_param::EventHost + 'static]
+            { $([$(#[$fmeta])*] $fvis $field: $ty),+ }
+        );
+    };
+
+}
+
 #[cfg(not(target_family = "wasm"))]
 pub use rayon;
 
@@ -944,20 +1194,140 @@ pub struct EntitySlot {
 /// Liveness is authoritative here: `deallocate` refuses stale or already-freed
 /// handles, so an id can never enter the free list twice and two live entities
 /// can never share an id and generation.
+///
+/// Handles can also be reserved through a shared borrow with
+/// [`reserve`](Self::reserve), from any thread. A reservation is one atomic
+/// counter bump that names the handle the next allocation would have
+/// produced, recycled ids first, so the handle is generation-correct before
+/// it is live. Every `&mut` method settles outstanding reservations before
+/// touching the free list, which keeps the promised ids out of anyone else's
+/// hands; settled handles are live and wait in `settled` until their owner
+/// drains them with [`take_reserved`](Self::take_reserved).
+///
+/// Pending reservations are part of the allocator's state: they are cloned
+/// and serialized with it, so a restored allocator still owes the same
+/// handles and settles them exactly as the original would have.
 #[derive(Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EntityAllocator {
     pub next_id: u32,
     pub free_ids: Vec<(u32, u32)>,
     pub slots: Vec<EntitySlot>,
+    /// How many handles [`reserve`](Self::reserve) has handed out since the
+    /// last settle.
+    #[cfg_attr(feature = "serde", serde(default))]
+    reserved: std::sync::atomic::AtomicU32,
+    /// Reserved handles already made live, in reservation order, that the
+    /// owning world has not yet given rows.
+    #[cfg_attr(feature = "serde", serde(default))]
+    settled: Vec<Entity>,
+}
+
+impl Clone for EntityAllocator {
+    fn clone(&self) -> Self {
+        Self {
+            next_id: self.next_id,
+            free_ids: self.free_ids.clone(),
+            slots: self.slots.clone(),
+            reserved: std::sync::atomic::AtomicU32::new(
+                self.reserved.load(std::sync::atomic::Ordering::Relaxed),
+            ),
+            settled: self.settled.clone(),
+        }
+    }
 }
 
 impl EntityAllocator {
+    /// Reserves a handle through a shared borrow. The handle is exactly the
+    /// one a later allocation would have produced, recycled ids first, so it
+    /// carries the right generation; it is not alive until the reservation
+    /// settles, which any `&mut` method on the allocator does first. Safe to
+    /// call from many threads at once: each call is one atomic update.
+    ///
+    /// # Panics
+    /// Panics when the reservation would run past the `u32` id space.
+    pub fn reserve(&self) -> Entity {
+        let offset = self.claim_reservations(1);
+        self.reserved_handle(offset)
+    }
+
+    /// Reserves `count` handles with one atomic update, appending them to
+    /// `entities` in the order they will settle.
+    ///
+    /// # Panics
+    /// Panics when the reservations would run past the `u32` id space.
+    pub fn reserve_batch(&self, count: usize, entities: &mut Vec<Entity>) {
+        let claimed = u32::try_from(count)
+            .unwrap_or_else(|_| panic!("cannot reserve {count} entities: handles use u32 ids"));
+        let start = self.claim_reservations(claimed);
+        entities.reserve(count);
+        for offset in start..start + count {
+            entities.push(self.reserved_handle(offset));
+        }
+    }
+
+    /// Bumps the reservation counter by `count`, returning the offset of the
+    /// first claimed reservation. Refuses to wrap, which would hand the same
+    /// offsets out twice.
+    fn claim_reservations(&self, count: u32) -> usize {
+        let ordering = std::sync::atomic::Ordering::Relaxed;
+        self.reserved
+            .fetch_update(ordering, ordering, |reserved| reserved.checked_add(count))
+            .unwrap_or_else(|reserved| {
+                panic!(
+                    "cannot reserve {count} more entities: {reserved} reservations are outstanding"
+                )
+            }) as usize
+    }
+
+    /// The handle the `offset`th reservation names: the free list is popped
+    /// from the back, then fresh ids continue from `next_id`, the same order
+    /// [`allocate_batch`](Self::allocate_batch) hands them out in.
+    fn reserved_handle(&self, offset: usize) -> Entity {
+        let recycled = self.free_ids.len();
+        if offset < recycled {
+            let (id, generation) = self.free_ids[recycled - 1 - offset];
+            Entity { id, generation }
+        } else {
+            let id = u32::try_from(offset - recycled)
+                .ok()
+                .and_then(|fresh| self.next_id.checked_add(fresh))
+                .expect("reserved entity ids ran past the u32 id space");
+            Entity { id, generation: 0 }
+        }
+    }
+
+    /// Handles reserved but not yet drained by
+    /// [`take_reserved`](Self::take_reserved), settled or not.
+    pub fn reserved_count(&self) -> usize {
+        self.reserved.load(std::sync::atomic::Ordering::Relaxed) as usize + self.settled.len()
+    }
+
+    /// Makes every outstanding reservation live, moving the handles into
+    /// `settled`. A no-op when nothing is reserved.
+    #[inline]
+    pub fn settle_reservations(&mut self) {
+        let count = std::mem::take(self.reserved.get_mut()) as usize;
+        if count > 0 {
+            let mut settled = std::mem::take(&mut self.settled);
+            self.allocate_batch_settled(count, &mut settled);
+            self.settled = settled;
+        }
+    }
+
+    /// Settles outstanding reservations and drains every reserved handle, in
+    /// reservation order, for the owner to give rows.
+    pub fn take_reserved(&mut self) -> Vec<Entity> {
+        self.settle_reservations();
+        std::mem::take(&mut self.settled)
+    }
+
     /// Forces a handle live at its exact id and generation, the primitive a
     /// replica uses when applying a replicated spawn. Extends the slot
     /// table as needed, removes the id from the free list, and keeps
-    /// `next_id` ahead of it.
+    /// `next_id` ahead of it; ids it jumps over go on the free list.
     pub fn revive(&mut self, entity: Entity) {
+        self.settle_reservations();
         let index = entity.id as usize;
         if self.slots.len() <= index {
             self.slots.resize(
@@ -974,12 +1344,34 @@ impl EntityAllocator {
         };
         self.free_ids.retain(|&(id, _)| id != entity.id);
         if self.next_id <= entity.id {
+            self.free_ids
+                .extend((self.next_id..entity.id).rev().map(|id| (id, 0)));
             self.next_id = entity.id + 1;
         }
     }
 
+    /// [`revive`](Self::revive), but only for a handle this allocator has
+    /// never issued at that generation or later: its id is past every id
+    /// handed out, or free with `entity.generation` at least the one the
+    /// free list would issue next. Returns false, changing nothing but
+    /// settling reservations, when the id is live or was retired at a later
+    /// generation, since reviving it would bring retired handles back.
+    pub fn try_revive(&mut self, entity: Entity) -> bool {
+        self.settle_reservations();
+        if entity.id < self.next_id
+            && !self.free_ids.iter().any(|&(id, next_generation)| {
+                id == entity.id && entity.generation >= next_generation
+            })
+        {
+            return false;
+        }
+        self.revive(entity);
+        true
+    }
+
     #[inline]
     pub fn allocate(&mut self) -> Entity {
+        self.settle_reservations();
         let entity = if let Some((id, next_generation)) = self.free_ids.pop() {
             Entity {
                 id,
@@ -1005,6 +1397,11 @@ impl EntityAllocator {
     /// Fresh ids are contiguous, so their liveness slots are written with one
     /// bulk fill instead of a store per entity.
     pub fn allocate_batch(&mut self, count: usize, entities: &mut Vec<Entity>) {
+        self.settle_reservations();
+        self.allocate_batch_settled(count, entities);
+    }
+
+    fn allocate_batch_settled(&mut self, count: usize, entities: &mut Vec<Entity>) {
         entities.reserve(count);
         let recycled = count.min(self.free_ids.len());
         for _ in 0..recycled {
@@ -1045,6 +1442,7 @@ impl EntityAllocator {
     /// generations and double frees, leaving the allocator untouched.
     #[inline]
     pub fn deallocate(&mut self, entity: Entity) -> bool {
+        self.settle_reservations();
         if !self.is_alive(entity) {
             return false;
         }
@@ -1111,6 +1509,68 @@ impl EntityLocations {
             loc.allocated = false;
         }
     }
+
+    pub fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::of_vec(&self.locations)
+    }
+
+    /// Returns spare capacity. Every slot is kept, since a slot past the
+    /// live ids may still carry a retired generation.
+    pub fn shrink_to_fit(&mut self) {
+        self.locations.shrink_to_fit();
+    }
+}
+
+/// Live and allocated bytes of one storage structure or a sum of several,
+/// as reported by the dynamic world's `stats`. Counts the structure's own
+/// buffers only: heap memory owned by the values inside them, such as a
+/// `String`'s bytes, is not included.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    pub live_bytes: usize,
+    pub capacity_bytes: usize,
+}
+
+impl MemoryUsage {
+    pub fn of_vec<T>(values: &Vec<T>) -> Self {
+        Self::of_elements::<T>(values.len(), values.capacity())
+    }
+
+    pub fn of_elements<T>(len: usize, capacity: usize) -> Self {
+        let size = std::mem::size_of::<T>();
+        MemoryUsage {
+            live_bytes: len * size,
+            capacity_bytes: capacity * size,
+        }
+    }
+
+    /// Allocated bytes holding no live value, what `shrink_to_fit` returns.
+    pub fn wasted_bytes(&self) -> usize {
+        self.capacity_bytes - self.live_bytes
+    }
+}
+
+impl std::ops::Add for MemoryUsage {
+    type Output = MemoryUsage;
+
+    fn add(self, other: MemoryUsage) -> MemoryUsage {
+        MemoryUsage {
+            live_bytes: self.live_bytes + other.live_bytes,
+            capacity_bytes: self.capacity_bytes + other.capacity_bytes,
+        }
+    }
+}
+
+impl std::ops::AddAssign for MemoryUsage {
+    fn add_assign(&mut self, other: MemoryUsage) {
+        *self = *self + other;
+    }
+}
+
+impl std::iter::Sum for MemoryUsage {
+    fn sum<I: Iterator<Item = MemoryUsage>>(iter: I) -> MemoryUsage {
+        iter.fold(MemoryUsage::default(), |total, usage| total + usage)
+    }
 }
 
 /// Returns true if `tick` was stamped after `since_tick`, treating ticks as a
@@ -1216,6 +1676,24 @@ impl SparseTagSet {
         self.dense.clear();
         self.sparse.fill(SPARSE_TAG_ABSENT);
     }
+
+    pub fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::of_vec(&self.dense) + MemoryUsage::of_vec(&self.sparse)
+    }
+
+    /// Returns spare capacity, and drops the sparse tail past the highest
+    /// member id, which holds nothing but absent markers.
+    pub fn shrink_to_fit(&mut self) {
+        let end = self
+            .dense
+            .iter()
+            .map(|entity| entity.id as usize + 1)
+            .max()
+            .unwrap_or(0);
+        self.sparse.truncate(end);
+        self.sparse.shrink_to_fit();
+        self.dense.shrink_to_fit();
+    }
 }
 
 /// Archetype graph edges for one table: which table an entity lands in when a
@@ -1323,11 +1801,20 @@ pub enum StructuralChangeKind {
     ComponentsRemoved,
     TagsAdded,
     TagsRemoved,
+    Disabled,
+    Enabled,
+    /// A batch add: `entity` is the first entity moved and the full list
+    /// lives in the matching [`StructuralBatch`].
+    ComponentsAddedBatch,
+    /// A batch remove, laid out like [`Self::ComponentsAddedBatch`].
+    ComponentsRemovedBatch,
 }
 
 /// One structural mutation recorded by the world: a spawn, a despawn, or a
 /// component add or remove. `mask` holds the components involved: the full
-/// mask for spawns and despawns, the delta for adds and removes. Consumers
+/// mask for spawns and despawns, the delta for adds and removes. Tag flips
+/// and enable/disable carry the tag's bit instead (the group tag index for
+/// a dynamic group, zero for the macro worlds' built-in disabled set). Consumers
 /// track their own `sequence` cursor via `structural_changes_since` and the
 /// owner trims consumed entries with `trim_structural_log`.
 #[derive(Debug, Clone, Copy)]
@@ -1339,6 +1826,137 @@ pub struct StructuralChange {
     pub mask: u64,
 }
 
+/// The entity list behind a batch entry in the structural log, keyed by the
+/// entry's `sequence`. A batch add or remove writes one log entry plus one of
+/// these rather than one entry per entity; consumers expand it with the
+/// world's `change_entities`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StructuralBatch {
+    pub sequence: u64,
+    pub entities: Vec<Entity>,
+}
+
+/// Sorts `(table, row)` pairs for a batch migration: grouped by table, rows
+/// descending, duplicates dropped.
+#[doc(hidden)]
+pub fn sort_batch_rows(rows: &mut Vec<(usize, usize)>) {
+    rows.sort_unstable_by(|left, right| left.0.cmp(&right.0).then(right.1.cmp(&left.1)));
+    rows.dedup();
+}
+
+/// Reorders `values` in place so row `i` ends up holding what row `order[i]`
+/// held, following each permutation cycle with swaps. `order` must be a
+/// permutation of `0..values.len()`; an empty `values` (a tick column with
+/// change detection off) is left alone.
+#[doc(hidden)]
+pub fn apply_row_order<T>(values: &mut [T], order: &[usize]) {
+    if values.is_empty() {
+        return;
+    }
+    debug_assert_eq!(values.len(), order.len(), "row order must cover every row");
+    apply_row_order_with(order, |current, source| values.swap(current, source));
+}
+
+/// The cycle walk behind [`apply_row_order`], for storage that swaps rows
+/// itself rather than through a slice. `swap` is only called with two
+/// distinct rows.
+pub(crate) fn apply_row_order_with(order: &[usize], mut swap: impl FnMut(usize, usize)) {
+    let mut placed = vec![false; order.len()];
+    for start in 0..order.len() {
+        let mut current = start;
+        while !placed[current] {
+            placed[current] = true;
+            let source = order[current];
+            if source == start {
+                break;
+            }
+            swap(current, source);
+            current = source;
+        }
+    }
+}
+
+/// Rows per partial in the parallel reductions (`par_fold` and friends).
+/// A partial never spans a block boundary (`row % PAR_REDUCE_BLOCK == 0`)
+/// or a skipped row, so the partition, and with it the exact order partials
+/// are combined in, depends only on which rows match and never on how many
+/// threads ran the fold.
+pub const PAR_REDUCE_BLOCK: usize = 1024;
+
+/// Folds the matching rows of one table, visited in ascending row order,
+/// into ordered partials under the [`PAR_REDUCE_BLOCK`] partition rule.
+#[doc(hidden)]
+pub struct RowPartials<A> {
+    partials: Vec<A>,
+    current: Option<A>,
+    next_row: usize,
+}
+
+impl<A> Default for RowPartials<A> {
+    fn default() -> Self {
+        Self {
+            partials: Vec::new(),
+            current: None,
+            next_row: 0,
+        }
+    }
+}
+
+impl<A> RowPartials<A> {
+    pub fn fold(&mut self, row: usize, identity: impl FnOnce() -> A, step: impl FnOnce(A) -> A) {
+        let accumulator = match self.current.take() {
+            Some(accumulator) if row == self.next_row && !row.is_multiple_of(PAR_REDUCE_BLOCK) => {
+                accumulator
+            }
+            Some(accumulator) => {
+                self.partials.push(accumulator);
+                identity()
+            }
+            None => identity(),
+        };
+        self.current = Some(step(accumulator));
+        self.next_row = row + 1;
+    }
+
+    pub fn finish(mut self) -> Vec<A> {
+        if let Some(accumulator) = self.current.take() {
+            self.partials.push(accumulator);
+        }
+        self.partials
+    }
+}
+
+/// Combines ordered partials left to right, or returns `identity()` when
+/// nothing matched.
+#[doc(hidden)]
+pub fn combine_partials<A>(
+    partials: impl IntoIterator<Item = A>,
+    identity: impl FnOnce() -> A,
+    combine: impl FnMut(A, A) -> A,
+) -> A {
+    partials
+        .into_iter()
+        .reduce(combine)
+        .unwrap_or_else(identity)
+}
+
+/// The entities one structural entry covers: the batch list for batch kinds,
+/// otherwise just the entry's own entity. Shared by every world kind, which
+/// each keep their batches sorted by sequence.
+pub fn structural_change_entities<'a>(
+    batches: &'a [StructuralBatch],
+    change: &'a StructuralChange,
+) -> &'a [Entity] {
+    match change.kind {
+        StructuralChangeKind::ComponentsAddedBatch
+        | StructuralChangeKind::ComponentsRemovedBatch => batches
+            .binary_search_by_key(&change.sequence, |batch| batch.sequence)
+            .map_or(&[][..], |index| &batches[index].entities),
+        _ => std::slice::from_ref(&change.entity),
+    }
+}
+
 /// Backstop for event channels whose events are never consumed or expired.
 /// When the buffer reaches this length the oldest events are dropped, so a
 /// channel with no consumer stays bounded instead of leaking.
@@ -1508,6 +2126,14 @@ impl<T> EventChannel<T> {
         self.trim(sequence);
     }
 
+    pub fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::of_vec(&self.events)
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.events.shrink_to_fit();
+    }
+
     /// Returns the number of buffered events.
     pub fn len(&self) -> usize {
         self.events.len()
@@ -1519,103 +2145,221 @@ impl<T> EventChannel<T> {
     }
 }
 
+/// A failing system's error, boxed with its original type so a handler can
+/// downcast it.
+pub type BoxedSystemError = Box<dyn std::error::Error + Send + Sync>;
+
+/// The return type of a schedule's lowered systems: `Ok(())`, or the
+/// failing system's error.
+pub type SystemResult = Result<(), BoxedSystemError>;
+
+/// A value a system may return. `()` is an infallible system; a
+/// `Result<(), E>` whose error converts into a [`BoxedSystemError`] (any
+/// `Error + Send + Sync`, a `String`, or a `&str`) is a fallible one, and
+/// its error goes to the schedule's [`ErrorPolicy`].
+pub trait SystemOutput {
+    /// Lowers the output to a [`SystemResult`].
+    fn into_system_result(self) -> SystemResult;
+}
+
+impl SystemOutput for () {
+    fn into_system_result(self) -> SystemResult {
+        Ok(())
+    }
+}
+
+impl<E: Into<BoxedSystemError>> SystemOutput for Result<(), E> {
+    fn into_system_result(self) -> SystemResult {
+        self.map_err(Into::into)
+    }
+}
+
+/// A failure returned by a fallible system, tagged with the name it was
+/// registered under. The system's own error is kept, not flattened to a
+/// string, so `error.downcast_ref::<E>()` recovers it. It is shared, so the
+/// same failure can sit in [`Schedule::errors`] and travel the world's event
+/// bus as an event.
+#[derive(Clone, Debug)]
+pub struct SystemError {
+    pub system: &'static str,
+    pub error: std::sync::Arc<dyn std::error::Error + Send + Sync>,
+}
+
+impl SystemError {
+    /// Tags a system's boxed error with the system's name.
+    pub fn new(system: &'static str, error: BoxedSystemError) -> Self {
+        Self {
+            system,
+            error: error.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for SystemError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "system \"{}\" failed: {}", self.system, self.error)
+    }
+}
+
+impl std::error::Error for SystemError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&*self.error)
+    }
+}
+
+/// A user handler for [`ErrorPolicy::Handler`], given the world and the error.
+pub type ErrorHandler<W> = Box<dyn FnMut(&mut W, &SystemError) + Send>;
+
+/// What a [`Schedule`] does when a system returns an error. Every policy
+/// also records the error in [`Schedule::errors`] for the pass. Nothing is
+/// printed; a host with an event bus can take failures as events through
+/// `ErrorPolicy::send_events` (with the `dynamic` feature), and any host can
+/// forward them from a [`Handler`](Self::Handler).
+#[derive(Default)]
+pub enum ErrorPolicy<W> {
+    /// Records the error in [`Schedule::errors`] and runs the remaining
+    /// systems. The default.
+    #[default]
+    Log,
+    /// Skips the failing system on later passes until
+    /// [`Schedule::enable`] turns it back on.
+    Disable,
+    /// Stops the pass; the systems after the failing one do not run.
+    Abort,
+    /// Hands the error and the world to a user handler, then continues.
+    Handler(ErrorHandler<W>),
+}
+
 struct ScheduleEntry<W> {
     name: &'static str,
-    system: Box<dyn FnMut(&mut W) + Send>,
+    system: Box<dyn FnMut(&mut W) -> SystemResult + Send>,
+    disabled: bool,
+}
+
+impl<W> ScheduleEntry<W> {
+    fn new<F, Out>(name: &'static str, mut system: F) -> Self
+    where
+        F: FnMut(&mut W) -> Out + Send + 'static,
+        Out: SystemOutput,
+    {
+        Self {
+            name,
+            system: Box::new(move |world: &mut W| system(world).into_system_result()),
+            disabled: false,
+        }
+    }
 }
 
 pub struct Schedule<W> {
     entries: Vec<ScheduleEntry<W>>,
+    error_policy: ErrorPolicy<W>,
+    errors: Vec<SystemError>,
 }
 
 impl<W> Schedule<W> {
     pub fn new() -> Self {
         Self {
             entries: Vec::new(),
+            error_policy: ErrorPolicy::Log,
+            errors: Vec::new(),
         }
     }
 
-    pub fn push<F>(&mut self, name: &'static str, system: F) -> &mut Self
+    /// Sets what happens when a system returns an error. Applies to every
+    /// system in the schedule from the next pass on.
+    pub fn set_error_policy(&mut self, policy: ErrorPolicy<W>) -> &mut Self {
+        self.error_policy = policy;
+        self
+    }
+
+    /// The errors systems returned during the most recent [`run`](Self::run),
+    /// in the order they occurred. Cleared at the start of each run.
+    pub fn errors(&self) -> &[SystemError] {
+        &self.errors
+    }
+
+    pub fn push<F, Out>(&mut self, name: &'static str, system: F) -> &mut Self
     where
-        F: FnMut(&mut W) + Send + 'static,
+        F: FnMut(&mut W) -> Out + Send + 'static,
+        Out: SystemOutput,
     {
         self.assert_unique(name);
-        self.entries.push(ScheduleEntry {
-            name,
-            system: Box::new(system),
-        });
+        self.entries.push(ScheduleEntry::new(name, system));
         self
     }
 
-    pub fn push_readonly<F>(&mut self, name: &'static str, mut system: F) -> &mut Self
+    pub fn push_readonly<F, Out>(&mut self, name: &'static str, mut system: F) -> &mut Self
     where
-        F: FnMut(&W) + Send + 'static,
+        F: FnMut(&W) -> Out + Send + 'static,
+        Out: SystemOutput,
     {
-        self.assert_unique(name);
-        self.entries.push(ScheduleEntry {
-            name,
-            system: Box::new(move |world: &mut W| {
-                system(&*world);
-            }),
-        });
-        self
+        self.push(name, move |world: &mut W| system(&*world))
     }
 
     /// Adds a system that runs only when the condition holds. The condition
     /// reads the world at each pass; a false skips the system for that pass
     /// without removing it from the schedule.
-    pub fn push_if<C, F>(&mut self, name: &'static str, condition: C, mut system: F) -> &mut Self
+    pub fn push_if<C, F, Out>(
+        &mut self,
+        name: &'static str,
+        condition: C,
+        mut system: F,
+    ) -> &mut Self
     where
         C: Fn(&W) -> bool + Send + 'static,
-        F: FnMut(&mut W) + Send + 'static,
+        F: FnMut(&mut W) -> Out + Send + 'static,
+        Out: SystemOutput,
     {
         self.push(name, move |world: &mut W| {
             if condition(world) {
-                system(world);
+                system(world).into_system_result()
+            } else {
+                Ok(())
             }
         })
     }
 
-    pub fn insert_before<F>(&mut self, target: &str, name: &'static str, system: F) -> &mut Self
+    pub fn insert_before<F, Out>(
+        &mut self,
+        target: &str,
+        name: &'static str,
+        system: F,
+    ) -> &mut Self
     where
-        F: FnMut(&mut W) + Send + 'static,
+        F: FnMut(&mut W) -> Out + Send + 'static,
+        Out: SystemOutput,
     {
         self.assert_unique(name);
         let index = self.index_of_or_panic(target, "insert_before");
-        self.entries.insert(
-            index,
-            ScheduleEntry {
-                name,
-                system: Box::new(system),
-            },
-        );
+        self.entries.insert(index, ScheduleEntry::new(name, system));
         self
     }
 
-    pub fn insert_after<F>(&mut self, target: &str, name: &'static str, system: F) -> &mut Self
+    pub fn insert_after<F, Out>(&mut self, target: &str, name: &'static str, system: F) -> &mut Self
     where
-        F: FnMut(&mut W) + Send + 'static,
+        F: FnMut(&mut W) -> Out + Send + 'static,
+        Out: SystemOutput,
     {
         self.assert_unique(name);
         let index = self.index_of_or_panic(target, "insert_after");
-        self.entries.insert(
-            index + 1,
-            ScheduleEntry {
-                name,
-                system: Box::new(system),
-            },
-        );
+        self.entries
+            .insert(index + 1, ScheduleEntry::new(name, system));
         self
     }
 
-    pub fn replace<F>(&mut self, name: &str, system: F) -> &mut Self
+    /// Swaps the system registered under `name` for a new one, keeping its
+    /// position. A system the [`ErrorPolicy::Disable`] policy switched off
+    /// is switched back on.
+    pub fn replace<F, Out>(&mut self, name: &str, system: F) -> &mut Self
     where
-        F: FnMut(&mut W) + Send + 'static,
+        F: FnMut(&mut W) -> Out + Send + 'static,
+        Out: SystemOutput,
     {
         let index = self
             .index_of(name)
             .unwrap_or_else(|| panic!("Schedule::replace: system \"{name}\" not found"));
-        self.entries[index].system = Box::new(system);
+        let name = self.entries[index].name;
+        self.entries[index] = ScheduleEntry::new(name, system);
         self
     }
 
@@ -1629,6 +2373,26 @@ impl<W> Schedule<W> {
         self.entries.iter().any(|entry| entry.name == name)
     }
 
+    /// Whether the system under `name` is switched off, by
+    /// [`ErrorPolicy::Disable`] or [`disable`](Self::disable).
+    pub fn is_disabled(&self, name: &str) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| entry.name == name && entry.disabled)
+    }
+
+    /// Switches a system off without removing it. Returns `false` when no
+    /// system has that name.
+    pub fn disable(&mut self, name: &str) -> bool {
+        self.set_disabled(name, true)
+    }
+
+    /// Switches a disabled system back on. Returns `false` when no system has
+    /// that name.
+    pub fn enable(&mut self, name: &str) -> bool {
+        self.set_disabled(name, false)
+    }
+
     pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
         self.entries.iter().map(|entry| entry.name)
     }
@@ -1641,9 +2405,44 @@ impl<W> Schedule<W> {
         self.entries.is_empty()
     }
 
+    /// Runs every enabled system in order, routing each returned error
+    /// through the schedule's [`ErrorPolicy`].
     pub fn run(&mut self, world: &mut W) {
+        self.errors.clear();
         for entry in &mut self.entries {
-            (entry.system)(world);
+            if entry.disabled {
+                continue;
+            }
+            let Err(error) = (entry.system)(world) else {
+                continue;
+            };
+            let error = SystemError::new(entry.name, error);
+            let abort = match &mut self.error_policy {
+                ErrorPolicy::Log => false,
+                ErrorPolicy::Disable => {
+                    entry.disabled = true;
+                    false
+                }
+                ErrorPolicy::Abort => true,
+                ErrorPolicy::Handler(handler) => {
+                    handler(world, &error);
+                    false
+                }
+            };
+            self.errors.push(error);
+            if abort {
+                break;
+            }
+        }
+    }
+
+    fn set_disabled(&mut self, name: &str, disabled: bool) -> bool {
+        match self.index_of(name) {
+            Some(index) => {
+                self.entries[index].disabled = disabled;
+                true
+            }
+            None => false,
         }
     }
 
@@ -1678,11 +2477,41 @@ impl<W> Default for Schedule<W> {
 /// inspectable through [`stages`](Self::stages).
 pub struct Stages<W> {
     pub stages: Vec<(&'static str, Schedule<W>)>,
+    conditions: Vec<(&'static str, StageCondition<W>)>,
 }
 
+type StageCondition<W> = Box<dyn FnMut(&mut W) -> bool + Send>;
+
 impl<W> Stages<W> {
     pub fn new() -> Self {
-        Self { stages: Vec::new() }
+        Self {
+            stages: Vec::new(),
+            conditions: Vec::new(),
+        }
+    }
+
+    /// Runs stage `name` only on passes where `condition` holds, replacing
+    /// any earlier condition on it. A false skips every system in the stage
+    /// for that pass. Panics like [`stage_mut`](Self::stage_mut) when the
+    /// stage is not declared.
+    pub fn set_condition<C>(&mut self, name: &str, condition: C) -> &mut Self
+    where
+        C: FnMut(&mut W) -> bool + Send + 'static,
+    {
+        let name = self.stages[self.stage_index(name)].0;
+        self.conditions
+            .retain(|(stage_name, _)| *stage_name != name);
+        self.conditions.push((name, Box::new(condition)));
+        self
+    }
+
+    /// Removes the condition on stage `name`, so it runs every pass again.
+    /// Returns `false` when the stage had none.
+    pub fn clear_condition(&mut self, name: &str) -> bool {
+        let len_before = self.conditions.len();
+        self.conditions
+            .retain(|(stage_name, _)| *stage_name != name);
+        self.conditions.len() != len_before
     }
 
     /// Appends a stage at the end of the run order. Panics on a duplicate
@@ -1701,12 +2530,17 @@ impl<W> Stages<W> {
     /// declared stage list when the stage does not exist, so a part pushing
     /// into a missing stage fails at wiring time, not silently.
     pub fn stage_mut(&mut self, name: &str) -> &mut Schedule<W> {
+        let index = self.stage_index(name);
+        &mut self.stages[index].1
+    }
+
+    fn stage_index(&self, name: &str) -> usize {
         match self
             .stages
             .iter()
             .position(|(stage_name, _)| *stage_name == name)
         {
-            Some(index) => &mut self.stages[index].1,
+            Some(index) => index,
             None => {
                 let declared: Vec<&'static str> = self
                     .stages
@@ -1718,17 +2552,35 @@ impl<W> Stages<W> {
         }
     }
 
-    /// Runs every stage in declaration order.
+    /// Runs every stage in declaration order, skipping a stage whose
+    /// condition does not hold this pass.
     pub fn run(&mut self, world: &mut W) {
-        for (_name, schedule) in &mut self.stages {
-            schedule.run(world);
+        for (name, schedule) in &mut self.stages {
+            if Self::condition_holds(&mut self.conditions, name, world) {
+                schedule.run(world);
+            }
         }
     }
 
     /// Runs one stage by name, for loops that drive stages on different
-    /// cadences. Panics like [`stage_mut`](Self::stage_mut) when missing.
+    /// cadences, subject to the stage's condition. Panics like
+    /// [`stage_mut`](Self::stage_mut) when missing.
     pub fn run_stage(&mut self, name: &str, world: &mut W) {
-        self.stage_mut(name).run(world);
+        let index = self.stage_index(name);
+        if Self::condition_holds(&mut self.conditions, name, world) {
+            self.stages[index].1.run(world);
+        }
+    }
+
+    fn condition_holds(
+        conditions: &mut [(&'static str, StageCondition<W>)],
+        name: &str,
+        world: &mut W,
+    ) -> bool {
+        conditions
+            .iter_mut()
+            .find(|(stage_name, _)| *stage_name == name)
+            .is_none_or(|(_, condition)| condition(world))
     }
 }
 
@@ -2055,6 +2907,142 @@ macro_rules! ecs_kernel_impl {
                 }
             }
 
+            /// One table's columns split for typed tuple queries: each
+            /// column the table carries, claimable once.
+            #[allow(unused)]
+            pub struct [<$world Columns>]<'a> {
+                $($(#[$comp_attr])* pub $name: Option<$crate::static_query::ColumnMut<'a, $type>>,)*
+                marker: std::marker::PhantomData<&'a ()>,
+            }
+
+            /// The shared form of [<$world Columns>], copied into every
+            /// element of a read-only typed query.
+            #[allow(unused)]
+            #[derive(Clone, Copy)]
+            pub struct [<$world ColumnsRef>]<'a> {
+                $($(#[$comp_attr])* pub $name: Option<$crate::static_query::ColumnRef<'a, $type>>,)*
+                marker: std::marker::PhantomData<&'a ()>,
+            }
+
+            impl $crate::static_query::StaticTable for [<$world ComponentArrays>] {
+                type Columns<'a> = [<$world Columns>]<'a>;
+                type ColumnsRef<'a> = [<$world ColumnsRef>]<'a>;
+
+                fn table_mask(&self) -> u64 {
+                    self.mask
+                }
+
+                fn columns_mut(&mut self) -> (&[$crate::Entity], [<$world Columns>]<'_>) {
+                    let mask = self.mask;
+                    let Self {
+                        entity_indices,
+                        $(
+                            $(#[$comp_attr])* $name,
+                            $(#[$comp_attr])* [<$name _changed>],
+                            $(#[$comp_attr])* [<$name _peak_changed>],
+                        )*
+                        ..
+                    } = self;
+                    let columns = [<$world Columns>] {
+                        $(
+                            $(#[$comp_attr])*
+                            $name: (mask & $mask != 0).then(|| $crate::static_query::ColumnMut {
+                                data: $name.as_mut_slice(),
+                                changed: [<$name _changed>].as_mut_slice(),
+                                peak_changed: [<$name _peak_changed>],
+                            }),
+                        )*
+                        marker: std::marker::PhantomData,
+                    };
+                    (entity_indices, columns)
+                }
+
+                fn columns_ref(&self) -> (&[$crate::Entity], [<$world ColumnsRef>]<'_>) {
+                    let mask = self.mask;
+                    let columns = [<$world ColumnsRef>] {
+                        $(
+                            $(#[$comp_attr])*
+                            $name: (mask & $mask != 0).then(|| $crate::static_query::ColumnRef {
+                                data: self.$name.as_slice(),
+                                changed: self.[<$name _changed>].as_slice(),
+                            }),
+                        )*
+                        marker: std::marker::PhantomData,
+                    };
+                    (&self.entity_indices, columns)
+                }
+            }
+
+            $(
+                $(#[$comp_attr])*
+                impl $crate::static_query::StaticColumn<
+                    [<$world ComponentArrays>],
+                    $crate::static_query::ColumnMarker<{ $mask }>,
+                > for $type {
+                    const MASK: u64 = $mask;
+
+                    fn take<'a>(
+                        columns: &mut [<$world Columns>]<'a>,
+                    ) -> Option<$crate::static_query::ColumnMut<'a, Self>>
+                    where
+                        [<$world ComponentArrays>]: 'a,
+                    {
+                        columns.$name.take()
+                    }
+
+                    fn get<'a>(
+                        columns: [<$world ColumnsRef>]<'a>,
+                    ) -> Option<$crate::static_query::ColumnRef<'a, Self>>
+                    where
+                        [<$world ComponentArrays>]: 'a,
+                    {
+                        columns.$name
+                    }
+                }
+            )*
+
+            /// One run of aligned rows from a matched table, handed out by
+            /// `for_each_chunk`. Every column slice covers the same rows as
+            /// `entities`; columns the table lacks are empty. Writes through
+            /// these slices are not stamped, like other raw column loops.
+            #[allow(unused)]
+            pub struct [<$world Chunk>]<'a> {
+                pub entities: &'a [$crate::Entity],
+                $($(#[$comp_attr])* pub $name: &'a mut [$type],)*
+            }
+
+            #[allow(unused)]
+            impl<'a> [<$world Chunk>]<'a> {
+                pub fn len(&self) -> usize {
+                    self.entities.len()
+                }
+
+                pub fn is_empty(&self) -> bool {
+                    self.entities.is_empty()
+                }
+
+                fn split_at(self, mid: usize) -> (Self, Self) {
+                    let (left_entities, right_entities) = self.entities.split_at(mid);
+                    $(
+                        $(#[$comp_attr])*
+                        let [<$name _split>] = {
+                            let len = self.$name.len();
+                            self.$name.split_at_mut(mid.min(len))
+                        };
+                    )*
+                    (
+                        Self {
+                            entities: left_entities,
+                            $($(#[$comp_attr])* $name: [<$name _split>].0,)*
+                        },
+                        Self {
+                            entities: right_entities,
+                            $($(#[$comp_attr])* $name: [<$name _split>].1,)*
+                        },
+                    )
+                }
+            }
+
             #[allow(unused)]
             fn [<get_component_index_ $world:snake>](mask: u64) -> Option<usize> {
                 match mask {
@@ -2150,6 +3138,82 @@ macro_rules! ecs_kernel_impl {
                 }
             }
 
+            /// Moves many rows of one table at once, column by column. `rows`
+            /// must be distinct and sorted descending, so every swap-remove
+            /// pulls its replacement from past the rows still to move and a
+            /// run at the table's tail is a string of pops.
+            #[allow(unused)]
+            fn [<move_entities_ $world:snake>](
+                world: &mut $world,
+                from_table: usize,
+                rows: &[usize],
+                to_table: usize,
+            ) {
+                let tick = world.current_tick;
+                {
+                    let [source, destination] = world
+                        .tables
+                        .get_disjoint_mut([from_table, to_table])
+                        .expect("batch migration source and destination must differ");
+                    $(
+                        $(#[$comp_attr])*
+                        {
+                            let in_source = source.mask & $mask != 0;
+                            if destination.mask & $mask != 0 {
+                                if in_source {
+                                    for &row in rows {
+                                        destination.$name.push(source.$name.swap_remove(row));
+                                        source.[<$name _changed>].swap_remove(row);
+                                    }
+                                } else {
+                                    let length = destination.$name.len() + rows.len();
+                                    destination.$name.resize_with(length, Default::default);
+                                }
+                                let length = destination.$name.len();
+                                destination.[<$name _changed>].resize(length, tick);
+                                destination.[<$name _peak_changed>] = tick;
+                            } else if in_source {
+                                for &row in rows {
+                                    source.$name.swap_remove(row);
+                                    source.[<$name _changed>].swap_remove(row);
+                                }
+                            }
+                        }
+                    )*
+                }
+                for &row in rows {
+                    let entity = world.tables[from_table].entity_indices.swap_remove(row);
+                    let destination = &mut world.tables[to_table].entity_indices;
+                    destination.push(entity);
+                    let new_index = destination.len() - 1;
+                    [<insert_location_ $world:snake>](&mut world.entity_locations, entity, (to_table, new_index));
+                    if let Some(&swapped) = world.tables[from_table].entity_indices.get(row) {
+                        [<insert_location_ $world:snake>](&mut world.entity_locations, swapped, (from_table, row));
+                    }
+                }
+            }
+
+            /// Permutes one table so row `i` holds what row `order[i]` held,
+            /// across every column and its ticks, then rewrites locations.
+            #[allow(unused)]
+            fn [<reorder_table_ $world:snake>](world: &mut $world, table_index: usize, order: &[usize]) {
+                if order.iter().enumerate().all(|(row, &source)| row == source) {
+                    return;
+                }
+                let table = &mut world.tables[table_index];
+                $(
+                    $(#[$comp_attr])*
+                    {
+                        $crate::apply_row_order(&mut table.$name, order);
+                        $crate::apply_row_order(&mut table.[<$name _changed>], order);
+                    }
+                )*
+                $crate::apply_row_order(&mut table.entity_indices, order);
+                for (row, &entity) in table.entity_indices.iter().enumerate() {
+                    [<insert_location_ $world:snake>](&mut world.entity_locations, entity, (table_index, row));
+                }
+            }
+
             #[allow(unused)]
             fn [<get_or_create_table_ $world:snake>](world: &mut $world, mask: u64) -> usize {
                 debug_assert_eq!(
@@ -2198,12 +3262,24 @@ macro_rules! ecs_kernel_impl {
                 )
             }
 
+            /// The disabled set a walk must skip, or `None` when the caller
+            /// opted in or nothing is disabled, which keeps the walk on its
+            /// unfiltered path.
+            #[allow(unused)]
+            fn [<skip_disabled_ $world:snake>](
+                disabled: &$crate::SparseTagSet,
+                include_disabled: bool,
+            ) -> Option<&$crate::SparseTagSet> {
+                (!include_disabled && !disabled.is_empty()).then_some(disabled)
+            }
+
             #[allow(unused)]
             fn [<tables_for_each_ $world:snake>]<F, P>(
                 tables: &[[<$world ComponentArrays>]],
                 query_cache: &std::collections::HashMap<u64, Vec<usize>>,
                 include: u64,
                 exclude: u64,
+                disabled: Option<&$crate::SparseTagSet>,
                 filter: P,
                 mut f: F,
             ) where
@@ -2217,7 +3293,7 @@ macro_rules! ecs_kernel_impl {
                             continue;
                         }
                         for (index, &entity) in table.entity_indices.iter().enumerate() {
-                            if filter(entity) {
+                            if filter(entity) && !disabled.is_some_and(|set| set.contains(entity)) {
                                 f(entity, table, index);
                             }
                         }
@@ -2229,7 +3305,7 @@ macro_rules! ecs_kernel_impl {
                         continue;
                     }
                     for (index, &entity) in table.entity_indices.iter().enumerate() {
-                        if filter(entity) {
+                        if filter(entity) && !disabled.is_some_and(|set| set.contains(entity)) {
                             f(entity, table, index);
                         }
                     }
@@ -2242,6 +3318,7 @@ macro_rules! ecs_kernel_impl {
                 query_cache: &mut std::collections::HashMap<u64, Vec<usize>>,
                 include: u64,
                 exclude: u64,
+                disabled: Option<&$crate::SparseTagSet>,
                 filter: P,
                 mut f: F,
             ) where
@@ -2257,7 +3334,7 @@ macro_rules! ecs_kernel_impl {
                     }
                     for index in 0..table.entity_indices.len() {
                         let entity = table.entity_indices[index];
-                        if filter(entity) {
+                        if filter(entity) && !disabled.is_some_and(|set| set.contains(entity)) {
                             f(entity, table, index);
                         }
                     }
@@ -2270,6 +3347,7 @@ macro_rules! ecs_kernel_impl {
                 tables: &mut [[<$world ComponentArrays>]],
                 include: u64,
                 exclude: u64,
+                disabled: Option<&$crate::SparseTagSet>,
                 filter: P,
                 f: F,
             ) where
@@ -2283,20 +3361,121 @@ macro_rules! ecs_kernel_impl {
                     .for_each(|table| {
                         for index in 0..table.entity_indices.len() {
                             let entity = table.entity_indices[index];
-                            if filter(entity) {
+                            if filter(entity) && !disabled.is_some_and(|set| set.contains(entity)) {
                                 f(entity, table, index);
                             }
                         }
                     });
             }
 
+            /// Folds every matched table's rows into ordered partials, one
+            /// table per task, under the `PAR_REDUCE_BLOCK` partition rule.
+            #[cfg(not(target_family = "wasm"))]
+            #[allow(unused)]
+            fn [<tables_par_fold_ $world:snake>]<A, I, F, P>(
+                tables: &mut [[<$world ComponentArrays>]],
+                include: u64,
+                exclude: u64,
+                disabled: Option<&$crate::SparseTagSet>,
+                filter: P,
+                identity: &I,
+                fold: &F,
+            ) -> Vec<A>
+            where
+                A: Send,
+                I: Fn() -> A + Send + Sync,
+                F: Fn(A, $crate::Entity, &[<$world ComponentArrays>], usize) -> A + Send + Sync,
+                P: Fn($crate::Entity) -> bool + Send + Sync,
+            {
+                use $crate::rayon::prelude::*;
+                tables
+                    .par_iter_mut()
+                    .filter(|table| table.mask & include == include && table.mask & exclude == 0)
+                    .map(|table| {
+                        let table = &*table;
+                        let mut partials = $crate::RowPartials::default();
+                        for (index, &entity) in table.entity_indices.iter().enumerate() {
+                            if filter(entity) && !disabled.is_some_and(|set| set.contains(entity)) {
+                                partials.fold(index, identity, |accumulator| {
+                                    fold(accumulator, entity, table, index)
+                                });
+                            }
+                        }
+                        partials.finish()
+                    })
+                    .collect::<Vec<Vec<A>>>()
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+
+            /// Cuts every matched table into chunks of at most `chunk_len`
+            /// contiguous rows that pass the disabled check and `filter`.
             #[allow(unused)]
+            fn [<tables_chunks_ $world:snake>]<'a, P>(
+                tables: &'a mut [[<$world ComponentArrays>]],
+                include: u64,
+                exclude: u64,
+                disabled: Option<&$crate::SparseTagSet>,
+                filter: P,
+                chunk_len: usize,
+            ) -> Vec<[<$world Chunk>]<'a>>
+            where
+                P: Fn($crate::Entity) -> bool,
+            {
+                assert!(chunk_len > 0, "chunk length must be greater than zero");
+                let mut chunks = Vec::new();
+                for table in tables.iter_mut() {
+                    if table.mask & include != include
+                        || table.mask & exclude != 0
+                        || table.entity_indices.is_empty()
+                    {
+                        continue;
+                    }
+                    let entities: &'a [$crate::Entity] = &table.entity_indices;
+                    let matched = |row: usize| {
+                        let entity = entities[row];
+                        filter(entity) && !disabled.is_some_and(|set| set.contains(entity))
+                    };
+                    let mut rest = [<$world Chunk>] {
+                        entities,
+                        $($(#[$comp_attr])* $name: table.$name.as_mut_slice(),)*
+                    };
+                    let mut offset = 0;
+                    let mut row = 0;
+                    while row < entities.len() {
+                        if !matched(row) {
+                            row += 1;
+                            continue;
+                        }
+                        let mut end = row + 1;
+                        while end < entities.len() && matched(end) {
+                            end += 1;
+                        }
+                        let (_, tail) = rest.split_at(row - offset);
+                        rest = tail;
+                        offset = row;
+                        while offset < end {
+                            let len = chunk_len.min(end - offset);
+                            let (chunk, tail) = rest.split_at(len);
+                            chunks.push(chunk);
+                            rest = tail;
+                            offset += len;
+                        }
+                        row = end;
+                    }
+                }
+                chunks
+            }
+
+            #[allow(unused, clippy::too_many_arguments)]
             fn [<tables_for_each_mut_changed_ $world:snake>]<F, P>(
                 tables: &mut [[<$world ComponentArrays>]],
                 query_cache: &mut std::collections::HashMap<u64, Vec<usize>>,
                 include: u64,
                 exclude: u64,
                 since_tick: u32,
+                disabled: Option<&$crate::SparseTagSet>,
                 filter: P,
                 mut f: F,
             ) where
@@ -2329,7 +3508,7 @@ macro_rules! ecs_kernel_impl {
 
                     for index in 0..table.entity_indices.len() {
                         let entity = table.entity_indices[index];
-                        if !filter(entity) {
+                        if !filter(entity) || disabled.is_some_and(|set| set.contains(entity)) {
                             continue;
                         }
 
@@ -2525,6 +3704,7 @@ macro_rules! ecs_kernel_impl {
                 world: &'world $world,
                 include: u64,
                 exclude: u64,
+                include_disabled: bool,
             }
 
             #[allow(unused)]
@@ -2534,6 +3714,7 @@ macro_rules! ecs_kernel_impl {
                         world,
                         include: 0,
                         exclude: 0,
+                        include_disabled: false,
                     }
                 }
 
@@ -2547,11 +3728,20 @@ macro_rules! ecs_kernel_impl {
                     self
                 }
 
+                pub fn include_disabled(mut self) -> Self {
+                    self.include_disabled = true;
+                    self
+                }
+
                 pub fn iter<F>(self, f: F)
                 where
                     F: FnMut($crate::Entity, &[<$world ComponentArrays>], usize),
                 {
-                    self.world.for_each(self.include, self.exclude, f);
+                    if self.include_disabled {
+                        self.world.for_each_including_disabled(self.include, self.exclude, f);
+                    } else {
+                        self.world.for_each(self.include, self.exclude, f);
+                    }
                 }
             }
 
@@ -2560,6 +3750,7 @@ macro_rules! ecs_kernel_impl {
                 world: &'world mut $world,
                 include: u64,
                 exclude: u64,
+                include_disabled: bool,
             }
 
             #[allow(unused)]
@@ -2569,6 +3760,7 @@ macro_rules! ecs_kernel_impl {
                         world,
                         include: 0,
                         exclude: 0,
+                        include_disabled: false,
                     }
                 }
 
@@ -2582,11 +3774,87 @@ macro_rules! ecs_kernel_impl {
                     self
                 }
 
+                pub fn include_disabled(mut self) -> Self {
+                    self.include_disabled = true;
+                    self
+                }
+
                 pub fn iter<F>(self, f: F)
                 where
                     F: FnMut($crate::Entity, &mut [<$world ComponentArrays>], usize),
                 {
-                    self.world.for_each_mut(self.include, self.exclude, f);
+                    if self.include_disabled {
+                        self.world.for_each_mut_including_disabled(self.include, self.exclude, f);
+                    } else {
+                        self.world.for_each_mut(self.include, self.exclude, f);
+                    }
+                }
+
+                /// Folds every match into one value in parallel with a
+                /// fixed combination order, so the result does not depend on
+                /// the pool size. See the world's `par_fold`.
+                #[cfg(not(target_family = "wasm"))]
+                pub fn par_fold<A, I, F, C>(self, identity: I, fold: F, combine: C) -> A
+                where
+                    A: Send,
+                    I: Fn() -> A + Send + Sync,
+                    F: Fn(A, $crate::Entity, &[<$world ComponentArrays>], usize) -> A + Send + Sync,
+                    C: FnMut(A, A) -> A,
+                {
+                    self.world.par_fold_matching(
+                        self.include,
+                        self.exclude,
+                        self.include_disabled,
+                        identity,
+                        fold,
+                        combine,
+                    )
+                }
+
+                /// Maps every match and reduces the mapped values in a fixed
+                /// order, `None` when nothing matched.
+                #[cfg(not(target_family = "wasm"))]
+                pub fn par_reduce<T, M, R>(self, map: M, reduce: R) -> Option<T>
+                where
+                    T: Send,
+                    M: Fn($crate::Entity, &[<$world ComponentArrays>], usize) -> T + Send + Sync,
+                    R: Fn(T, T) -> T + Send + Sync,
+                {
+                    self.par_fold(
+                        || None,
+                        |accumulator, entity, table, index| {
+                            let value = map(entity, table, index);
+                            Some(match accumulator {
+                                Some(accumulator) => reduce(accumulator, value),
+                                None => value,
+                            })
+                        },
+                        |left, right| match (left, right) {
+                            (Some(left), Some(right)) => Some(reduce(left, right)),
+                            (left, right) => left.or(right),
+                        },
+                    )
+                }
+
+                /// Maps every match in parallel and collects the values in
+                /// the order `iter` visits them.
+                #[cfg(not(target_family = "wasm"))]
+                pub fn par_map_collect<T, M>(self, map: M) -> Vec<T>
+                where
+                    T: Send,
+                    M: Fn($crate::Entity, &[<$world ComponentArrays>], usize) -> T + Send + Sync,
+                {
+                    self.par_fold(
+                        Vec::new,
+                        |mut values, entity, table, index| {
+                            values.push(map(entity, table, index));
+                            values
+                        },
+                        |mut left, mut right| {
+                            left.append(&mut right);
+                            left
+                        },
+                    )
                 }
             }
 
@@ -2601,11 +3869,56 @@ macro_rules! ecs_kernel_impl {
                 pub fn query_mut(&mut self) -> [<$world QueryBuilderMut>]<'_> {
                     [<$world QueryBuilderMut>]::new(self)
                 }
+                /// Starts a typed tuple query over `Q`, such as
+                /// `(&mut Position, &Velocity, Option<&Health>)`, matched by
+                /// component type. See [`static_query`]($crate::static_query).
+                pub fn query_tuple<Q>(&mut self) -> $crate::static_query::StaticQuery<'_, Self, Q> {
+                    $crate::static_query::StaticQuery::new(self)
+                }
+
+                /// The read-only form of `query_tuple`, with `iter`,
+                /// `single`, and `iter_combinations`.
+                pub fn query_tuple_ref<Q>(&self) -> $crate::static_query::StaticQueryRef<'_, Self, Q> {
+                    $crate::static_query::StaticQueryRef::new(self)
+                }
+
 
                 pub fn contains_entity(&self, entity: $crate::Entity) -> bool {
                     [<get_location_ $world:snake>](&self.entity_locations, entity).is_some()
                 }
 
+                /// Disables an entity in this world: its row stays, but
+                /// `for_each`, the query builders, and the entity-visiting
+                /// per-component iterators skip it until `enable`. Raw
+                /// enumeration (`query_entities*`, `query_first_entity`) and
+                /// the column and slice iterators still see it. Recorded in
+                /// the structural log as `Disabled` with mask 0. Returns
+                /// false when the entity has no row here or is already
+                /// disabled.
+                pub fn disable(&mut self, entity: $crate::Entity) -> bool {
+                    if !self.contains_entity(entity) || !self.disabled.insert(entity) {
+                        return false;
+                    }
+                    self.record_structural(entity, $crate::StructuralChangeKind::Disabled, 0);
+                    true
+                }
+
+                pub fn enable(&mut self, entity: $crate::Entity) -> bool {
+                    if !self.disabled.remove(entity) {
+                        return false;
+                    }
+                    self.record_structural(entity, $crate::StructuralChangeKind::Enabled, 0);
+                    true
+                }
+
+                pub fn is_disabled(&self, entity: $crate::Entity) -> bool {
+                    self.disabled.contains(entity)
+                }
+
+                pub fn query_disabled(&self) -> impl Iterator<Item = $crate::Entity> + '_ {
+                    self.disabled.iter()
+                }
+
                 $(
                     $(#[$comp_attr])*
                     #[inline]
@@ -2707,6 +4020,7 @@ macro_rules! ecs_kernel_impl {
                             &self.query_cache,
                             $mask,
                             0,
+                            [<skip_disabled_ $world:snake>](&self.disabled, false),
                             |_| true,
                             |entity, table, index| f(entity, &table.$name[index]),
                         );
@@ -2757,8 +4071,38 @@ macro_rules! ecs_kernel_impl {
                             .filter(|table| table.mask & $mask != 0)
                             .map(|table| table.$name.as_mut_slice())
                     }
+
+                    /// Reorders the rows of every table carrying this
+                    /// component by `key`, moving all of a table's columns
+                    /// together and fixing entity locations. Equal keys keep
+                    /// their relative order, and change ticks travel with
+                    /// their rows instead of being stamped.
+                    $(#[$comp_attr])*
+                    pub fn [<sort_table_rows_by_ $name>]<K: Ord>(&mut self, mut key: impl FnMut(&$type) -> K) {
+                        for table_index in 0..self.tables.len() {
+                            let table = &self.tables[table_index];
+                            if table.mask & $mask == 0 || table.entity_indices.len() < 2 {
+                                continue;
+                            }
+                            let mut order: Vec<usize> = (0..table.entity_indices.len()).collect();
+                            order.sort_by_cached_key(|&row| key(&table.$name[row]));
+                            [<reorder_table_ $world:snake>](self, table_index, &order);
+                        }
+                    }
                 )*
 
+                /// Reorders every table's rows by ascending entity id, with
+                /// the same tick-preserving permutation as the
+                /// `sort_table_rows_by_*` methods.
+                pub fn sort_by_entity(&mut self) {
+                    for table_index in 0..self.tables.len() {
+                        let entities = &self.tables[table_index].entity_indices;
+                        let mut order: Vec<usize> = (0..entities.len()).collect();
+                        order.sort_unstable_by_key(|&row| entities[row].id);
+                        [<reorder_table_ $world:snake>](self, table_index, &order);
+                    }
+                }
+
                 pub fn spawn_entities_with(
                     &mut self,
                     allocator: &mut $crate::EntityAllocator,
@@ -2922,6 +4266,7 @@ macro_rules! ecs_kernel_impl {
                         }
                     }
 
+                    self.disabled.remove(entity);
                     let next_generation = entity.generation.wrapping_add(1);
                     let should_retire = match self.entity_locations.get(entity.id) {
                         None => true,
@@ -2955,19 +4300,7 @@ macro_rules! ecs_kernel_impl {
                             return true;
                         }
 
-                        let target_table = if mask.count_ones() == 1 {
-                            [<get_component_index_ $world:snake>](mask)
-                                .and_then(|component_index| self.table_edges[table_index].add_edges[component_index])
-                        } else {
-                            self.table_edges[table_index].multi_add_cache.get(&mask).copied()
-                        };
-
-                        let new_table_index = target_table.unwrap_or_else(|| {
-                            let new_index = [<get_or_create_table_ $world:snake>](self, current_mask | mask);
-                            self.table_edges[table_index].multi_add_cache.insert(mask, new_index);
-                            new_index
-                        });
-
+                        let new_table_index = self.resolve_add_target(table_index, mask);
                         [<move_entity_ $world:snake>](self, entity, table_index, array_index, new_table_index);
                         self.record_structural(entity, $crate::StructuralChangeKind::ComponentsAdded, mask & !current_mask);
                         true
@@ -3017,19 +4350,7 @@ macro_rules! ecs_kernel_impl {
                             return true;
                         }
 
-                        let target_table = if mask.count_ones() == 1 {
-                            [<get_component_index_ $world:snake>](mask)
-                                .and_then(|component_index| self.table_edges[table_index].remove_edges[component_index])
-                        } else {
-                            self.table_edges[table_index].multi_remove_cache.get(&mask).copied()
-                        };
-
-                        let new_table_index = target_table.unwrap_or_else(|| {
-                            let new_index = [<get_or_create_table_ $world:snake>](self, current_mask & !mask);
-                            self.table_edges[table_index].multi_remove_cache.insert(mask, new_index);
-                            new_index
-                        });
-
+                        let new_table_index = self.resolve_remove_target(table_index, mask);
                         [<move_entity_ $world:snake>](self, entity, table_index, array_index, new_table_index);
                         self.record_structural(entity, $crate::StructuralChangeKind::ComponentsRemoved, current_mask & mask);
                         true
@@ -3038,6 +4359,130 @@ macro_rules! ecs_kernel_impl {
                     }
                 }
 
+                /// The table a row of `table_index` moves to when `mask` is
+                /// added, creating and caching the edge on first use.
+                fn resolve_add_target(&mut self, table_index: usize, mask: u64) -> usize {
+                    let target_table = if mask.count_ones() == 1 {
+                        [<get_component_index_ $world:snake>](mask)
+                            .and_then(|component_index| self.table_edges[table_index].add_edges[component_index])
+                    } else {
+                        self.table_edges[table_index].multi_add_cache.get(&mask).copied()
+                    };
+                    target_table.unwrap_or_else(|| {
+                        let current_mask = self.tables[table_index].mask;
+                        let new_index = [<get_or_create_table_ $world:snake>](self, current_mask | mask);
+                        self.table_edges[table_index].multi_add_cache.insert(mask, new_index);
+                        new_index
+                    })
+                }
+
+                /// The removal counterpart of [`Self::resolve_add_target`].
+                fn resolve_remove_target(&mut self, table_index: usize, mask: u64) -> usize {
+                    let target_table = if mask.count_ones() == 1 {
+                        [<get_component_index_ $world:snake>](mask)
+                            .and_then(|component_index| self.table_edges[table_index].remove_edges[component_index])
+                    } else {
+                        self.table_edges[table_index].multi_remove_cache.get(&mask).copied()
+                    };
+                    target_table.unwrap_or_else(|| {
+                        let current_mask = self.tables[table_index].mask;
+                        let new_index = [<get_or_create_table_ $world:snake>](self, current_mask & !mask);
+                        self.table_edges[table_index].multi_remove_cache.insert(mask, new_index);
+                        new_index
+                    })
+                }
+
+                /// [`Self::add_components`] for many entities at once. Rows are
+                /// grouped by source table and each group moves column by
+                /// column, writing one
+                /// [`ComponentsAddedBatch`]($crate::StructuralChangeKind::ComponentsAddedBatch)
+                /// entry rather than one entry per entity. Entities without a
+                /// row here take the single-entity path. Returns how many
+                /// entities gained components.
+                pub fn add_components_batch(&mut self, entities: &[$crate::Entity], mask: u64) -> usize {
+                    debug_assert_eq!(
+                        mask & ![<$world:snake:upper _ALL_COMPONENTS>],
+                        0,
+                        "component masks must not contain tag bits or unknown component bits"
+                    );
+                    let mut rows = Vec::with_capacity(entities.len());
+                    let mut moved = 0;
+                    for &entity in entities {
+                        match [<get_location_ $world:snake>](&self.entity_locations, entity) {
+                            Some((table_index, array_index)) => {
+                                if self.tables[table_index].mask & mask != mask {
+                                    rows.push((table_index, array_index));
+                                }
+                            }
+                            None => {
+                                if self.add_components(entity, mask) && Self::KERNEL_ALLOW_INSERT {
+                                    moved += 1;
+                                }
+                            }
+                        }
+                    }
+                    $crate::sort_batch_rows(&mut rows);
+                    for group in rows.chunk_by(|left, right| left.0 == right.0) {
+                        let table_index = group[0].0;
+                        let current_mask = self.tables[table_index].mask;
+                        let group_rows: Vec<usize> = group.iter().map(|&(_, row)| row).collect();
+                        let moved_entities: Vec<$crate::Entity> = group_rows
+                            .iter()
+                            .map(|&row| self.tables[table_index].entity_indices[row])
+                            .collect();
+                        let new_table_index = self.resolve_add_target(table_index, mask);
+                        [<move_entities_ $world:snake>](self, table_index, &group_rows, new_table_index);
+                        moved += moved_entities.len();
+                        self.record_structural_batch(
+                            moved_entities,
+                            $crate::StructuralChangeKind::ComponentsAddedBatch,
+                            mask & !current_mask,
+                        );
+                    }
+                    moved
+                }
+
+                /// [`Self::remove_components`] for many entities at once, grouped
+                /// and moved like [`Self::add_components_batch`] under one
+                /// [`ComponentsRemovedBatch`]($crate::StructuralChangeKind::ComponentsRemovedBatch)
+                /// entry per source table. Returns how many entities lost
+                /// components.
+                pub fn remove_components_batch(&mut self, entities: &[$crate::Entity], mask: u64) -> usize {
+                    debug_assert_eq!(
+                        mask & ![<$world:snake:upper _ALL_COMPONENTS>],
+                        0,
+                        "component masks must not contain tag bits or unknown component bits"
+                    );
+                    let mut rows = Vec::with_capacity(entities.len());
+                    for &entity in entities {
+                        if let Some((table_index, array_index)) = [<get_location_ $world:snake>](&self.entity_locations, entity) {
+                            if self.tables[table_index].mask & mask != 0 {
+                                rows.push((table_index, array_index));
+                            }
+                        }
+                    }
+                    $crate::sort_batch_rows(&mut rows);
+                    let mut moved = 0;
+                    for group in rows.chunk_by(|left, right| left.0 == right.0) {
+                        let table_index = group[0].0;
+                        let current_mask = self.tables[table_index].mask;
+                        let group_rows: Vec<usize> = group.iter().map(|&(_, row)| row).collect();
+                        let moved_entities: Vec<$crate::Entity> = group_rows
+                            .iter()
+                            .map(|&row| self.tables[table_index].entity_indices[row])
+                            .collect();
+                        let new_table_index = self.resolve_remove_target(table_index, mask);
+                        [<move_entities_ $world:snake>](self, table_index, &group_rows, new_table_index);
+                        moved += moved_entities.len();
+                        self.record_structural_batch(
+                            moved_entities,
+                            $crate::StructuralChangeKind::ComponentsRemovedBatch,
+                            current_mask & mask,
+                        );
+                    }
+                    moved
+                }
+
                 pub fn component_mask(&self, entity: $crate::Entity) -> Option<u64> {
                     [<get_location_ $world:snake>](&self.entity_locations, entity)
                         .map(|(table_index, _)| self.tables[table_index].mask)
@@ -3075,6 +4520,7 @@ macro_rules! ecs_kernel_impl {
                 fn record_structural(&mut self, entity: $crate::Entity, kind: $crate::StructuralChangeKind, mask: u64) {
                     if self.structural_log.len() >= $crate::STRUCTURAL_LOG_CAPACITY {
                         self.structural_log.clear();
+                        self.structural_batches.clear();
                     }
                     self.structural_sequence += 1;
                     self.structural_log.push($crate::StructuralChange {
@@ -3085,6 +4531,24 @@ macro_rules! ecs_kernel_impl {
                     });
                 }
 
+                /// Records one batch entry: the log entry names the first
+                /// entity and the full list is kept in `structural_batches`.
+                fn record_structural_batch(
+                    &mut self,
+                    entities: Vec<$crate::Entity>,
+                    kind: $crate::StructuralChangeKind,
+                    mask: u64,
+                ) {
+                    let Some(&first) = entities.first() else {
+                        return;
+                    };
+                    self.record_structural(first, kind, mask);
+                    self.structural_batches.push($crate::StructuralBatch {
+                        sequence: self.structural_sequence,
+                        entities,
+                    });
+                }
+
                 pub fn structural_sequence(&self) -> u64 {
                     self.structural_sequence
                 }
@@ -3094,13 +4558,30 @@ macro_rules! ecs_kernel_impl {
                     &self.structural_log[start..]
                 }
 
+                /// The entity list of the batch entry recorded at `sequence`,
+                /// if it is still in the log.
+                pub fn structural_batch(&self, sequence: u64) -> Option<&[$crate::Entity]> {
+                    self.structural_batches
+                        .binary_search_by_key(&sequence, |batch| batch.sequence)
+                        .ok()
+                        .map(|index| self.structural_batches[index].entities.as_slice())
+                }
+
+                /// Every entity a log entry covers, expanding batch entries.
+                pub fn change_entities<'a>(&'a self, change: &'a $crate::StructuralChange) -> &'a [$crate::Entity] {
+                    $crate::structural_change_entities(&self.structural_batches, change)
+                }
+
                 pub fn trim_structural_log(&mut self, up_to_sequence: u64) {
                     let end = self.structural_log.partition_point(|change| change.sequence <= up_to_sequence);
                     self.structural_log.drain(..end);
+                    let end = self.structural_batches.partition_point(|batch| batch.sequence <= up_to_sequence);
+                    self.structural_batches.drain(..end);
                 }
 
                 pub fn clear_structural_log(&mut self) {
                     self.structural_log.clear();
+                    self.structural_batches.clear();
                 }
 
                 pub fn query_entities(&self, mask: u64) -> [<$world EntityQueryIter>]<'_> {
@@ -3198,6 +4679,7 @@ macro_rules! ecs_kernel_impl {
                         &self.query_cache,
                         include,
                         exclude,
+                        [<skip_disabled_ $world:snake>](&self.disabled, false),
                         |entity| {
                             include_tags.iter().all(|tag_set| tag_set.contains(entity))
                                 && !exclude_tags.iter().any(|tag_set| tag_set.contains(entity))
@@ -3222,6 +4704,7 @@ macro_rules! ecs_kernel_impl {
                         &mut self.query_cache,
                         include,
                         exclude,
+                        [<skip_disabled_ $world:snake>](&self.disabled, false),
                         |entity| {
                             include_tags.iter().all(|tag_set| tag_set.contains(entity))
                                 && !exclude_tags.iter().any(|tag_set| tag_set.contains(entity))
@@ -3246,6 +4729,7 @@ macro_rules! ecs_kernel_impl {
                         &mut self.tables,
                         include,
                         exclude,
+                        [<skip_disabled_ $world:snake>](&self.disabled, false),
                         |entity| {
                             include_tags.iter().all(|tag_set| tag_set.contains(entity))
                                 && !exclude_tags.iter().any(|tag_set| tag_set.contains(entity))
@@ -3373,6 +4857,8 @@ macro_rules! ecs_impl {
                 DespawnEntities { entities: Vec<$crate::Entity> },
                 AddComponents { entity: $crate::Entity, mask: u64 },
                 RemoveComponents { entity: $crate::Entity, mask: u64 },
+                AddComponentsBatch { entities: Vec<$crate::Entity>, mask: u64 },
+                RemoveComponentsBatch { entities: Vec<$crate::Entity>, mask: u64 },
                 $(
                     $(#[$comp_attr])*
                     [<Set $mask:camel>] { entity: $crate::Entity, value: $type },
@@ -3398,7 +4884,9 @@ macro_rules! ecs_impl {
                 pub current_tick: u32,
                 pub last_tick: u32,
                 pub structural_log: Vec<$crate::StructuralChange>,
+                pub structural_batches: Vec<$crate::StructuralBatch>,
                 pub structural_sequence: u64,
+                pub disabled: $crate::SparseTagSet,
                 $(pub $tag_name: $crate::SparseTagSet,)*
                 pub command_buffer: Vec<Command>,
                 $(pub $event_name: $crate::EventChannel<$event_type>,)*
@@ -3471,14 +4959,102 @@ macro_rules! ecs_impl {
             }
         }
 
+        $crate::paste::paste! {
+            impl $crate::static_query::StaticWorld for $world {
+                type Table = ComponentArrays;
+
+                #[allow(unused_mut, unused_variables)]
+                fn query_parts(
+                    &mut self,
+                    include: u64,
+                    exclude: u64,
+                    include_disabled: bool,
+                ) -> Option<$crate::static_query::QueryParts<'_, ComponentArrays>> {
+                    let (tag_include, tag_exclude) =
+                        self.reduce_tag_masks(include & ALL_TAGS_MASK, exclude & ALL_TAGS_MASK)?;
+                    let Self { tables, disabled, current_tick, last_tick, $($tag_name,)* .. } = self;
+                    let mut filter = $crate::static_query::RowFilter {
+                        disabled: [<skip_disabled_ $world:snake>](disabled, include_disabled),
+                        ..Default::default()
+                    };
+                    $(
+                        if tag_include & $tag_mask != 0 {
+                            filter.include_tags.push(&*$tag_name);
+                        }
+                        if tag_exclude & $tag_mask != 0 {
+                            filter.exclude_tags.push(&*$tag_name);
+                        }
+                    )*
+                    Some($crate::static_query::QueryParts {
+                        tables,
+                        include: include & !ALL_TAGS_MASK,
+                        exclude: exclude & !ALL_TAGS_MASK,
+                        filter,
+                        last_tick: *last_tick,
+                        current_tick: *current_tick,
+                    })
+                }
+
+                #[allow(unused_mut, unused_variables)]
+                fn query_parts_ref(
+                    &self,
+                    include: u64,
+                    exclude: u64,
+                    include_disabled: bool,
+                ) -> Option<$crate::static_query::QueryPartsRef<'_, ComponentArrays>> {
+                    let (tag_include, tag_exclude) =
+                        self.reduce_tag_masks(include & ALL_TAGS_MASK, exclude & ALL_TAGS_MASK)?;
+                    let mut filter = $crate::static_query::RowFilter {
+                        disabled: [<skip_disabled_ $world:snake>](&self.disabled, include_disabled),
+                        ..Default::default()
+                    };
+                    $(
+                        if tag_include & $tag_mask != 0 {
+                            filter.include_tags.push(&self.$tag_name);
+                        }
+                        if tag_exclude & $tag_mask != 0 {
+                            filter.exclude_tags.push(&self.$tag_name);
+                        }
+                    )*
+                    Some($crate::static_query::QueryPartsRef {
+                        tables: &self.tables,
+                        include: include & !ALL_TAGS_MASK,
+                        exclude: exclude & !ALL_TAGS_MASK,
+                        filter,
+                        last_tick: self.last_tick,
+                    })
+                }
+            }
+        }
+
         $crate::paste::paste! {
             #[allow(unused)]
             impl $world {
+                /// Visits every entity matching `include` and `exclude`
+                /// (components and tags), skipping disabled entities.
                 #[inline]
                 pub fn for_each<F>(&self, include: u64, exclude: u64, f: F)
                 where
                     F: FnMut($crate::Entity, &ComponentArrays, usize),
                 {
+                    self.for_each_matching(include, exclude, false, f);
+                }
+
+                /// [`for_each`](Self::for_each) with disabled entities
+                /// visited too.
+                #[inline]
+                pub fn for_each_including_disabled<F>(&self, include: u64, exclude: u64, f: F)
+                where
+                    F: FnMut($crate::Entity, &ComponentArrays, usize),
+                {
+                    self.for_each_matching(include, exclude, true, f);
+                }
+
+                fn for_each_matching<F>(&self, include: u64, exclude: u64, include_disabled: bool, f: F)
+                where
+                    F: FnMut($crate::Entity, &ComponentArrays, usize),
+                {
+                    let disabled = [<skip_disabled_ $world:snake>](&self.disabled, include_disabled);
                     let component_include = include & !ALL_TAGS_MASK;
                     let component_exclude = exclude & !ALL_TAGS_MASK;
                     let Some((tag_include, tag_exclude)) =
@@ -3493,6 +5069,7 @@ macro_rules! ecs_impl {
                             &self.query_cache,
                             component_include,
                             component_exclude,
+                            disabled,
                             |_| true,
                             f,
                         );
@@ -3502,6 +5079,7 @@ macro_rules! ecs_impl {
                             &self.query_cache,
                             component_include,
                             component_exclude,
+                            disabled,
                             |entity| self.entity_matches_tags(entity, tag_include, tag_exclude),
                             f,
                         );
@@ -3513,6 +5091,22 @@ macro_rules! ecs_impl {
                 where
                     F: FnMut($crate::Entity, &mut ComponentArrays, usize),
                 {
+                    self.for_each_mut_matching(include, exclude, false, f);
+                }
+
+                #[inline]
+                pub fn for_each_mut_including_disabled<F>(&mut self, include: u64, exclude: u64, f: F)
+                where
+                    F: FnMut($crate::Entity, &mut ComponentArrays, usize),
+                {
+                    self.for_each_mut_matching(include, exclude, true, f);
+                }
+
+                fn for_each_mut_matching<F>(&mut self, include: u64, exclude: u64, include_disabled: bool, f: F)
+                where
+                    F: FnMut($crate::Entity, &mut ComponentArrays, usize),
+                {
+                    let disabled = [<skip_disabled_ $world:snake>](&self.disabled, include_disabled);
                     let component_include = include & !ALL_TAGS_MASK;
                     let component_exclude = exclude & !ALL_TAGS_MASK;
                     let Some((tag_include, tag_exclude)) =
@@ -3527,6 +5121,7 @@ macro_rules! ecs_impl {
                             &mut self.query_cache,
                             component_include,
                             component_exclude,
+                            disabled,
                             |_| true,
                             f,
                         );
@@ -3538,6 +5133,7 @@ macro_rules! ecs_impl {
                             query_cache,
                             component_include,
                             component_exclude,
+                            disabled,
                             |entity| {
                                 let _ = entity;
                                 $(
@@ -3561,6 +5157,7 @@ macro_rules! ecs_impl {
                 where
                     F: Fn($crate::Entity, &mut ComponentArrays, usize) + Send + Sync,
                 {
+                    let disabled = [<skip_disabled_ $world:snake>](&self.disabled, false);
                     let component_include = include & !ALL_TAGS_MASK;
                     let component_exclude = exclude & !ALL_TAGS_MASK;
                     let Some((tag_include, tag_exclude)) =
@@ -3574,6 +5171,7 @@ macro_rules! ecs_impl {
                             &mut self.tables,
                             component_include,
                             component_exclude,
+                            disabled,
                             |_| true,
                             f,
                         );
@@ -3584,6 +5182,7 @@ macro_rules! ecs_impl {
                             tables,
                             component_include,
                             component_exclude,
+                            disabled,
                             |entity| {
                                 let _ = entity;
                                 $(
@@ -3601,6 +5200,135 @@ macro_rules! ecs_impl {
                     }
                 }
 
+                /// Folds every entity matching `include` and `exclude` into
+                /// one value in parallel. Each matched table folds its rows
+                /// into partials, starting a fresh one from `identity()` at
+                /// every `PAR_REDUCE_BLOCK` boundary and after every skipped
+                /// row; the partials are then combined left to right in table
+                /// and row order. The result is bitwise identical whatever
+                /// the pool size, so float sums stay lockstep-safe.
+                #[cfg(not(target_family = "wasm"))]
+                pub fn par_fold<A, I, F, C>(&mut self, include: u64, exclude: u64, identity: I, fold: F, combine: C) -> A
+                where
+                    A: Send,
+                    I: Fn() -> A + Send + Sync,
+                    F: Fn(A, $crate::Entity, &ComponentArrays, usize) -> A + Send + Sync,
+                    C: FnMut(A, A) -> A,
+                {
+                    self.par_fold_matching(include, exclude, false, identity, fold, combine)
+                }
+
+                #[cfg(not(target_family = "wasm"))]
+                fn par_fold_matching<A, I, F, C>(
+                    &mut self,
+                    include: u64,
+                    exclude: u64,
+                    include_disabled: bool,
+                    identity: I,
+                    fold: F,
+                    combine: C,
+                ) -> A
+                where
+                    A: Send,
+                    I: Fn() -> A + Send + Sync,
+                    F: Fn(A, $crate::Entity, &ComponentArrays, usize) -> A + Send + Sync,
+                    C: FnMut(A, A) -> A,
+                {
+                    let disabled = [<skip_disabled_ $world:snake>](&self.disabled, include_disabled);
+                    let component_include = include & !ALL_TAGS_MASK;
+                    let component_exclude = exclude & !ALL_TAGS_MASK;
+                    let Some((tag_include, tag_exclude)) =
+                        self.reduce_tag_masks(include & ALL_TAGS_MASK, exclude & ALL_TAGS_MASK)
+                    else {
+                        return identity();
+                    };
+
+                    let Self { tables, $($tag_name,)* .. } = self;
+                    $(let $tag_name = &*$tag_name;)*
+                    let partials = [<tables_par_fold_ $world:snake>](
+                        tables,
+                        component_include,
+                        component_exclude,
+                        disabled,
+                        |entity| {
+                            let _ = entity;
+                            $(
+                                if tag_include & $tag_mask != 0 && !$tag_name.contains(entity) {
+                                    return false;
+                                }
+                                if tag_exclude & $tag_mask != 0 && $tag_name.contains(entity) {
+                                    return false;
+                                }
+                            )*
+                            true
+                        },
+                        &identity,
+                        &fold,
+                    );
+                    $crate::combine_partials(partials, &identity, combine)
+                }
+
+                /// Runs `f` over fixed-size chunks of every matched table:
+                /// each run of matching rows is cut into `chunk_len`-row
+                /// chunks plus a shorter remainder, and every column slice in
+                /// a chunk lines up with its `entities`. Tag filters and
+                /// disabled entities split runs, so chunks hold only matching
+                /// rows. Panics if `chunk_len` is zero.
+                pub fn for_each_chunk<F>(&mut self, include: u64, exclude: u64, chunk_len: usize, mut f: F)
+                where
+                    F: FnMut([<$world Chunk>]<'_>),
+                {
+                    for chunk in self.chunks(include, exclude, chunk_len) {
+                        f(chunk);
+                    }
+                }
+
+                /// The parallel form of [`for_each_chunk`](Self::for_each_chunk),
+                /// spreading chunks rather than whole tables across the pool.
+                #[cfg(not(target_family = "wasm"))]
+                pub fn par_for_each_chunk<F>(&mut self, include: u64, exclude: u64, chunk_len: usize, f: F)
+                where
+                    F: Fn([<$world Chunk>]<'_>) + Send + Sync,
+                {
+                    use $crate::rayon::prelude::*;
+                    self.chunks(include, exclude, chunk_len)
+                        .into_par_iter()
+                        .for_each(f);
+                }
+
+                fn chunks(&mut self, include: u64, exclude: u64, chunk_len: usize) -> Vec<[<$world Chunk>]<'_>> {
+                    let disabled = [<skip_disabled_ $world:snake>](&self.disabled, false);
+                    let component_include = include & !ALL_TAGS_MASK;
+                    let component_exclude = exclude & !ALL_TAGS_MASK;
+                    let Some((tag_include, tag_exclude)) =
+                        self.reduce_tag_masks(include & ALL_TAGS_MASK, exclude & ALL_TAGS_MASK)
+                    else {
+                        assert!(chunk_len > 0, "chunk length must be greater than zero");
+                        return Vec::new();
+                    };
+                    let Self { tables, $($tag_name,)* .. } = self;
+                    $(let $tag_name = &*$tag_name;)*
+                    [<tables_chunks_ $world:snake>](
+                        tables,
+                        component_include,
+                        component_exclude,
+                        disabled,
+                        |entity| {
+                            let _ = entity;
+                            $(
+                                if tag_include & $tag_mask != 0 && !$tag_name.contains(entity) {
+                                    return false;
+                                }
+                                if tag_exclude & $tag_mask != 0 && $tag_name.contains(entity) {
+                                    return false;
+                                }
+                            )*
+                            true
+                        },
+                        chunk_len,
+                    )
+                }
+
                 #[inline]
                 pub fn for_each_mut_changed<F>(&mut self, include: u64, exclude: u64, f: F)
                 where
@@ -3614,6 +5342,7 @@ macro_rules! ecs_impl {
                 where
                     F: FnMut($crate::Entity, &mut ComponentArrays, usize),
                 {
+                    let disabled = [<skip_disabled_ $world:snake>](&self.disabled, false);
                     let component_include = include & !ALL_TAGS_MASK;
                     let component_exclude = exclude & !ALL_TAGS_MASK;
                     let Some((tag_include, tag_exclude)) =
@@ -3629,6 +5358,7 @@ macro_rules! ecs_impl {
                             component_include,
                             component_exclude,
                             since_tick,
+                            disabled,
                             |_| true,
                             f,
                         );
@@ -3641,6 +5371,7 @@ macro_rules! ecs_impl {
                             component_include,
                             component_exclude,
                             since_tick,
+                            disabled,
                             |entity| {
                                 let _ = entity;
                                 $(
@@ -3660,8 +5391,9 @@ macro_rules! ecs_impl {
 
                 $(
                     $(#[$comp_attr])*
-                    /// Visits the component mutably for every entity matching
-                    /// `mask` (components and tags), stamping change ticks.
+                    /// Visits the component mutably for every enabled entity
+                    /// matching `mask` (components and tags), stamping change
+                    /// ticks.
                     /// Scans tables by mask rather than the query cache: the
                     /// per-entity tag checks here live inside a per-component
                     /// macro repetition, which rules out the borrow shapes the
@@ -3673,12 +5405,13 @@ macro_rules! ecs_impl {
                         let component_include = (mask & !ALL_TAGS_MASK) | $mask;
                         let tag_include = mask & ALL_TAGS_MASK;
                         let current_tick = self.current_tick;
+                        let skip_disabled = !self.disabled.is_empty();
 
                         for table_index in 0..self.tables.len() {
                             if self.tables[table_index].mask & component_include != component_include {
                                 continue;
                             }
-                            if tag_include == 0 {
+                            if tag_include == 0 && !skip_disabled {
                                 let table = &mut self.tables[table_index];
                                 for index in 0..table.entity_indices.len() {
                                     let entity = table.entity_indices[index];
@@ -3689,6 +5422,9 @@ macro_rules! ecs_impl {
                             } else {
                                 for index in 0..self.tables[table_index].entity_indices.len() {
                                     let entity = self.tables[table_index].entity_indices[index];
+                                    if skip_disabled && self.disabled.contains(entity) {
+                                        continue;
+                                    }
                                     if !self.entity_matches_tags(entity, tag_include, 0) {
                                         continue;
                                     }
@@ -3845,6 +5581,26 @@ macro_rules! ecs_impl {
                     self.command_buffer.push(Command::RemoveComponents { entity, mask });
                 }
 
+                /// The deferred [`add_components_batch`](Self::add_components_batch).
+                pub fn queue_add_components_batch(&mut self, entities: Vec<$crate::Entity>, mask: u64) {
+                    debug_assert_eq!(
+                        mask & ALL_TAGS_MASK,
+                        0,
+                        "component masks must not contain tag bits; use queue_add_<tag> for tags"
+                    );
+                    self.command_buffer.push(Command::AddComponentsBatch { entities, mask });
+                }
+
+                /// The deferred [`remove_components_batch`](Self::remove_components_batch).
+                pub fn queue_remove_components_batch(&mut self, entities: Vec<$crate::Entity>, mask: u64) {
+                    debug_assert_eq!(
+                        mask & ALL_TAGS_MASK,
+                        0,
+                        "component masks must not contain tag bits; use queue_remove_<tag> for tags"
+                    );
+                    self.command_buffer.push(Command::RemoveComponentsBatch { entities, mask });
+                }
+
                 $(
                     $(#[$comp_attr])*
                     pub fn [<queue_set_ $name>](&mut self, entity: $crate::Entity, value: $type) {
@@ -3882,6 +5638,12 @@ macro_rules! ecs_impl {
                             Command::RemoveComponents { entity, mask } => {
                                 self.remove_components(entity, mask);
                             }
+                            Command::AddComponentsBatch { entities, mask } => {
+                                self.add_components_batch(&entities, mask);
+                            }
+                            Command::RemoveComponentsBatch { entities, mask } => {
+                                self.remove_components_batch(&entities, mask);
+                            }
                             $(
                                 $(#[$comp_attr])*
                                 Command::[<Set $mask:camel>] { entity, value } => {
@@ -3979,7 +5741,61 @@ macro_rules! ecs_multi_impl {
                     pub current_tick: u32,
                     pub last_tick: u32,
                     pub structural_log: Vec<$crate::StructuralChange>,
+                    pub structural_batches: Vec<$crate::StructuralBatch>,
                     pub structural_sequence: u64,
+                    pub disabled: $crate::SparseTagSet,
+                }
+
+                impl $crate::static_query::StaticWorld for $world_name {
+                    type Table = [<$world_name ComponentArrays>];
+
+                    fn query_parts(
+                        &mut self,
+                        include: u64,
+                        exclude: u64,
+                        include_disabled: bool,
+                    ) -> Option<$crate::static_query::QueryParts<'_, Self::Table>> {
+                        debug_assert_eq!(
+                            include & ![<$world_name:snake:upper _ALL_COMPONENTS>],
+                            0,
+                            "per-world queries take component masks only"
+                        );
+                        let Self { tables, disabled, current_tick, last_tick, .. } = self;
+                        Some($crate::static_query::QueryParts {
+                            tables,
+                            include,
+                            exclude,
+                            filter: $crate::static_query::RowFilter {
+                                disabled: [<skip_disabled_ $world_name:snake>](disabled, include_disabled),
+                                ..Default::default()
+                            },
+                            last_tick: *last_tick,
+                            current_tick: *current_tick,
+                        })
+                    }
+
+                    fn query_parts_ref(
+                        &self,
+                        include: u64,
+                        exclude: u64,
+                        include_disabled: bool,
+                    ) -> Option<$crate::static_query::QueryPartsRef<'_, Self::Table>> {
+                        debug_assert_eq!(
+                            include & ![<$world_name:snake:upper _ALL_COMPONENTS>],
+                            0,
+                            "per-world queries take component masks only"
+                        );
+                        Some($crate::static_query::QueryPartsRef {
+                            tables: &self.tables,
+                            include,
+                            exclude,
+                            filter: $crate::static_query::RowFilter {
+                                disabled: [<skip_disabled_ $world_name:snake>](&self.disabled, include_disabled),
+                                ..Default::default()
+                            },
+                            last_tick: self.last_tick,
+                        })
+                    }
                 }
 
                 #[allow(unused)]
@@ -4011,6 +5827,22 @@ macro_rules! ecs_multi_impl {
                     where
                         F: FnMut($crate::Entity, &[<$world_name ComponentArrays>], usize),
                     {
+                        self.for_each_matching(include, exclude, false, f);
+                    }
+
+                    #[inline]
+                    pub fn for_each_including_disabled<F>(&self, include: u64, exclude: u64, f: F)
+                    where
+                        F: FnMut($crate::Entity, &[<$world_name ComponentArrays>], usize),
+                    {
+                        self.for_each_matching(include, exclude, true, f);
+                    }
+
+                    fn for_each_matching<F>(&self, include: u64, exclude: u64, include_disabled: bool, f: F)
+                    where
+                        F: FnMut($crate::Entity, &[<$world_name ComponentArrays>], usize),
+                    {
+                        let disabled = [<skip_disabled_ $world_name:snake>](&self.disabled, include_disabled);
                         debug_assert_eq!(
                             include & ![<$world_name:snake:upper _ALL_COMPONENTS>],
                             0,
@@ -4021,6 +5853,7 @@ macro_rules! ecs_multi_impl {
                             &self.query_cache,
                             include,
                             exclude,
+                            disabled,
                             |_| true,
                             f,
                         );
@@ -4029,41 +5862,146 @@ macro_rules! ecs_multi_impl {
                     #[inline]
                     pub fn for_each_mut<F>(&mut self, include: u64, exclude: u64, f: F)
                     where
-                        F: FnMut($crate::Entity, &mut [<$world_name ComponentArrays>], usize),
+                        F: FnMut($crate::Entity, &mut [<$world_name ComponentArrays>], usize),
+                    {
+                        self.for_each_mut_matching(include, exclude, false, f);
+                    }
+
+                    #[inline]
+                    pub fn for_each_mut_including_disabled<F>(&mut self, include: u64, exclude: u64, f: F)
+                    where
+                        F: FnMut($crate::Entity, &mut [<$world_name ComponentArrays>], usize),
+                    {
+                        self.for_each_mut_matching(include, exclude, true, f);
+                    }
+
+                    fn for_each_mut_matching<F>(&mut self, include: u64, exclude: u64, include_disabled: bool, f: F)
+                    where
+                        F: FnMut($crate::Entity, &mut [<$world_name ComponentArrays>], usize),
+                    {
+                        let disabled = [<skip_disabled_ $world_name:snake>](&self.disabled, include_disabled);
+                        debug_assert_eq!(
+                            include & ![<$world_name:snake:upper _ALL_COMPONENTS>],
+                            0,
+                            "per-world queries take component masks only; use for_each_mut_with_tags for tag filtering"
+                        );
+                        [<tables_for_each_mut_ $world_name:snake>](
+                            &mut self.tables,
+                            &mut self.query_cache,
+                            include,
+                            exclude,
+                            disabled,
+                            |_| true,
+                            f,
+                        );
+                    }
+
+                    #[cfg(not(target_family = "wasm"))]
+                    #[inline]
+                    pub fn par_for_each_mut<F>(&mut self, include: u64, exclude: u64, f: F)
+                    where
+                        F: Fn($crate::Entity, &mut [<$world_name ComponentArrays>], usize) + Send + Sync,
+                    {
+                        let disabled = [<skip_disabled_ $world_name:snake>](&self.disabled, false);
+                        debug_assert_eq!(
+                            include & ![<$world_name:snake:upper _ALL_COMPONENTS>],
+                            0,
+                            "per-world queries take component masks only; use par_for_each_mut_with_tags for tag filtering"
+                        );
+                        [<tables_par_for_each_mut_ $world_name:snake>](
+                            &mut self.tables,
+                            include,
+                            exclude,
+                            disabled,
+                            |_| true,
+                            f,
+                        );
+                    }
+
+                    /// Folds every match into one value in parallel, as the
+                    /// single-world `par_fold` does.
+                    #[cfg(not(target_family = "wasm"))]
+                    pub fn par_fold<A, I, F, C>(&mut self, include: u64, exclude: u64, identity: I, fold: F, combine: C) -> A
+                    where
+                        A: Send,
+                        I: Fn() -> A + Send + Sync,
+                        F: Fn(A, $crate::Entity, &[<$world_name ComponentArrays>], usize) -> A + Send + Sync,
+                        C: FnMut(A, A) -> A,
+                    {
+                        self.par_fold_matching(include, exclude, false, identity, fold, combine)
+                    }
+
+                    #[cfg(not(target_family = "wasm"))]
+                    fn par_fold_matching<A, I, F, C>(
+                        &mut self,
+                        include: u64,
+                        exclude: u64,
+                        include_disabled: bool,
+                        identity: I,
+                        fold: F,
+                        combine: C,
+                    ) -> A
+                    where
+                        A: Send,
+                        I: Fn() -> A + Send + Sync,
+                        F: Fn(A, $crate::Entity, &[<$world_name ComponentArrays>], usize) -> A + Send + Sync,
+                        C: FnMut(A, A) -> A,
                     {
+                        let disabled = [<skip_disabled_ $world_name:snake>](&self.disabled, include_disabled);
                         debug_assert_eq!(
                             include & ![<$world_name:snake:upper _ALL_COMPONENTS>],
                             0,
-                            "per-world queries take component masks only; use for_each_mut_with_tags for tag filtering"
+                            "per-world queries take component masks only"
                         );
-                        [<tables_for_each_mut_ $world_name:snake>](
+                        let partials = [<tables_par_fold_ $world_name:snake>](
                             &mut self.tables,
-                            &mut self.query_cache,
                             include,
                             exclude,
+                            disabled,
                             |_| true,
-                            f,
+                            &identity,
+                            &fold,
                         );
+                        $crate::combine_partials(partials, &identity, combine)
+                    }
+
+                    /// Runs `f` over fixed-size chunks of every matched table,
+                    /// as the single-world `for_each_chunk` does.
+                    pub fn for_each_chunk<F>(&mut self, include: u64, exclude: u64, chunk_len: usize, mut f: F)
+                    where
+                        F: FnMut([<$world_name Chunk>]<'_>),
+                    {
+                        for chunk in self.chunks(include, exclude, chunk_len) {
+                            f(chunk);
+                        }
                     }
 
                     #[cfg(not(target_family = "wasm"))]
-                    #[inline]
-                    pub fn par_for_each_mut<F>(&mut self, include: u64, exclude: u64, f: F)
+                    pub fn par_for_each_chunk<F>(&mut self, include: u64, exclude: u64, chunk_len: usize, f: F)
                     where
-                        F: Fn($crate::Entity, &mut [<$world_name ComponentArrays>], usize) + Send + Sync,
+                        F: Fn([<$world_name Chunk>]<'_>) + Send + Sync,
                     {
+                        use $crate::rayon::prelude::*;
+                        self.chunks(include, exclude, chunk_len)
+                            .into_par_iter()
+                            .for_each(f);
+                    }
+
+                    fn chunks(&mut self, include: u64, exclude: u64, chunk_len: usize) -> Vec<[<$world_name Chunk>]<'_>> {
+                        let disabled = [<skip_disabled_ $world_name:snake>](&self.disabled, false);
                         debug_assert_eq!(
                             include & ![<$world_name:snake:upper _ALL_COMPONENTS>],
                             0,
-                            "per-world queries take component masks only; use par_for_each_mut_with_tags for tag filtering"
+                            "per-world queries take component masks only"
                         );
-                        [<tables_par_for_each_mut_ $world_name:snake>](
+                        [<tables_chunks_ $world_name:snake>](
                             &mut self.tables,
                             include,
                             exclude,
+                            disabled,
                             |_| true,
-                            f,
-                        );
+                            chunk_len,
+                        )
                     }
 
                     #[inline]
@@ -4079,6 +6017,7 @@ macro_rules! ecs_multi_impl {
                     where
                         F: FnMut($crate::Entity, &mut [<$world_name ComponentArrays>], usize),
                     {
+                        let disabled = [<skip_disabled_ $world_name:snake>](&self.disabled, false);
                         debug_assert_eq!(
                             include & ![<$world_name:snake:upper _ALL_COMPONENTS>],
                             0,
@@ -4090,6 +6029,7 @@ macro_rules! ecs_multi_impl {
                             include,
                             exclude,
                             since_tick,
+                            disabled,
                             |_| true,
                             f,
                         );
@@ -4106,12 +6046,14 @@ macro_rules! ecs_multi_impl {
                                 0,
                                 "per-world queries take component masks only"
                             );
+                            let disabled = [<skip_disabled_ $world_name:snake>](&self.disabled, false);
                             let current_tick = self.current_tick;
                             [<tables_for_each_mut_ $world_name:snake>](
                                 &mut self.tables,
                                 &mut self.query_cache,
                                 mask | $mask,
                                 0,
+                                disabled,
                                 |_| true,
                                 |entity, table, index| {
                                     table.[<$name _changed>][index] = current_tick;
@@ -4281,6 +6223,34 @@ macro_rules! ecs_multi_impl {
                     }
                 }
 
+                /// Disables the entity in every world, including worlds it
+                /// has no row in yet, so components added later stay
+                /// hidden too. Per-world queries skip it until `enable`;
+                /// recorded once in this lifecycle log as `Disabled`.
+                /// Returns false for dead or already-disabled handles.
+                pub fn disable(&mut self, entity: $crate::Entity) -> bool {
+                    if !self.allocator.is_alive(entity) || self.is_disabled(entity) {
+                        return false;
+                    }
+                    $(self.[<$world_name:snake>].disabled.insert(entity);)+
+                    self.record_structural(entity, $crate::StructuralChangeKind::Disabled, 0);
+                    true
+                }
+
+                pub fn enable(&mut self, entity: $crate::Entity) -> bool {
+                    let mut enabled = false;
+                    $(enabled |= self.[<$world_name:snake>].disabled.remove(entity);)+
+                    if enabled {
+                        self.record_structural(entity, $crate::StructuralChangeKind::Enabled, 0);
+                    }
+                    enabled
+                }
+
+                /// Whether any world holds the entity disabled.
+                pub fn is_disabled(&self, entity: $crate::Entity) -> bool {
+                    false $(|| self.[<$world_name:snake>].disabled.contains(entity))+
+                }
+
                 fn record_structural(&mut self, entity: $crate::Entity, kind: $crate::StructuralChangeKind, mask: u64) {
                     if self.structural_log.len() >= $crate::STRUCTURAL_LOG_CAPACITY {
                         self.structural_log.clear();
@@ -5653,6 +7623,57 @@ mod tests {
         assert_eq!(changes[1].mask, VELOCITY);
     }
 
+    #[test]
+    fn test_batch_add_and_remove_components() {
+        let mut world = World::default();
+        let plain = world.spawn_entities(POSITION, 6);
+        let moving = world.spawn_entities(POSITION | VELOCITY, 2);
+        for (index, &entity) in plain.iter().enumerate() {
+            world.set_position(
+                entity,
+                Position {
+                    x: index as f32,
+                    y: 0.0,
+                },
+            );
+        }
+        let cursor = world.structural_sequence();
+
+        let chosen = [plain[5], plain[0], plain[3], moving[0], plain[0]];
+        assert_eq!(world.add_components_batch(&chosen, VELOCITY), 3);
+        for (index, &entity) in plain.iter().enumerate() {
+            assert_eq!(world.get_position(entity).unwrap().x, index as f32);
+            assert_eq!(
+                world.get_velocity(entity).is_some(),
+                matches!(index, 0 | 3 | 5)
+            );
+        }
+        let changes = world.structural_changes_since(cursor).to_vec();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].kind, StructuralChangeKind::ComponentsAddedBatch);
+        assert_eq!(changes[0].mask, VELOCITY);
+        let mut logged = world.change_entities(&changes[0]).to_vec();
+        logged.sort_by_key(|entity| entity.id);
+        assert_eq!(logged, vec![plain[0], plain[3], plain[5]]);
+
+        world.queue_remove_components_batch(vec![plain[3], moving[1]], VELOCITY);
+        world.apply_commands();
+        assert!(world.get_velocity(plain[3]).is_none());
+        assert!(world.get_velocity(moving[1]).is_none());
+        assert_eq!(world.get_position(plain[3]).unwrap().x, 3.0);
+        let removed = world.structural_changes_since(changes[0].sequence);
+        assert_eq!(removed.len(), 1);
+        assert_eq!(
+            removed[0].kind,
+            StructuralChangeKind::ComponentsRemovedBatch
+        );
+        assert_eq!(world.change_entities(&removed[0]).len(), 2);
+
+        world.trim_structural_log(world.structural_sequence());
+        assert!(world.structural_batches.is_empty());
+        assert!(world.structural_batch(changes[0].sequence).is_none());
+    }
+
     #[test]
     fn test_tick_is_newer() {
         assert!(crate::tick_is_newer(1, 0));
@@ -5690,6 +7711,110 @@ mod tests {
         assert_ne!((other.id, other.generation), (reused.id, reused.generation));
     }
 
+    #[test]
+    fn test_allocator_reservation_matches_allocation_order() {
+        let mut allocator = EntityAllocator::default();
+        let first = allocator.allocate();
+        let second = allocator.allocate();
+        assert!(allocator.deallocate(first));
+
+        let shared = &allocator;
+        let recycled = shared.reserve();
+        let fresh = shared.reserve();
+        assert_eq!(
+            (recycled.id, recycled.generation),
+            (first.id, first.generation + 1)
+        );
+        assert_eq!((fresh.id, fresh.generation), (second.id + 1, 0));
+        assert!(!allocator.is_alive(recycled));
+        assert_eq!(allocator.reserved_count(), 2);
+
+        let after = allocator.allocate();
+        assert!(allocator.is_alive(recycled));
+        assert!(allocator.is_alive(fresh));
+        assert_ne!(after.id, recycled.id);
+        assert_ne!(after.id, fresh.id);
+        assert_eq!(allocator.take_reserved(), vec![recycled, fresh]);
+        assert_eq!(allocator.reserved_count(), 0);
+    }
+
+    #[test]
+    fn test_allocator_try_revive_refuses_retired_generations() {
+        let mut allocator = EntityAllocator::default();
+        let mut entity = allocator.allocate();
+        for _ in 0..3 {
+            allocator.deallocate(entity);
+            entity = allocator.allocate();
+        }
+        allocator.deallocate(entity);
+        assert_eq!(entity.generation, 3);
+
+        let retired = Entity {
+            id: 0,
+            generation: 0,
+        };
+        assert!(!allocator.try_revive(retired));
+        assert!(!allocator.is_alive(retired));
+        let next = Entity {
+            id: 0,
+            generation: 4,
+        };
+        assert!(allocator.try_revive(next));
+        assert!(allocator.is_alive(next));
+        assert!(!allocator.try_revive(next), "already live");
+    }
+
+    #[test]
+    fn test_allocator_revive_frees_skipped_ids() {
+        let mut allocator = EntityAllocator::default();
+        allocator.revive(Entity {
+            id: 3,
+            generation: 0,
+        });
+        let mut ids: Vec<u32> = (0..4).map(|_| allocator.allocate().id).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![0, 1, 2, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "handles use u32 ids")]
+    fn test_allocator_reserve_batch_rejects_counts_past_u32() {
+        let allocator = EntityAllocator::default();
+        allocator.reserve_batch(u32::MAX as usize + 1, &mut Vec::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "reservations are outstanding")]
+    fn test_allocator_reserve_batch_refuses_to_wrap() {
+        let allocator = EntityAllocator::default();
+        allocator
+            .reserved
+            .store(u32::MAX - 1, std::sync::atomic::Ordering::Relaxed);
+        allocator.reserve_batch(2, &mut Vec::new());
+    }
+
+    #[test]
+    fn test_allocator_reservation_across_threads() {
+        let allocator = EntityAllocator::default();
+        let handles: Vec<Entity> = std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..4)
+                .map(|_| scope.spawn(|| (0..64).map(|_| allocator.reserve()).collect::<Vec<_>>()))
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().unwrap())
+                .collect()
+        });
+        let unique: std::collections::HashSet<u32> =
+            handles.iter().map(|entity| entity.id).collect();
+        assert_eq!(unique.len(), 256);
+
+        let mut allocator = allocator;
+        let settled = allocator.take_reserved();
+        assert_eq!(settled.len(), 256);
+        assert!(handles.iter().all(|&entity| allocator.is_alive(entity)));
+    }
+
     struct Lcg(u64);
 
     impl Lcg {
@@ -6554,6 +8679,76 @@ mod tests {
         assert_eq!(count, 3);
     }
 
+    #[test]
+    fn test_disabled_entities_are_skipped_until_enabled() {
+        let mut world = World::default();
+        let entities = world.spawn_entities(POSITION, 3);
+        let paused = entities[0];
+        world.add_player(paused);
+        let cursor = world.structural_sequence();
+
+        assert!(world.disable(paused));
+        assert!(!world.disable(paused));
+        assert!(world.is_disabled(paused));
+
+        let mut visited = Vec::new();
+        world.for_each(POSITION, 0, |entity, _table, _idx| visited.push(entity));
+        assert_eq!(visited, entities[1..]);
+        visited.clear();
+        world.for_each(POSITION | PLAYER, 0, |entity, _table, _idx| {
+            visited.push(entity)
+        });
+        assert!(visited.is_empty(), "tag filters compose with the skip");
+        world.for_each_mut(POSITION, 0, |_entity, table, idx| {
+            table.position[idx].x = 1.0
+        });
+        world.query_position_mut(0, |_entity, position| position.y = 1.0);
+        world.iter_position(|entity, _position| assert_ne!(entity, paused));
+        assert_eq!(
+            world
+                .get_position(paused)
+                .map(|position| (position.x, position.y)),
+            Some((0.0, 0.0))
+        );
+
+        let mut count = 0;
+        world
+            .query()
+            .with(POSITION)
+            .include_disabled()
+            .iter(|_entity, _table, _idx| count += 1);
+        assert_eq!(count, 3);
+        assert_eq!(
+            world.query_entities(POSITION).count(),
+            3,
+            "raw enumeration sees every row"
+        );
+
+        assert!(world.enable(paused));
+        count = 0;
+        world
+            .query_mut()
+            .with(POSITION)
+            .iter(|_entity, _table, _idx| count += 1);
+        assert_eq!(count, 3);
+        let kinds: Vec<StructuralChangeKind> = world
+            .structural_changes_since(cursor)
+            .iter()
+            .map(|change| change.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                StructuralChangeKind::Disabled,
+                StructuralChangeKind::Enabled
+            ]
+        );
+
+        world.disable(paused);
+        world.despawn_entities(&[paused]);
+        assert_eq!(world.query_disabled().count(), 0);
+    }
+
     #[test]
     fn test_query_component_mut_with_tag_filter() {
         let mut world = World::default();
@@ -6641,6 +8836,187 @@ mod tests {
         assert_eq!(total_entities, 9);
     }
 
+    #[test]
+    fn test_for_each_chunk_splits_tables_into_aligned_chunks() {
+        let mut world = World::default();
+        let entities = world.spawn_entities(POSITION | VELOCITY, 10);
+        world.spawn_entities(POSITION, 5);
+        for &entity in &entities {
+            world.set_velocity(entity, Velocity { x: 2.0, y: 0.0 });
+        }
+        world.disable(entities[4]);
+        world.add_player(entities[8]);
+
+        let mut lengths = Vec::new();
+        world.for_each_chunk(POSITION | VELOCITY, PLAYER, 3, |chunk| {
+            assert_eq!(chunk.position.len(), chunk.len());
+            assert_eq!(chunk.velocity.len(), chunk.len());
+            assert!(chunk.health.is_empty());
+            lengths.push(chunk.len());
+            for (position, velocity) in chunk.position.iter_mut().zip(chunk.velocity.iter()) {
+                position.x += velocity.x;
+            }
+        });
+        assert_eq!(lengths, vec![3, 1, 3, 1]);
+
+        for (index, &entity) in entities.iter().enumerate() {
+            let expected = if index == 4 || index == 8 { 0.0 } else { 2.0 };
+            assert_eq!(world.get_position(entity).unwrap().x, expected);
+        }
+
+        let mut total = 0;
+        world.for_each_chunk(POSITION, 0, 4, |chunk| total += chunk.len());
+        assert_eq!(total, 14);
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    #[test]
+    fn test_par_for_each_chunk_covers_every_row() {
+        let mut world = World::default();
+        world.spawn_entities(POSITION | VELOCITY, 1000);
+        world.spawn_entities(POSITION, 30);
+
+        world.par_for_each_chunk(POSITION, 0, 64, |chunk| {
+            assert!(chunk.len() <= 64);
+            for position in chunk.position.iter_mut() {
+                position.x += 1.0;
+            }
+        });
+
+        let mut total = 0;
+        for slice in world.iter_position_slices() {
+            assert!(slice.iter().all(|position| position.x == 1.0));
+            total += slice.len();
+        }
+        assert_eq!(total, 1030);
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    #[test]
+    fn test_par_fold_is_bitwise_stable_across_pool_sizes() {
+        let mut world = World::default();
+        let movers = world.spawn_entities(POSITION | VELOCITY, 3000);
+        let loners = world.spawn_entities(POSITION, 2500);
+        for (index, &entity) in movers.iter().chain(&loners).enumerate() {
+            let x = (index as f32 * 0.37).sin() * 1.0e4 + 1.0e-3 * (index % 7) as f32;
+            world.set_position(entity, Position { x, y: 0.0 });
+        }
+        for &entity in movers.iter().step_by(89) {
+            world.disable(entity);
+        }
+        world.add_player(loners[5]);
+
+        let mut sums = Vec::new();
+        for threads in [1, 2, 5] {
+            let pool = crate::rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            let sum = pool.install(|| {
+                world.par_fold(
+                    POSITION,
+                    PLAYER,
+                    || 0.0f32,
+                    |sum, _entity, table, index| sum + table.position[index].x,
+                    |left, right| left + right,
+                )
+            });
+            sums.push(sum.to_bits());
+        }
+        assert!(sums.iter().all(|&bits| bits == sums[0]));
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    #[test]
+    fn test_query_builder_par_reduce_and_par_map_collect() {
+        let mut world = World::default();
+        let entities = world.spawn_entities(POSITION, 1500);
+        world.spawn_entities(POSITION | HEALTH, 4);
+        for (index, &entity) in entities.iter().enumerate() {
+            world.set_position(
+                entity,
+                Position {
+                    x: index as f32,
+                    y: 0.0,
+                },
+            );
+        }
+        world.disable(entities[1499]);
+
+        let mut sequential = Vec::new();
+        world
+            .query()
+            .with(POSITION)
+            .iter(|entity, _table, _index| sequential.push(entity));
+        let collected = world
+            .query_mut()
+            .with(POSITION)
+            .par_map_collect(|entity, _table, _index| entity);
+        assert_eq!(collected, sequential);
+
+        let widest = world
+            .query_mut()
+            .with(POSITION)
+            .without(HEALTH)
+            .par_reduce(|_entity, table, index| table.position[index].x, f32::max);
+        assert_eq!(widest, Some(1498.0));
+        let widest = world
+            .query_mut()
+            .with(POSITION)
+            .include_disabled()
+            .par_reduce(|_entity, table, index| table.position[index].x, f32::max);
+        assert_eq!(widest, Some(1499.0));
+        assert_eq!(
+            world
+                .query_mut()
+                .with(VELOCITY)
+                .par_reduce(|_entity, _table, _index| 1, |left, right| left + right),
+            None
+        );
+        assert_eq!(
+            world.query_mut().with(PLAYER).par_fold(
+                || 3,
+                |count, _entity, _table, _index| count + 1,
+                |left, right| left + right
+            ),
+            3
+        );
+    }
+
+    #[test]
+    fn test_sort_table_rows_by_and_sort_by_entity() {
+        let mut world = World::default();
+        let keys = [5.0, 3.0, 0.0, 4.0, 1.0, 2.0];
+        let entities = world.spawn_entities(POSITION | HEALTH, keys.len());
+        for (&entity, &x) in entities.iter().zip(&keys) {
+            world.set_position(entity, Position { x, y: 0.0 });
+            world.set_health(entity, Health { value: x * 10.0 });
+        }
+        world.increment_tick();
+
+        world.sort_table_rows_by_position(|position| position.x as i32);
+
+        let mut order = Vec::new();
+        for slice in world.iter_position_slices() {
+            order.extend(slice.iter().map(|position| position.x));
+        }
+        assert_eq!(order, vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+        for (&entity, &x) in entities.iter().zip(&keys) {
+            assert_eq!(world.get_position(entity).unwrap().x, x);
+            assert_eq!(world.get_health(entity).unwrap().value, x * 10.0);
+        }
+        assert_eq!(
+            world.query_entities_changed(POSITION).count(),
+            0,
+            "sorting moves rows without marking them changed"
+        );
+
+        world.sort_by_entity();
+        assert_eq!(world.query_entities(POSITION).collect::<Vec<_>>(), entities);
+        world.despawn_entities(&entities[..2]);
+        assert_eq!(world.get_health(entities[2]).unwrap().value, 0.0);
+    }
+
     #[test]
     fn test_simd_slice_vectorizable_operation() {
         let mut world = World::default();
@@ -7625,6 +10001,95 @@ mod tests {
         assert_eq!(*observed.lock().unwrap(), 42.0);
     }
 
+    fn failing_system(world: &mut World) -> Result<(), String> {
+        world.resources._delta_time += 1.0;
+        Err(format!(
+            "delta time reached {}",
+            world.resources._delta_time
+        ))
+    }
+
+    fn counting_system(world: &mut World) {
+        world.resources._delta_time += 10.0;
+    }
+
+    #[test]
+    fn test_schedule_error_policies() {
+        let mut world = World::default();
+        let mut schedule = Schedule::new();
+        schedule.push("fails", failing_system);
+        schedule.push("counts", counting_system);
+
+        schedule.run(&mut world);
+        assert_eq!(world.resources._delta_time, 11.0);
+        assert_eq!(schedule.errors().len(), 1);
+        assert_eq!(schedule.errors()[0].system, "fails");
+        assert_eq!(
+            schedule.errors()[0].error.to_string(),
+            "delta time reached 1"
+        );
+
+        schedule.set_error_policy(ErrorPolicy::Abort);
+        schedule.run(&mut world);
+        assert_eq!(world.resources._delta_time, 12.0);
+        assert_eq!(schedule.errors().len(), 1);
+
+        schedule.set_error_policy(ErrorPolicy::Disable);
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert_eq!(world.resources._delta_time, 33.0);
+        assert!(schedule.is_disabled("fails"));
+        assert!(schedule.errors().is_empty());
+
+        assert!(schedule.enable("fails"));
+        let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        schedule.set_error_policy(ErrorPolicy::Handler(Box::new(
+            move |world: &mut World, error: &SystemError| {
+                world.resources._delta_time = 0.0;
+                sink.lock().unwrap().push(error.to_string());
+            },
+        )));
+        schedule.run(&mut world);
+        assert_eq!(world.resources._delta_time, 10.0);
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec!["system \"fails\" failed: delta time reached 34".to_string()]
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct MissingAsset(&'static str);
+
+    impl std::fmt::Display for MissingAsset {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "missing asset {}", self.0)
+        }
+    }
+
+    impl std::error::Error for MissingAsset {}
+
+    #[test]
+    fn test_schedule_errors_keep_their_type() {
+        let mut world = World::default();
+        let mut schedule = Schedule::new();
+        schedule.push("load", |_world: &mut World| {
+            Err::<(), _>(MissingAsset("level.map"))
+        });
+        schedule.run(&mut world);
+
+        let error = &schedule.errors()[0];
+        assert_eq!(
+            error.error.downcast_ref::<MissingAsset>(),
+            Some(&MissingAsset("level.map"))
+        );
+        assert!(std::error::Error::source(error).is_some());
+        assert_eq!(
+            error.to_string(),
+            "system \"load\" failed: missing asset level.map"
+        );
+    }
+
     #[test]
     #[should_panic(expected = "already exists")]
     fn test_schedule_push_duplicate_panics() {
@@ -8481,6 +10946,37 @@ mod tests {
             assert!(!ecs.has_player(entity));
         }
 
+        #[test]
+        fn test_multi_world_disable_spans_worlds() {
+            let mut ecs = GameEcs::default();
+            let paused = ecs.spawn();
+            let running = ecs.spawn();
+            ecs.core_world.set_position(paused, Position::default());
+            ecs.core_world.set_position(running, Position::default());
+
+            assert!(ecs.disable(paused));
+            ecs.render_world.set_sprite(paused, Sprite { id: 1 });
+            ecs.render_world.set_sprite(running, Sprite { id: 2 });
+
+            let mut sprites = Vec::new();
+            ecs.render_world
+                .for_each(MW_SPRITE, 0, |entity, _table, _idx| sprites.push(entity));
+            assert_eq!(sprites, vec![running], "later rows stay hidden");
+            let mut positions = 0;
+            ecs.core_world
+                .for_each_with_tags(MW_POSITION, 0, &[], &[], |_entity, _table, _idx| {
+                    positions += 1
+                });
+            assert_eq!(positions, 1);
+
+            assert!(ecs.enable(paused));
+            assert!(!ecs.is_disabled(paused));
+            ecs.disable(paused);
+            ecs.despawn(paused);
+            assert_eq!(ecs.core_world.query_disabled().count(), 0);
+            assert_eq!(ecs.render_world.query_disabled().count(), 0);
+        }
+
         #[test]
         fn test_multi_world_entity_in_one_world_only() {
             let mut ecs = GameEcs::default();
@@ -9120,6 +11616,37 @@ mod tests {
             assert_eq!(ecs.core_world.get_position(e2).unwrap().y, 7.0);
         }
 
+        #[cfg(not(target_family = "wasm"))]
+        #[test]
+        fn test_multi_world_par_fold() {
+            let mut ecs = GameEcs::default();
+            for index in 0..10 {
+                let entity = ecs.spawn();
+                ecs.core_world.set_position(
+                    entity,
+                    Position {
+                        x: index as f32,
+                        y: 0.0,
+                    },
+                );
+            }
+
+            let total = ecs.core_world.par_fold(
+                MW_POSITION,
+                0,
+                || 0.0f32,
+                |sum, _entity, table, index| sum + table.position[index].x,
+                |left, right| left + right,
+            );
+            assert_eq!(total, 45.0);
+            let collected = ecs
+                .core_world
+                .query_mut()
+                .with(MW_POSITION)
+                .par_map_collect(|_entity, table, index| table.position[index].x as u32);
+            assert_eq!(collected, (0..10).collect::<Vec<u32>>());
+        }
+
         #[derive(Default, Clone)]
         struct MultiModelEntity {
             core_mask: Option<u64>,
@@ -49,9 +49,10 @@
 //! assert_eq!(world.resource::<Ticks>().unwrap().0, 1);
 //! ```
 
-use crate::Schedule;
+use crate::condition::IntoCondition;
 use crate::dynamic::ResourceHost;
 use crate::system_param::{EventHost, IntoSystem};
+use crate::{Schedule, SystemOutput, SystemResult};
 use std::marker::PhantomData;
 
 /// The current value of state type `S`, a resource inserted by
@@ -204,8 +205,9 @@ where
     }
 }
 
-/// The marker for a plain world system, `FnMut(&mut W)`, in a gate.
-pub struct PlainMarker;
+/// The marker for a plain world system, `FnMut(&mut W)`, in a gate. `Out`
+/// is the system's return type.
+pub struct PlainMarker<Out = ()>(PhantomData<fn() -> Out>);
 
 /// The marker for a system-parameter function in a gate.
 pub struct ParamMarker<Marker>(PhantomData<fn() -> Marker>);
@@ -216,15 +218,16 @@ pub struct ParamMarker<Marker>(PhantomData<fn() -> Marker>);
 /// this, so a tuple may freely mix the two shapes.
 pub trait GatedSystem<W, Marker> {
     /// Lowers the system to a runner the gate calls when its condition holds.
-    fn into_gated_runner(self) -> impl FnMut(&mut W) + Send + 'static;
+    fn into_gated_runner(self) -> impl FnMut(&mut W) -> SystemResult + Send + 'static;
 }
 
-impl<W, F> GatedSystem<W, PlainMarker> for F
+impl<W, F, Out> GatedSystem<W, PlainMarker<Out>> for F
 where
-    F: FnMut(&mut W) + Send + 'static,
+    F: FnMut(&mut W) -> Out + Send + 'static,
+    Out: SystemOutput,
 {
-    fn into_gated_runner(self) -> impl FnMut(&mut W) + Send + 'static {
-        self
+    fn into_gated_runner(mut self) -> impl FnMut(&mut W) -> SystemResult + Send + 'static {
+        move |world: &mut W| self(world).into_system_result()
     }
 }
 
@@ -232,7 +235,7 @@ impl<W, Marker, F> GatedSystem<W, ParamMarker<Marker>> for F
 where
     F: IntoSystem<W, Marker>,
 {
-    fn into_gated_runner(self) -> impl FnMut(&mut W) + Send + 'static {
+    fn into_gated_runner(self) -> impl FnMut(&mut W) -> SystemResult + Send + 'static {
         self.into_runner()
     }
 }
@@ -247,17 +250,19 @@ pub struct GroupMarker<Marker>(PhantomData<fn() -> Marker>);
 /// gate checks its condition once and then runs the whole group in order. A
 /// single system resolves through [`SingleMarker`]; a tuple through
 /// [`GroupMarker`]. A tuple may mix plain world systems and system-parameter
-/// functions. Implemented for tuples of up to sixteen systems.
+/// functions. Implemented for tuples of up to sixteen systems. A member
+/// that returns an error ends the group's pass, and the gate hands that
+/// error to the schedule's [`ErrorPolicy`](crate::ErrorPolicy).
 pub trait IntoGroupRunner<W, Marker> {
     /// Builds the group's runner, initializing each member's system once.
-    fn into_group_runner(self) -> impl FnMut(&mut W) + Send + 'static;
+    fn into_group_runner(self) -> impl FnMut(&mut W) -> SystemResult + Send + 'static;
 }
 
 impl<W, Marker, S> IntoGroupRunner<W, SingleMarker<Marker>> for S
 where
     S: GatedSystem<W, Marker>,
 {
-    fn into_group_runner(self) -> impl FnMut(&mut W) + Send + 'static {
+    fn into_group_runner(self) -> impl FnMut(&mut W) -> SystemResult + Send + 'static {
         self.into_gated_runner()
     }
 }
@@ -269,11 +274,12 @@ macro_rules! impl_group_runner_tuple {
         where
             $($system: GatedSystem<W, $marker>,)+
         {
-            fn into_group_runner(self) -> impl FnMut(&mut W) + Send + 'static {
+            fn into_group_runner(self) -> impl FnMut(&mut W) -> SystemResult + Send + 'static {
                 let ($($runner,)+) = self;
                 $(let mut $runner = $runner.into_gated_runner();)+
                 move |world: &mut W| {
-                    $($runner(world);)+
+                    $($runner(world)?;)+
+                    Ok(())
                 }
             }
         }
@@ -322,17 +328,21 @@ impl_group_runner_tuple!(
     S9 M9 r9, S10 M10 r10, S11 M11 r11, S12 M12 r12, S13 M13 r13, S14 M14 r14, S15 M15 r15
 );
 
-/// Gates a system, or a tuple of systems, on an arbitrary world condition,
-/// returning one runner that checks `condition` each pass and runs the group
-/// only when it holds. Push the result onto a schedule.
-pub fn run_if<W, Marker>(
-    condition: impl Fn(&W) -> bool + Send + 'static,
+/// Gates a system, or a tuple of systems, on any
+/// [`IntoCondition`](crate::condition::IntoCondition), returning one runner
+/// that checks `condition` each pass and runs the group only when it holds.
+/// Push the result onto a schedule.
+pub fn run_if<W, ConditionMarker, Marker>(
+    condition: impl IntoCondition<W, ConditionMarker>,
     systems: impl IntoGroupRunner<W, Marker>,
-) -> impl FnMut(&mut W) + Send + 'static {
+) -> impl FnMut(&mut W) -> SystemResult + Send + 'static {
+    let mut condition = condition.into_condition();
     let mut runner = systems.into_group_runner();
     move |world: &mut W| {
         if condition(world) {
-            runner(world);
+            runner(world)
+        } else {
+            Ok(())
         }
     }
 }
@@ -342,7 +352,7 @@ pub fn run_if<W, Marker>(
 pub fn while_in<S, W, Marker>(
     state: S,
     systems: impl IntoGroupRunner<W, Marker>,
-) -> impl FnMut(&mut W) + Send + 'static
+) -> impl FnMut(&mut W) -> SystemResult + Send + 'static
 where
     S: Copy + PartialEq + Send + Sync + 'static,
     W: ResourceHost,
@@ -356,7 +366,7 @@ where
 pub fn while_in_any<S, W, Marker>(
     states: impl IntoIterator<Item = S>,
     systems: impl IntoGroupRunner<W, Marker>,
-) -> impl FnMut(&mut W) + Send + 'static
+) -> impl FnMut(&mut W) -> SystemResult + Send + 'static
 where
     S: Copy + PartialEq + Send + Sync + 'static,
     W: ResourceHost,
@@ -374,7 +384,7 @@ where
 pub fn on_enter<S, W, Marker>(
     state: S,
     systems: impl IntoGroupRunner<W, Marker>,
-) -> impl FnMut(&mut W) + Send + 'static
+) -> impl FnMut(&mut W) -> SystemResult + Send + 'static
 where
     S: Copy + PartialEq + Send + Sync + 'static,
     W: EventHost + 'static,
@@ -387,9 +397,7 @@ where
             .consume::<StateTransition<S>>(&mut cursor)
             .iter()
             .any(|transition| transition.after == state);
-        if entered {
-            runner(host);
-        }
+        if entered { runner(host) } else { Ok(()) }
     }
 }
 
@@ -399,7 +407,7 @@ where
 pub fn on_exit<S, W, Marker>(
     state: S,
     systems: impl IntoGroupRunner<W, Marker>,
-) -> impl FnMut(&mut W) + Send + 'static
+) -> impl FnMut(&mut W) -> SystemResult + Send + 'static
 where
     S: Copy + PartialEq + Send + Sync + 'static,
     W: EventHost + 'static,
@@ -412,9 +420,7 @@ where
             .consume::<StateTransition<S>>(&mut cursor)
             .iter()
             .any(|transition| transition.before == Some(state));
-        if exited {
-            runner(host);
-        }
+        if exited { runner(host) } else { Ok(()) }
     }
 }
 
@@ -0,0 +1,1220 @@
+//! Typed tuple queries for `ecs!` worlds: the static tier's counterpart of
+//! [`DynWorld::query`](crate::dynamic::DynWorld), written against the
+//! generated `ComponentArrays` tables instead of erased columns.
+//!
+//! Every `ecs!` world (and every `ecs_multi!` member world) gets
+//! `query_tuple::<Q>()` over `&mut self` and `query_tuple_ref::<Q>()` over
+//! `&self`. `Q` is a component reference or a tuple of up to eight of them:
+//! `&T`, `&mut T`, `Option<&T>`, and `Option<&mut T>`, plus `&T` and
+//! `Option<&T>` for the read-only form. Filters take the world's masks, as
+//! the rest of the static tier does:
+//!
+//! ```rust
+//! use freecs::ecs;
+//!
+//! ecs! {
+//!     World {
+//!         position: Position => POSITION,
+//!         velocity: Velocity => VELOCITY,
+//!         health: Health => HEALTH,
+//!     }
+//!     Tags {
+//!         player => PLAYER,
+//!     }
+//!     Resources {}
+//! }
+//!
+//! #[derive(Default, Debug, Clone, Copy)]
+//! pub struct Position { pub x: f32 }
+//! #[derive(Default, Debug, Clone, Copy)]
+//! pub struct Velocity { pub x: f32 }
+//! #[derive(Default, Debug, Clone, Copy)]
+//! pub struct Health { pub value: f32 }
+//!
+//! let mut world = World::default();
+//! let hero = world.spawn_entities(POSITION | VELOCITY | HEALTH, 1)[0];
+//! world.spawn_entities(POSITION | VELOCITY, 3);
+//! world.add_player(hero);
+//!
+//! world
+//!     .query_tuple::<(&mut Position, &Velocity, Option<&Health>)>()
+//!     .for_each(|_entity, (position, velocity, health)| {
+//!         position.x += velocity.x * health.map_or(1.0, |health| health.value);
+//!     });
+//!
+//! let (player, _position) = world
+//!     .query_tuple_ref::<&Position>()
+//!     .with(PLAYER)
+//!     .single()
+//!     .unwrap();
+//! assert_eq!(player, hero);
+//! ```
+//!
+//! Components are matched by type. The generated impls are keyed by each
+//! component's mask as well, so a world that stores one type under two
+//! names still compiles; querying that type is then ambiguous and has to go
+//! through the mask-based API. Everything here is safe code: tuple elements
+//! claim disjoint columns by destructuring the table, so no two elements
+//! can alias.
+
+use crate::{Entity, SparseTagSet, tick_is_newer};
+use std::marker::PhantomData;
+
+/// Disambiguates the generated [`StaticColumn`] impls by component mask.
+pub struct ColumnMarker<const MASK: u64>;
+
+/// One component column claimed for writing, with its tick column.
+pub struct ColumnMut<'a, T> {
+    pub data: &'a mut [T],
+    pub changed: &'a mut [u32],
+    pub peak_changed: &'a mut u32,
+}
+
+/// One component column borrowed for reading, with its tick column.
+pub struct ColumnRef<'a, T> {
+    pub data: &'a [T],
+    pub changed: &'a [u32],
+}
+
+impl<T> Clone for ColumnRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ColumnRef<'_, T> {}
+
+impl<'a, T> From<ColumnMut<'a, T>> for ColumnRef<'a, T> {
+    fn from(column: ColumnMut<'a, T>) -> Self {
+        Self {
+            data: column.data,
+            changed: column.changed,
+        }
+    }
+}
+
+/// A generated `ComponentArrays` table split into per-component column
+/// borrows. `Columns` holds each present column once so elements can take
+/// it; `ColumnsRef` is the shared, copyable form.
+pub trait StaticTable {
+    type Columns<'a>
+    where
+        Self: 'a;
+    type ColumnsRef<'a>: Copy
+    where
+        Self: 'a;
+    fn table_mask(&self) -> u64;
+    fn columns_mut(&mut self) -> (&[Entity], Self::Columns<'_>);
+    fn columns_ref(&self) -> (&[Entity], Self::ColumnsRef<'_>);
+}
+
+/// Implemented by the macro for each component type of a world, keyed by
+/// the world's table type and the component's [`ColumnMarker`].
+pub trait StaticColumn<T: StaticTable, M>: Sized + 'static {
+    const MASK: u64;
+    /// Claims the column, or `None` when the table lacks it or an earlier
+    /// element already claimed it.
+    fn take<'a>(columns: &mut T::Columns<'a>) -> Option<ColumnMut<'a, Self>>
+    where
+        T: 'a;
+    fn get<'a>(columns: T::ColumnsRef<'a>) -> Option<ColumnRef<'a, Self>>
+    where
+        T: 'a;
+}
+
+/// One element of a mutable typed tuple query.
+pub trait StaticQueryElement<T: StaticTable, M> {
+    type Fetch<'a>;
+    type Item<'item>;
+    const MASK: u64;
+    const REQUIRED: bool;
+    fn fetch<'a>(columns: &mut T::Columns<'a>, current_tick: u32) -> Self::Fetch<'a>
+    where
+        T: 'a;
+    fn changed_newer(fetch: &Self::Fetch<'_>, index: usize, since_tick: u32) -> bool;
+    /// Hands out row `index`, stamping a mutable element's tick as it goes.
+    fn item<'fetch>(fetch: &'fetch mut Self::Fetch<'_>, index: usize) -> Self::Item<'fetch>;
+}
+
+/// One element of a read-only typed tuple query. Fetches are `Copy`, so
+/// items borrow the world rather than the iterator.
+pub trait StaticReadElement<T: StaticTable, M> {
+    type ReadFetch<'a>: Copy;
+    type Item<'a>;
+    const MASK: u64;
+    const REQUIRED: bool;
+    fn read_fetch<'a>(columns: T::ColumnsRef<'a>) -> Self::ReadFetch<'a>
+    where
+        T: 'a;
+    fn read_changed_newer(fetch: Self::ReadFetch<'_>, index: usize, since_tick: u32) -> bool;
+    fn read_item<'a>(fetch: Self::ReadFetch<'a>, index: usize) -> Self::Item<'a>;
+}
+
+fn column_changed(changed: &[u32], index: usize, since_tick: u32) -> bool {
+    changed
+        .get(index)
+        .is_some_and(|&tick| tick_is_newer(tick, since_tick))
+}
+
+fn stamp_row<T>(column: &mut ColumnMut<'_, T>, index: usize, tick: u32) {
+    if let Some(changed) = column.changed.get_mut(index) {
+        *changed = tick;
+    }
+    *column.peak_changed = tick;
+}
+
+impl<T: StaticTable, M, C: StaticColumn<T, M>> StaticQueryElement<T, M> for &C {
+    type Fetch<'a> = ColumnRef<'a, C>;
+    type Item<'item> = &'item C;
+    const MASK: u64 = C::MASK;
+    const REQUIRED: bool = true;
+
+    fn fetch<'a>(columns: &mut T::Columns<'a>, _current_tick: u32) -> Self::Fetch<'a>
+    where
+        T: 'a,
+    {
+        C::take(columns)
+            .expect("required query element column missing")
+            .into()
+    }
+
+    fn changed_newer(fetch: &Self::Fetch<'_>, index: usize, since_tick: u32) -> bool {
+        column_changed(fetch.changed, index, since_tick)
+    }
+
+    fn item<'fetch>(fetch: &'fetch mut Self::Fetch<'_>, index: usize) -> Self::Item<'fetch> {
+        &fetch.data[index]
+    }
+}
+
+impl<T: StaticTable, M, C: StaticColumn<T, M>> StaticQueryElement<T, M> for &mut C {
+    type Fetch<'a> = (ColumnMut<'a, C>, u32);
+    type Item<'item> = &'item mut C;
+    const MASK: u64 = C::MASK;
+    const REQUIRED: bool = true;
+
+    fn fetch<'a>(columns: &mut T::Columns<'a>, current_tick: u32) -> Self::Fetch<'a>
+    where
+        T: 'a,
+    {
+        (
+            C::take(columns).expect("required query element column missing"),
+            current_tick,
+        )
+    }
+
+    fn changed_newer(fetch: &Self::Fetch<'_>, index: usize, since_tick: u32) -> bool {
+        column_changed(fetch.0.changed, index, since_tick)
+    }
+
+    fn item<'fetch>(fetch: &'fetch mut Self::Fetch<'_>, index: usize) -> Self::Item<'fetch> {
+        let (column, tick) = fetch;
+        stamp_row(column, index, *tick);
+        &mut column.data[index]
+    }
+}
+
+impl<T: StaticTable, M, C: StaticColumn<T, M>> StaticQueryElement<T, M> for Option<&C> {
+    type Fetch<'a> = Option<ColumnRef<'a, C>>;
+    type Item<'item> = Option<&'item C>;
+    const MASK: u64 = C::MASK;
+    const REQUIRED: bool = false;
+
+    fn fetch<'a>(columns: &mut T::Columns<'a>, _current_tick: u32) -> Self::Fetch<'a>
+    where
+        T: 'a,
+    {
+        C::take(columns).map(ColumnRef::from)
+    }
+
+    fn changed_newer(fetch: &Self::Fetch<'_>, index: usize, since_tick: u32) -> bool {
+        fetch
+            .as_ref()
+            .is_some_and(|column| column_changed(column.changed, index, since_tick))
+    }
+
+    fn item<'fetch>(fetch: &'fetch mut Self::Fetch<'_>, index: usize) -> Self::Item<'fetch> {
+        fetch.as_ref().map(|column| &column.data[index])
+    }
+}
+
+impl<T: StaticTable, M, C: StaticColumn<T, M>> StaticQueryElement<T, M> for Option<&mut C> {
+    type Fetch<'a> = Option<(ColumnMut<'a, C>, u32)>;
+    type Item<'item> = Option<&'item mut C>;
+    const MASK: u64 = C::MASK;
+    const REQUIRED: bool = false;
+
+    fn fetch<'a>(columns: &mut T::Columns<'a>, current_tick: u32) -> Self::Fetch<'a>
+    where
+        T: 'a,
+    {
+        C::take(columns).map(|column| (column, current_tick))
+    }
+
+    fn changed_newer(fetch: &Self::Fetch<'_>, index: usize, since_tick: u32) -> bool {
+        fetch
+            .as_ref()
+            .is_some_and(|(column, _)| column_changed(column.changed, index, since_tick))
+    }
+
+    fn item<'fetch>(fetch: &'fetch mut Self::Fetch<'_>, index: usize) -> Self::Item<'fetch> {
+        fetch.as_mut().map(|(column, tick)| {
+            stamp_row(column, index, *tick);
+            &mut column.data[index]
+        })
+    }
+}
+
+impl<T: StaticTable, M, C: StaticColumn<T, M>> StaticReadElement<T, M> for &C {
+    type ReadFetch<'a> = ColumnRef<'a, C>;
+    type Item<'a> = &'a C;
+    const MASK: u64 = C::MASK;
+    const REQUIRED: bool = true;
+
+    fn read_fetch<'a>(columns: T::ColumnsRef<'a>) -> Self::ReadFetch<'a>
+    where
+        T: 'a,
+    {
+        C::get(columns).expect("required query element column missing")
+    }
+
+    fn read_changed_newer(fetch: Self::ReadFetch<'_>, index: usize, since_tick: u32) -> bool {
+        column_changed(fetch.changed, index, since_tick)
+    }
+
+    fn read_item<'a>(fetch: Self::ReadFetch<'a>, index: usize) -> Self::Item<'a> {
+        &fetch.data[index]
+    }
+}
+
+impl<T: StaticTable, M, C: StaticColumn<T, M>> StaticReadElement<T, M> for Option<&C> {
+    type ReadFetch<'a> = Option<ColumnRef<'a, C>>;
+    type Item<'a> = Option<&'a C>;
+    const MASK: u64 = C::MASK;
+    const REQUIRED: bool = false;
+
+    fn read_fetch<'a>(columns: T::ColumnsRef<'a>) -> Self::ReadFetch<'a>
+    where
+        T: 'a,
+    {
+        C::get(columns)
+    }
+
+    fn read_changed_newer(fetch: Self::ReadFetch<'_>, index: usize, since_tick: u32) -> bool {
+        fetch.is_some_and(|column| column_changed(column.changed, index, since_tick))
+    }
+
+    fn read_item<'a>(fetch: Self::ReadFetch<'a>, index: usize) -> Self::Item<'a> {
+        fetch.map(|column| &column.data[index])
+    }
+}
+
+/// A mutable typed query: one [`StaticQueryElement`] or a tuple of them.
+pub trait StaticQueryTuple<T: StaticTable, M> {
+    type Fetch<'a>;
+    type Item<'item>;
+    /// Every element's component mask.
+    const MASK: u64;
+    /// The masks a table must carry: the non-`Option` elements'.
+    const REQUIRED: u64;
+    /// Whether no component appears twice, which a mutable tuple needs.
+    const DISJOINT: bool;
+    fn fetch<'a>(columns: &mut T::Columns<'a>, current_tick: u32) -> Self::Fetch<'a>
+    where
+        T: 'a;
+    fn changed_newer(
+        fetch: &Self::Fetch<'_>,
+        index: usize,
+        changed_mask: u64,
+        since_tick: u32,
+    ) -> bool;
+    fn item<'fetch>(fetch: &'fetch mut Self::Fetch<'_>, index: usize) -> Self::Item<'fetch>;
+}
+
+/// A read-only typed query: one [`StaticReadElement`] or a tuple of them.
+pub trait StaticReadQueryTuple<T: StaticTable, M> {
+    type ReadFetch<'a>: Copy;
+    type Item<'a>;
+    const MASK: u64;
+    const REQUIRED: u64;
+    fn read_fetch<'a>(columns: T::ColumnsRef<'a>) -> Self::ReadFetch<'a>
+    where
+        T: 'a;
+    fn read_changed_newer(
+        fetch: Self::ReadFetch<'_>,
+        index: usize,
+        changed_mask: u64,
+        since_tick: u32,
+    ) -> bool;
+    fn read_item<'a>(fetch: Self::ReadFetch<'a>, index: usize) -> Self::Item<'a>;
+}
+
+const fn masks_disjoint(masks: &[u64]) -> bool {
+    let mut seen = 0;
+    let mut index = 0;
+    while index < masks.len() {
+        if seen & masks[index] != 0 {
+            return false;
+        }
+        seen |= masks[index];
+        index += 1;
+    }
+    true
+}
+
+const fn required_mask(masks: &[(u64, bool)]) -> u64 {
+    let mut required = 0;
+    let mut index = 0;
+    while index < masks.len() {
+        if masks[index].1 {
+            required |= masks[index].0;
+        }
+        index += 1;
+    }
+    required
+}
+
+macro_rules! impl_static_bare_element {
+    ($($element:ty),+) => {
+        $(
+            impl<'element, T: StaticTable, M, C: StaticColumn<T, M>> StaticQueryTuple<T, M>
+                for $element
+            {
+                type Fetch<'a> = <$element as StaticQueryElement<T, M>>::Fetch<'a>;
+                type Item<'item> = <$element as StaticQueryElement<T, M>>::Item<'item>;
+                const MASK: u64 = C::MASK;
+                const REQUIRED: u64 = required_mask(&[(
+                    C::MASK,
+                    <$element as StaticQueryElement<T, M>>::REQUIRED,
+                )]);
+                const DISJOINT: bool = true;
+
+                fn fetch<'a>(columns: &mut T::Columns<'a>, current_tick: u32) -> Self::Fetch<'a>
+                where
+                    T: 'a,
+                {
+                    <$element as StaticQueryElement<T, M>>::fetch(columns, current_tick)
+                }
+
+                fn changed_newer(
+                    fetch: &Self::Fetch<'_>,
+                    index: usize,
+                    _changed_mask: u64,
+                    since_tick: u32,
+                ) -> bool {
+                    <$element as StaticQueryElement<T, M>>::changed_newer(fetch, index, since_tick)
+                }
+
+                fn item<'fetch>(
+                    fetch: &'fetch mut Self::Fetch<'_>,
+                    index: usize,
+                ) -> Self::Item<'fetch> {
+                    <$element as StaticQueryElement<T, M>>::item(fetch, index)
+                }
+            }
+        )+
+    };
+}
+
+impl_static_bare_element!(
+    &'element C,
+    &'element mut C,
+    Option<&'element C>,
+    Option<&'element mut C>
+);
+
+macro_rules! impl_static_bare_read_element {
+    ($($element:ty),+) => {
+        $(
+            impl<'element, T: StaticTable, M, C: StaticColumn<T, M>> StaticReadQueryTuple<T, M>
+                for $element
+            {
+                type ReadFetch<'a> = <$element as StaticReadElement<T, M>>::ReadFetch<'a>;
+                type Item<'a> = <$element as StaticReadElement<T, M>>::Item<'a>;
+                const MASK: u64 = C::MASK;
+                const REQUIRED: u64 = required_mask(&[(
+                    C::MASK,
+                    <$element as StaticReadElement<T, M>>::REQUIRED,
+                )]);
+
+                fn read_fetch<'a>(columns: T::ColumnsRef<'a>) -> Self::ReadFetch<'a>
+                where
+                    T: 'a,
+                {
+                    <$element as StaticReadElement<T, M>>::read_fetch(columns)
+                }
+
+                fn read_changed_newer(
+                    fetch: Self::ReadFetch<'_>,
+                    index: usize,
+                    _changed_mask: u64,
+                    since_tick: u32,
+                ) -> bool {
+                    <$element as StaticReadElement<T, M>>::read_changed_newer(
+                        fetch, index, since_tick,
+                    )
+                }
+
+                fn read_item<'a>(fetch: Self::ReadFetch<'a>, index: usize) -> Self::Item<'a> {
+                    <$element as StaticReadElement<T, M>>::read_item(fetch, index)
+                }
+            }
+        )+
+    };
+}
+
+impl_static_bare_read_element!(&'element C, Option<&'element C>);
+
+macro_rules! impl_static_query_tuple {
+    ($(($element:ident, $marker:ident, $position:tt)),+) => {
+        impl<T: StaticTable, $($marker,)+ $($element: StaticQueryElement<T, $marker>,)+>
+            StaticQueryTuple<T, ($($marker,)+)> for ($($element,)+)
+        {
+            type Fetch<'a> = ($($element::Fetch<'a>,)+);
+            type Item<'item> = ($($element::Item<'item>,)+);
+            const MASK: u64 = 0 $(| $element::MASK)+;
+            const REQUIRED: u64 = required_mask(&[$(($element::MASK, $element::REQUIRED)),+]);
+            const DISJOINT: bool = masks_disjoint(&[$($element::MASK),+]);
+
+            fn fetch<'a>(columns: &mut T::Columns<'a>, current_tick: u32) -> Self::Fetch<'a>
+            where
+                T: 'a,
+            {
+                ($($element::fetch(columns, current_tick),)+)
+            }
+
+            fn changed_newer(
+                fetch: &Self::Fetch<'_>,
+                index: usize,
+                changed_mask: u64,
+                since_tick: u32,
+            ) -> bool {
+                $(
+                    (changed_mask & $element::MASK != 0
+                        && $element::changed_newer(&fetch.$position, index, since_tick))
+                )||+
+            }
+
+            fn item<'fetch>(fetch: &'fetch mut Self::Fetch<'_>, index: usize) -> Self::Item<'fetch> {
+                ($($element::item(&mut fetch.$position, index),)+)
+            }
+        }
+
+        impl<T: StaticTable, $($marker,)+ $($element: StaticReadElement<T, $marker>,)+>
+            StaticReadQueryTuple<T, ($($marker,)+)> for ($($element,)+)
+        {
+            type ReadFetch<'a> = ($($element::ReadFetch<'a>,)+);
+            type Item<'a> = ($($element::Item<'a>,)+);
+            const MASK: u64 = 0 $(| $element::MASK)+;
+            const REQUIRED: u64 = required_mask(&[$(($element::MASK, $element::REQUIRED)),+]);
+
+            fn read_fetch<'a>(columns: T::ColumnsRef<'a>) -> Self::ReadFetch<'a>
+            where
+                T: 'a,
+            {
+                ($($element::read_fetch(columns),)+)
+            }
+
+            fn read_changed_newer(
+                fetch: Self::ReadFetch<'_>,
+                index: usize,
+                changed_mask: u64,
+                since_tick: u32,
+            ) -> bool {
+                $(
+                    (changed_mask & $element::MASK != 0
+                        && $element::read_changed_newer(fetch.$position, index, since_tick))
+                )||+
+            }
+
+            fn read_item<'a>(fetch: Self::ReadFetch<'a>, index: usize) -> Self::Item<'a> {
+                ($($element::read_item(fetch.$position, index),)+)
+            }
+        }
+    };
+}
+
+impl_static_query_tuple!((A, MA, 0));
+impl_static_query_tuple!((A, MA, 0), (B, MB, 1));
+impl_static_query_tuple!((A, MA, 0), (B, MB, 1), (C, MC, 2));
+impl_static_query_tuple!((A, MA, 0), (B, MB, 1), (C, MC, 2), (D, MD, 3));
+impl_static_query_tuple!((A, MA, 0), (B, MB, 1), (C, MC, 2), (D, MD, 3), (E, ME, 4));
+impl_static_query_tuple!(
+    (A, MA, 0),
+    (B, MB, 1),
+    (C, MC, 2),
+    (D, MD, 3),
+    (E, ME, 4),
+    (F, MF, 5)
+);
+impl_static_query_tuple!(
+    (A, MA, 0),
+    (B, MB, 1),
+    (C, MC, 2),
+    (D, MD, 3),
+    (E, ME, 4),
+    (F, MF, 5),
+    (G, MG, 6)
+);
+impl_static_query_tuple!(
+    (A, MA, 0),
+    (B, MB, 1),
+    (C, MC, 2),
+    (D, MD, 3),
+    (E, ME, 4),
+    (F, MF, 5),
+    (G, MG, 6),
+    (H, MH, 7)
+);
+
+/// The per-entity filters a world resolves from a query's masks: tag sets
+/// an entity must or must not be in, and the disabled set to skip.
+#[derive(Default)]
+pub struct RowFilter<'w> {
+    pub include_tags: Vec<&'w SparseTagSet>,
+    pub exclude_tags: Vec<&'w SparseTagSet>,
+    pub disabled: Option<&'w SparseTagSet>,
+}
+
+impl RowFilter<'_> {
+    pub fn matches(&self, entity: Entity) -> bool {
+        self.include_tags.iter().all(|set| set.contains(entity))
+            && !self.exclude_tags.iter().any(|set| set.contains(entity))
+            && !self.disabled.is_some_and(|set| set.contains(entity))
+    }
+}
+
+/// A world's tables and resolved filters for one mutable query run.
+pub struct QueryParts<'w, T> {
+    pub tables: &'w mut [T],
+    pub include: u64,
+    pub exclude: u64,
+    pub filter: RowFilter<'w>,
+    pub last_tick: u32,
+    pub current_tick: u32,
+}
+
+/// A world's tables and resolved filters for one read-only query run.
+pub struct QueryPartsRef<'w, T> {
+    pub tables: &'w [T],
+    pub include: u64,
+    pub exclude: u64,
+    pub filter: RowFilter<'w>,
+    pub last_tick: u32,
+}
+
+/// Implemented by the macro for every static world: splits query masks
+/// into component masks and row filters. `None` means nothing can match,
+/// such as an included tag with no members.
+pub trait StaticWorld {
+    type Table: StaticTable;
+    fn query_parts(
+        &mut self,
+        include: u64,
+        exclude: u64,
+        include_disabled: bool,
+    ) -> Option<QueryParts<'_, Self::Table>>;
+    fn query_parts_ref(
+        &self,
+        include: u64,
+        exclude: u64,
+        include_disabled: bool,
+    ) -> Option<QueryPartsRef<'_, Self::Table>>;
+}
+
+/// A mutable typed query in progress, from the generated `query_tuple`.
+pub struct StaticQuery<'w, W: StaticWorld, Q> {
+    pub world: &'w mut W,
+    pub include: u64,
+    pub exclude: u64,
+    pub changed_mask: u64,
+    pub include_disabled: bool,
+    pub marker: PhantomData<Q>,
+}
+
+impl<'w, W: StaticWorld, Q> StaticQuery<'w, W, Q> {
+    pub fn new(world: &'w mut W) -> Self {
+        Self {
+            world,
+            include: 0,
+            exclude: 0,
+            changed_mask: 0,
+            include_disabled: false,
+            marker: PhantomData,
+        }
+    }
+
+    /// Only visit entities carrying every component and tag in `mask`.
+    pub fn with(mut self, mask: u64) -> Self {
+        self.include |= mask;
+        self
+    }
+
+    /// Skip entities carrying any component or tag in `mask`.
+    pub fn without(mut self, mask: u64) -> Self {
+        self.exclude |= mask;
+        self
+    }
+
+    /// Only visit entities where a component in `mask` changed since the
+    /// last tick. Every component in `mask` must be in the query tuple.
+    pub fn changed(mut self, mask: u64) -> Self {
+        self.changed_mask |= mask;
+        self
+    }
+
+    pub fn include_disabled(mut self) -> Self {
+        self.include_disabled = true;
+        self
+    }
+
+    /// Runs the query, handing each match's items to `f`. Mutable elements
+    /// stamp their rows as changed when handed out.
+    ///
+    /// # Panics
+    /// Panics if the tuple names one component twice, or a `changed` mask
+    /// names a component outside the tuple.
+    pub fn for_each<M>(
+        self,
+        mut f: impl for<'item> FnMut(Entity, <Q as StaticQueryTuple<W::Table, M>>::Item<'item>),
+    ) where
+        Q: StaticQueryTuple<W::Table, M>,
+    {
+        assert!(
+            Q::DISJOINT,
+            "a mutable query tuple may name each component only once"
+        );
+        assert_eq!(
+            self.changed_mask & !Q::MASK,
+            0,
+            "changed filters must name components present in the query tuple"
+        );
+        let changed_mask = self.changed_mask;
+        let Some(parts) = self.world.query_parts(
+            self.include | Q::REQUIRED,
+            self.exclude,
+            self.include_disabled,
+        ) else {
+            return;
+        };
+        for table in parts.tables.iter_mut() {
+            let table_mask = table.table_mask();
+            if table_mask & parts.include != parts.include || table_mask & parts.exclude != 0 {
+                continue;
+            }
+            let (entities, mut columns) = table.columns_mut();
+            if entities.is_empty() {
+                continue;
+            }
+            let mut fetch = Q::fetch(&mut columns, parts.current_tick);
+            for (index, &entity) in entities.iter().enumerate() {
+                if !parts.filter.matches(entity) {
+                    continue;
+                }
+                if changed_mask != 0
+                    && !Q::changed_newer(&fetch, index, changed_mask, parts.last_tick)
+                {
+                    continue;
+                }
+                f(entity, Q::item(&mut fetch, index));
+            }
+        }
+    }
+}
+
+/// A read-only typed query in progress, from the generated
+/// `query_tuple_ref`.
+pub struct StaticQueryRef<'w, W: StaticWorld, Q> {
+    pub world: &'w W,
+    pub include: u64,
+    pub exclude: u64,
+    pub changed_mask: u64,
+    pub include_disabled: bool,
+    pub marker: PhantomData<Q>,
+}
+
+impl<'w, W: StaticWorld, Q> StaticQueryRef<'w, W, Q> {
+    pub fn new(world: &'w W) -> Self {
+        Self {
+            world,
+            include: 0,
+            exclude: 0,
+            changed_mask: 0,
+            include_disabled: false,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn with(mut self, mask: u64) -> Self {
+        self.include |= mask;
+        self
+    }
+
+    pub fn without(mut self, mask: u64) -> Self {
+        self.exclude |= mask;
+        self
+    }
+
+    /// Only visit entities where a component in `mask` changed since the
+    /// last tick. Every component in `mask` must be in the query tuple.
+    pub fn changed(mut self, mask: u64) -> Self {
+        self.changed_mask |= mask;
+        self
+    }
+
+    pub fn include_disabled(mut self) -> Self {
+        self.include_disabled = true;
+        self
+    }
+
+    /// Runs the query as an iterator of `(Entity, items)`. Items borrow the
+    /// world, not the iterator, so they survive collection.
+    pub fn iter<M>(self) -> StaticQueryIter<'w, W::Table, Q, M>
+    where
+        Q: StaticReadQueryTuple<W::Table, M>,
+    {
+        assert_eq!(
+            self.changed_mask & !Q::MASK,
+            0,
+            "changed filters must name components present in the query tuple"
+        );
+        StaticQueryIter {
+            parts: self.world.query_parts_ref(
+                self.include | Q::REQUIRED,
+                self.exclude,
+                self.include_disabled,
+            ),
+            changed_mask: self.changed_mask,
+            table_index: 0,
+            row_index: 0,
+            current: None,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn for_each<M>(
+        self,
+        mut f: impl FnMut(Entity, <Q as StaticReadQueryTuple<W::Table, M>>::Item<'w>),
+    ) where
+        Q: StaticReadQueryTuple<W::Table, M>,
+    {
+        for (entity, item) in self.iter() {
+            f(entity, item);
+        }
+    }
+
+    /// The exactly-one match: `Some` when precisely one entity matches,
+    /// `None` for zero or several.
+    #[allow(clippy::type_complexity)]
+    pub fn single<M>(self) -> Option<(Entity, <Q as StaticReadQueryTuple<W::Table, M>>::Item<'w>)>
+    where
+        Q: StaticReadQueryTuple<W::Table, M>,
+    {
+        let mut matches = self.iter();
+        let first = matches.next()?;
+        if matches.next().is_some() {
+            return None;
+        }
+        Some(first)
+    }
+
+    /// Every unordered pair of distinct matches, in match order. Matches are
+    /// collected once up front.
+    pub fn iter_combinations<M>(self) -> StaticQueryCombinations<'w, W::Table, Q, M>
+    where
+        Q: StaticReadQueryTuple<W::Table, M>,
+        Q::Item<'w>: Copy,
+    {
+        StaticQueryCombinations {
+            items: self.iter().collect(),
+            first: 0,
+            second: 1,
+        }
+    }
+}
+
+/// The iterator behind [`StaticQueryRef::iter`]. Walks matching tables in
+/// order, fetching each table's columns once.
+pub struct StaticQueryIter<'w, T: StaticTable, Q: StaticReadQueryTuple<T, M>, M> {
+    parts: Option<QueryPartsRef<'w, T>>,
+    changed_mask: u64,
+    table_index: usize,
+    row_index: usize,
+    current: Option<(&'w [Entity], Q::ReadFetch<'w>)>,
+    marker: PhantomData<M>,
+}
+
+impl<'w, T: StaticTable, Q: StaticReadQueryTuple<T, M>, M> Iterator
+    for StaticQueryIter<'w, T, Q, M>
+{
+    type Item = (Entity, Q::Item<'w>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let parts = self.parts.as_ref()?;
+        loop {
+            if let Some((entities, fetch)) = self.current {
+                while self.row_index < entities.len() {
+                    let index = self.row_index;
+                    self.row_index += 1;
+                    let entity = entities[index];
+                    if !parts.filter.matches(entity) {
+                        continue;
+                    }
+                    if self.changed_mask != 0
+                        && !Q::read_changed_newer(fetch, index, self.changed_mask, parts.last_tick)
+                    {
+                        continue;
+                    }
+                    return Some((entity, Q::read_item(fetch, index)));
+                }
+                self.current = None;
+            }
+
+            let table = parts.tables.get(self.table_index)?;
+            self.table_index += 1;
+            let table_mask = table.table_mask();
+            if table_mask & parts.include != parts.include || table_mask & parts.exclude != 0 {
+                continue;
+            }
+            let (entities, columns) = table.columns_ref();
+            if !entities.is_empty() {
+                self.row_index = 0;
+                self.current = Some((entities, Q::read_fetch(columns)));
+            }
+        }
+    }
+}
+
+/// The iterator behind [`StaticQueryRef::iter_combinations`]: yields each
+/// unordered pair of distinct matches exactly once, in match order.
+pub struct StaticQueryCombinations<'w, T: StaticTable, Q: StaticReadQueryTuple<T, M>, M> {
+    pub items: Vec<(Entity, Q::Item<'w>)>,
+    pub first: usize,
+    pub second: usize,
+}
+
+impl<'w, T: StaticTable, Q: StaticReadQueryTuple<T, M>, M> Iterator
+    for StaticQueryCombinations<'w, T, Q, M>
+where
+    Q::Item<'w>: Copy,
+{
+    type Item = ((Entity, Q::Item<'w>), (Entity, Q::Item<'w>));
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.second >= self.items.len() {
+            if self.first + 2 >= self.items.len() {
+                return None;
+            }
+            self.first += 1;
+            self.second = self.first + 1;
+        }
+        let pair = (self.items[self.first], self.items[self.second]);
+        self.second += 1;
+        Some(pair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ecs;
+
+    #[derive(Default, Debug, Clone, Copy, PartialEq)]
+    pub struct Position {
+        pub x: f32,
+    }
+
+    #[derive(Default, Debug, Clone, Copy, PartialEq)]
+    pub struct Velocity {
+        pub x: f32,
+    }
+
+    #[derive(Default, Debug, Clone, Copy, PartialEq)]
+    pub struct Health {
+        pub value: f32,
+    }
+
+    mod single {
+        use super::*;
+        use crate::static_query::StaticQueryRef;
+
+        ecs! {
+            World {
+                position: Position => POSITION,
+                velocity: Velocity => VELOCITY,
+                health: Health => HEALTH,
+            }
+            Tags {
+                player => PLAYER,
+                enemy => ENEMY,
+            }
+            Resources {}
+        }
+
+        fn spawn(world: &mut World, x: f32, health: Option<f32>) -> crate::Entity {
+            let mask = if health.is_some() {
+                POSITION | VELOCITY | HEALTH
+            } else {
+                POSITION | VELOCITY
+            };
+            let entity = world.spawn_entities(mask, 1)[0];
+            world.set_position(entity, Position { x });
+            world.set_velocity(entity, Velocity { x: 1.0 });
+            if let Some(value) = health {
+                world.set_health(entity, Health { value });
+            }
+            entity
+        }
+
+        #[test]
+        fn mutable_tuple_writes_and_reads_optional_columns() {
+            let mut world = World::default();
+            let plain = spawn(&mut world, 0.0, None);
+            let hardy = spawn(&mut world, 10.0, Some(2.0));
+
+            let mut visited = 0;
+            world
+                .query_tuple::<(&mut Position, &Velocity, Option<&Health>)>()
+                .for_each(|_entity, (position, velocity, health)| {
+                    position.x += velocity.x * health.map_or(1.0, |health| health.value);
+                    visited += 1;
+                });
+
+            assert_eq!(visited, 2);
+            assert_eq!(world.get_position(plain), Some(&Position { x: 1.0 }));
+            assert_eq!(world.get_position(hardy), Some(&Position { x: 12.0 }));
+
+            world
+                .query_tuple::<Option<&mut Health>>()
+                .with(POSITION)
+                .for_each(|_entity, health| {
+                    if let Some(health) = health {
+                        health.value = 0.0;
+                    }
+                });
+            assert_eq!(world.get_health(hardy), Some(&Health { value: 0.0 }));
+        }
+
+        #[test]
+        fn filters_by_component_and_tag_masks() {
+            let mut world = World::default();
+            let hero = spawn(&mut world, 1.0, Some(5.0));
+            let grunt = spawn(&mut world, 2.0, None);
+            let boss = spawn(&mut world, 3.0, Some(9.0));
+            world.add_player(hero);
+            world.add_enemy(grunt);
+            world.add_enemy(boss);
+
+            let collect = |query: StaticQueryRef<'_, World, &Position>| {
+                let mut entities: Vec<_> = query.iter().map(|(entity, _)| entity).collect();
+                entities.sort_by_key(|entity| entity.id);
+                entities
+            };
+            let mut expected = vec![grunt, boss];
+            expected.sort_by_key(|entity| entity.id);
+            assert_eq!(collect(world.query_tuple_ref().with(ENEMY)), expected);
+            assert_eq!(
+                collect(world.query_tuple_ref().with(ENEMY | HEALTH)),
+                vec![boss]
+            );
+            assert_eq!(
+                collect(world.query_tuple_ref().without(HEALTH)),
+                vec![grunt]
+            );
+            assert_eq!(collect(world.query_tuple_ref().without(ENEMY)), vec![hero]);
+
+            let mut tagged = Vec::new();
+            world
+                .query_tuple::<&mut Velocity>()
+                .with(PLAYER)
+                .for_each(|entity, _velocity| tagged.push(entity));
+            assert_eq!(tagged, vec![hero]);
+        }
+
+        #[test]
+        fn changed_filter_sees_writes_since_last_tick() {
+            let mut world = World::default();
+            let moved = spawn(&mut world, 0.0, None);
+            spawn(&mut world, 0.0, None);
+            world.add_player(moved);
+            world.step();
+
+            world
+                .query_tuple::<(&mut Position, &Velocity)>()
+                .with(PLAYER)
+                .for_each(|_entity, (position, velocity)| position.x += velocity.x);
+
+            let changed: Vec<_> = world
+                .query_tuple_ref::<&Position>()
+                .changed(POSITION)
+                .iter()
+                .map(|(entity, _)| entity)
+                .collect();
+            assert_eq!(changed, vec![moved]);
+
+            let mut seen = Vec::new();
+            world
+                .query_tuple::<(&mut Velocity, &Position)>()
+                .changed(POSITION)
+                .for_each(|entity, _| seen.push(entity));
+            assert_eq!(seen, vec![moved]);
+        }
+
+        #[test]
+        fn single_requires_exactly_one_match() {
+            let mut world = World::default();
+            let hero = spawn(&mut world, 4.0, Some(1.0));
+            spawn(&mut world, 0.0, None);
+
+            let (entity, (position, health)) = world
+                .query_tuple_ref::<(&Position, &Health)>()
+                .single()
+                .unwrap();
+            assert_eq!(entity, hero);
+            assert_eq!(position.x, 4.0);
+            assert_eq!(health.value, 1.0);
+            assert!(world.query_tuple_ref::<&Position>().single().is_none());
+            assert!(
+                world
+                    .query_tuple_ref::<&Position>()
+                    .with(PLAYER)
+                    .single()
+                    .is_none()
+            );
+        }
+
+        #[test]
+        fn iter_combinations_yields_each_pair_once() {
+            let mut world = World::default();
+            for x in 0..4 {
+                spawn(&mut world, x as f32, None);
+            }
+
+            let pairs: Vec<_> = world
+                .query_tuple_ref::<&Position>()
+                .iter_combinations()
+                .map(|((_, first), (_, second))| (first.x as u32, second.x as u32))
+                .collect();
+            assert_eq!(pairs, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+        }
+
+        #[test]
+        fn disabled_entities_are_skipped_unless_included() {
+            let mut world = World::default();
+            let hidden = spawn(&mut world, 0.0, None);
+            spawn(&mut world, 0.0, None);
+            world.disable(hidden);
+
+            assert_eq!(world.query_tuple_ref::<&Position>().iter().count(), 1);
+            assert_eq!(
+                world
+                    .query_tuple_ref::<&Position>()
+                    .include_disabled()
+                    .iter()
+                    .count(),
+                2
+            );
+
+            let mut visited = 0;
+            world
+                .query_tuple::<&mut Position>()
+                .include_disabled()
+                .for_each(|_, _| visited += 1);
+            assert_eq!(visited, 2);
+        }
+
+        #[test]
+        #[should_panic(expected = "may name each component only once")]
+        fn mutable_tuple_rejects_duplicate_components() {
+            let mut world = World::default();
+            spawn(&mut world, 0.0, None);
+            world
+                .query_tuple::<(&mut Position, &Position)>()
+                .for_each(|_, _| {});
+        }
+    }
+
+    mod duplicate_types {
+        use super::*;
+
+        ecs! {
+            Twins {
+                left: Position => LEFT,
+                right: Position => RIGHT,
+                velocity: Velocity => VELOCITY,
+            }
+            Tags {}
+            Resources {}
+        }
+
+        #[test]
+        fn unambiguous_components_stay_queryable() {
+            let mut world = Twins::default();
+            world.spawn_entities(LEFT | RIGHT | VELOCITY, 2);
+
+            world
+                .query_tuple::<&mut Velocity>()
+                .with(LEFT | RIGHT)
+                .for_each(|_, velocity| velocity.x = 3.0);
+            assert_eq!(
+                world
+                    .query_tuple_ref::<&Velocity>()
+                    .iter()
+                    .filter(|(_, velocity)| velocity.x == 3.0)
+                    .count(),
+                2
+            );
+        }
+    }
+
+    mod multi {
+        use super::*;
+
+        ecs! {
+            Game {
+                CoreWorld {
+                    position: Position => POSITION,
+                    velocity: Velocity => VELOCITY,
+                }
+                StatsWorld {
+                    health: Health => HEALTH,
+                }
+            }
+            Tags {}
+            Events {}
+            Resources {}
+        }
+
+        #[test]
+        fn member_worlds_run_typed_queries() {
+            let mut ecs = Game::default();
+            let mover = ecs.spawn();
+            ecs.core_world.set_position(mover, Position { x: 1.0 });
+            ecs.core_world.set_velocity(mover, Velocity { x: 2.0 });
+            let hidden = ecs.spawn();
+            ecs.core_world.set_position(hidden, Position { x: 0.0 });
+            ecs.core_world.set_velocity(hidden, Velocity { x: 2.0 });
+            ecs.stats_world.set_health(mover, Health { value: 7.0 });
+            ecs.disable(hidden);
+
+            ecs.core_world
+                .query_tuple::<(&mut Position, &Velocity)>()
+                .for_each(|_, (position, velocity)| position.x += velocity.x);
+            assert_eq!(
+                ecs.core_world.get_position(mover),
+                Some(&Position { x: 3.0 })
+            );
+            assert_eq!(
+                ecs.core_world.get_position(hidden),
+                Some(&Position { x: 0.0 })
+            );
+
+            let (entity, health) = ecs
+                .stats_world
+                .query_tuple_ref::<&Health>()
+                .single()
+                .unwrap();
+            assert_eq!(entity, mover);
+            assert_eq!(health.value, 7.0);
+        }
+    }
+}
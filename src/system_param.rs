@@ -45,7 +45,9 @@
 //! [`ResourceMap`](crate::dynamic::ResourceMap) through the same take/put
 //! scope [`resources_scope`](crate::dynamic::ResourceHostExt::resources_scope)
 //! uses, so they never alias a query's table borrow. Resource parameters
-//! come first, query parameters after.
+//! come first, query parameters after. `Option<Res<T>>` and
+//! `Option<ResMut<T>>` resolve to `None` for an absent resource instead of
+//! panicking, and [`Local`] holds per-system state in the runner itself.
 //!
 //! A single query parameter borrows the world directly and pays nothing
 //! beyond the query itself. Several query parameters in one system, such as
@@ -54,8 +56,9 @@
 //! [`for_each`](Query::for_each), so the cost is one borrow check per call
 //! rather than anything per entity. Because `for_each` consumes the query,
 //! two of them run in sequence, never nested. Type-level filters ([`With`],
-//! [`Without`], [`Changed`], [`Added`], [`WithTag`], [`WithoutTag`], and
-//! tuples of them) narrow a query as `Query<(&mut Position,), With<Player>>`.
+//! [`Without`], [`Changed`], [`Added`], [`WithTag`], [`WithoutTag`],
+//! [`IncludeDisabled`], and tuples of them) narrow a query as
+//! `Query<(&mut Position,), With<Player>>`.
 //! [`ParamSet`] groups queries behind `p0()`/`p1()` accessors when you would
 //! rather name a set than list the queries.
 //!
@@ -103,6 +106,33 @@
 //! assert_eq!(world.get::<Position>(world.query_ref::<&Position>().single().unwrap().0).unwrap().x, 1.0);
 //! ```
 //!
+//! A system may return `Result<(), E>` instead of `()`, for any `E` that
+//! boxes into a [`BoxedSystemError`](crate::BoxedSystemError): an error
+//! type, a `String`, or a `&str`. The schedule routes the error through its
+//! [`ErrorPolicy`](crate::ErrorPolicy) under the system's name, after the
+//! runner has put back every extracted resource.
+//!
+//! Every runner also keeps its own change-tick baseline in a
+//! [`SystemTicks`], swapped into the host through
+//! [`ResourceHost::enter_system_ticks`] for the span of each run. So
+//! [`Changed`] and [`Added`], and the builder filters on any query the
+//! system makes, compare against the tick this system last ran at, not the
+//! world's last step.
+//!
+//! The [`system_param!`](crate::system_param) macro declares a struct of
+//! parameters a system takes as one argument, nested bundles included, and
+//! each bundle reports the union of its fields' [`SystemAccess`] for
+//! conflict checks.
+//!
+//! [`NonSend`] and [`NonSendMut`] resolve like [`Res`] and [`ResMut`], but
+//! from the host's [`NonSendResources`], which hold values that are not
+//! `Send` or `Sync`, such as window handles or `Rc` caches. Those values live
+//! on the thread that inserted them, so a system taking one must run there.
+//! The sequential [`Schedule`] always runs systems on the calling thread.
+//! freecs has no parallel executor, so nothing here reads
+//! [`SystemAccess::is_pinned`]; it is a report for an executor of your own,
+//! and a pinned system run on the wrong thread panics at its first access.
+//!
 //! [`EventReader`] and [`EventWriter`] are extract parameters over the host's
 //! event bus. A writer buffers its sends and flushes them after the system
 //! returns; a reader keeps its own cursor in the runner, so it sees each event
@@ -139,10 +169,16 @@
 //! ```
 
 use crate::Entity;
-use crate::Schedule;
-use crate::dynamic::{DynEcs, DynJoin, DynQuery, DynWorld, EventBus, QueryTuple, ResourceHost};
+use crate::condition::IntoCondition;
+use crate::dynamic::{
+    ComponentData, DynEcs, DynJoin, DynQuery, DynWorld, EventBus, NonSendResources, QueryTuple,
+    ResourceHost, SystemTicks,
+};
+use crate::{ErrorPolicy, Schedule, SystemError, SystemOutput, SystemResult};
+use std::any::TypeId;
 use std::cell::RefCell;
 use std::marker::PhantomData;
+use std::rc::Rc;
 
 /// A shared reference to a resource of type `T`, resolved for a system
 /// parameter. Dereferences to `T`.
@@ -176,6 +212,59 @@ impl<T> std::ops::DerefMut for ResMut<'_, T> {
     }
 }
 
+/// A shared reference to a non-send resource of type `T`, resolved for a
+/// system parameter from the host's
+/// [`NonSendResources`]. Dereferences to `T`. A system taking one runs on
+/// the thread that owns those resources and panics anywhere else; its
+/// [`SystemAccess::is_pinned`] reports this to an executor of your own.
+pub struct NonSend<'world, T> {
+    value: &'world T,
+}
+
+impl<T> std::ops::Deref for NonSend<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+/// The exclusive form of [`NonSend`]. Dereferences to `T` and, mutably,
+/// writes through to it.
+pub struct NonSendMut<'world, T> {
+    value: &'world mut T,
+}
+
+impl<T> std::ops::Deref for NonSendMut<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T> std::ops::DerefMut for NonSendMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.value
+    }
+}
+
+/// A host that owns [`NonSendResources`], so [`NonSend`] and [`NonSendMut`]
+/// parameters resolve against it. [`DynWorld`] and [`DynEcs`] both embed one.
+pub trait NonSendHost {
+    fn non_send_mut(&mut self) -> &mut NonSendResources;
+}
+
+impl NonSendHost for DynWorld {
+    fn non_send_mut(&mut self) -> &mut NonSendResources {
+        &mut self.non_send
+    }
+}
+
+impl NonSendHost for DynEcs {
+    fn non_send_mut(&mut self) -> &mut NonSendResources {
+        &mut self.non_send
+    }
+}
+
 /// A host that owns an [`EventBus`], so [`EventReader`] and [`EventWriter`]
 /// parameters resolve against it. [`DynWorld`] and [`DynEcs`] both embed one;
 /// a bare [`ResourceHost`] does not, which is why event parameters are
@@ -210,9 +299,22 @@ impl EventHost for DynEcs {
     }
 }
 
+impl<W: EventHost> ErrorPolicy<W> {
+    /// Sends each failure onto the host's event bus as a [`SystemError`]
+    /// event and runs the remaining systems, so an
+    /// [`EventReader<SystemError>`](EventReader) or
+    /// [`EventBus::read`] sees it like any other event.
+    pub fn send_events() -> Self {
+        Self::Handler(Box::new(|world: &mut W, error: &SystemError| {
+            world.event_bus_mut().send(error.clone());
+        }))
+    }
+}
+
 /// A system parameter resolved by taking data out of the host before the
-/// system runs and writing data back after. [`Res`], [`ResMut`],
-/// [`EventReader`], and [`EventWriter`] are the extract parameters. Each
+/// system runs and writing data back after. [`Res`], [`ResMut`], their
+/// `Option` forms, [`NonSend`], [`NonSendMut`], [`Local`], [`EventReader`],
+/// and [`EventWriter`] are the extract parameters. Each
 /// carries a [`State`](Self::State) kept in the runner between runs (an event
 /// reader's cursor, say), produces an [`Owned`](Self::Owned) value the
 /// parameter borrows for the call, and flushes through [`apply`](Self::apply)
@@ -255,7 +357,7 @@ impl<W: ResourceHost, T: Send + Sync + 'static> ExtractParam<W> for Res<'_, T> {
         Res { value: owned }
     }
     fn apply(_state: &mut (), owned: T, host: &mut W) {
-        host.resource_map_mut().insert(owned);
+        host.resource_map_mut().restore(owned);
     }
 }
 
@@ -280,6 +382,122 @@ impl<W: ResourceHost, T: Send + Sync + 'static> ExtractParam<W> for ResMut<'_, T
     }
 }
 
+impl<W: ResourceHost, T: Send + Sync + 'static> ExtractParam<W> for Option<Res<'_, T>> {
+    type State = ();
+    type Owned = Option<T>;
+    type Item<'item> = Option<Res<'item, T>>;
+    fn init() -> Self::State {}
+    fn extract(_state: &mut (), host: &mut W) -> Option<T> {
+        host.resource_map_mut().remove::<T>()
+    }
+    fn build(owned: &mut Option<T>) -> Option<Res<'_, T>> {
+        owned.as_mut().map(|value| Res { value })
+    }
+    fn apply(_state: &mut (), owned: Option<T>, host: &mut W) {
+        if let Some(value) = owned {
+            host.resource_map_mut().restore(value);
+        }
+    }
+}
+
+impl<W: ResourceHost, T: Send + Sync + 'static> ExtractParam<W> for Option<ResMut<'_, T>> {
+    type State = ();
+    type Owned = Option<T>;
+    type Item<'item> = Option<ResMut<'item, T>>;
+    fn init() -> Self::State {}
+    fn extract(_state: &mut (), host: &mut W) -> Option<T> {
+        host.resource_map_mut().remove::<T>()
+    }
+    fn build(owned: &mut Option<T>) -> Option<ResMut<'_, T>> {
+        owned.as_mut().map(|value| ResMut { value })
+    }
+    fn apply(_state: &mut (), owned: Option<T>, host: &mut W) {
+        if let Some(value) = owned {
+            host.resource_map_mut().insert(value);
+        }
+    }
+}
+
+fn extract_non_send<W: NonSendHost, T: 'static>(host: &mut W) -> T {
+    host.non_send_mut().remove::<T>().unwrap_or_else(|| {
+        panic!(
+            "system requires non-send resource {} to be present",
+            std::any::type_name::<T>()
+        )
+    })
+}
+
+impl<W: NonSendHost, T: 'static> ExtractParam<W> for NonSend<'_, T> {
+    type State = ();
+    type Owned = T;
+    type Item<'item> = NonSend<'item, T>;
+    fn init() -> Self::State {}
+    fn extract(_state: &mut (), host: &mut W) -> T {
+        extract_non_send(host)
+    }
+    fn build(owned: &mut T) -> NonSend<'_, T> {
+        NonSend { value: owned }
+    }
+    fn apply(_state: &mut (), owned: T, host: &mut W) {
+        host.non_send_mut().insert(owned);
+    }
+}
+
+impl<W: NonSendHost, T: 'static> ExtractParam<W> for NonSendMut<'_, T> {
+    type State = ();
+    type Owned = T;
+    type Item<'item> = NonSendMut<'item, T>;
+    fn init() -> Self::State {}
+    fn extract(_state: &mut (), host: &mut W) -> T {
+        extract_non_send(host)
+    }
+    fn build(owned: &mut T) -> NonSendMut<'_, T> {
+        NonSendMut { value: owned }
+    }
+    fn apply(_state: &mut (), owned: T, host: &mut W) {
+        host.non_send_mut().insert(owned);
+    }
+}
+
+/// Per-system state of type `T`, kept in the runner between runs and
+/// starting from `T::default()`. Each system gets its own value, even when
+/// two systems name the same type, so state that only one system reads no
+/// longer needs to live in the world as a resource. Dereferences to `T`.
+pub struct Local<'a, T> {
+    value: &'a mut T,
+}
+
+impl<T> std::ops::Deref for Local<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T> std::ops::DerefMut for Local<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.value
+    }
+}
+
+impl<W, T: Default + Send + 'static> ExtractParam<W> for Local<'_, T> {
+    type State = T;
+    type Owned = T;
+    type Item<'item> = Local<'item, T>;
+    fn init() -> T {
+        T::default()
+    }
+    fn extract(state: &mut T, _host: &mut W) -> T {
+        std::mem::take(state)
+    }
+    fn build(owned: &mut T) -> Local<'_, T> {
+        Local { value: owned }
+    }
+    fn apply(state: &mut T, owned: T, _host: &mut W) {
+        *state = owned;
+    }
+}
+
 /// A system parameter that reads events of type `T` from the host's event
 /// bus, delivering each event to this system exactly once across frames. The
 /// reader keeps its own cursor in the runner, so two systems reading the same
@@ -369,6 +587,215 @@ impl<W: EventHost, T: Send + Sync + 'static> ExtractParam<W> for EventWriter<'_,
     }
 }
 
+/// The resources, events, and components a system parameter touches, split
+/// into reads and writes. Collected through [`SystemParamAccess`] without a
+/// world, so two systems or two [`system_param!`](crate::system_param)
+/// bundles can be checked for overlap before either runs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SystemAccess {
+    /// Resources borrowed shared.
+    pub resources_read: Vec<(TypeId, &'static str)>,
+    /// Resources borrowed exclusively.
+    pub resources_written: Vec<(TypeId, &'static str)>,
+    /// Event types read through an [`EventReader`].
+    pub events_read: Vec<(TypeId, &'static str)>,
+    /// Event types sent through an [`EventWriter`].
+    pub events_written: Vec<(TypeId, &'static str)>,
+    /// Components a [`Query`] fetches shared.
+    pub components_read: Vec<(TypeId, &'static str)>,
+    /// Components a [`Query`] fetches mutably.
+    pub components_written: Vec<(TypeId, &'static str)>,
+    /// Non-send resources borrowed shared through [`NonSend`].
+    pub non_send_read: Vec<(TypeId, &'static str)>,
+    /// Non-send resources borrowed exclusively through [`NonSendMut`].
+    pub non_send_written: Vec<(TypeId, &'static str)>,
+}
+
+impl SystemAccess {
+    /// Collects the access of parameter `P`, or of a tuple listing a
+    /// system's parameters in order.
+    pub fn of<P: SystemParamAccess + ?Sized>() -> Self {
+        let mut access = Self::default();
+        P::add_access(&mut access);
+        access
+    }
+
+    /// The type names both sides touch where at least one side writes, in
+    /// the order this side lists them. Empty when the two can run side by
+    /// side.
+    pub fn conflicts(&self, other: &SystemAccess) -> Vec<&'static str> {
+        let mut names = Vec::new();
+        let pairs = [
+            (&self.resources_read, &other.resources_written),
+            (&self.resources_written, &other.resources_read),
+            (&self.resources_written, &other.resources_written),
+            (&self.events_read, &other.events_written),
+            (&self.events_written, &other.events_read),
+            (&self.events_written, &other.events_written),
+            (&self.components_read, &other.components_written),
+            (&self.components_written, &other.components_read),
+            (&self.components_written, &other.components_written),
+            (&self.non_send_read, &other.non_send_written),
+            (&self.non_send_written, &other.non_send_read),
+            (&self.non_send_written, &other.non_send_written),
+        ];
+        for (ours, theirs) in pairs {
+            for (type_id, name) in ours {
+                if theirs.iter().any(|(other_id, _)| other_id == type_id) && !names.contains(name) {
+                    names.push(*name);
+                }
+            }
+        }
+        names
+    }
+
+    /// Whether this access overlaps `other` with a write on either side.
+    pub fn conflicts_with(&self, other: &SystemAccess) -> bool {
+        !self.conflicts(other).is_empty()
+    }
+
+    /// Whether the system touches non-send resources, which pins it to the
+    /// thread that owns them. Nothing in freecs consults this: [`Schedule`]
+    /// runs every system on the calling thread. An executor of your own that
+    /// spreads systems across worker threads should run a pinned system on
+    /// the thread holding the world's non-send resources; run anywhere else,
+    /// the system panics when it resolves the resource.
+    pub fn is_pinned(&self) -> bool {
+        !self.non_send_read.is_empty() || !self.non_send_written.is_empty()
+    }
+
+    /// The resource type names this access takes more than once with a
+    /// write among them. A system whose parameters list such a resource
+    /// panics on its first run, because the first parameter lifts the
+    /// resource out before the second asks for it.
+    pub fn resource_overlaps(&self) -> Vec<&'static str> {
+        let mut names = Vec::new();
+        for (index, (type_id, name)) in self.resources_written.iter().enumerate() {
+            let repeated = self.resources_written[index + 1..]
+                .iter()
+                .chain(&self.resources_read)
+                .any(|(other_id, _)| other_id == type_id);
+            if repeated && !names.contains(name) {
+                names.push(*name);
+            }
+        }
+        names
+    }
+
+    /// Appends every entry of `other` to this access.
+    pub fn extend(&mut self, other: &SystemAccess) {
+        self.resources_read.extend_from_slice(&other.resources_read);
+        self.resources_written
+            .extend_from_slice(&other.resources_written);
+        self.events_read.extend_from_slice(&other.events_read);
+        self.events_written.extend_from_slice(&other.events_written);
+        self.components_read
+            .extend_from_slice(&other.components_read);
+        self.components_written
+            .extend_from_slice(&other.components_written);
+        self.non_send_read.extend_from_slice(&other.non_send_read);
+        self.non_send_written
+            .extend_from_slice(&other.non_send_written);
+    }
+}
+
+fn entry<T: 'static>() -> (TypeId, &'static str) {
+    (TypeId::of::<T>(), std::any::type_name::<T>())
+}
+
+/// A system parameter that can describe what it touches. Implemented for
+/// every built-in parameter and for each [`system_param!`](crate::system_param)
+/// bundle, which reports the union of its fields.
+pub trait SystemParamAccess {
+    /// Appends this parameter's reads and writes to `access`.
+    fn add_access(access: &mut SystemAccess);
+}
+
+impl<T: 'static> SystemParamAccess for Res<'_, T> {
+    fn add_access(access: &mut SystemAccess) {
+        access.resources_read.push(entry::<T>());
+    }
+}
+
+impl<T: 'static> SystemParamAccess for ResMut<'_, T> {
+    fn add_access(access: &mut SystemAccess) {
+        access.resources_written.push(entry::<T>());
+    }
+}
+
+impl<T: 'static> SystemParamAccess for Option<Res<'_, T>> {
+    fn add_access(access: &mut SystemAccess) {
+        access.resources_read.push(entry::<T>());
+    }
+}
+
+impl<T: 'static> SystemParamAccess for Option<ResMut<'_, T>> {
+    fn add_access(access: &mut SystemAccess) {
+        access.resources_written.push(entry::<T>());
+    }
+}
+
+impl<T: 'static> SystemParamAccess for NonSend<'_, T> {
+    fn add_access(access: &mut SystemAccess) {
+        access.non_send_read.push(entry::<T>());
+    }
+}
+
+impl<T: 'static> SystemParamAccess for NonSendMut<'_, T> {
+    fn add_access(access: &mut SystemAccess) {
+        access.non_send_written.push(entry::<T>());
+    }
+}
+
+impl<T> SystemParamAccess for Local<'_, T> {
+    fn add_access(_access: &mut SystemAccess) {}
+}
+
+impl<T: Clone + Send + Sync + 'static> SystemParamAccess for EventReader<'_, T> {
+    fn add_access(access: &mut SystemAccess) {
+        access.events_read.push(entry::<T>());
+    }
+}
+
+impl<T: Send + Sync + 'static> SystemParamAccess for EventWriter<'_, T> {
+    fn add_access(access: &mut SystemAccess) {
+        access.events_written.push(entry::<T>());
+    }
+}
+
+impl<Q: QueryTuple, F: QueryFilter> SystemParamAccess for Query<'_, Q, F> {
+    fn add_access(access: &mut SystemAccess) {
+        let mut types = Vec::new();
+        Q::component_types(&mut types);
+        for (type_id, name, mutable) in types {
+            if mutable {
+                access.components_written.push((type_id, name));
+            } else {
+                access.components_read.push((type_id, name));
+            }
+        }
+    }
+}
+
+macro_rules! impl_param_access_tuple {
+    ($($param:ident),+) => {
+        impl<$($param: SystemParamAccess),+> SystemParamAccess for ($($param,)+) {
+            fn add_access(access: &mut SystemAccess) {
+                $($param::add_access(access);)+
+            }
+        }
+    };
+}
+
+impl_param_access_tuple!(P0);
+impl_param_access_tuple!(P0, P1);
+impl_param_access_tuple!(P0, P1, P2);
+impl_param_access_tuple!(P0, P1, P2, P3);
+impl_param_access_tuple!(P0, P1, P2, P3, P4);
+impl_param_access_tuple!(P0, P1, P2, P3, P4, P5);
+impl_param_access_tuple!(P0, P1, P2, P3, P4, P5, P6);
+impl_param_access_tuple!(P0, P1, P2, P3, P4, P5, P6, P7);
+
 /// A type-level query filter: [`With`], [`Without`], [`Changed`], [`Added`],
 /// the unit type for no filter, or a tuple of filters applied in order.
 pub trait QueryFilter {
@@ -386,7 +813,7 @@ impl QueryFilter for () {
 /// fetching it.
 pub struct With<T>(PhantomData<fn() -> T>);
 
-impl<T: Send + Sync + Default + 'static> QueryFilter for With<T> {
+impl<T: ComponentData> QueryFilter for With<T> {
     fn apply<Q: QueryTuple>(query: DynQuery<'_, Q>) -> DynQuery<'_, Q> {
         query.with::<T>()
     }
@@ -395,27 +822,29 @@ impl<T: Send + Sync + Default + 'static> QueryFilter for With<T> {
 /// Restricts a query to entities that do not carry component `T`.
 pub struct Without<T>(PhantomData<fn() -> T>);
 
-impl<T: Send + Sync + Default + 'static> QueryFilter for Without<T> {
+impl<T: ComponentData> QueryFilter for Without<T> {
     fn apply<Q: QueryTuple>(query: DynQuery<'_, Q>) -> DynQuery<'_, Q> {
         query.without::<T>()
     }
 }
 
-/// Restricts a query to entities whose component `T` changed since the last
-/// step. `T` must appear in the query tuple.
+/// Restricts a query to entities whose component `T` changed since this
+/// system last ran, or since the last step on its first run. `T` must
+/// appear in the query tuple.
 pub struct Changed<T>(PhantomData<fn() -> T>);
 
-impl<T: Send + Sync + Default + 'static> QueryFilter for Changed<T> {
+impl<T: ComponentData> QueryFilter for Changed<T> {
     fn apply<Q: QueryTuple>(query: DynQuery<'_, Q>) -> DynQuery<'_, Q> {
         query.changed::<T>()
     }
 }
 
-/// Restricts a query to entities that gained component `T` since the last
-/// step. `T` must appear in the query tuple.
+/// Restricts a query to entities that gained component `T` since this
+/// system last ran, or since the last step on its first run. `T` must
+/// appear in the query tuple.
 pub struct Added<T>(PhantomData<fn() -> T>);
 
-impl<T: Send + Sync + Default + 'static> QueryFilter for Added<T> {
+impl<T: ComponentData> QueryFilter for Added<T> {
     fn apply<Q: QueryTuple>(query: DynQuery<'_, Q>) -> DynQuery<'_, Q> {
         query.added::<T>()
     }
@@ -439,6 +868,16 @@ impl<T: 'static> QueryFilter for WithoutTag<T> {
     }
 }
 
+/// Lets a query visit disabled entities, which every query skips by
+/// default.
+pub struct IncludeDisabled;
+
+impl QueryFilter for IncludeDisabled {
+    fn apply<Q: QueryTuple>(query: DynQuery<'_, Q>) -> DynQuery<'_, Q> {
+        query.include_disabled()
+    }
+}
+
 macro_rules! impl_query_filter_tuple {
     ($($filter:ident),+) => {
         impl<$($filter: QueryFilter),+> QueryFilter for ($($filter,)+) {
@@ -458,6 +897,7 @@ impl_query_filter_tuple!(A, B, C, D);
 enum QueryState<'world, Q: QueryTuple> {
     Eager(DynQuery<'world, Q>),
     Lazy(&'world RefCell<&'world mut DynWorld>),
+    Shared(Rc<RefCell<&'world mut DynWorld>>),
     Join(DynJoin<'world, Q>),
 }
 
@@ -480,6 +920,11 @@ impl<'world, Q: QueryTuple, F: QueryFilter> Query<'world, Q, F> {
                 let world: &mut DynWorld = &mut guard;
                 F::apply(world.query::<Q>()).for_each(f);
             }
+            QueryState::Shared(cell) => {
+                let mut guard = cell.borrow_mut();
+                let world: &mut DynWorld = &mut guard;
+                F::apply(world.query::<Q>()).for_each(f);
+            }
             QueryState::Join(join) => join.for_each(f),
         }
     }
@@ -497,6 +942,11 @@ impl<'world, Q: QueryTuple, F: QueryFilter> Query<'world, Q, F> {
                 let world: &mut DynWorld = &mut guard;
                 F::apply(world.query::<Q>()).par_for_each(f);
             }
+            QueryState::Shared(cell) => {
+                let mut guard = cell.borrow_mut();
+                let world: &mut DynWorld = &mut guard;
+                F::apply(world.query::<Q>()).par_for_each(f);
+            }
             QueryState::Join(join) => join.par_for_each(f),
         }
     }
@@ -555,6 +1005,10 @@ pub trait MultiQueryParam {
     type Item<'world>;
     /// Resolves the parameter against the shared world cell.
     fn build_lazy<'world>(cell: &'world RefCell<&'world mut DynWorld>) -> Self::Item<'world>;
+    /// Resolves the parameter against a world cell the parameter values
+    /// co-own, the form a [`system_param!`](crate::system_param) query bundle
+    /// uses when it stands alone as a system's world parameter.
+    fn build_shared<'world>(cell: &Rc<RefCell<&'world mut DynWorld>>) -> Self::Item<'world>;
 }
 
 impl<'a, Q: QueryTuple, F: QueryFilter> MultiQueryParam for Query<'a, Q, F> {
@@ -565,6 +1019,12 @@ impl<'a, Q: QueryTuple, F: QueryFilter> MultiQueryParam for Query<'a, Q, F> {
             filter: PhantomData,
         }
     }
+    fn build_shared<'world>(cell: &Rc<RefCell<&'world mut DynWorld>>) -> Query<'world, Q, F> {
+        Query {
+            state: QueryState::Shared(Rc::clone(cell)),
+            filter: PhantomData,
+        }
+    }
 }
 
 /// A set of conflicting query parameters accessed one at a time. Holds the
@@ -624,6 +1084,23 @@ impl<'world, P0: WorldParam, P1: WorldParam, P2: WorldParam> ParamSet<'world, (P
     }
 }
 
+impl<P0: SystemParamAccess, P1: SystemParamAccess> SystemParamAccess for ParamSet<'_, (P0, P1)> {
+    fn add_access(access: &mut SystemAccess) {
+        P0::add_access(access);
+        P1::add_access(access);
+    }
+}
+
+impl<P0: SystemParamAccess, P1: SystemParamAccess, P2: SystemParamAccess> SystemParamAccess
+    for ParamSet<'_, (P0, P1, P2)>
+{
+    fn add_access(access: &mut SystemAccess) {
+        P0::add_access(access);
+        P1::add_access(access);
+        P2::add_access(access);
+    }
+}
+
 /// Converts a system-parameter function into a runner the
 /// [`Schedule`] accepts, over a world type `W`. `Marker` is inferred from
 /// the function's parameter types. Resource-only systems (`fn(Res<A>,
@@ -634,7 +1111,7 @@ impl<'world, P0: WorldParam, P1: WorldParam, P2: WorldParam> ParamSet<'world, (P
 pub trait IntoSystem<W, Marker>: Sized {
     /// Wraps the function into a closure that resolves its parameters from
     /// the world on each call.
-    fn into_runner(self) -> impl FnMut(&mut W) + Send + 'static;
+    fn into_runner(self) -> impl FnMut(&mut W) -> SystemResult + Send + 'static;
 }
 
 /// Registers system-parameter functions on a [`Schedule<W>`](crate::Schedule),
@@ -651,16 +1128,17 @@ pub trait ScheduleExt<W> {
 
     /// Adds a system-parameter function that runs only on the passes where
     /// `condition` holds, the param-system form of
-    /// [`Schedule::push_if`](crate::Schedule::push_if). The condition reads
-    /// the world each pass; a false skips the system for that pass without
-    /// removing it. This is how a run condition composes with resource,
-    /// query, and event parameters, so gating a system on a state the host
-    /// carries stays declarative: pass `|world| in_state(world, ...)` as the
-    /// condition. The state machinery itself belongs to the host, not here.
-    fn add_system_if<Marker>(
+    /// [`Schedule::push_if`](crate::Schedule::push_if). The condition is
+    /// evaluated each pass; a false skips the system for that pass without
+    /// removing it. Any [`IntoCondition`] works: a `|world: &W| ...`
+    /// closure, a param function such as `fn(Res<Paused>) -> bool`, a
+    /// built-in such as [`resource_exists`](crate::condition::resource_exists),
+    /// or a composition through [`and`](crate::condition::and),
+    /// [`or`](crate::condition::or), and [`not`](crate::condition::not).
+    fn add_system_if<ConditionMarker, Marker>(
         &mut self,
         name: &'static str,
-        condition: impl Fn(&W) -> bool + Send + 'static,
+        condition: impl IntoCondition<W, ConditionMarker>,
         system: impl IntoSystem<W, Marker>,
     ) -> &mut Self;
 
@@ -681,13 +1159,21 @@ impl<W> ScheduleExt<W> for Schedule<W> {
         self.push(name, system.into_runner())
     }
 
-    fn add_system_if<Marker>(
+    fn add_system_if<ConditionMarker, Marker>(
         &mut self,
         name: &'static str,
-        condition: impl Fn(&W) -> bool + Send + 'static,
+        condition: impl IntoCondition<W, ConditionMarker>,
         system: impl IntoSystem<W, Marker>,
     ) -> &mut Self {
-        self.push_if(name, condition, system.into_runner())
+        let mut condition = condition.into_condition();
+        let mut runner = system.into_runner();
+        self.push(name, move |world: &mut W| {
+            if condition(world) {
+                runner(world)
+            } else {
+                Ok(())
+            }
+        })
     }
 
     fn add_systems<Marker>(&mut self, systems: impl IntoSystems<W, Marker>) -> &mut Self {
@@ -762,78 +1248,108 @@ impl_into_systems!(
     (S7, M7)
 );
 
+/// Runs one system pass with the host's change filters rebased onto the
+/// system's own last run, restoring the host's baseline even if it panics.
+fn run_with_ticks<W: ResourceHost, Out: SystemOutput>(
+    host: &mut W,
+    ticks: &mut SystemTicks,
+    run: impl FnOnce(&mut W) -> Out,
+) -> SystemResult {
+    host.enter_system_ticks(ticks);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| run(host)));
+    host.exit_system_ticks(ticks);
+    finish(result)
+}
+
+/// Lowers a caught system run to its result, resuming a panic.
+fn finish<Out: SystemOutput>(result: std::thread::Result<Out>) -> SystemResult {
+    match result {
+        Ok(output) => output.into_system_result(),
+        Err(panic) => std::panic::resume_unwind(panic),
+    }
+}
+
 /// The marker for a system whose parameters are all extract parameters.
-pub struct ExtractSystemMarker<E>(PhantomData<fn() -> E>);
+/// `Out` is the system's return type.
+pub struct ExtractSystemMarker<E, Out = ()>(PhantomData<fn() -> (E, Out)>);
 
 /// The marker for a system with extract parameters and one trailing
 /// world-borrowing parameter.
-pub struct QuerySystemMarker<E, Q>(PhantomData<fn() -> (E, Q)>);
+pub struct QuerySystemMarker<E, Q, Out = ()>(PhantomData<fn() -> (E, Q)>, PhantomData<fn() -> Out>);
 
-impl<Func, Q> IntoSystem<DynWorld, QuerySystemMarker<(), Q>> for Func
+impl<Func, Q, Out> IntoSystem<DynWorld, QuerySystemMarker<(), Q, Out>> for Func
 where
     Q: WorldParam,
-    Func: FnMut(Q) + for<'world> FnMut(Q::Item<'world>) + Send + 'static,
+    Out: SystemOutput,
+    Func: FnMut(Q) -> Out + for<'world> FnMut(Q::Item<'world>) -> Out + Send + 'static,
 {
-    fn into_runner(mut self) -> impl FnMut(&mut DynWorld) + Send + 'static {
+    fn into_runner(mut self) -> impl FnMut(&mut DynWorld) -> SystemResult + Send + 'static {
+        let mut ticks = SystemTicks::default();
         move |world: &mut DynWorld| {
-            let query = Q::build(world);
-            self(query);
+            run_with_ticks(world, &mut ticks, |world| {
+                let query = Q::build(world);
+                self(query)
+            })
         }
     }
 }
 
 /// The marker for a system whose parameters are all extract parameters
 /// followed by a final `&mut W` host argument.
-pub struct HostSystemMarker<E>(PhantomData<fn() -> E>);
+pub struct HostSystemMarker<E, Out = ()>(PhantomData<fn() -> (E, Out)>);
 
 macro_rules! impl_extract_system {
     ($($param:ident $state:ident $owned:ident),+) => {
-        impl<W, Func, $($param,)+> IntoSystem<W, ExtractSystemMarker<($($param,)+)>>
+        impl<W, Func, Out, $($param,)+> IntoSystem<W, ExtractSystemMarker<($($param,)+), Out>>
             for Func
         where
-            W: 'static,
+            W: ResourceHost + 'static,
+            Out: SystemOutput,
             $($param: ExtractParam<W>,)+
-            Func: FnMut($($param,)+)
-                + for<'item> FnMut($($param::Item<'item>,)+)
+            Func: FnMut($($param,)+) -> Out
+                + for<'item> FnMut($($param::Item<'item>,)+) -> Out
                 + Send
                 + 'static,
         {
-            fn into_runner(mut self) -> impl FnMut(&mut W) + Send + 'static {
+            fn into_runner(mut self) -> impl FnMut(&mut W) -> SystemResult + Send + 'static {
                 $(let mut $state = <$param as ExtractParam<W>>::init();)+
+                let mut ticks = SystemTicks::default();
                 move |host: &mut W| {
                     $(let mut $owned = <$param as ExtractParam<W>>::extract(&mut $state, host);)+
+                    host.enter_system_ticks(&mut ticks);
                     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                        self($(<$param as ExtractParam<W>>::build(&mut $owned),)+);
+                        self($(<$param as ExtractParam<W>>::build(&mut $owned),)+)
                     }));
+                    host.exit_system_ticks(&mut ticks);
                     $(<$param as ExtractParam<W>>::apply(&mut $state, $owned, host);)+
-                    if let Err(panic) = result {
-                        std::panic::resume_unwind(panic);
-                    }
+                    finish(result)
                 }
             }
         }
 
-        impl<W, Func, $($param,)+> IntoSystem<W, HostSystemMarker<($($param,)+)>>
+        impl<W, Func, Out, $($param,)+> IntoSystem<W, HostSystemMarker<($($param,)+), Out>>
             for Func
         where
-            W: 'static,
+            W: ResourceHost + 'static,
+            Out: SystemOutput,
             $($param: ExtractParam<W>,)+
-            Func: FnMut($($param,)+ &mut W)
-                + for<'item> FnMut($($param::Item<'item>,)+ &'item mut W)
+            Func: FnMut($($param,)+ &mut W) -> Out
+                + for<'item> FnMut($($param::Item<'item>,)+ &'item mut W) -> Out
                 + Send
                 + 'static,
         {
-            fn into_runner(mut self) -> impl FnMut(&mut W) + Send + 'static {
+            fn into_runner(mut self) -> impl FnMut(&mut W) -> SystemResult + Send + 'static {
                 $(let mut $state = <$param as ExtractParam<W>>::init();)+
+                let mut ticks = SystemTicks::default();
                 move |host: &mut W| {
                     $(let mut $owned = <$param as ExtractParam<W>>::extract(&mut $state, host);)+
+                    host.enter_system_ticks(&mut ticks);
                     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                        self($(<$param as ExtractParam<W>>::build(&mut $owned),)+ host);
+                        self($(<$param as ExtractParam<W>>::build(&mut $owned),)+ host)
                     }));
+                    host.exit_system_ticks(&mut ticks);
                     $(<$param as ExtractParam<W>>::apply(&mut $state, $owned, host);)+
-                    if let Err(panic) = result {
-                        std::panic::resume_unwind(panic);
-                    }
+                    finish(result)
                 }
             }
         }
@@ -842,29 +1358,31 @@ macro_rules! impl_extract_system {
 
 macro_rules! impl_extract_query_system {
     ($($param:ident $state:ident $owned:ident),+) => {
-        impl<Func, $($param,)+ Q>
-            IntoSystem<DynWorld, QuerySystemMarker<($($param,)+), Q>> for Func
+        impl<Func, Out, $($param,)+ Q>
+            IntoSystem<DynWorld, QuerySystemMarker<($($param,)+), Q, Out>> for Func
         where
+            Out: SystemOutput,
             $($param: ExtractParam<DynWorld>,)+
             Q: WorldParam,
-            Func: FnMut($($param,)+ Q)
-                + for<'item> FnMut($($param::Item<'item>,)+ Q::Item<'item>)
+            Func: FnMut($($param,)+ Q) -> Out
+                + for<'item> FnMut($($param::Item<'item>,)+ Q::Item<'item>) -> Out
                 + Send
                 + 'static,
         {
-            fn into_runner(mut self) -> impl FnMut(&mut DynWorld) + Send + 'static {
+            fn into_runner(mut self) -> impl FnMut(&mut DynWorld) -> SystemResult + Send + 'static {
                 $(let mut $state = <$param as ExtractParam<DynWorld>>::init();)+
+                let mut ticks = SystemTicks::default();
                 move |world: &mut DynWorld| {
                     $(let mut $owned =
                         <$param as ExtractParam<DynWorld>>::extract(&mut $state, world);)+
+                    world.enter_system_ticks(&mut ticks);
                     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
                         let query = Q::build(world);
-                        self($(<$param as ExtractParam<DynWorld>>::build(&mut $owned),)+ query);
+                        self($(<$param as ExtractParam<DynWorld>>::build(&mut $owned),)+ query)
                     }));
+                    world.exit_system_ticks(&mut ticks);
                     $(<$param as ExtractParam<DynWorld>>::apply(&mut $state, $owned, world);)+
-                    if let Err(panic) = result {
-                        std::panic::resume_unwind(panic);
-                    }
+                    finish(result)
                 }
             }
         }
@@ -907,43 +1425,49 @@ impl_extract_query_system!(
     P5 state5 owned5, P6 state6 owned6
 );
 
-impl<Func, Q> IntoSystem<DynEcs, QuerySystemMarker<(), Q>> for Func
+impl<Func, Q, Out> IntoSystem<DynEcs, QuerySystemMarker<(), Q, Out>> for Func
 where
     Q: EcsParam,
-    Func: FnMut(Q) + for<'ecs> FnMut(Q::Item<'ecs>) + Send + 'static,
+    Out: SystemOutput,
+    Func: FnMut(Q) -> Out + for<'ecs> FnMut(Q::Item<'ecs>) -> Out + Send + 'static,
 {
-    fn into_runner(mut self) -> impl FnMut(&mut DynEcs) + Send + 'static {
+    fn into_runner(mut self) -> impl FnMut(&mut DynEcs) -> SystemResult + Send + 'static {
+        let mut ticks = SystemTicks::default();
         move |ecs: &mut DynEcs| {
-            let query = Q::build_ecs(ecs);
-            self(query);
+            run_with_ticks(ecs, &mut ticks, |ecs| {
+                let query = Q::build_ecs(ecs);
+                self(query)
+            })
         }
     }
 }
 
 macro_rules! impl_extract_ecs_query_system {
     ($($param:ident $state:ident $owned:ident),+) => {
-        impl<Func, $($param,)+ Q>
-            IntoSystem<DynEcs, QuerySystemMarker<($($param,)+), Q>> for Func
+        impl<Func, Out, $($param,)+ Q>
+            IntoSystem<DynEcs, QuerySystemMarker<($($param,)+), Q, Out>> for Func
         where
+            Out: SystemOutput,
             $($param: ExtractParam<DynEcs>,)+
             Q: EcsParam,
-            Func: FnMut($($param,)+ Q)
-                + for<'item> FnMut($($param::Item<'item>,)+ Q::Item<'item>)
+            Func: FnMut($($param,)+ Q) -> Out
+                + for<'item> FnMut($($param::Item<'item>,)+ Q::Item<'item>) -> Out
                 + Send
                 + 'static,
         {
-            fn into_runner(mut self) -> impl FnMut(&mut DynEcs) + Send + 'static {
+            fn into_runner(mut self) -> impl FnMut(&mut DynEcs) -> SystemResult + Send + 'static {
                 $(let mut $state = <$param as ExtractParam<DynEcs>>::init();)+
+                let mut ticks = SystemTicks::default();
                 move |ecs: &mut DynEcs| {
                     $(let mut $owned = <$param as ExtractParam<DynEcs>>::extract(&mut $state, ecs);)+
+                    ecs.enter_system_ticks(&mut ticks);
                     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
                         let query = Q::build_ecs(ecs);
-                        self($(<$param as ExtractParam<DynEcs>>::build(&mut $owned),)+ query);
+                        self($(<$param as ExtractParam<DynEcs>>::build(&mut $owned),)+ query)
                     }));
+                    ecs.exit_system_ticks(&mut ticks);
                     $(<$param as ExtractParam<DynEcs>>::apply(&mut $state, $owned, ecs);)+
-                    if let Err(panic) = result {
-                        std::panic::resume_unwind(panic);
-                    }
+                    finish(result)
                 }
             }
         }
@@ -970,25 +1494,32 @@ impl_extract_ecs_query_system!(
 
 /// The marker for a system with resource parameters and two or more
 /// world-borrowing query parameters resolved through a shared world cell.
-pub struct MultiQuerySystemMarker<R, Q>(PhantomData<fn() -> (R, Q)>);
+pub struct MultiQuerySystemMarker<R, Q, Out = ()>(
+    PhantomData<fn() -> (R, Q)>,
+    PhantomData<fn() -> Out>,
+);
 
 macro_rules! impl_multi_query_bare {
     ($($query:ident $qbind:ident),+) => {
-        impl<Func, $($query,)+>
-            IntoSystem<DynWorld, MultiQuerySystemMarker<(), ($($query,)+)>> for Func
+        impl<Func, Out, $($query,)+>
+            IntoSystem<DynWorld, MultiQuerySystemMarker<(), ($($query,)+), Out>> for Func
         where
+            Out: SystemOutput,
             $($query: MultiQueryParam,)+
-            Func: FnMut($($query,)+)
-                + for<'world> FnMut($($query::Item<'world>,)+)
+            Func: FnMut($($query,)+) -> Out
+                + for<'world> FnMut($($query::Item<'world>,)+) -> Out
                 + Send
                 + 'static,
         {
             #[allow(non_snake_case)]
-            fn into_runner(mut self) -> impl FnMut(&mut DynWorld) + Send + 'static {
+            fn into_runner(mut self) -> impl FnMut(&mut DynWorld) -> SystemResult + Send + 'static {
+                let mut ticks = SystemTicks::default();
                 move |world: &mut DynWorld| {
-                    let cell = RefCell::new(world);
-                    $(let $qbind = $query::build_lazy(&cell);)+
-                    self($($qbind,)+);
+                    run_with_ticks(world, &mut ticks, |world| {
+                        let cell = RefCell::new(world);
+                        $(let $qbind = $query::build_lazy(&cell);)+
+                        self($($qbind,)+)
+                    })
                 }
             }
         }
@@ -997,33 +1528,36 @@ macro_rules! impl_multi_query_bare {
 
 macro_rules! impl_extract_multi_query {
     (($($param:ident $state:ident $owned:ident),+), ($($query:ident $qbind:ident),+)) => {
-        impl<Func, $($param,)+ $($query,)+>
-            IntoSystem<DynWorld, MultiQuerySystemMarker<($($param,)+), ($($query,)+)>> for Func
+        impl<Func, Out, $($param,)+ $($query,)+>
+            IntoSystem<DynWorld, MultiQuerySystemMarker<($($param,)+), ($($query,)+), Out>>
+            for Func
         where
+            Out: SystemOutput,
             $($param: ExtractParam<DynWorld>,)+
             $($query: MultiQueryParam,)+
-            Func: FnMut($($param,)+ $($query,)+)
-                + for<'item> FnMut($($param::Item<'item>,)+ $($query::Item<'item>,)+)
+            Func: FnMut($($param,)+ $($query,)+) -> Out
+                + for<'item> FnMut($($param::Item<'item>,)+ $($query::Item<'item>,)+) -> Out
                 + Send
                 + 'static,
         {
-            fn into_runner(mut self) -> impl FnMut(&mut DynWorld) + Send + 'static {
+            fn into_runner(mut self) -> impl FnMut(&mut DynWorld) -> SystemResult + Send + 'static {
                 $(let mut $state = <$param as ExtractParam<DynWorld>>::init();)+
+                let mut ticks = SystemTicks::default();
                 move |world: &mut DynWorld| {
                     $(let mut $owned =
                         <$param as ExtractParam<DynWorld>>::extract(&mut $state, world);)+
+                    world.enter_system_ticks(&mut ticks);
                     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
                         let cell = RefCell::new(&mut *world);
                         $(let $qbind = $query::build_lazy(&cell);)+
                         self(
                             $(<$param as ExtractParam<DynWorld>>::build(&mut $owned),)+
                             $($qbind,)+
-                        );
+                        )
                     }));
+                    world.exit_system_ticks(&mut ticks);
                     $(<$param as ExtractParam<DynWorld>>::apply(&mut $state, $owned, world);)+
-                    if let Err(panic) = result {
-                        std::panic::resume_unwind(panic);
-                    }
+                    finish(result)
                 }
             }
         }
@@ -1097,7 +1631,7 @@ mod tests {
 
     fn run<Marker>(world: &mut DynWorld, system: impl IntoSystem<DynWorld, Marker>) {
         let mut runner = system.into_runner();
-        runner(world);
+        runner(world).unwrap();
     }
 
     #[test]
@@ -1202,6 +1736,27 @@ mod tests {
         assert_eq!(moved, 1);
     }
 
+    #[test]
+    fn query_param_skips_disabled_unless_asked() {
+        let mut world = DynWorld::new();
+        let paused = world.spawn((Position { x: 0.0, y: 0.0 },));
+        world.spawn((Position { x: 0.0, y: 0.0 },));
+        world.disable(paused);
+
+        run(&mut world, |query: Query<&mut Position>| {
+            query.for_each(|_entity, position| position.x += 1.0);
+        });
+        assert_eq!(world.get::<Position>(paused).unwrap().x, 0.0);
+
+        run(
+            &mut world,
+            |query: Query<&mut Position, IncludeDisabled>| {
+                query.for_each(|_entity, position| position.x += 1.0);
+            },
+        );
+        assert_eq!(world.get::<Position>(paused).unwrap().x, 1.0);
+    }
+
     #[test]
     fn changed_filter_visits_only_mutated() {
         let mut world = DynWorld::new();
@@ -1487,6 +2042,67 @@ mod tests {
         assert_eq!(marked, vec![9.0]);
     }
 
+    #[test]
+    fn changed_filter_compares_against_the_systems_last_run() {
+        let mut world = DynWorld::new();
+        world.set_change_detection(true);
+        world.insert_resources((Enabled(true), Seen(Vec::new())));
+        let entity = world.spawn((Position { x: 0.0, y: 0.0 },));
+        world.step();
+
+        let mut schedule = Schedule::new();
+        schedule.add_system_if(
+            "observe",
+            |world: &DynWorld| world.resource::<Enabled>().unwrap().0,
+            |mut seen: ResMut<Seen>, query: Query<&Position, Changed<Position>>| {
+                query.for_each(|_entity, position| seen.0.push(position.x as u32));
+            },
+        );
+        schedule.add_system("write", |query: Query<&mut Position>| {
+            query.for_each(|_entity, position| position.y += 1.0);
+        });
+
+        schedule.run(&mut world);
+        world.step();
+        world.resource_mut::<Enabled>().unwrap().0 = false;
+        world.get_mut::<Position>(entity).unwrap().x = 1.0;
+        schedule.run(&mut world);
+        world.step();
+        world.resource_mut::<Enabled>().unwrap().0 = true;
+        schedule.run(&mut world);
+        world.step();
+        schedule.run(&mut world);
+
+        assert_eq!(world.resource::<Seen>().unwrap().0, vec![1, 1]);
+    }
+
+    #[test]
+    fn host_system_queries_see_their_own_baseline() {
+        let mut world = DynWorld::new();
+        world.set_change_detection(true);
+        world.insert_resource(Tally(0));
+        let entity = world.spawn((Position { x: 0.0, y: 0.0 },));
+        world.step();
+
+        let mut runner = (|mut tally: ResMut<Tally>, world: &mut DynWorld| {
+            tally.0 += world
+                .query_ref::<&Position>()
+                .changed::<Position>()
+                .iter()
+                .count() as u32;
+        })
+        .into_runner();
+        runner(&mut world).unwrap();
+        assert_eq!(world.resource::<Tally>().unwrap().0, 0);
+
+        world.get_mut::<Position>(entity).unwrap().x = 1.0;
+        world.step();
+        world.step();
+        runner(&mut world).unwrap();
+        runner(&mut world).unwrap();
+        assert_eq!(world.resource::<Tally>().unwrap().0, 1);
+    }
+
     #[test]
     fn tuple_filter_combines_constraints() {
         let mut world = DynWorld::new();
@@ -1541,6 +2157,298 @@ mod tests {
         run(&mut world, |_delta: Res<DeltaTime>| {});
     }
 
+    #[test]
+    fn optional_resources_resolve_to_none_when_absent() {
+        let mut world = DynWorld::new();
+        world.insert_resource(Seen(Vec::new()));
+        let mut schedule = Schedule::new();
+        schedule.add_system(
+            "observe",
+            |delta: Option<Res<DeltaTime>>,
+             score: Option<ResMut<Score>>,
+             mut seen: ResMut<Seen>| {
+                seen.0.push(delta.map_or(0, |delta| delta.0 as u32));
+                if let Some(mut score) = score {
+                    score.0 += 1;
+                }
+            },
+        );
+
+        schedule.run(&mut world);
+        world.insert_resources((DeltaTime(4.0), Score(0)));
+        schedule.run(&mut world);
+
+        assert_eq!(world.resource::<Seen>().unwrap().0, vec![0, 4]);
+        assert_eq!(world.resource::<Score>().unwrap().0, 1);
+        assert_eq!(world.resource::<DeltaTime>().unwrap().0, 4.0);
+    }
+
+    #[test]
+    fn locals_persist_per_system_on_any_host() {
+        fn count(mut calls: Local<u32>, mut score: ResMut<Score>) {
+            *calls += 1;
+            score.0 = score.0 * 10 + *calls;
+        }
+
+        let mut engine = Engine {
+            resources: ResourceMap::default(),
+            frames: 0,
+        };
+        engine.resources.insert(Score(0));
+        let mut schedule = Schedule::<Engine>::new();
+        schedule.add_systems((count, count));
+        schedule.run(&mut engine);
+        schedule.run(&mut engine);
+
+        assert_eq!(engine.resources.get::<Score>().unwrap().0, 1122);
+    }
+
+    #[test]
+    fn fallible_param_systems_report_through_the_schedule() {
+        fn spend(mut score: ResMut<Score>, query: Query<&Position>) -> Result<(), String> {
+            let mut count = 0;
+            query.for_each(|_entity, _position| count += 1);
+            score.0 = score.0.checked_sub(count).ok_or("score exhausted")?;
+            Ok(())
+        }
+
+        let mut world = DynWorld::new();
+        world.insert_resource(Score(1));
+        world.spawn((Position { x: 0.0, y: 0.0 },));
+        let mut schedule = Schedule::new();
+        schedule.set_error_policy(crate::ErrorPolicy::Disable);
+        schedule.add_system("spend", spend);
+
+        schedule.run(&mut world);
+        assert!(schedule.errors().is_empty());
+        schedule.run(&mut world);
+        assert_eq!(schedule.errors()[0].system, "spend");
+        assert_eq!(schedule.errors()[0].error.to_string(), "score exhausted");
+        assert!(schedule.is_disabled("spend"));
+        assert_eq!(world.resource::<Score>().unwrap().0, 0);
+    }
+
+    #[test]
+    fn send_events_policy_puts_failures_on_the_event_bus() {
+        fn spend(mut score: ResMut<Score>) -> Result<(), String> {
+            score.0 = score.0.checked_sub(1).ok_or("score exhausted")?;
+            Ok(())
+        }
+
+        let mut world = DynWorld::new();
+        world.insert_resource(Score(0));
+        let mut schedule = Schedule::new();
+        schedule.set_error_policy(ErrorPolicy::send_events());
+        schedule.add_system("spend", spend);
+        schedule.run(&mut world);
+
+        let sent = world.read_events::<SystemError>();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].system, "spend");
+        assert_eq!(sent[0].error.to_string(), "score exhausted");
+        assert_eq!(schedule.errors().len(), 1);
+    }
+
+    #[test]
+    fn one_shot_systems_keep_their_state_between_runs() {
+        use crate::dynamic::RunSystemError;
+
+        fn open_shop(
+            mut opened: Local<u32>,
+            reader: EventReader<Collision>,
+            mut seen: ResMut<Seen>,
+        ) -> Result<(), String> {
+            *opened += 1;
+            seen.0
+                .extend(reader.iter().map(|collision| collision.entity));
+            seen.0.push(100 + *opened);
+            if *opened > 2 {
+                Err("shop already open".to_string())
+            } else {
+                Ok(())
+            }
+        }
+
+        let mut world = DynWorld::new();
+        world.insert_resource(Seen(Vec::new()));
+        let shop = world.register_system(open_shop);
+        world.send(Collision { entity: 1 });
+
+        world.run_system(shop).unwrap();
+        world.run_system(shop).unwrap();
+        let Err(RunSystemError::Failed(error)) = world.run_system(shop) else {
+            panic!("the third run fails");
+        };
+        assert_eq!(error.error.to_string(), "shop already open");
+        assert_eq!(world.resource::<Seen>().unwrap().0, vec![1, 101, 102, 103]);
+
+        assert!(world.unregister_system(shop));
+        assert!(matches!(world.run_system(shop), Err(RunSystemError::NotFound(id)) if id == shop));
+    }
+
+    #[test]
+    fn queued_one_shot_systems_run_with_the_command_buffer() {
+        use crate::dynamic::{RunSystemError, SystemId};
+
+        struct Target(Option<SystemId>);
+
+        fn spawn_level(mut score: ResMut<Score>, query: Query<&Position>) {
+            let mut count = 0;
+            query.for_each(|_entity, _position| count += 1);
+            score.0 += count;
+        }
+
+        fn run_once(target: Res<Target>, world: &mut DynWorld) {
+            let id = target.0.unwrap();
+            assert!(matches!(
+                world.run_system(id),
+                Err(RunSystemError::Recursive(running)) if running == id
+            ));
+            world.spawn((Position { x: 0.0, y: 0.0 },));
+            assert!(world.unregister_system(id));
+        }
+
+        let mut world = DynWorld::new();
+        world.insert_resources((Score(0), Target(None)));
+        let level = world.register_system(spawn_level);
+        let once = world.register_system(run_once);
+        world.resource_mut::<Target>().unwrap().0 = Some(once);
+
+        world.queue_run_system(once);
+        world.queue_run_system(level);
+        assert_eq!(world.resource::<Score>().unwrap().0, 0);
+        assert!(world.apply_commands().is_empty());
+        assert_eq!(world.resource::<Score>().unwrap().0, 1);
+        assert!(matches!(world.run_system(once), Err(RunSystemError::NotFound(id)) if id == once));
+
+        fn refuse(_score: Res<Score>) -> Result<(), String> {
+            Err("level locked".to_string())
+        }
+        let locked = world.register_system(refuse);
+        world.queue_run_system(once);
+        world.queue_run_system(locked);
+        let errors = world.apply_commands();
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(errors[0], RunSystemError::NotFound(id) if id == once));
+        let RunSystemError::Failed(failure) = &errors[1] else {
+            panic!("the locked level's own error is returned");
+        };
+        assert_eq!(failure.error.to_string(), "level locked");
+    }
+
+    crate::system_param! {
+        struct Counters<'a> where W: ResourceHost {
+            score: ResMut<'a, Score>,
+            tally: Option<ResMut<'a, Tally>>,
+            calls: Local<'a, u32>,
+        }
+    }
+
+    crate::system_param! {
+        struct Frame<'a> {
+            delta: Res<'a, DeltaTime>,
+            counters: Counters<'a>,
+            collisions: EventWriter<'a, Collision>,
+        }
+    }
+
+    crate::system_param! {
+        struct Motion<'w>: queries {
+            positions: Query<'w, &'static mut Position>,
+            velocities: Query<'w, &'static Velocity>,
+        }
+    }
+
+    #[test]
+    fn bundles_resolve_as_one_argument_and_nest() {
+        fn count(mut counters: Counters) {
+            *counters.calls += 1;
+            counters.score.0 += *counters.calls;
+            if let Some(tally) = counters.tally.as_mut() {
+                tally.0 += 1;
+            }
+        }
+
+        let mut engine = Engine {
+            resources: ResourceMap::default(),
+            frames: 0,
+        };
+        engine.resources.insert(Score(0));
+        let mut schedule = Schedule::<Engine>::new();
+        schedule.add_system("count", count);
+        schedule.run(&mut engine);
+        schedule.run(&mut engine);
+        assert_eq!(engine.resources.get::<Score>().unwrap().0, 3);
+
+        fn advance(mut frame: Frame, motion: Motion) {
+            let mut speed = 0.0;
+            motion
+                .velocities
+                .for_each(|_entity, velocity| speed += velocity.x);
+            let step = speed * frame.delta.0;
+            motion
+                .positions
+                .for_each(|_entity, position| position.x += step);
+            frame.counters.score.0 += 1;
+            frame.collisions.send(Collision { entity: 7 });
+        }
+
+        let mut world = DynWorld::new();
+        world.insert_resources((DeltaTime(0.5), Score(0), Tally(0)));
+        let entity = world.spawn((Position { x: 1.0, y: 0.0 }, Velocity { x: 4.0, y: 0.0 }));
+        run(&mut world, advance);
+        run(
+            &mut world,
+            |mut frame: Frame, motion: Motion, health: Query<&Health>| {
+                frame.counters.score.0 += 10;
+                health.for_each(|_entity, _health| unreachable!());
+                motion
+                    .positions
+                    .for_each(|_entity, position| position.y += 1.0);
+            },
+        );
+
+        assert_eq!(
+            world.get::<Position>(entity).unwrap(),
+            &Position { x: 3.0, y: 1.0 }
+        );
+        assert_eq!(world.resource::<Score>().unwrap().0, 11);
+        assert_eq!(world.resource::<Tally>().unwrap().0, 0);
+        assert_eq!(
+            world.events.read::<Collision>().to_vec(),
+            vec![Collision { entity: 7 }]
+        );
+    }
+
+    #[test]
+    fn bundles_report_combined_access() {
+        let frame = SystemAccess::of::<Frame>();
+        assert_eq!(frame.resources_read.len(), 1);
+        assert_eq!(frame.resources_written.len(), 2);
+        assert_eq!(frame.events_written.len(), 1);
+        assert!(frame.resource_overlaps().is_empty());
+
+        let motion = SystemAccess::of::<Motion>();
+        assert_eq!(motion.components_written[0].0, TypeId::of::<Position>());
+        assert_eq!(motion.components_read[0].0, TypeId::of::<Velocity>());
+
+        let reader = SystemAccess::of::<(Res<Score>, Query<&Position>)>();
+        let mut conflicts = frame.conflicts(&reader);
+        conflicts.extend(motion.conflicts(&reader));
+        assert_eq!(
+            conflicts,
+            vec![
+                std::any::type_name::<Score>(),
+                std::any::type_name::<Position>()
+            ]
+        );
+        assert!(!SystemAccess::of::<Res<DeltaTime>>().conflicts_with(&frame));
+        assert_eq!(
+            SystemAccess::of::<(Frame, Res<Score>)>().resource_overlaps(),
+            vec![std::any::type_name::<Score>()]
+        );
+    }
+
     fn emit_pair(mut writer: EventWriter<Collision>) {
         writer.send(Collision { entity: 1 });
         writer.send(Collision { entity: 2 });
@@ -1721,6 +2629,65 @@ mod tests {
         assert_eq!(ecs.resource::<Score>().unwrap().0, 2);
     }
 
+    #[test]
+    fn non_send_params_resolve_on_the_owner_thread() {
+        use std::rc::Rc;
+
+        struct Window {
+            frames: Vec<u32>,
+            _handle: Rc<()>,
+        }
+
+        fn draw(mut window: NonSendMut<Window>, score: Res<Score>, query: Query<&Position>) {
+            let mut count = 0;
+            query.for_each(|_entity, _position| count += 1);
+            window.frames.push(score.0 + count);
+        }
+
+        let mut world = DynWorld::new();
+        world.insert_resource(Score(10));
+        world.insert_non_send(Window {
+            frames: Vec::new(),
+            _handle: Rc::new(()),
+        });
+        world.spawn((Position::default(),));
+
+        let mut schedule = Schedule::new();
+        schedule.add_system("draw", draw);
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        let frames = world.non_send_scope(|_world, window: &mut Window| window.frames.clone());
+        assert_eq!(frames, vec![11, 11]);
+
+        let seen: Rc<RefCell<Vec<u32>>> = Rc::new(RefCell::new(Vec::new()));
+        let mut ecs = DynEcs::new();
+        ecs.insert_non_send(Rc::clone(&seen));
+        let mut group_schedule = Schedule::<DynEcs>::new();
+        group_schedule.add_system("mark", |seen: NonSend<Rc<RefCell<Vec<u32>>>>| {
+            seen.borrow_mut().push(1)
+        });
+        group_schedule.run(&mut ecs);
+        assert_eq!(*seen.borrow(), vec![1]);
+
+        let access = SystemAccess::of::<(NonSendMut<Window>, Res<Score>)>();
+        assert!(access.is_pinned());
+        assert!(!SystemAccess::of::<Res<Score>>().is_pinned());
+        assert!(access.conflicts_with(&SystemAccess::of::<NonSend<Window>>()));
+    }
+
+    #[test]
+    fn non_send_systems_panic_off_the_owner_thread() {
+        let mut world = DynWorld::new();
+        world.insert_non_send(std::rc::Rc::new(0u32));
+        let mut schedule = Schedule::new();
+        schedule.add_system("read", |_value: NonSend<std::rc::Rc<u32>>| {});
+
+        let moved =
+            std::thread::scope(|scope| scope.spawn(|| schedule.run(&mut world)).join().is_err());
+        assert!(moved, "a worker thread cannot reach the owner's values");
+        schedule.run(&mut world);
+    }
+
     #[test]
     fn add_system_if_gates_a_param_system() {
         let mut world = DynWorld::new();
@@ -0,0 +1,804 @@
+//! Local-to-global transform propagation over [`ChildOf`] links.
+//!
+//! An entity carries a [`LocalTransform`], its placement relative to its
+//! parent, and [`TransformPropagation`] writes the matching
+//! [`GlobalTransform`], the product of every local transform from the root
+//! down. Both are generic over the affine type, [`Affine2`] for 2D scenes and
+//! [`Affine3`] (the default) for 3D ones, and a world can carry both kinds
+//! side by side with one propagation pass each.
+//!
+//! The pass is incremental. It keeps a [`HierarchyIndex`] and its own
+//! cursors, and recomputes only the subtrees under entities whose local
+//! transform or parent link changed since the last pass, so a static scene
+//! costs next to nothing. That needs the change ticks and the structural log,
+//! both opt-in: turn on [`DynWorld::set_change_detection`] and
+//! [`DynWorld::structural_logging`] before spawning. A world that keeps
+//! neither gets a full pass every time, with the same results. Each depth of
+//! the dirty forest is computed on the rayon pool, one level after another.
+//!
+//! ```rust
+//! use freecs::dynamic::{ChildOf, DynWorld};
+//! use freecs::transform::{Affine3, GlobalTransform, LocalTransform, TransformPropagation};
+//!
+//! let mut world = DynWorld::new();
+//! world.set_change_detection(true);
+//! world.structural_logging = true;
+//!
+//! let ship = world.spawn((LocalTransform(Affine3::from_translation([10.0, 0.0, 0.0])),));
+//! let turret = world.spawn((
+//!     LocalTransform(Affine3::from_translation([0.0, 2.0, 0.0])),
+//!     ChildOf(ship),
+//! ));
+//!
+//! let mut propagation = TransformPropagation::<Affine3>::new();
+//! propagation.propagate(&mut world);
+//!
+//! let global = world.get::<GlobalTransform>(turret).unwrap();
+//! assert_eq!(global.0.translation, [10.0, 2.0, 0.0]);
+//! ```
+//!
+//! In a [`DynEcs`] group the links and the transforms can live in different
+//! member worlds. [`TransformPropagation::propagate_in`] finds both by type
+//! route, or pass the two worlds to
+//! [`propagate_split`](TransformPropagation::propagate_split) directly.
+
+use std::collections::{HashMap, HashSet};
+use std::ops::Mul;
+
+use crate::dynamic::{
+    ChildOf, Component, DynEcs, DynWorld, HierarchyError, HierarchyIndex, structural_log_reaches,
+};
+use crate::{Entity, StructuralChangeKind};
+
+/// An affine transform that [`TransformPropagation`] can compose.
+/// `Default` must be the identity, and `parent * child` maps child space
+/// into parent space.
+pub trait Affine: Copy + Default + Mul<Output = Self> + Send + Sync + 'static {
+    /// The transform undoing this one. The linear part must be invertible.
+    fn inverse(self) -> Self;
+}
+
+/// A 2D affine transform: a column-major 2x2 linear part and a translation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Affine2 {
+    pub matrix: [[f32; 2]; 2],
+    pub translation: [f32; 2],
+}
+
+impl Default for Affine2 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Affine2 {
+    pub const IDENTITY: Self = Self {
+        matrix: [[1.0, 0.0], [0.0, 1.0]],
+        translation: [0.0, 0.0],
+    };
+
+    pub fn from_translation(translation: [f32; 2]) -> Self {
+        Self {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn from_scale(scale: [f32; 2]) -> Self {
+        Self {
+            matrix: [[scale[0], 0.0], [0.0, scale[1]]],
+            translation: [0.0, 0.0],
+        }
+    }
+
+    /// Counter-clockwise rotation by `angle` radians.
+    pub fn from_angle(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            matrix: [[cos, sin], [-sin, cos]],
+            translation: [0.0, 0.0],
+        }
+    }
+
+    pub fn transform_point(&self, point: [f32; 2]) -> [f32; 2] {
+        let [x, y] = self.transform_vector(point);
+        [x + self.translation[0], y + self.translation[1]]
+    }
+
+    pub fn transform_vector(&self, vector: [f32; 2]) -> [f32; 2] {
+        let [column_x, column_y] = self.matrix;
+        [
+            column_x[0] * vector[0] + column_y[0] * vector[1],
+            column_x[1] * vector[0] + column_y[1] * vector[1],
+        ]
+    }
+}
+
+impl Mul for Affine2 {
+    type Output = Self;
+
+    fn mul(self, child: Self) -> Self {
+        Self {
+            matrix: [
+                self.transform_vector(child.matrix[0]),
+                self.transform_vector(child.matrix[1]),
+            ],
+            translation: self.transform_point(child.translation),
+        }
+    }
+}
+
+impl Affine for Affine2 {
+    fn inverse(self) -> Self {
+        let [[a, b], [c, d]] = self.matrix;
+        let inverse_determinant = 1.0 / (a * d - b * c);
+        let linear = Self {
+            matrix: [
+                [d * inverse_determinant, -b * inverse_determinant],
+                [-c * inverse_determinant, a * inverse_determinant],
+            ],
+            translation: [0.0, 0.0],
+        };
+        let [x, y] = linear.transform_vector(self.translation);
+        Self {
+            translation: [-x, -y],
+            ..linear
+        }
+    }
+}
+
+/// A 3D affine transform: a column-major 3x3 linear part and a translation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Affine3 {
+    pub matrix: [[f32; 3]; 3],
+    pub translation: [f32; 3],
+}
+
+impl Default for Affine3 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Affine3 {
+    pub const IDENTITY: Self = Self {
+        matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        translation: [0.0, 0.0, 0.0],
+    };
+
+    pub fn from_translation(translation: [f32; 3]) -> Self {
+        Self {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn from_scale(scale: [f32; 3]) -> Self {
+        Self {
+            matrix: [
+                [scale[0], 0.0, 0.0],
+                [0.0, scale[1], 0.0],
+                [0.0, 0.0, scale[2]],
+            ],
+            translation: [0.0, 0.0, 0.0],
+        }
+    }
+
+    /// Right-handed rotation by `angle` radians about the z axis.
+    pub fn from_rotation_z(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            matrix: [[cos, sin, 0.0], [-sin, cos, 0.0], [0.0, 0.0, 1.0]],
+            translation: [0.0, 0.0, 0.0],
+        }
+    }
+
+    /// Right-handed rotation by `angle` radians about the y axis.
+    pub fn from_rotation_y(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            matrix: [[cos, 0.0, -sin], [0.0, 1.0, 0.0], [sin, 0.0, cos]],
+            translation: [0.0, 0.0, 0.0],
+        }
+    }
+
+    /// Right-handed rotation by `angle` radians about the x axis.
+    pub fn from_rotation_x(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            matrix: [[1.0, 0.0, 0.0], [0.0, cos, sin], [0.0, -sin, cos]],
+            translation: [0.0, 0.0, 0.0],
+        }
+    }
+
+    pub fn transform_point(&self, point: [f32; 3]) -> [f32; 3] {
+        let [x, y, z] = self.transform_vector(point);
+        [
+            x + self.translation[0],
+            y + self.translation[1],
+            z + self.translation[2],
+        ]
+    }
+
+    pub fn transform_vector(&self, vector: [f32; 3]) -> [f32; 3] {
+        let [column_x, column_y, column_z] = self.matrix;
+        [
+            column_x[0] * vector[0] + column_y[0] * vector[1] + column_z[0] * vector[2],
+            column_x[1] * vector[0] + column_y[1] * vector[1] + column_z[1] * vector[2],
+            column_x[2] * vector[0] + column_y[2] * vector[1] + column_z[2] * vector[2],
+        ]
+    }
+}
+
+impl Mul for Affine3 {
+    type Output = Self;
+
+    fn mul(self, child: Self) -> Self {
+        Self {
+            matrix: [
+                self.transform_vector(child.matrix[0]),
+                self.transform_vector(child.matrix[1]),
+                self.transform_vector(child.matrix[2]),
+            ],
+            translation: self.transform_point(child.translation),
+        }
+    }
+}
+
+impl Affine for Affine3 {
+    fn inverse(self) -> Self {
+        let [column_x, column_y, column_z] = self.matrix;
+        let row_x = cross(column_y, column_z);
+        let row_y = cross(column_z, column_x);
+        let row_z = cross(column_x, column_y);
+        let inverse_determinant = 1.0 / dot(column_x, row_x);
+        let mut matrix = [[0.0; 3]; 3];
+        for (column, entry) in matrix.iter_mut().enumerate() {
+            *entry = [
+                row_x[column] * inverse_determinant,
+                row_y[column] * inverse_determinant,
+                row_z[column] * inverse_determinant,
+            ];
+        }
+        let linear = Self {
+            matrix,
+            translation: [0.0, 0.0, 0.0],
+        };
+        let [x, y, z] = linear.transform_vector(self.translation);
+        Self {
+            translation: [-x, -y, -z],
+            ..linear
+        }
+    }
+}
+
+fn cross(left: [f32; 3], right: [f32; 3]) -> [f32; 3] {
+    [
+        left[1] * right[2] - left[2] * right[1],
+        left[2] * right[0] - left[0] * right[2],
+        left[0] * right[1] - left[1] * right[0],
+    ]
+}
+
+fn dot(left: [f32; 3], right: [f32; 3]) -> f32 {
+    left[0] * right[0] + left[1] * right[1] + left[2] * right[2]
+}
+
+/// An entity's placement relative to its [`ChildOf`] parent, or to the world
+/// when it has none. Written by the user.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocalTransform<A: Affine = Affine3>(pub A);
+
+impl<A: Affine> Component for LocalTransform<A> {}
+
+/// An entity's placement in world space, written by
+/// [`TransformPropagation`]. Reads reflect the last pass.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlobalTransform<A: Affine = Affine3>(pub A);
+
+impl<A: Affine> Component for GlobalTransform<A> {}
+
+/// What [`TransformPropagation::reparent`] preserves across the move.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReparentTransform {
+    /// The local transform stays, so the entity moves with its new parent.
+    KeepLocal,
+    /// The local transform is recomputed against the new parent so the
+    /// entity stays where it is in world space.
+    KeepGlobal,
+}
+
+/// The propagation pass for one affine type: owned by the consumer, run once
+/// per frame after the local transforms are written.
+///
+/// Each pass recomputes the subtree under every entity whose
+/// [`LocalTransform`] or [`ChildOf`] link changed, plus the children of
+/// entities that lost their local transform or were despawned, which become
+/// roots. A child whose parent has no local transform, or whose parent is
+/// gone, is placed relative to the world. Entities without a local transform
+/// get no global one. A link the index rejects for closing a cycle places
+/// its entity as a root until the cycle is broken.
+pub struct TransformPropagation<A: Affine = Affine3> {
+    pub hierarchy: HierarchyIndex,
+    pub structural_cursor: u64,
+    pub tick_cursor: u32,
+    levels: Vec<Vec<Entity>>,
+    computed: HashMap<Entity, A>,
+}
+
+impl<A: Affine> Default for TransformPropagation<A> {
+    fn default() -> Self {
+        Self {
+            hierarchy: HierarchyIndex::new(),
+            structural_cursor: 0,
+            tick_cursor: u32::MAX,
+            levels: Vec::new(),
+            computed: HashMap::new(),
+        }
+    }
+}
+
+impl<A: Affine> TransformPropagation<A> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Propagates within one world holding both the links and the
+    /// transforms. Returns the number of global transforms written.
+    pub fn propagate(&mut self, world: &mut DynWorld) -> usize {
+        let dirty = self.collect_dirty(None, world);
+        self.hierarchy.sync(world);
+        let written = self.run(dirty, world);
+        // A first global moves its row to a new table, which restamps the
+        // row's links as changed. The links themselves did not change, so
+        // the index skips past those stamps along with this pass's own.
+        self.hierarchy.structural_cursor = world.structural_sequence();
+        self.hierarchy.tick_cursor = world.current_tick();
+        self.finish(world);
+        written
+    }
+
+    /// Propagates with the [`ChildOf`] links in one world and the transforms
+    /// in another, the split a [`DynEcs`] group can route them into. Fences
+    /// both worlds' change ticks. Returns the number of global transforms
+    /// written.
+    pub fn propagate_split(&mut self, links: &mut DynWorld, transforms: &mut DynWorld) -> usize {
+        let dirty = self.collect_dirty(Some(links), transforms);
+        self.hierarchy.sync(links);
+        let written = self.run(dirty, transforms);
+        self.finish(transforms);
+        written
+    }
+
+    /// Propagates through a [`DynEcs`] group, resolving the member worlds
+    /// holding [`ChildOf`] and [`LocalTransform`] by type route. Does nothing
+    /// and returns 0 when no member world registers the local transform; a
+    /// group with no links propagates every entity as a root.
+    pub fn propagate_in(&mut self, ecs: &mut DynEcs) -> usize {
+        let Some(transforms) = ecs.route::<LocalTransform<A>>() else {
+            return 0;
+        };
+        let links = ecs.route::<ChildOf>().unwrap_or(transforms);
+        if links == transforms {
+            return self.propagate(&mut ecs.worlds[transforms]);
+        }
+        let (links, transforms) = if links < transforms {
+            let (head, tail) = ecs.worlds.split_at_mut(transforms);
+            (&mut head[links], &mut tail[0])
+        } else {
+            let (head, tail) = ecs.worlds.split_at_mut(links);
+            (&mut tail[0], &mut head[transforms])
+        };
+        self.propagate_split(links, transforms)
+    }
+
+    /// Moves `child` under `parent` as its last child through the pass's
+    /// [`HierarchyIndex`], refusing cycles. With
+    /// [`ReparentTransform::KeepGlobal`] the child's local transform is
+    /// rewritten so its global stays put, computed from the globals of the
+    /// last pass. Either way the next pass places the moved subtree.
+    pub fn reparent(
+        &mut self,
+        world: &mut DynWorld,
+        child: Entity,
+        parent: Entity,
+        keep: ReparentTransform,
+    ) -> Result<(), HierarchyError> {
+        let local = self.relocated_local(world, child, parent, keep);
+        self.hierarchy.reparent(world, child, parent)?;
+        if let Some(local) = local {
+            world.set(child, LocalTransform(local));
+        }
+        Ok(())
+    }
+
+    /// [`reparent`](Self::reparent) with the links and the transforms in
+    /// different worlds.
+    pub fn reparent_split(
+        &mut self,
+        links: &mut DynWorld,
+        transforms: &mut DynWorld,
+        child: Entity,
+        parent: Entity,
+        keep: ReparentTransform,
+    ) -> Result<(), HierarchyError> {
+        let local = self.relocated_local(transforms, child, parent, keep);
+        self.hierarchy.reparent(links, child, parent)?;
+        if let Some(local) = local {
+            transforms.set(child, LocalTransform(local));
+        }
+        Ok(())
+    }
+
+    /// The local transform that keeps `child`'s last global under `parent`,
+    /// or `None` when the local should stay as it is.
+    fn relocated_local(
+        &self,
+        transforms: &DynWorld,
+        child: Entity,
+        parent: Entity,
+        keep: ReparentTransform,
+    ) -> Option<A> {
+        if keep == ReparentTransform::KeepLocal {
+            return None;
+        }
+        let global = transforms.get::<GlobalTransform<A>>(child)?.0;
+        Some(
+            self.parent_global(parent, transforms)
+                .map_or(global, |parent| parent.inverse() * global),
+        )
+    }
+
+    /// Whether a world keeps what the incremental path diffs against, with
+    /// its log still reaching back to `cursor`.
+    fn can_track_incrementally(world: &DynWorld, cursor: u64) -> bool {
+        world.structural_logging
+            && world.change_detection()
+            && structural_log_reaches(world, cursor)
+    }
+
+    /// Gathers the entities to recompute before the index sync consumes the
+    /// link changes. Entries in the second list stand for their children,
+    /// resolved once the index is current.
+    fn collect_dirty(
+        &self,
+        links: Option<&DynWorld>,
+        transforms: &DynWorld,
+    ) -> Option<(Vec<Entity>, Vec<Entity>)> {
+        let links_world = links.unwrap_or(transforms);
+        if !Self::can_track_incrementally(links_world, self.hierarchy.structural_cursor)
+            || !Self::can_track_incrementally(transforms, self.structural_cursor)
+        {
+            return None;
+        }
+
+        // A rejected link may be accepted once its cycle is broken elsewhere,
+        // without its own link changing.
+        let mut dirty: Vec<Entity> = self.hierarchy.rejected.clone();
+        let mut orphaning: Vec<Entity> = Vec::new();
+
+        if let Some(key) = links_world.lookup_key::<ChildOf>() {
+            for change in links_world.structural_changes_since(self.hierarchy.structural_cursor) {
+                match change.kind {
+                    StructuralChangeKind::Despawned => orphaning.push(change.entity),
+                    StructuralChangeKind::ComponentsRemoved
+                    | StructuralChangeKind::ComponentsRemovedBatch
+                        if change.mask & key.mask != 0 =>
+                    {
+                        dirty.extend_from_slice(links_world.change_entities(change))
+                    }
+                    _ => {}
+                }
+            }
+            dirty.extend(
+                links_world.query_entities_changed_since(key.mask, self.hierarchy.tick_cursor),
+            );
+        }
+
+        if let Some(key) = transforms.lookup_key::<LocalTransform<A>>() {
+            for change in transforms.structural_changes_since(self.structural_cursor) {
+                match change.kind {
+                    StructuralChangeKind::Despawned => orphaning.push(change.entity),
+                    StructuralChangeKind::ComponentsRemoved
+                    | StructuralChangeKind::ComponentsRemovedBatch
+                        if change.mask & key.mask != 0 =>
+                    {
+                        orphaning.extend_from_slice(transforms.change_entities(change))
+                    }
+                    _ => {}
+                }
+            }
+            dirty.extend(transforms.query_entities_changed_since(key.mask, self.tick_cursor));
+        }
+
+        Some((dirty, orphaning))
+    }
+
+    /// Resolves the dirty set against the synced index and recomputes it
+    /// level by level. `None` means a full pass over every local transform.
+    fn run(
+        &mut self,
+        dirty: Option<(Vec<Entity>, Vec<Entity>)>,
+        transforms: &mut DynWorld,
+    ) -> usize {
+        let Some(key) = transforms.lookup_key::<LocalTransform<A>>() else {
+            return 0;
+        };
+        let dirty: HashSet<Entity> = match dirty {
+            Some((mut dirty, orphaning)) => {
+                for parent in orphaning {
+                    dirty.extend_from_slice(self.hierarchy.children(parent));
+                }
+                dirty.into_iter().collect()
+            }
+            None => transforms.query_entities(key.mask).collect(),
+        };
+        if dirty.is_empty() {
+            return 0;
+        }
+
+        // Roots of the dirty forest are the dirty entities with no dirty
+        // ancestor; everything below them is recomputed anyway.
+        let roots: Vec<Entity> = dirty
+            .iter()
+            .copied()
+            .filter(|&entity| !self.has_dirty_ancestor(entity, &dirty))
+            .collect();
+        self.build_levels(&roots);
+
+        self.computed.clear();
+        let mut written = 0;
+        let levels = std::mem::take(&mut self.levels);
+        for level in &levels {
+            let placed = self.place_level(level, transforms);
+            for (entity, global) in placed {
+                transforms.set(entity, GlobalTransform(global));
+                self.computed.insert(entity, global);
+                written += 1;
+            }
+        }
+        self.levels = levels;
+        self.levels.clear();
+        self.computed.clear();
+        written
+    }
+
+    /// The index holds no cycle, so the walk up ends at a root.
+    fn has_dirty_ancestor(&self, entity: Entity, dirty: &HashSet<Entity>) -> bool {
+        let mut current = entity;
+        while let Some(&parent) = self.hierarchy.parent_of.get(&current) {
+            if dirty.contains(&parent) {
+                return true;
+            }
+            current = parent;
+        }
+        false
+    }
+
+    /// Breadth-first levels from `roots` down into [`Self::levels`].
+    fn build_levels(&mut self, roots: &[Entity]) {
+        let mut frontier = roots.to_vec();
+        while !frontier.is_empty() {
+            let mut next = Vec::new();
+            for &parent in &frontier {
+                next.extend_from_slice(self.hierarchy.children(parent));
+            }
+            self.levels.push(std::mem::replace(&mut frontier, next));
+        }
+    }
+
+    /// Computes one level's globals from the level above. Entities without
+    /// a local transform are skipped, which makes their children roots.
+    #[cfg(not(target_family = "wasm"))]
+    fn place_level(&self, level: &[Entity], transforms: &DynWorld) -> Vec<(Entity, A)> {
+        use crate::rayon::prelude::*;
+
+        level
+            .par_iter()
+            .filter_map(|&entity| self.place(entity, transforms))
+            .collect()
+    }
+
+    #[cfg(target_family = "wasm")]
+    fn place_level(&self, level: &[Entity], transforms: &DynWorld) -> Vec<(Entity, A)> {
+        level
+            .iter()
+            .filter_map(|&entity| self.place(entity, transforms))
+            .collect()
+    }
+
+    fn place(&self, entity: Entity, transforms: &DynWorld) -> Option<(Entity, A)> {
+        let local = transforms.get::<LocalTransform<A>>(entity)?.0;
+        let parent_global = self
+            .hierarchy
+            .parent_of
+            .get(&entity)
+            .and_then(|parent| self.parent_global(*parent, transforms));
+        Some((entity, parent_global.map_or(local, |parent| parent * local)))
+    }
+
+    fn parent_global(&self, parent: Entity, transforms: &DynWorld) -> Option<A> {
+        if let Some(&global) = self.computed.get(&parent) {
+            return Some(global);
+        }
+        transforms.get::<LocalTransform<A>>(parent)?;
+        transforms
+            .get::<GlobalTransform<A>>(parent)
+            .map(|global| global.0)
+    }
+
+    /// Fences the transforms world after this pass's writes, which restamp
+    /// the local transforms of rows that gained a global, so the next pass
+    /// sees only what changed after this one.
+    fn finish(&mut self, transforms: &mut DynWorld) {
+        self.structural_cursor = transforms.structural_sequence();
+        self.tick_cursor = transforms.current_tick();
+        transforms.increment_tick();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translation(world: &DynWorld, entity: Entity) -> [f32; 3] {
+        world
+            .get::<GlobalTransform>(entity)
+            .expect("global transform written")
+            .0
+            .translation
+    }
+
+    fn tracked_world() -> DynWorld {
+        let mut world = DynWorld::new();
+        world.set_change_detection(true);
+        world.structural_logging = true;
+        world
+    }
+
+    #[test]
+    fn propagation_composes_down_the_hierarchy_and_only_revisits_changes() {
+        let mut world = tracked_world();
+        let mut propagation = TransformPropagation::<Affine3>::new();
+
+        let root = world.spawn((LocalTransform(Affine3::from_translation([1.0, 0.0, 0.0])),));
+        let child = world.spawn((
+            LocalTransform(Affine3::from_scale([2.0, 2.0, 2.0])),
+            ChildOf(root),
+        ));
+        let grandchild = world.spawn((
+            LocalTransform(Affine3::from_translation([0.0, 1.0, 0.0])),
+            ChildOf(child),
+        ));
+        let other = world.spawn((LocalTransform(Affine3::from_translation([5.0, 0.0, 0.0])),));
+
+        assert_eq!(propagation.propagate(&mut world), 4);
+        assert_eq!(translation(&world, grandchild), [1.0, 2.0, 0.0]);
+        assert_eq!(translation(&world, other), [5.0, 0.0, 0.0]);
+
+        assert_eq!(propagation.propagate(&mut world), 0);
+
+        world.set(child, LocalTransform(Affine3::IDENTITY));
+        assert_eq!(propagation.propagate(&mut world), 2);
+        assert_eq!(translation(&world, grandchild), [1.0, 1.0, 0.0]);
+
+        world.set(grandchild, ChildOf(other));
+        assert_eq!(propagation.propagate(&mut world), 1);
+        assert_eq!(translation(&world, grandchild), [5.0, 1.0, 0.0]);
+
+        world.despawn_entities(&[other]);
+        assert_eq!(propagation.propagate(&mut world), 1);
+        assert_eq!(translation(&world, grandchild), [0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn reparenting_keeps_either_the_local_or_the_global_placement() {
+        let mut world = tracked_world();
+        let mut propagation = TransformPropagation::<Affine3>::new();
+
+        let platform = world.spawn((LocalTransform(
+            Affine3::from_translation([0.0, 4.0, 0.0]) * Affine3::from_rotation_z(0.5),
+        ),));
+        let crate_entity =
+            world.spawn((LocalTransform(Affine3::from_translation([3.0, 0.0, 0.0])),));
+        let lamp = world.spawn((LocalTransform(Affine3::from_translation([1.0, 0.0, 0.0])),));
+        propagation.propagate(&mut world);
+
+        propagation
+            .reparent(
+                &mut world,
+                crate_entity,
+                platform,
+                ReparentTransform::KeepGlobal,
+            )
+            .unwrap();
+        propagation
+            .reparent(&mut world, lamp, platform, ReparentTransform::KeepLocal)
+            .unwrap();
+        assert!(
+            propagation
+                .reparent(&mut world, platform, lamp, ReparentTransform::KeepLocal)
+                .is_err()
+        );
+        propagation.propagate(&mut world);
+
+        let kept = translation(&world, crate_entity);
+        for (axis, expected) in [3.0, 0.0, 0.0].into_iter().enumerate() {
+            assert!((kept[axis] - expected).abs() < 1e-5);
+        }
+        let expected = (Affine3::from_translation([0.0, 4.0, 0.0]) * Affine3::from_rotation_z(0.5))
+            .transform_point([1.0, 0.0, 0.0]);
+        assert_eq!(translation(&world, lamp), expected);
+        assert_eq!(
+            propagation.hierarchy.children(platform),
+            &[crate_entity, lamp]
+        );
+    }
+
+    #[test]
+    fn untracked_worlds_and_cycles_still_propagate() {
+        let mut world = DynWorld::new();
+        let mut propagation = TransformPropagation::<Affine2>::new();
+
+        let root = world.spawn((LocalTransform(Affine2::from_angle(
+            std::f32::consts::FRAC_PI_2,
+        )),));
+        let child = world.spawn((
+            LocalTransform(Affine2::from_translation([1.0, 0.0])),
+            ChildOf(root),
+        ));
+        let cycle_a = world.spawn((LocalTransform(Affine2::from_translation([1.0, 0.0])),));
+        let cycle_b = world.spawn((
+            LocalTransform(Affine2::from_translation([1.0, 0.0])),
+            ChildOf(cycle_a),
+        ));
+        world.set(cycle_a, ChildOf(cycle_b));
+
+        assert_eq!(propagation.propagate(&mut world), 4);
+        let placed = world.get::<GlobalTransform<Affine2>>(child).unwrap().0;
+        assert!((placed.translation[0]).abs() < 1e-6);
+        assert!((placed.translation[1] - 1.0).abs() < 1e-6);
+        assert!(world.get::<GlobalTransform<Affine2>>(cycle_b).is_some());
+        assert_eq!(propagation.propagate(&mut world), 4);
+    }
+
+    #[test]
+    fn propagation_spans_member_worlds_in_a_group() {
+        let mut ecs = DynEcs::new();
+        let links = ecs.add_world(crate::dynamic::ComponentRegistry::new());
+        let transforms = ecs.add_world(crate::dynamic::ComponentRegistry::new());
+        for world in &mut ecs.worlds {
+            world.set_change_detection(true);
+            world.structural_logging = true;
+        }
+
+        let root = ecs.spawn();
+        let child = ecs.spawn();
+        ecs.worlds[links].set(child, ChildOf(root));
+        ecs.worlds[transforms].set(
+            root,
+            LocalTransform(Affine3::from_translation([0.0, 0.0, 3.0])),
+        );
+        ecs.worlds[transforms].set(
+            child,
+            LocalTransform(Affine3::from_translation([1.0, 0.0, 0.0])),
+        );
+
+        let mut propagation = TransformPropagation::<Affine3>::new();
+        assert_eq!(propagation.propagate_in(&mut ecs), 2);
+        assert_eq!(translation(&ecs.worlds[transforms], child), [1.0, 0.0, 3.0]);
+        assert_eq!(propagation.propagate_in(&mut ecs), 0);
+
+        ecs.worlds[transforms].set(root, LocalTransform(Affine3::IDENTITY));
+        assert_eq!(propagation.propagate_in(&mut ecs), 2);
+        assert_eq!(translation(&ecs.worlds[transforms], child), [1.0, 0.0, 0.0]);
+
+        ecs.worlds[links].remove::<ChildOf>(child);
+        assert_eq!(propagation.propagate_in(&mut ecs), 1);
+        assert_eq!(translation(&ecs.worlds[transforms], child), [1.0, 0.0, 0.0]);
+    }
+}